@@ -197,6 +197,7 @@ impl WindowUDFImpl for SimplifySmoothItUdf {
                     partition_by: window_function.params.partition_by,
                     order_by: window_function.params.order_by,
                     window_frame: window_function.params.window_frame,
+                    filter: window_function.params.filter,
                     null_treatment: window_function.params.null_treatment,
                 },
             }))
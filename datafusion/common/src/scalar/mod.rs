@@ -54,10 +54,11 @@ use arrow::datatypes::{
     i256, ArrowDictionaryKeyType, ArrowNativeType, ArrowTimestampType, DataType,
     Date32Type, Date64Type, Field, Float32Type, Int16Type, Int32Type, Int64Type,
     Int8Type, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit,
-    IntervalYearMonthType, TimeUnit, TimestampMicrosecondType, TimestampMillisecondType,
-    TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type,
-    UInt8Type, UnionFields, UnionMode, DECIMAL128_MAX_PRECISION,
+    IntervalYearMonthType, Schema, TimeUnit, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type,
+    UInt32Type, UInt64Type, UInt8Type, UnionFields, UnionMode, DECIMAL128_MAX_PRECISION,
 };
+use arrow::error::ArrowError;
 use arrow::util::display::{array_value_to_string, ArrayFormatter, FormatOptions};
 use half::f16;
 pub use struct_builder::ScalarStructBuilder;
@@ -3003,6 +3004,55 @@ impl ScalarValue {
         ScalarValue::from(value).cast_to(target_type)
     }
 
+    /// Try to parse the JSON encoded `json` into a ScalarValue of type
+    /// `target_type`
+    ///
+    /// Values are converted the same way as when reading JSON files: strings
+    /// are parsed into dates and timestamps, arrays into lists, and objects
+    /// into structs. `null` becomes a NULL of `target_type`.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::DataType;
+    /// # use datafusion_common::ScalarValue;
+    /// let value = ScalarValue::try_from_json_str(r#""2021-02-01""#, &DataType::Date32).unwrap();
+    /// assert_eq!(value, ScalarValue::Date32(Some(18659)));
+    ///
+    /// let list_type = DataType::new_list(DataType::Int64, true);
+    /// let value = ScalarValue::try_from_json_str("[1, null, 3]", &list_type).unwrap();
+    /// assert_eq!(value.to_string(), "[1, , 3]");
+    ///
+    /// assert!(ScalarValue::try_from_json_str("[1, 2", &list_type).is_err());
+    /// ```
+    pub fn try_from_json_str(json: &str, target_type: &DataType) -> Result<Self> {
+        // Decode the value as the only field of a single row
+        let decode = || -> Result<Option<ArrayRef>, ArrowError> {
+            let schema =
+                Schema::new(vec![Field::new("value", target_type.clone(), true)]);
+            let mut decoder = arrow::json::ReaderBuilder::new(Arc::new(schema))
+                .with_strict_mode(true)
+                .build_decoder()?;
+            let row = format!("{{\"value\": {json}}}");
+            if decoder.decode(row.as_bytes())? != row.len() {
+                return Ok(None);
+            }
+            Ok(decoder
+                .flush()?
+                .filter(|batch| batch.num_rows() == 1)
+                .map(|batch| Arc::clone(batch.column(0))))
+        };
+
+        match decode() {
+            Ok(Some(array)) => ScalarValue::try_from_array(&array, 0),
+            Ok(None) => _exec_err!(
+                "Can not convert JSON value {json} to {target_type}: expected a single value"
+            ),
+            Err(e) => {
+                _exec_err!("Can not convert JSON value {json} to {target_type}: {e}")
+            }
+        }
+    }
+
     /// Returns the Some(`&str`) representation of `ScalarValue` of logical string type
     ///
     /// Returns `None` if this `ScalarValue` is not a logical string type or the
@@ -4114,12 +4164,12 @@ mod tests {
         as_map_array, as_string_array, as_struct_array, as_uint32_array, as_uint64_array,
     };
 
+    use crate::assert_contains;
     use crate::test_util::batches_to_string;
     use arrow::array::{types::Float64Type, NullBufferBuilder};
     use arrow::buffer::{Buffer, OffsetBuffer};
     use arrow::compute::{is_null, kernels};
     use arrow::datatypes::Fields;
-    use arrow::error::ArrowError;
     use arrow::util::pretty::pretty_format_columns;
     use chrono::NaiveDate;
     use insta::assert_snapshot;
@@ -5710,6 +5760,88 @@ mod tests {
         assert_eq!(scalar, ScalarValue::Utf8(Some("foo".to_string())));
     }
 
+    #[test]
+    fn test_scalar_value_try_from_json_str() {
+        let from_json = |json: &str, data_type: &DataType| {
+            ScalarValue::try_from_json_str(json, data_type).unwrap()
+        };
+
+        assert_eq!(
+            from_json("42", &DataType::Int64),
+            ScalarValue::Int64(Some(42))
+        );
+        assert_eq!(
+            from_json("null", &DataType::Int64),
+            ScalarValue::Int64(None)
+        );
+        assert_eq!(
+            from_json(r#""foo""#, &DataType::Utf8),
+            ScalarValue::from("foo")
+        );
+        assert_eq!(
+            from_json(r#""2021-02-01""#, &DataType::Date32),
+            ScalarValue::Date32(Some(18659))
+        );
+
+        let list_type = DataType::new_list(DataType::Utf8, true);
+        assert_eq!(
+            from_json(r#"["one", null]"#, &list_type),
+            ScalarValue::List(ScalarValue::new_list_nullable(
+                &[ScalarValue::from("one"), ScalarValue::Utf8(None)],
+                &DataType::Utf8,
+            ))
+        );
+
+        let struct_type = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let value = from_json(r#"{"a": "2021-02-01"}"#, &struct_type);
+        assert_eq!(value.data_type(), struct_type);
+        assert_eq!(value.to_string(), "{a:2021-02-01,b:}");
+        assert!(from_json("null", &struct_type).is_null());
+    }
+
+    #[test]
+    fn test_scalar_value_try_from_json_str_error() {
+        let err = |json: &str, data_type: &DataType| {
+            ScalarValue::try_from_json_str(json, data_type)
+                .unwrap_err()
+                .strip_backtrace()
+        };
+
+        // malformed JSON
+        assert_contains!(
+            err("[1, 2", &DataType::new_list(DataType::Int64, true)),
+            "Can not convert JSON value [1, 2 to List("
+        );
+        assert_contains!(
+            err("1 2", &DataType::Int64),
+            "Can not convert JSON value 1 2 to Int64"
+        );
+        // more than one value
+        assert_contains!(
+            err(r#"1}{"value": 2"#, &DataType::Int64),
+            "expected a single value"
+        );
+        // incompatible with the target type
+        assert_contains!(
+            err(r#""foo""#, &DataType::Date32),
+            "Can not convert JSON value \"foo\" to Date32"
+        );
+        assert_contains!(
+            err(
+                r#"{"c": 1}"#,
+                &DataType::Struct(Fields::from(vec![Field::new(
+                    "a",
+                    DataType::Int32,
+                    true
+                )]))
+            ),
+            "Can not convert JSON value {\"c\": 1} to Struct("
+        );
+    }
+
     #[test]
     fn test_scalar_struct() {
         let field_a = Arc::new(Field::new("A", DataType::Int32, false));
@@ -33,6 +33,10 @@ use crate::Column;
 /// If `preserve_nulls` is true (the default), nulls from the input
 /// column are carried through to the output.
 ///
+/// Empty lists are not carried through to the output, unless
+/// `preserve_empty_lists` is true, in which case each one produces a
+/// single null, like a null list does when `preserve_nulls` is true.
+///
 /// # Examples
 ///
 /// ## `Unnest(c1)`, preserve_nulls: false
@@ -70,6 +74,8 @@ use crate::Column;
 pub struct UnnestOptions {
     /// Should nulls in the input be preserved? Defaults to true
     pub preserve_nulls: bool,
+    /// Should empty lists in the input be preserved as nulls? Defaults to false
+    preserve_empty_lists: bool,
     /// If specific columns need to be unnested multiple times (e.g at different depth),
     /// declare them here. Any unnested columns not being mentioned inside this option
     /// will be unnested with depth = 1
@@ -90,6 +96,7 @@ impl Default for UnnestOptions {
         Self {
             // default to true to maintain backwards compatible behavior
             preserve_nulls: true,
+            preserve_empty_lists: false,
             recursions: vec![],
         }
     }
@@ -108,6 +115,18 @@ impl UnnestOptions {
         self
     }
 
+    /// Set the behavior with empty lists in the input as described on
+    /// [`Self`]
+    pub fn with_preserve_empty_lists(mut self, preserve_empty_lists: bool) -> Self {
+        self.preserve_empty_lists = preserve_empty_lists;
+        self
+    }
+
+    /// Returns whether empty lists in the input are preserved as nulls
+    pub fn preserve_empty_lists(&self) -> bool {
+        self.preserve_empty_lists
+    }
+
     /// Set the recursions for the unnest operation
     pub fn with_recursions(mut self, recursion: RecursionUnnestOption) -> Self {
         self.recursions.push(recursion);
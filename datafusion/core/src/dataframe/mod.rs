@@ -67,6 +67,7 @@ use datafusion_functions::core::coalesce;
 use datafusion_functions_aggregate::expr_fn::{
     avg, count, max, median, min, stddev, sum,
 };
+use datafusion_functions_window::expr_fn::row_number;
 
 use async_trait::async_trait;
 use datafusion_catalog::Session;
@@ -424,6 +425,29 @@ impl DataFrame {
         self.select(expr)
     }
 
+    /// Expand a list/struct column into a set of rows and new columns.
+    ///
+    /// Each element of a list produces a row, in the order of the elements,
+    /// with the values of the other columns repeated. Rows with an empty
+    /// list are dropped, and rows with a null list produce a single null.
+    ///
+    /// See [`Self::unnest_columns`] for an example and
+    /// [`Self::unnest_column_with_options`] to change how empty and null
+    /// lists are handled.
+    pub fn unnest_column(self, column: &str) -> Result<DataFrame> {
+        self.unnest_columns(&[column])
+    }
+
+    /// Expand a list/struct column into a set of rows and new columns, with
+    /// behavior controlled by [`UnnestOptions`].
+    pub fn unnest_column_with_options(
+        self,
+        column: &str,
+        options: UnnestOptions,
+    ) -> Result<DataFrame> {
+        self.unnest_columns_with_options(&[column], options)
+    }
+
     /// Expand multiple list/struct columns into a set of rows and new columns.
     ///
     /// See also: [`UnnestOptions`] documentation for the behavior of `unnest`
@@ -597,6 +621,45 @@ impl DataFrame {
 
     /// Return a new DataFrame that adds the result of evaluating one or more
     /// window functions ([`Expr::WindowFunction`]) to the existing columns
+    ///
+    /// The output rows are not guaranteed to be in the same order as the
+    /// input, so add a [`DataFrame::sort`] if a specific order is needed, or
+    /// use [`DataFrame::window_preserving_order`] to keep the input order.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::functions_window::expr_fn::lag;
+    /// # use datafusion::logical_expr::ExprFunctionExt;
+    /// # use datafusion_common::assert_batches_eq;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example_long.csv", CsvReadOptions::new()).await?;
+    ///
+    /// // The following use is the equivalent of
+    /// // "SELECT a, lag(b) OVER (ORDER BY a) AS prev_b ORDER BY a"
+    /// let prev_b = lag(col("b"), None, None)
+    ///     .order_by(vec![col("a").sort(true, true)])
+    ///     .build()?;
+    /// let df = df
+    ///     .window(vec![prev_b.alias("prev_b")])?
+    ///     .sort(vec![col("a").sort(true, true)])?
+    ///     .select_columns(&["a", "prev_b"])?;
+    /// let expected = vec![
+    ///     "+---+--------+",
+    ///     "| a | prev_b |",
+    ///     "+---+--------+",
+    ///     "| 1 |        |",
+    ///     "| 4 | 2      |",
+    ///     "| 7 | 5      |",
+    ///     "+---+--------+"
+    /// ];
+    /// assert_batches_eq!(expected, &df.collect().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn window(self, window_exprs: Vec<Expr>) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::from(self.plan)
             .window(window_exprs)?
@@ -608,6 +671,58 @@ impl DataFrame {
         })
     }
 
+    /// Return a new DataFrame that adds the result of evaluating one or more
+    /// window functions ([`Expr::WindowFunction`]) to the existing columns,
+    /// like [`DataFrame::window`], with the rows in the same order as the
+    /// input
+    ///
+    /// The rows are numbered before the window functions are evaluated and
+    /// sorted by that number afterwards. If the input has more than one
+    /// partition, the order of the rows of each input partition is kept, but
+    /// rows of different partitions may be interleaved.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::functions_window::expr_fn::lag;
+    /// # use datafusion::logical_expr::ExprFunctionExt;
+    /// # use datafusion_common::assert_batches_eq;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example_long.csv", CsvReadOptions::new()).await?;
+    ///
+    /// // The following use is the equivalent of
+    /// // "SELECT a, lag(b) OVER (ORDER BY a DESC) AS next_b", with the rows of
+    /// // "tests/data/example_long.csv" in the order of the file
+    /// let next_b = lag(col("b"), None, None)
+    ///     .order_by(vec![col("a").sort(false, true)])
+    ///     .build()?;
+    /// let df = df
+    ///     .window_preserving_order(vec![next_b.alias("next_b")])?
+    ///     .select_columns(&["a", "next_b"])?;
+    /// let expected = vec![
+    ///     "+---+--------+",
+    ///     "| a | next_b |",
+    ///     "+---+--------+",
+    ///     "| 1 | 5      |",
+    ///     "| 4 | 8      |",
+    ///     "| 7 |        |",
+    ///     "+---+--------+"
+    /// ];
+    /// assert_batches_eq!(expected, &df.collect().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn window_preserving_order(self, window_exprs: Vec<Expr>) -> Result<DataFrame> {
+        const INPUT_ORDER: &str = "__datafusion_window_input_order";
+        self.window(vec![row_number().alias(INPUT_ORDER)])?
+            .window(window_exprs)?
+            .sort(vec![col(INPUT_ORDER).sort(true, false)])?
+            .drop_columns(&[INPUT_ORDER])
+    }
+
     /// Returns a new `DataFrame` with a limited number of rows.
     ///
     /// # Arguments
@@ -48,7 +48,7 @@ use crate::{
         DropView, Execute, LogicalPlan, LogicalPlanBuilder, Prepare, SetVariable,
         TableType, UNNAMED_TABLE,
     },
-    physical_expr::PhysicalExpr,
+    physical_expr::{PhysicalExpr, PhysicalSortExpr},
     physical_plan::ExecutionPlan,
     variable::{VarProvider, VarType},
 };
@@ -78,7 +78,8 @@ use datafusion_expr::{
     expr_rewriter::FunctionRewrite,
     logical_plan::{DdlStatement, Statement},
     planner::ExprPlanner,
-    Expr, UserDefinedLogicalNode, WindowUDF,
+    type_coercion::CoercionRules,
+    Expr, SortExpr, UserDefinedLogicalNode, WindowUDF,
 };
 use datafusion_optimizer::analyzer::type_coercion::TypeCoercion;
 use datafusion_optimizer::Analyzer;
@@ -652,6 +653,29 @@ impl SessionContext {
         self.state.read().create_logical_expr(sql, df_schema)
     }
 
+    /// Creates logical expressions from SQL query text without a schema.
+    ///
+    /// Unlike [`Self::parse_sql_expr`], identifiers are not resolved: each
+    /// becomes a column reference whether or not such a column exists, and no
+    /// type information is used. This is useful to find the columns an
+    /// expression refers to before a table is known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion_common::Result;
+    /// # fn main() -> Result<()> {
+    /// let expr = SessionContext::new().parse_unresolved_sql_expr("a + b * 2")?;
+    /// assert_eq!(expr, col("a") + col("b") * lit(2i64));
+    /// assert_eq!(expr.column_refs().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_unresolved_sql_expr(&self, sql: &str) -> Result<Expr> {
+        self.state.read().create_unresolved_logical_expr(sql)
+    }
+
     /// Execute the [`LogicalPlan`], return a [`DataFrame`]. This API
     /// is not featured limited (so all SQL such as `CREATE TABLE` and
     /// `COPY` will be run).
@@ -773,6 +797,186 @@ impl SessionContext {
         self.state.read().create_physical_expr(expr, df_schema)
     }
 
+    /// Create a [`PhysicalExpr`] from an [`Expr`] without applying type
+    /// coercion, for expressions that have already been coerced.
+    ///
+    /// No casts are added, so the caller is responsible for the expression
+    /// being type-correct. Returns an error if type coercion would have
+    /// changed the expression.
+    ///
+    /// See [`SessionState::create_physical_expr_no_coercion`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion::prelude::*;
+    /// # use datafusion_common::DFSchema;
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let df_schema = DFSchema::try_from(schema).unwrap();
+    /// let ctx = SessionContext::new();
+    ///
+    /// // `1i32` already has the type of `a`, so the expression is used as is
+    /// let expr = col("a").eq(lit(1i32));
+    /// let physical_expr = ctx
+    ///   .create_physical_expr_no_coercion(expr, &df_schema)
+    ///   .unwrap();
+    /// assert_eq!(physical_expr.to_string(), "a@0 = 1");
+    ///
+    /// // `1i64` would need to be cast to Int32
+    /// let expr = col("a").eq(lit(1i64));
+    /// assert!(ctx.create_physical_expr_no_coercion(expr, &df_schema).is_err());
+    /// ```
+    pub fn create_physical_expr_no_coercion(
+        &self,
+        expr: Expr,
+        df_schema: &DFSchema,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        self.state
+            .read()
+            .create_physical_expr_no_coercion(expr, df_schema)
+    }
+
+    /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
+    /// coercion with the provided [`CoercionRules`].
+    ///
+    /// See [`SessionState::create_physical_expr_with_coercion`] for more
+    /// details.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion::prelude::*;
+    /// # use datafusion_common::{plan_err, DFSchema, ExprSchema, Result};
+    /// # use datafusion_expr::type_coercion::CoercionRules;
+    /// # use datafusion_expr::ExprSchemable;
+    /// /// Never coerces integers to strings
+    /// #[derive(Debug)]
+    /// struct NoIntToString;
+    ///
+    /// impl CoercionRules for NoIntToString {
+    ///     fn coerce(
+    ///         &self,
+    ///         expr: Expr,
+    ///         from: &DataType,
+    ///         to: &DataType,
+    ///         schema: &dyn ExprSchema,
+    ///     ) -> Result<Expr> {
+    ///         if from.is_integer() && to == &DataType::Utf8 {
+    ///             return plan_err!("Can not coerce {expr} to {to}");
+    ///         }
+    ///         expr.cast_to(to, schema)
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+    /// let df_schema = DFSchema::try_from(schema).unwrap();
+    /// let ctx = SessionContext::new();
+    /// let expr = col("a").eq(lit(1i32));
+    /// assert!(ctx
+    ///     .create_physical_expr_with_coercion(expr, &df_schema, &NoIntToString)
+    ///     .is_err());
+    /// ```
+    pub fn create_physical_expr_with_coercion(
+        &self,
+        expr: Expr,
+        df_schema: &DFSchema,
+        rules: &dyn CoercionRules,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        self.state
+            .read()
+            .create_physical_expr_with_coercion(expr, df_schema, rules)
+    }
+
+    /// Create a [`PhysicalExpr`] from each [`Expr`] in `exprs`, for example
+    /// to build a predicate per file partition, setting up the state needed
+    /// to plan them only once.
+    ///
+    /// The physical expressions are in the order of `exprs`. See
+    /// [`SessionState::create_physical_exprs`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion::prelude::*;
+    /// # use datafusion_common::DFSchema;
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let df_schema = DFSchema::try_from(schema).unwrap();
+    /// let exprs = vec![col("a").gt(lit(1i64)), col("a").lt(lit(10i64))];
+    /// let physical_exprs = SessionContext::new()
+    ///   .create_physical_exprs(exprs, &df_schema)
+    ///   .unwrap();
+    /// assert_eq!(physical_exprs.len(), 2);
+    /// ```
+    pub fn create_physical_exprs(
+        &self,
+        exprs: Vec<Expr>,
+        df_schema: &DFSchema,
+    ) -> Result<Vec<Arc<dyn PhysicalExpr>>> {
+        self.state.read().create_physical_exprs(exprs, df_schema)
+    }
+
+    /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
+    /// coercion and function rewrites, using the provided [`ExecutionProps`]
+    /// instead of the session's.
+    ///
+    /// This is useful to control the values of scalar variables or the query
+    /// execution start time (e.g. for `now()`) across several expressions.
+    ///
+    /// See [`Self::create_physical_expr`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion::prelude::*;
+    /// # use datafusion_common::DFSchema;
+    /// # use datafusion_expr::execution_props::ExecutionProps;
+    /// let expr = col("a").eq(lit(1i64));
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let df_schema = DFSchema::try_from(schema).unwrap();
+    /// let props = ExecutionProps::new();
+    /// let physical_expr = SessionContext::new()
+    ///   .create_physical_expr_with_props(expr, &df_schema, &props).unwrap();
+    /// ```
+    pub fn create_physical_expr_with_props(
+        &self,
+        expr: Expr,
+        df_schema: &DFSchema,
+        execution_props: &ExecutionProps,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        self.state.read().create_physical_expr_with_props(
+            expr,
+            df_schema,
+            execution_props,
+        )
+    }
+
+    /// Create a [`PhysicalSortExpr`] from a [`SortExpr`], for example to
+    /// build a sort of a physical plan directly.
+    ///
+    /// See [`SessionState::create_physical_sort_expr`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion::prelude::*;
+    /// # use datafusion_common::DFSchema;
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let df_schema = DFSchema::try_from(schema).unwrap();
+    /// // sort by `a` descending, with NULLs first
+    /// let sort_expr = SessionContext::new()
+    ///   .create_physical_sort_expr(col("a").sort(false, true), &df_schema)
+    ///   .unwrap();
+    /// assert_eq!(sort_expr.to_string(), "a@0 DESC");
+    /// ```
+    pub fn create_physical_sort_expr(
+        &self,
+        sort: SortExpr,
+        df_schema: &DFSchema,
+    ) -> Result<PhysicalSortExpr> {
+        self.state.read().create_physical_sort_expr(sort, df_schema)
+    }
+
     // return an empty dataframe
     fn return_empty_dataframe(&self) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::empty(false).build()?;
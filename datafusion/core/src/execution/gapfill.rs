@@ -0,0 +1,246 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ResolveDateBinGapfill`] rewrites aggregates grouped by `date_bin_gapfill`
+
+use std::sync::Arc;
+
+use crate::functions::datetime::date_bin;
+use crate::functions_aggregate::min_max::{max, min};
+use crate::functions_nested::range::gen_series;
+
+use arrow::datatypes::{DataType, IntervalUnit, TimeUnit};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::tree_node::{
+    Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
+};
+use datafusion_common::{plan_err, Column, Result, UnnestOptions};
+use datafusion_expr::expr::{Alias, ScalarFunction};
+use datafusion_expr::{
+    cast, Aggregate, Expr, ExprSchemable, JoinType, LogicalPlan, LogicalPlanBuilder,
+    Projection,
+};
+use datafusion_optimizer::AnalyzerRule;
+
+/// Name of the `date_bin_gapfill` function
+const DATE_BIN_GAPFILL: &str = "date_bin_gapfill";
+
+/// Table alias of the aggregate with the data
+const DATA_ALIAS: &str = "__gapfill_data";
+
+/// Table alias of all the combinations of group keys and time buckets
+const GRID_ALIAS: &str = "__gapfill_grid";
+
+/// Rewrites aggregates that are grouped by `date_bin_gapfill` so that they
+/// also produce rows for the time buckets that have no data.
+///
+/// The aggregate is computed with `date_bin` instead. Every combination of
+/// the other group keys and the time buckets between the first and the last
+/// bucket with data is then left joined with it. The aggregates of the
+/// buckets with no data are therefore `NULL`.
+///
+/// `date_bin_gapfill` can only be used as a `GROUP BY` expression: it is an
+/// error to use it anywhere else, or more than once in an aggregate.
+#[derive(Default, Debug)]
+pub struct ResolveDateBinGapfill;
+
+impl ResolveDateBinGapfill {
+    /// Create a new [`ResolveDateBinGapfill`] rule
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AnalyzerRule for ResolveDateBinGapfill {
+    fn analyze(&self, plan: LogicalPlan, _: &ConfigOptions) -> Result<LogicalPlan> {
+        if !plan.exists(|plan| Ok(plan.expressions().iter().any(contains_gapfill)))? {
+            return Ok(plan);
+        }
+
+        // The rewritten aggregates have nullable aggregate columns, so the
+        // schemas of the plans above them are recomputed
+        let plan = plan
+            .transform_up(|plan| match plan {
+                LogicalPlan::Aggregate(aggregate) => rewrite_aggregate(aggregate),
+                plan => plan.recompute_schema().map(Transformed::yes),
+            })
+            .data()?;
+
+        plan.apply(|plan| {
+            if plan.expressions().iter().any(contains_gapfill) {
+                return plan_err!(
+                    "{DATE_BIN_GAPFILL} can only be used as a GROUP BY expression of an aggregate"
+                );
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(plan)
+    }
+
+    fn name(&self) -> &str {
+        "resolve_date_bin_gapfill"
+    }
+}
+
+fn is_gapfill(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::ScalarFunction(ScalarFunction { func, .. }) if func.name() == DATE_BIN_GAPFILL
+    )
+}
+
+fn unalias(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Alias(Alias { expr, .. }) => unalias(expr),
+        expr => expr,
+    }
+}
+
+fn contains_gapfill(expr: &Expr) -> bool {
+    expr.exists(|expr| Ok(is_gapfill(expr))).unwrap()
+}
+
+fn rewrite_aggregate(aggregate: Aggregate) -> Result<Transformed<LogicalPlan>> {
+    let gapfill_indices = aggregate
+        .group_expr
+        .iter()
+        .enumerate()
+        .filter(|(_, expr)| is_gapfill(unalias(expr)))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    let gapfill_idx = match gapfill_indices.as_slice() {
+        [] => return Ok(Transformed::no(LogicalPlan::Aggregate(aggregate))),
+        [idx] => *idx,
+        _ => {
+            return plan_err!(
+                "{DATE_BIN_GAPFILL} can only be used once in the GROUP BY of an aggregate"
+            )
+        }
+    };
+
+    let Aggregate {
+        input,
+        mut group_expr,
+        aggr_expr,
+        schema,
+        ..
+    } = aggregate;
+    let num_group_exprs = group_expr.len();
+
+    let (_, bucket_field) = schema.qualified_field(gapfill_idx);
+    let bucket_name = bucket_field.name().clone();
+    let bucket_type = bucket_field.data_type().clone();
+    let bucket = || Expr::Column(Column::new_unqualified(&bucket_name));
+
+    let Expr::ScalarFunction(ScalarFunction { args, .. }) =
+        unalias(&group_expr[gapfill_idx]).clone()
+    else {
+        unreachable!("checked by is_gapfill")
+    };
+    let stride = args[0].clone();
+    if !stride.column_refs().is_empty() {
+        return plan_err!("The interval of {DATE_BIN_GAPFILL} must be a constant");
+    }
+    group_expr[gapfill_idx] = date_bin().call(args).alias(&bucket_name);
+
+    // The aggregate computed over the buckets with data
+    let data = LogicalPlan::Aggregate(Aggregate::try_new(input, group_expr, aggr_expr)?);
+
+    // All the buckets between the first and the last bucket with data
+    let series_type = match &bucket_type {
+        DataType::Timestamp(_, tz) => {
+            DataType::Timestamp(TimeUnit::Nanosecond, tz.clone())
+        }
+        other => return plan_err!("Unexpected {DATE_BIN_GAPFILL} type {other}"),
+    };
+    let stride_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    let buckets = LogicalPlanBuilder::from(data.clone())
+        .aggregate(
+            Vec::<Expr>::new(),
+            vec![min(bucket()).alias("first"), max(bucket()).alias("last")],
+        )?
+        .project(vec![gen_series(
+            cast(
+                Expr::Column(Column::from_name("first")),
+                series_type.clone(),
+            ),
+            cast(Expr::Column(Column::from_name("last")), series_type),
+            stride.cast_to(&stride_type, data.schema())?,
+        )
+        .alias(&bucket_name)])?
+        .unnest_column_with_options(
+            Column::new_unqualified(&bucket_name),
+            UnnestOptions::new().with_preserve_nulls(false),
+        )?
+        .project(vec![cast(bucket(), bucket_type).alias(&bucket_name)])?;
+
+    // Every combination of the other group keys and the buckets
+    let group_columns = (0..num_group_exprs)
+        .map(|idx| Column::from(schema.qualified_field(idx)))
+        .collect::<Vec<_>>();
+    let other_keys = group_columns
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != gapfill_idx)
+        .map(|(_, column)| Expr::Column(column.clone()))
+        .collect::<Vec<_>>();
+    let grid = if other_keys.is_empty() {
+        buckets
+    } else {
+        LogicalPlanBuilder::from(data.clone())
+            .aggregate(other_keys, Vec::<Expr>::new())?
+            .cross_join(buckets.build()?)?
+    };
+
+    let (grid_keys, data_keys): (Vec<_>, Vec<_>) = group_columns
+        .iter()
+        .map(|column| {
+            (
+                Column::new(Some(GRID_ALIAS), &column.name),
+                Column::new(Some(DATA_ALIAS), &column.name),
+            )
+        })
+        .unzip();
+    let joined = grid
+        .alias(GRID_ALIAS)?
+        .join_detailed(
+            LogicalPlanBuilder::from(data).alias(DATA_ALIAS)?.build()?,
+            JoinType::Left,
+            (grid_keys, data_keys),
+            None,
+            true,
+        )?
+        .build()?;
+
+    // Restore the columns of the original aggregate
+    let exprs = schema
+        .iter()
+        .enumerate()
+        .map(|(idx, (qualifier, field))| {
+            let alias = if idx < num_group_exprs {
+                GRID_ALIAS
+            } else {
+                DATA_ALIAS
+            };
+            Expr::Column(Column::new(Some(alias), field.name()))
+                .alias_qualified(qualifier.cloned(), field.name())
+        })
+        .collect::<Vec<_>>();
+    Projection::try_new(exprs, Arc::new(joined))
+        .map(LogicalPlan::Projection)
+        .map(Transformed::yes)
+}
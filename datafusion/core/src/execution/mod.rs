@@ -18,6 +18,8 @@
 //! Shared state for query planning and execution.
 
 pub mod context;
+#[cfg(all(feature = "datetime_expressions", feature = "nested_expressions"))]
+pub mod gapfill;
 pub mod session_state;
 pub use session_state::{SessionState, SessionStateBuilder};
 
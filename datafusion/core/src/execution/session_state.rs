@@ -34,6 +34,7 @@ use datafusion_catalog::information_schema::{
     InformationSchemaProvider, INFORMATION_SCHEMA,
 };
 
+use arrow::compute::SortOptions;
 use arrow::datatypes::{DataType, SchemaRef};
 use datafusion_catalog::MemoryCatalogProviderList;
 use datafusion_catalog::{TableFunction, TableFunctionImpl};
@@ -43,8 +44,8 @@ use datafusion_common::display::{PlanType, StringifiedPlan, ToStringifiedPlan};
 use datafusion_common::file_options::file_type::FileType;
 use datafusion_common::tree_node::TreeNode;
 use datafusion_common::{
-    config_err, exec_err, not_impl_err, plan_datafusion_err, DFSchema, DataFusionError,
-    ResolvedTableReference, TableReference,
+    config_err, exec_err, not_impl_err, plan_datafusion_err, plan_err, DFSchema,
+    DataFusionError, ResolvedTableReference, TableReference,
 };
 use datafusion_execution::config::SessionConfig;
 use datafusion_execution::runtime_env::RuntimeEnv;
@@ -54,10 +55,11 @@ use datafusion_expr::expr_rewriter::FunctionRewrite;
 use datafusion_expr::planner::{ExprPlanner, TypePlanner};
 use datafusion_expr::registry::{FunctionRegistry, SerializerRegistry};
 use datafusion_expr::simplify::SimplifyInfo;
+use datafusion_expr::type_coercion::CoercionRules;
 use datafusion_expr::var_provider::{is_system_variables, VarType};
 use datafusion_expr::{
-    AggregateUDF, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF, TableSource,
-    WindowUDF,
+    AggregateUDF, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF, SortExpr,
+    TableSource, WindowUDF,
 };
 use datafusion_optimizer::simplify_expressions::ExprSimplifier;
 use datafusion_optimizer::{
@@ -65,6 +67,7 @@ use datafusion_optimizer::{
 };
 use datafusion_physical_expr::create_physical_expr;
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use datafusion_physical_expr_common::sort_expr::PhysicalSortExpr;
 use datafusion_physical_optimizer::optimizer::PhysicalOptimizer;
 use datafusion_physical_optimizer::PhysicalOptimizerRule;
 use datafusion_physical_plan::ExecutionPlan;
@@ -542,6 +545,27 @@ impl SessionState {
         query.sql_to_expr_with_alias(sql_expr, df_schema, &mut PlannerContext::new())
     }
 
+    /// Creates a datafusion style AST [`Expr`] from a SQL string without
+    /// resolving column references against a schema.
+    ///
+    /// See example on [SessionContext::parse_unresolved_sql_expr](crate::execution::context::SessionContext::parse_unresolved_sql_expr)
+    pub fn create_unresolved_logical_expr(
+        &self,
+        sql: &str,
+    ) -> datafusion_common::Result<Expr> {
+        let dialect = self.config.options().sql_parser.dialect.as_str();
+
+        let sql_expr = self.sql_to_expr_with_alias(sql, dialect)?;
+
+        let provider = SessionContextProvider {
+            state: self,
+            tables: HashMap::new(),
+        };
+
+        let query = SqlToRel::new_with_options(&provider, self.get_parser_options());
+        query.sql_to_unresolved_expr_with_alias(sql_expr, &mut PlannerContext::new())
+    }
+
     /// Returns the [`Analyzer`] for this session
     pub fn analyzer(&self) -> &Analyzer {
         &self.analyzer
@@ -677,20 +701,155 @@ impl SessionState {
         &self,
         expr: Expr,
         df_schema: &DFSchema,
+    ) -> datafusion_common::Result<Arc<dyn PhysicalExpr>> {
+        self.create_physical_expr_with_props(expr, df_schema, self.execution_props())
+    }
+
+    /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
+    /// coercion, and function rewrites, using the provided
+    /// [`ExecutionProps`] rather than the session's.
+    ///
+    /// See [`Self::create_physical_expr`] for more details.
+    pub fn create_physical_expr_with_props(
+        &self,
+        expr: Expr,
+        df_schema: &DFSchema,
+        execution_props: &ExecutionProps,
     ) -> datafusion_common::Result<Arc<dyn PhysicalExpr>> {
         let simplifier =
-            ExprSimplifier::new(SessionSimplifyProvider::new(self, df_schema));
+            ExprSimplifier::new(SessionSimplifyProvider::new(execution_props, df_schema));
         // apply type coercion here to ensure types match
-        let mut expr = simplifier.coerce(expr, df_schema)?;
+        let expr = simplifier.coerce(expr, df_schema)?;
+
+        let expr = self.apply_function_rewrites(expr, df_schema)?;
+        create_physical_expr(&expr, df_schema, execution_props)
+    }
+
+    /// Create a [`PhysicalExpr`] from each [`Expr`] in `exprs` after applying
+    /// type coercion, and function rewrites.
+    ///
+    /// This is the same as calling [`Self::create_physical_expr`] for each
+    /// expression, but the state needed to coerce the expressions is only
+    /// set up once. The physical expressions are in the order of `exprs`,
+    /// and if an expression can not be planned the error has its index.
+    pub fn create_physical_exprs(
+        &self,
+        exprs: Vec<Expr>,
+        df_schema: &DFSchema,
+    ) -> datafusion_common::Result<Vec<Arc<dyn PhysicalExpr>>> {
+        let execution_props = self.execution_props();
+        let simplifier =
+            ExprSimplifier::new(SessionSimplifyProvider::new(execution_props, df_schema));
+        exprs
+            .into_iter()
+            .enumerate()
+            .map(|(idx, expr)| {
+                let plan = || {
+                    let expr = simplifier.coerce(expr, df_schema)?;
+                    let expr = self.apply_function_rewrites(expr, df_schema)?;
+                    create_physical_expr(&expr, df_schema, execution_props)
+                };
+                plan().map_err(|e| {
+                    e.context(format!("Failed to create physical expression {idx}"))
+                })
+            })
+            .collect()
+    }
+
+    /// Create a [`PhysicalExpr`] from an [`Expr`] without applying type
+    /// coercion, so that no casts are added to the expression.
+    ///
+    /// The caller is responsible for the expression being type-correct, for
+    /// example by having coerced it already: the arguments of each operator
+    /// and function must have the types its kernel expects. This returns an
+    /// error if type coercion would have changed the expression, such as for
+    /// `a = 1` where `a` is a string column.
+    ///
+    /// Function rewrites are still applied. See [`Self::create_physical_expr`]
+    /// for more details.
+    pub fn create_physical_expr_no_coercion(
+        &self,
+        expr: Expr,
+        df_schema: &DFSchema,
+    ) -> datafusion_common::Result<Arc<dyn PhysicalExpr>> {
+        let execution_props = self.execution_props();
+        let simplifier =
+            ExprSimplifier::new(SessionSimplifyProvider::new(execution_props, df_schema));
+        // only check that coercion is not needed, the expression is used as is
+        let coerced = simplifier.coerce(expr.clone(), df_schema)?;
+        if coerced != expr {
+            return plan_err!(
+                "Expression {expr} does not have the types required by its operators and functions, it would need to be coerced to {coerced}"
+            );
+        }
+
+        let expr = self.apply_function_rewrites(expr, df_schema)?;
+        create_physical_expr(&expr, df_schema, execution_props)
+    }
+
+    /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
+    /// coercion with the provided [`CoercionRules`], and function rewrites.
+    ///
+    /// The rules decide how each argument that does not have the type
+    /// required by its operator or function is coerced, for example to
+    /// return an error rather than cast an integer to a string. With
+    /// [`DefaultCoercionRules`] this is the same as
+    /// [`Self::create_physical_expr`].
+    ///
+    /// [`DefaultCoercionRules`]: datafusion_expr::type_coercion::DefaultCoercionRules
+    pub fn create_physical_expr_with_coercion(
+        &self,
+        expr: Expr,
+        df_schema: &DFSchema,
+        rules: &dyn CoercionRules,
+    ) -> datafusion_common::Result<Arc<dyn PhysicalExpr>> {
+        let execution_props = self.execution_props();
+        let simplifier =
+            ExprSimplifier::new(SessionSimplifyProvider::new(execution_props, df_schema));
+        let expr = simplifier.coerce_with_rules(expr, df_schema, rules)?;
+
+        let expr = self.apply_function_rewrites(expr, df_schema)?;
+        create_physical_expr(&expr, df_schema, execution_props)
+    }
 
-        // rewrite Exprs to functions if necessary
+    /// Create a [`PhysicalSortExpr`] from a [`SortExpr`], such as one built
+    /// with [`Expr::sort`].
+    ///
+    /// The sorted expression is planned like [`Self::create_physical_expr`],
+    /// and the sort direction and NULL ordering are kept.
+    pub fn create_physical_sort_expr(
+        &self,
+        sort: SortExpr,
+        df_schema: &DFSchema,
+    ) -> datafusion_common::Result<PhysicalSortExpr> {
+        let SortExpr {
+            expr,
+            asc,
+            nulls_first,
+        } = sort;
+        Ok(PhysicalSortExpr {
+            expr: self.create_physical_expr(expr, df_schema)?,
+            options: SortOptions {
+                descending: !asc,
+                nulls_first,
+            },
+        })
+    }
+
+    /// Rewrites [`Expr`]s to functions if necessary, using the
+    /// [`FunctionRewrite`]s registered with the analyzer
+    fn apply_function_rewrites(
+        &self,
+        mut expr: Expr,
+        df_schema: &DFSchema,
+    ) -> datafusion_common::Result<Expr> {
         let config_options = self.config_options();
         for rewrite in self.analyzer.function_rewrites() {
             expr = expr
                 .transform_up(|expr| rewrite.rewrite(expr, df_schema, config_options))?
                 .data;
         }
-        create_physical_expr(&expr, df_schema, self.execution_props())
+        Ok(expr)
     }
 
     /// Return the session ID
@@ -1005,8 +1164,8 @@ impl SessionStateBuilder {
         }
     }
 
-    /// Adds defaults for table_factories, file formats, expr_planners and builtin
-    /// scalar, aggregate and windows functions.
+    /// Adds defaults for table_factories, file formats, expr_planners, builtin
+    /// scalar, aggregate and windows functions, and the analyzer rules they need.
     ///
     /// Note overwrites any previously registered items with the same name.
     pub fn with_default_features(mut self) -> Self {
@@ -1042,6 +1201,19 @@ impl SessionStateBuilder {
                     .map(|f| (f.name().to_string(), f)),
             );
 
+        // Unlike functions, rules are not keyed by name, so skip the rules that
+        // are already registered to not run them twice
+        let analyzer_rules = self.analyzer_rules.get_or_insert_with(Vec::new);
+        for rule in SessionStateDefaults::default_analyzer_rules() {
+            let registered = analyzer_rules
+                .iter()
+                .chain(self.analyzer.iter().flat_map(|a| a.rules.iter()))
+                .any(|r| r.name() == rule.name());
+            if !registered {
+                analyzer_rules.push(rule);
+            }
+        }
+
         self
     }
 
@@ -1925,13 +2097,16 @@ impl QueryPlanner for DefaultQueryPlanner {
 }
 
 struct SessionSimplifyProvider<'a> {
-    state: &'a SessionState,
+    execution_props: &'a ExecutionProps,
     df_schema: &'a DFSchema,
 }
 
 impl<'a> SessionSimplifyProvider<'a> {
-    fn new(state: &'a SessionState, df_schema: &'a DFSchema) -> Self {
-        Self { state, df_schema }
+    fn new(execution_props: &'a ExecutionProps, df_schema: &'a DFSchema) -> Self {
+        Self {
+            execution_props,
+            df_schema,
+        }
     }
 }
 
@@ -1945,7 +2120,7 @@ impl SimplifyInfo for SessionSimplifyProvider<'_> {
     }
 
     fn execution_props(&self) -> &ExecutionProps {
-        self.state.execution_props()
+        self.execution_props
     }
 
     fn get_data_type(&self, expr: &Expr) -> datafusion_common::Result<DataType> {
@@ -2143,6 +2318,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_with_default_features_twice() {
+        fn gapfill_rules(state: &SessionState) -> usize {
+            state
+                .analyzer()
+                .rules
+                .iter()
+                .filter(|rule| rule.name() == "resolve_date_bin_gapfill")
+                .count()
+        }
+
+        let state = SessionStateBuilder::new()
+            .with_default_features()
+            .with_default_features()
+            .build();
+        assert_eq!(gapfill_rules(&state), 1);
+
+        let state = SessionStateBuilder::new_from_existing(state)
+            .with_default_features()
+            .build();
+        assert_eq!(gapfill_rules(&state), 1);
+    }
+
     /// This test demonstrates why it's more convenient and somewhat necessary to provide
     /// an `expr_planners` method for `SessionState`.
     #[tokio::test]
@@ -27,6 +27,8 @@ use crate::datasource::file_format::parquet::ParquetFormatFactory;
 use crate::datasource::file_format::FileFormatFactory;
 use crate::datasource::provider::DefaultTableFactory;
 use crate::execution::context::SessionState;
+#[cfg(all(feature = "datetime_expressions", feature = "nested_expressions"))]
+use crate::execution::gapfill::ResolveDateBinGapfill;
 #[cfg(feature = "nested_expressions")]
 use crate::functions_nested;
 use crate::{functions, functions_aggregate, functions_table, functions_window};
@@ -37,6 +39,7 @@ use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_expr::planner::ExprPlanner;
 use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
+use datafusion_optimizer::AnalyzerRule;
 use std::collections::HashMap;
 use std::sync::Arc;
 use url::Url;
@@ -128,6 +131,17 @@ impl SessionStateDefaults {
         functions_table::all_default_table_functions()
     }
 
+    /// returns the list of default [`AnalyzerRule`]s needed by the default functions
+    pub fn default_analyzer_rules() -> Vec<Arc<dyn AnalyzerRule + Send + Sync>> {
+        vec![
+            #[cfg(all(
+                feature = "datetime_expressions",
+                feature = "nested_expressions"
+            ))]
+            Arc::new(ResolveDateBinGapfill::new()),
+        ]
+    }
+
     /// returns the list of default [`FileFormatFactory']'s
     pub fn default_file_formats() -> Vec<Arc<dyn FileFormatFactory>> {
         let file_formats: Vec<Arc<dyn FileFormatFactory>> = vec![
@@ -80,7 +80,7 @@ use datafusion_expr::logical_plan::builder::wrap_projection_for_join_if_necessar
 use datafusion_expr::{
     Analyze, DescribeTable, DmlStatement, Explain, ExplainFormat, Extension, FetchType,
     Filter, JoinType, RecursiveQuery, SkipType, StringifiedPlan, WindowFrame,
-    WindowFrameBound, WriteOp,
+    WindowFrameBound, WindowFunctionDefinition, WriteOp,
 };
 use datafusion_physical_expr::aggregate::{AggregateExprBuilder, AggregateFunctionExpr};
 use datafusion_physical_expr::expressions::{Column, Literal};
@@ -1514,6 +1514,7 @@ pub fn create_window_expr_with_name(
                     partition_by,
                     order_by,
                     window_frame,
+                    filter,
                     null_treatment,
                 },
         }) => {
@@ -1523,6 +1524,12 @@ pub fn create_window_expr_with_name(
                 create_physical_exprs(partition_by, logical_schema, execution_props)?;
             let order_by =
                 create_physical_sort_exprs(order_by, logical_schema, execution_props)?;
+            let filter = match filter {
+                Some(e) => {
+                    Some(create_physical_expr(e, logical_schema, execution_props)?)
+                }
+                None => None,
+            };
 
             if !is_window_frame_bound_valid(window_frame) {
                 return plan_err!(
@@ -1532,7 +1539,13 @@ pub fn create_window_expr_with_name(
             }
 
             let window_frame = Arc::new(window_frame.clone());
-            let ignore_nulls = null_treatment.unwrap_or(NullTreatment::RespectNulls)
+            let default_null_treatment = match fun {
+                WindowFunctionDefinition::AggregateUDF(udaf) => {
+                    udaf.default_null_treatment()
+                }
+                WindowFunctionDefinition::WindowUDF(_) => NullTreatment::RespectNulls,
+            };
+            let ignore_nulls = null_treatment.unwrap_or(default_null_treatment)
                 == NullTreatment::IgnoreNulls;
             windows::create_window_expr(
                 fun,
@@ -1543,6 +1556,7 @@ pub fn create_window_expr_with_name(
                 window_frame,
                 physical_schema,
                 ignore_nulls,
+                filter,
             )
         }
         other => plan_err!("Invalid window expression '{other:?}'"),
@@ -1609,7 +1623,8 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
                 None => None,
             };
 
-            let ignore_nulls = null_treatment.unwrap_or(NullTreatment::RespectNulls)
+            let ignore_nulls = null_treatment
+                .unwrap_or_else(|| func.default_null_treatment())
                 == NullTreatment::IgnoreNulls;
 
             let (agg_expr, filter, order_by) = {
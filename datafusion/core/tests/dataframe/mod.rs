@@ -3854,6 +3854,73 @@ async fn unnest_column_nulls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn unnest_column_empty_lists() -> Result<()> {
+    let df = table_with_lists_and_nulls().await?;
+
+    // The empty list of C is dropped
+    let results = df.clone().unnest_column("list")?.collect().await?;
+    assert_snapshot!(
+       batches_to_string(&results),
+        @r###"
+    +------+----+
+    | list | id |
+    +------+----+
+    | 1    | A  |
+    | 2    | A  |
+    |      | B  |
+    | 3    | D  |
+    +------+----+
+    "###
+    );
+
+    // Unnest, preserving empty lists (row with C is preserved)
+    let options = UnnestOptions::new().with_preserve_empty_lists(true);
+    let results = df
+        .clone()
+        .unnest_column_with_options("list", options)?
+        .collect()
+        .await?;
+    assert_snapshot!(
+       batches_to_string(&results),
+        @r###"
+    +------+----+
+    | list | id |
+    +------+----+
+    | 1    | A  |
+    | 2    | A  |
+    |      | B  |
+    |      | C  |
+    | 3    | D  |
+    +------+----+
+    "###
+    );
+
+    // Empty lists can be preserved independently of nulls
+    let options = UnnestOptions::new()
+        .with_preserve_nulls(false)
+        .with_preserve_empty_lists(true);
+    let results = df
+        .unnest_column_with_options("list", options)?
+        .collect()
+        .await?;
+    assert_snapshot!(
+       batches_to_string(&results),
+        @r###"
+    +------+----+
+    | list | id |
+    +------+----+
+    | 1    | A  |
+    | 2    | A  |
+    |      | C  |
+    | 3    | D  |
+    +------+----+
+    "###
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn unnest_fixed_list() -> Result<()> {
     let batch = get_fixed_list_batch()?;
@@ -16,21 +16,56 @@
 // under the License.
 
 use arrow::array::{
-    builder::{ListBuilder, StringBuilder},
-    ArrayRef, Int64Array, RecordBatch, StringArray, StructArray,
+    builder::{Int64Builder, ListBuilder, StringBuilder},
+    Array, ArrayRef, Date32Array, Int64Array, ListArray, RecordBatch, StringArray,
+    StructArray, TimestampNanosecondArray,
 };
-use arrow::datatypes::{DataType, Field};
+use arrow::compute::{lexsort_to_indices, SortOptions};
+use arrow::datatypes::{DataType, Field, Int64Type, TimeUnit};
 use arrow::util::pretty::{pretty_format_batches, pretty_format_columns};
+use chrono::{FixedOffset, NaiveDate, TimeZone};
 use datafusion::prelude::*;
-use datafusion_common::{DFSchema, ScalarValue};
+use datafusion_common::cast::as_float64_array;
+use datafusion_common::{
+    assert_contains, plan_err, Column, DFSchema, ExprSchema, Result, ScalarValue,
+    UnnestOptions,
+};
+use datafusion_expr::conditional_expressions::CaseBuilder;
 use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_expr::expr::WindowFunction;
 use datafusion_expr::simplify::SimplifyContext;
-use datafusion_expr::ExprFunctionExt;
-use datafusion_functions::core::expr_ext::FieldAccessor;
+use datafusion_expr::type_coercion::{CoercionRules, DefaultCoercionRules};
+use datafusion_expr::var_provider::{VarProvider, VarType};
+use datafusion_expr::{
+    AggregateUDF, ExprFunctionExt, ExprSchemable, NullsPlacement, Operator, WindowFrame,
+    WindowFrameBound, WindowFrameUnits,
+};
+use datafusion_functions::core::expr_ext::{FieldAccessor, NotNullAssertion};
+use datafusion_functions::json::expr_ext::JsonAccessor;
+use datafusion_functions_aggregate::approx_distinct::approx_distinct_udaf;
+use datafusion_functions_aggregate::approx_percentile_cont::approx_percentile_cont_udaf;
+use datafusion_functions_aggregate::array_agg::array_agg_udaf;
+use datafusion_functions_aggregate::average::avg_udaf;
+use datafusion_functions_aggregate::bit_and_or_xor::{bit_and, bit_or, bit_xor};
+use datafusion_functions_aggregate::bitmap_agg::bitmap_agg;
+use datafusion_functions_aggregate::correlation::corr;
+use datafusion_functions_aggregate::count::count_udaf;
+use datafusion_functions_aggregate::covariance::{covar_pop, covar_samp};
+use datafusion_functions_aggregate::expr_ext::SumOverflow;
 use datafusion_functions_aggregate::first_last::first_value_udaf;
-use datafusion_functions_aggregate::sum::sum_udaf;
-use datafusion_functions_nested::expr_ext::{IndexAccessor, SliceAccessor};
-use datafusion_optimizer::simplify_expressions::ExprSimplifier;
+use datafusion_functions_aggregate::flatten_agg::flatten_agg;
+use datafusion_functions_aggregate::geometric_harmonic_mean::{
+    geometric_mean, harmonic_mean,
+};
+use datafusion_functions_aggregate::median::median_udaf;
+use datafusion_functions_aggregate::mode::{mode, Mode, ModeTieBreak};
+use datafusion_functions_aggregate::string_agg_list::string_agg_list;
+use datafusion_functions_aggregate::sum::{sum_udaf, OverflowMode};
+use datafusion_functions_nested::expr_ext::{IndexAccessor, MapAccessor, SliceAccessor};
+use datafusion_functions_nested::reduce::{LIST_REDUCE_ACC, LIST_REDUCE_ELEM};
+use datafusion_functions_nested::take_while::LIST_TAKE_WHILE_ELEM;
+use datafusion_functions_window::expr_fn::{lag, lead, row_number};
+use datafusion_optimizer::simplify_expressions::{ExprSimplifier, ExprSimplifyExt};
 use sqlparser::ast::NullTreatment;
 /// Tests of using and evaluating `Expr`s outside the context of a LogicalPlan
 use std::sync::{Arc, LazyLock};
@@ -55,6 +90,381 @@ fn test_octet_length() {
     );
 }
 
+#[test]
+fn test_octet_length_return_type() -> Result<()> {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema())?;
+    for data_type in [
+        DataType::Utf8,
+        DataType::LargeUtf8,
+        DataType::Utf8View,
+        DataType::Binary,
+    ] {
+        let expr = octet_length(cast(col("id"), data_type.clone()));
+        assert_eq!(expr.data_type(&df_schema)?, DataType::Int64, "{data_type}");
+
+        let result = evaluate_batch(expr, &TEST_BATCH)?;
+        assert_eq!(
+            result.as_ref(),
+            &Int64Array::from(vec![1, 1, 1]),
+            "{data_type}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_like_with_escape() {
+    // `1%`, `2%` and `3%`
+    let value = concat(vec![col("id"), lit("%")]);
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        value.clone().like_with_escape(lit("1$%"), "$").unwrap(),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| true  |",
+            "| false |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+
+    // the escaped `%` is not a wildcard, so does not match the `0` of `10%`
+    let value = concat(vec![col("id"), lit("0%")]);
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        value.ilike_with_escape(lit("1$%"), "$").unwrap(),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| false |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        col("id").like_with_escape(lit("1\\%"), "\\").unwrap(),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| false |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+
+    let err = col("id").like_with_escape(lit("1$%"), "$$").unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Error during planning: Invalid escape character in LIKE expression"
+    );
+}
+
+#[test]
+fn test_hash() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        hash(vec![col("id"), lit("xxhash64")]),
+        vec![
+            "+----------------------+",
+            "| expr                 |",
+            "+----------------------+",
+            "| 13237225503670494420 |",
+            "| 6927017134761466251  |",
+            "| 2744517546871237796  |",
+            "+----------------------+",
+        ],
+    );
+
+    // NULL values give NULL
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        hash(vec![col("id"), col("i"), lit("murmur3")]),
+        vec![
+            "+----------------------+",
+            "| expr                 |",
+            "+----------------------+",
+            "| 14187524474090710013 |",
+            "|                      |",
+            "| 4621064056959283172  |",
+            "+----------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_regexp_extract() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        regexp_extract(col("id"), lit("(\\d+)"), lit(1)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 1    |",
+            "| 2    |",
+            "| 3    |",
+            "+------+",
+        ],
+    );
+
+    // the whole match is group 0, and a string that does not match is NULL
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        regexp_extract(col("id"), lit("[12]"), lit(0)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 1    |",
+            "| 2    |",
+            "|      |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_regexp_extract_group_out_of_range() {
+    let expr = regexp_extract(col("id"), lit("(\\d+)"), lit(2));
+    let err = SessionContext::new()
+        .read_batch(TEST_BATCH.clone())
+        .unwrap()
+        .select(vec![expr])
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "regexp_extract group index 2 is out of range for pattern (\\d+) with 1 capture groups"
+    );
+}
+
+#[test]
+fn test_regexp_count() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        regexp_count(col("props").field("a"), lit("\\d"), None, None),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 8    |",
+            "| 8    |",
+            "| 8    |",
+            "+------+",
+        ],
+    );
+
+    // case insensitive, with a NULL string
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        regexp_count(
+            col("payload").json_get("a.b"),
+            lit("X"),
+            Some(lit(1_i64)),
+            Some(lit("i")),
+        ),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 1    |",
+            "| 0    |",
+            "|      |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_to_char() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        to_char(col("date"), lit("%d/%m/%Y")),
+        vec![
+            "+------------+",
+            "| expr       |",
+            "+------------+",
+            "| 01/02/2021 |",
+            "|            |",
+            "| 03/02/2021 |",
+            "+------------+",
+        ],
+    );
+
+    // strings are coerced to dates
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        to_char(col("props").field("a"), lit("%Y%m%d")),
+        vec![
+            "+----------+",
+            "| expr     |",
+            "+----------+",
+            "| 20210201 |",
+            "| 20210202 |",
+            "| 20210203 |",
+            "+----------+",
+        ],
+    );
+
+    // a timestamp
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        to_char(
+            cast(col("date"), DataType::Timestamp(TimeUnit::Second, None)),
+            lit("%Y-%m-%d %H:%M"),
+        ),
+        vec![
+            "+------------------+",
+            "| expr             |",
+            "+------------------+",
+            "| 2021-02-01 00:00 |",
+            "|                  |",
+            "| 2021-02-03 00:00 |",
+            "+------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_from_char() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        from_char(to_char(col("date"), lit("%d/%m/%Y")), lit("%d/%m/%Y")),
+        vec![
+            "+---------------------+",
+            "| expr                |",
+            "+---------------------+",
+            "| 2021-02-01T00:00:00 |",
+            "|                     |",
+            "| 2021-02-03T00:00:00 |",
+            "+---------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_extract_date_parts() {
+    // date is 2021-02-01 NULL 2021-02-03
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        extract_date_parts(col("date")),
+        vec![
+            "+---------------------------------------------------------------+",
+            "| expr                                                          |",
+            "+---------------------------------------------------------------+",
+            "| {year: 2021, month: 2, day: 1, hour: 0, minute: 0, second: 0} |",
+            "|                                                               |",
+            "| {year: 2021, month: 2, day: 3, hour: 0, minute: 0, second: 0} |",
+            "+---------------------------------------------------------------+",
+        ],
+    );
+
+    // a NULL date gives a NULL struct
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        extract_date_parts(col("date")).is_null(),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| true  |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+
+    for (part, first, last) in [("year", 2021, 2021), ("month", 2, 2), ("day", 1, 3)] {
+        let first = format!("| {first:<4} |");
+        let last = format!("| {last:<4} |");
+        evaluate_expr_test(
+            extract_date_parts(col("date")).field(part),
+            vec![
+                "+------+", "| expr |", "+------+", &first, "|      |", &last, "+------+",
+            ],
+        );
+    }
+}
+
+#[test]
+fn test_to_char_invalid_format() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    for (expr, name) in [
+        (to_char(col("date"), lit("%Y-%Q")), "to_char"),
+        (from_char(col("id"), lit("%Y-%Q")), "from_char"),
+    ] {
+        let err = SessionContext::new()
+            .create_physical_expr(expr, &df_schema)
+            .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            format!("{name} format '%Y-%Q' is not a valid chrono format")
+        );
+    }
+}
+
+#[test]
+fn test_regexp_replace_all() {
+    // id || '0' is 10, 20 and 30
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        regexp_replace_all(
+            concat(vec![col("id"), lit("0")]),
+            lit("(\\d)(\\d)"),
+            lit("$2$1"),
+            None,
+        ),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 01   |",
+            "| 02   |",
+            "| 03   |",
+            "+------+",
+        ],
+    );
+
+    // named groups and flags
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        regexp_replace_all(
+            lit("aBc"),
+            lit("(?<letter>b)"),
+            lit("[${letter}]"),
+            Some(lit("i")),
+        ),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| a[B]c |",
+            "+-------+",
+        ],
+    );
+}
+
+#[test]
+fn test_regexp_replace_all_invalid_group_reference() {
+    let expr = regexp_replace_all(col("id"), lit("(\\d)"), lit("$2"), None);
+    let err = SessionContext::new()
+        .read_batch(TEST_BATCH.clone())
+        .unwrap()
+        .select(vec![expr])
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "regexp_replace_all replacement refers to capture group $2, which is not in pattern (\\d)"
+    );
+}
+
 #[test]
 fn test_eq() {
     // id = '2'
@@ -73,281 +483,3627 @@ fn test_eq() {
 }
 
 #[test]
-fn test_eq_with_coercion() {
-    // id = 2 (need to coerce the 2 to '2' to evaluate)
+fn test_is_not_distinct_from() {
+    // i IS NOT DISTINCT FROM NULL
     evaluate_expr_test(
-        col("id").eq(lit(2i32)),
+        col("i").is_not_distinct_from(lit(ScalarValue::Int64(None))),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| true  |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+
+    // operands are coerced as for `eq`
+    evaluate_expr_test(
+        col("i").is_not_distinct_from(lit(5_i32)),
         vec![
             "+-------+",
             "| expr  |",
             "+-------+",
             "| false |",
+            "| false |",
             "| true  |",
+            "+-------+",
+        ],
+    );
+    evaluate_expr_test(
+        col("i").eq(lit(5_i32)),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
             "| false |",
+            "|       |",
+            "| true  |",
             "+-------+",
         ],
     );
 }
 
 #[test]
-fn test_get_field() {
+fn test_is_distinct_from() {
+    // i IS DISTINCT FROM 10
     evaluate_expr_test(
-        col("props").field("a"),
+        col("i").is_distinct_from(lit(10_i64)),
         vec![
-            "+------------+",
-            "| expr       |",
-            "+------------+",
-            "| 2021-02-01 |",
-            "| 2021-02-02 |",
-            "| 2021-02-03 |",
-            "+------------+",
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| true  |",
+            "| true  |",
+            "+-------+",
         ],
     );
 }
 
 #[test]
-fn test_get_field_null() {
+fn test_eq_with_coercion() {
+    // id = 2 (need to coerce the 2 to '2' to evaluate)
+    evaluate_expr_test(
+        col("id").eq(lit(2i32)),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| true  |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+}
+
+#[test]
+fn test_case_builder() {
+    // CASE WHEN i IS NULL THEN -1 ELSE i END, where -1 is cast to Int64
+    let schema = Arc::new(DFSchema::try_from(TEST_BATCH.schema()).unwrap());
+    let expr = CaseBuilder::default()
+        .when(col("i").is_null(), lit(-1i32))
+        .with_schema(Arc::clone(&schema))
+        .otherwise(col("i"))
+        .unwrap();
+    assert_eq!(expr.get_type(&schema).unwrap(), DataType::Int64);
+    evaluate_expr_test(
+        expr,
+        vec![
+            "+------+", "| expr |", "+------+", "| 10   |", "| -1   |", "| 5    |",
+            "+------+",
+        ],
+    );
+
+    let err = CaseBuilder::default()
+        .when(col("i").is_null(), lit(-1i32))
+        .with_schema(schema)
+        .otherwise(col("props"))
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "CASE expression 'then' and 'else' values have incompatible data types"
+    );
+}
+
+#[test]
+fn test_get_field() {
+    evaluate_expr_test(
+        col("props").field("a"),
+        vec![
+            "+------------+",
+            "| expr       |",
+            "+------------+",
+            "| 2021-02-01 |",
+            "| 2021-02-02 |",
+            "| 2021-02-03 |",
+            "+------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_get_field_at() {
+    evaluate_expr_test(
+        col("props").field_at(0),
+        vec![
+            "+------------+",
+            "| expr       |",
+            "+------------+",
+            "| 2021-02-01 |",
+            "| 2021-02-02 |",
+            "| 2021-02-03 |",
+            "+------------+",
+        ],
+    );
+
+    // once simplified, access by position is the same as access by name
+    create_simplified_expr_test(col("props").field_at(0), "get_field(props@2, a)");
+    create_simplified_expr_test(col("props").field("a"), "get_field(props@2, a)");
+}
+
+#[test]
+fn test_data_type() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let expr = col("props").field("a");
+    assert_eq!(expr.data_type(&df_schema).unwrap(), DataType::Utf8);
+    let nullable = expr.nullable(&df_schema).unwrap();
+
+    // consistent with the physical expression
+    let physical_expr = SessionContext::new()
+        .create_physical_expr(expr, &df_schema)
+        .unwrap();
+    let schema = TEST_BATCH.schema();
+    assert_eq!(physical_expr.data_type(&schema).unwrap(), DataType::Utf8);
+    assert_eq!(physical_expr.nullable(&schema).unwrap(), nullable);
+
+    let err = col("missing").data_type(&df_schema).unwrap_err();
+    assert_contains!(err.to_string(), "No field named missing");
+}
+
+#[test]
+fn test_get_field_at_out_of_range() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(col("props").field_at(1), &df_schema)
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Field index 1 out of range for struct with 1 fields"
+    );
+}
+
+#[test]
+fn test_rewrite_column_refs_field_access() {
+    let rename = |c: &Column| {
+        if c.name == "props" {
+            Column::from_name("renamed")
+        } else {
+            c.clone()
+        }
+    };
+    let expr = col("props").field("a").alias("a");
+    assert_eq!(
+        expr.rewrite_column_refs(rename),
+        col("renamed").field("a").alias("a")
+    );
+
+    // the rewritten expression still evaluates against the original column
+    let expr = col("renamed").field("a");
+    evaluate_expr_test(
+        expr.rewrite_column_refs(|_| Column::from_name("props")),
+        vec![
+            "+------------+",
+            "| expr       |",
+            "+------------+",
+            "| 2021-02-01 |",
+            "| 2021-02-02 |",
+            "| 2021-02-03 |",
+            "+------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_get_field_null() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        lit(ScalarValue::Null).field("a"),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_get_field_null_simplified() {
+    create_simplified_expr_test(lit(ScalarValue::Null).field("a"), "NULL");
+
+    // a typed NULL struct simplifies to a NULL of the field's type
+    let fields = vec![Field::new("a", DataType::Int32, false)];
+    let null_struct = ScalarValue::try_from(DataType::Struct(fields.into())).unwrap();
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let props = ExecutionProps::new();
+    let simplifier = ExprSimplifier::new(
+        SimplifyContext::new(&props).with_schema(Arc::new(df_schema)),
+    );
+    assert_eq!(
+        simplifier
+            .simplify(lit(null_struct.clone()).field("a"))
+            .unwrap(),
+        lit(ScalarValue::Int32(None))
+    );
+    assert_eq!(
+        simplifier.simplify(lit(null_struct).field_at(0)).unwrap(),
+        lit(ScalarValue::Int32(None))
+    );
+
+    // a struct column that may contain NULLs is not simplified
+    create_simplified_expr_test(col("props").field("a"), "get_field(props@2, a)");
+}
+
+#[test]
+fn test_coalesce_struct() {
+    let left = named_struct(vec![lit("a"), col("props").field("a"), lit("i"), col("i")]);
+    let right = named_struct(vec![lit("i"), lit(0i64), lit("id"), col("id")]);
+    evaluate_expr_test(
+        coalesce_struct(left, right),
+        vec![
+            "+-------------------------------+",
+            "| expr                          |",
+            "+-------------------------------+",
+            "| {a: 2021-02-01, i: 10, id: 1} |",
+            "| {a: 2021-02-02, i: 0, id: 2}  |",
+            "| {a: 2021-02-03, i: 5, id: 3}  |",
+            "+-------------------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_coalesce_struct_type_mismatch() {
+    let left = named_struct(vec![lit("a"), col("i")]);
+    let right = named_struct(vec![lit("a"), col("id")]);
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(coalesce_struct(left, right), &df_schema)
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "coalesce_struct field a has type Int64 in the first struct and Utf8 in the second struct"
+    );
+}
+
+#[test]
+fn test_try_get_field() {
+    evaluate_expr_test(
+        col("props").try_field("a"),
+        vec![
+            "+------------+",
+            "| expr       |",
+            "+------------+",
+            "| 2021-02-01 |",
+            "| 2021-02-02 |",
+            "| 2021-02-03 |",
+            "+------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_try_get_field_missing() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        col("props").try_field("missing"),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "|      |",
+            "|      |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_nested_get_field() {
+    evaluate_expr_test(
+        col("props")
+            .field("a")
+            .eq(lit("2021-02-02"))
+            .or(col("id").eq(lit(1))),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| true  |",
+            "| true  |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+}
+
+#[test]
+fn test_struct_keys() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        struct_keys(col("props")),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| [a]  |",
+            "| [a]  |",
+            "| [a]  |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_struct_rename_fields() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        struct_rename_fields(col("props"), vec![("a", "date")]),
+        vec![
+            "+--------------------+",
+            "| expr               |",
+            "+--------------------+",
+            "| {date: 2021-02-01} |",
+            "| {date: 2021-02-02} |",
+            "| {date: 2021-02-03} |",
+            "+--------------------+",
+        ],
+    );
+
+    // the renamed field can be accessed by its new name
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        struct_rename_fields(col("props"), vec![("a", "date")]).field("date"),
+        vec![
+            "+------------+",
+            "| expr       |",
+            "+------------+",
+            "| 2021-02-01 |",
+            "| 2021-02-02 |",
+            "| 2021-02-03 |",
+            "+------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_struct_rename_fields_unknown_field() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(
+            struct_rename_fields(col("props"), vec![("b", "date")]),
+            &df_schema,
+        )
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "struct_rename_fields struct has no field named b"
+    );
+}
+
+#[test]
+fn test_list_index() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        col("list").index(lit(1i64)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| one  |",
+            "| two  |",
+            "| five |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_index_negative() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        col("list").index(lit(-1i64)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| one  |",
+            "| four |",
+            "| five |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_index_negative_out_of_bounds() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        col("list").index(lit(-3i64)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "| two  |",
+            "|      |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_range() {
+    evaluate_expr_test(
+        col("list").range(lit(1i64), lit(2i64)),
+        vec![
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| [one]        |",
+            "| [two, three] |",
+            "| [five]       |",
+            "+--------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_range_with_stride() {
+    evaluate_expr_test(
+        col("list").range_with_stride(lit(1i64), lit(3i64), lit(2i64)),
+        vec![
+            "+-------------+",
+            "| expr        |",
+            "+-------------+",
+            "| [one]       |",
+            "| [two, four] |",
+            "| [five]      |",
+            "+-------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_range_with_negative_stride() {
+    evaluate_expr_test(
+        col("list").range_with_stride(lit(3i64), lit(1i64), lit(-1i64)),
+        vec![
+            "+--------------------+",
+            "| expr               |",
+            "+--------------------+",
+            "| []                 |",
+            "| [four, three, two] |",
+            "| []                 |",
+            "+--------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_range_with_zero_stride() {
+    let batch = &TEST_BATCH;
+    let df_schema = DFSchema::try_from(batch.schema()).unwrap();
+    let expr = col("list").range_with_stride(lit(1i64), lit(3i64), lit(0i64));
+    let physical_expr = SessionContext::new()
+        .create_physical_expr(expr, &df_schema)
+        .unwrap();
+
+    let err = physical_expr.evaluate(batch).unwrap_err();
+    assert_contains!(err.to_string(), "array_slice got invalid stride: 0");
+}
+
+#[test]
+fn test_cast_list_elements() -> Result<()> {
+    let to_type = DataType::new_list(DataType::Int64, true);
+
+    // each element is cast
+    let batch = string_list_batch(vec![vec!["1"], vec!["2", "3", "4"], vec!["5"]]);
+    let schema = DFSchema::try_from(batch.schema())?;
+    let array = evaluate_batch(col("list").cast_to(&to_type, &schema)?, &batch)?;
+    assert_eq!(array.data_type(), &to_type);
+    let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), Some(3), Some(4)]),
+        Some(vec![Some(5)]),
+    ]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+
+    // an element that can not be cast is an error
+    let batch = string_list_batch(vec![vec!["1"], vec!["2", "x"]]);
+    let schema = DFSchema::try_from(batch.schema())?;
+    let err =
+        evaluate_batch(col("list").cast_to(&to_type, &schema)?, &batch).unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Cannot cast string 'x' to value of Int64 type"
+    );
+
+    // ... or NULL with TRY_CAST
+    let array = evaluate_batch(try_cast(col("list"), to_type.clone()), &batch)?;
+    let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), None]),
+    ]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn test_concat_list() {
+    evaluate_expr_test(
+        concat_list(vec![col("list"), lit("extra")]),
+        vec![
+            "+---------------------------+",
+            "| expr                      |",
+            "+---------------------------+",
+            "| [one, extra]              |",
+            "| [two, three, four, extra] |",
+            "| [five, extra]             |",
+            "+---------------------------+",
+        ],
+    );
+
+    evaluate_expr_test(
+        concat_list(vec![col("id"), col("list"), col("list")]),
+        vec![
+            "+-----------------------------------------+",
+            "| expr                                    |",
+            "+-----------------------------------------+",
+            "| [1, one, one]                           |",
+            "| [2, two, three, four, two, three, four] |",
+            "| [3, five, five]                         |",
+            "+-----------------------------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_concat_list_incompatible_types() {
+    let batch = &TEST_BATCH;
+    let df_schema = DFSchema::try_from(batch.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(concat_list(vec![col("list"), col("props")]), &df_schema)
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Failed to unify argument types of concat_list"
+    );
+}
+
+#[test]
+fn test_array_resize() {
+    // shorter lists are padded, and longer lists are truncated
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        array_resize(col("list"), lit(2), lit("pad")),
+        vec![
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| [one, pad]   |",
+            "| [two, three] |",
+            "| [five, pad]  |",
+            "+--------------+",
+        ],
+    );
+    // a NULL pad value pads with NULLs
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        array_resize(col("list"), lit(2), lit(ScalarValue::Utf8(None))),
+        vec![
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| [one, ]      |",
+            "| [two, three] |",
+            "| [five, ]     |",
+            "+--------------+",
+        ],
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        array_resize(col("list"), lit(2), lit(ScalarValue::Null)),
+        vec![
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| [one, ]      |",
+            "| [two, three] |",
+            "| [five, ]     |",
+            "+--------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_trim_array() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        trim_array(col("list"), lit(1)),
+        vec![
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| []           |",
+            "| [two, three] |",
+            "| []           |",
+            "+--------------+",
+        ],
+    );
+    // trimming more elements than a list has leaves it empty
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        trim_array(col("list"), lit(5)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| []   |",
+            "| []   |",
+            "| []   |",
+            "+------+",
+        ],
+    );
+
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(trim_array(col("list"), lit(-1i64)), &df_schema)
+        .unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Error during planning: trim_array expects a non-negative number of elements, got -1"
+    );
+}
+
+#[test]
+fn test_make_array_coercion() -> Result<()> {
+    // the Int32 and UInt8 literals are coerced to the Int64 type of i
+    let expr = make_array(vec![col("i"), lit(3), lit(7i32), lit(1u8)]);
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema())?;
+    let physical_expr =
+        SessionContext::new().create_physical_expr(expr.clone(), &df_schema)?;
+    assert_eq!(
+        physical_expr.data_type(&TEST_BATCH.schema())?,
+        DataType::new_list(DataType::Int64, true)
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        expr,
+        vec![
+            "+---------------+",
+            "| expr          |",
+            "+---------------+",
+            "| [10, 3, 7, 1] |",
+            "| [, 3, 7, 1]   |",
+            "| [5, 3, 7, 1]  |",
+            "+---------------+",
+        ],
+    );
+    Ok(())
+}
+
+#[test]
+fn test_make_array_incompatible_types() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(make_array(vec![col("i"), col("props")]), &df_schema)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Execution error: Function 'make_array' user-defined coercion failed with \"Error during planning: \
+        Failed to unify argument types of make_array: no common type for Int64, Struct(a Utf8)\""
+    );
+}
+
+#[test]
+fn test_nullif_list_empty() {
+    // removing "one" leaves an empty list in the first row
+    evaluate_expr_test(
+        nullif_list_empty(array_remove_all(col("list"), lit("one"))),
+        vec![
+            "+--------------------+",
+            "| expr               |",
+            "+--------------------+",
+            "|                    |",
+            "| [two, three, four] |",
+            "| [five]             |",
+            "+--------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_distinct() {
+    // duplicates are removed while keeping the first-seen order
+    evaluate_expr_test(
+        list_distinct(array_concat(vec![col("list"), col("list")])),
+        vec![
+            "+--------------------+",
+            "| expr               |",
+            "+--------------------+",
+            "| [one]              |",
+            "| [two, three, four] |",
+            "| [five]             |",
+            "+--------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_columns() {
+    let expr = col("props").field("a").eq(col("id"));
+    assert_eq!(
+        expr.columns(),
+        vec![Column::from_name("props"), Column::from_name("id")]
+    );
+
+    let expr = col("list")
+        .index(col("i"))
+        .eq(col("t.id"))
+        .or(col("id").eq(col("list").index(lit(1i64))));
+    assert_eq!(
+        expr.columns(),
+        vec![
+            Column::from_name("list"),
+            Column::from_name("i"),
+            Column::new(Some("t"), "id"),
+            Column::from_name("id"),
+        ]
+    );
+}
+
+#[test]
+fn test_find_subexpr() {
+    let is_get_field =
+        |e: &Expr| matches!(e, Expr::ScalarFunction(f) if f.name() == "get_field");
+    let expr = col("props").field("a").eq(lit("2021-02-02")).or(col("i")
+        .gt(lit(5i64))
+        .and(col("props").field("b").is_null()));
+    assert_eq!(
+        expr.find_subexpr(is_get_field),
+        vec![&col("props").field("a"), &col("props").field("b")]
+    );
+
+    // field accesses in aggregate arguments are found too
+    let expr = count_udaf()
+        .call(vec![col("props").field("a")])
+        .filter(col("props").field("b").is_not_null())
+        .build()
+        .unwrap();
+    assert_eq!(expr.find_subexpr(is_get_field).len(), 2);
+}
+
+#[test]
+fn test_count_nodes() {
+    // or, =, get_field, props, 'a', '2021-02-02', and, >, i, 5, is null,
+    // get_field, props, 'b'
+    let expr = col("props").field("a").eq(lit("2021-02-02")).or(col("i")
+        .gt(lit(5i64))
+        .and(col("props").field("b").is_null()));
+    assert_eq!(expr.count_nodes(), 14);
+
+    // a complexity budget can be enforced before planning
+    let max_nodes = 10;
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let small = col("i").gt(lit(5i64));
+    assert!(small.count_nodes() <= max_nodes);
+    SessionContext::new()
+        .create_physical_expr(small, &df_schema)
+        .unwrap();
+    assert!(expr.count_nodes() > max_nodes);
+}
+
+#[test]
+fn test_width_bucket() {
+    // i is 10, NULL and 5
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        width_bucket(col("i"), lit(0), lit(100), lit(10)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 2    |",
+            "|      |",
+            "| 1    |",
+            "+------+",
+        ],
+    );
+
+    // decreasing buckets, with values above and below the range
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        width_bucket(col("i"), lit(8), lit(4), lit(2)),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 0    |",
+            "|      |",
+            "| 2    |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_literal_from_chrono() {
+    let date = NaiveDate::from_ymd_opt(2021, 2, 2).unwrap();
+    evaluate_expr_test(
+        cast(col("props").field("a"), DataType::Date32).gt_eq(lit(date)),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| true  |",
+            "| true  |",
+            "+-------+",
+        ],
+    );
+
+    // compared to a timestamp of the same instant in another timezone
+    let offset = FixedOffset::west_opt(3600).unwrap();
+    let timestamp = offset.with_ymd_and_hms(2021, 2, 1, 23, 0, 0).unwrap();
+    evaluate_expr_test(
+        cast(
+            col("props").field("a"),
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+        )
+        .eq(lit(timestamp)),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "| true  |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+}
+
+#[test]
+fn test_literal_from_json() {
+    let date =
+        ScalarValue::try_from_json_str(r#""2021-02-01""#, &DataType::Date32).unwrap();
+    evaluate_expr_test(
+        cast(col("props").field("a"), DataType::Date32).eq(lit(date)),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| true  |",
+            "| false |",
+            "| false |",
+            "+-------+",
+        ],
+    );
+
+    let list_type = DataType::new_list(DataType::Utf8, true);
+    let list = ScalarValue::try_from_json_str(r#"["five", "one"]"#, &list_type).unwrap();
+    evaluate_expr_test(
+        array_has(lit(list), col("list").index(lit(1i64))),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| true  |",
+            "| false |",
+            "| true  |",
+            "+-------+",
+        ],
+    );
+}
+
+#[test]
+fn test_levenshtein_normalized() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        levenshtein_normalized(col("id"), lit("2")),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 0.0  |",
+            "| 1.0  |",
+            "| 0.0  |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_split_part_all() {
+    evaluate_expr_test(
+        split_part_all(col("props").field("a"), lit("-")),
+        vec![
+            "+----------------+",
+            "| expr           |",
+            "+----------------+",
+            "| [2021, 02, 01] |",
+            "| [2021, 02, 02] |",
+            "| [2021, 02, 03] |",
+            "+----------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_json_get() {
+    evaluate_expr_test(
+        col("payload").json_get("a.b"),
+        vec![
+            "+-------------+",
+            "| expr        |",
+            "+-------------+",
+            "| x           |",
+            "| [1,{\"c\":2}] |",
+            "|             |",
+            "+-------------+",
+        ],
+    );
+
+    // missing paths are NULL
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        col("payload").json_get("a.b[1].c"),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "| 2    |",
+            "|      |",
+            "+------+",
+        ],
+    );
+
+    evaluate_expr_test(
+        col("payload").json_get_array("tags"),
+        vec![
+            "+----------+",
+            "| expr     |",
+            "+----------+",
+            "| [t1, t2] |",
+            "| []       |",
+            "|          |",
+            "+----------+",
+        ],
+    );
+}
+
+#[test]
+fn test_json_array_length_and_object_keys() {
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        json_array_length(col("json_values")),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 3    |",
+            "|      |",
+            "|      |",
+            "+------+",
+        ],
+    );
+    // invalid JSON is NULL
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        json_array_length(col("payload")),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "|      |",
+            "|      |",
+            "+------+",
+        ],
+    );
+
+    // the keys are sorted
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        json_object_keys(col("json_values")),
+        vec![
+            "+--------+",
+            "| expr   |",
+            "+--------+",
+            "|        |",
+            "| [y, z] |",
+            "|        |",
+            "+--------+",
+        ],
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        json_object_keys(col("payload")),
+        vec![
+            "+-----------+",
+            "| expr      |",
+            "+-----------+",
+            "| [a, tags] |",
+            "| [a, tags] |",
+            "|           |",
+            "+-----------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_position() {
+    // 1-based, like `index`
+    evaluate_expr_test(
+        list_position(col("list"), lit("three")),
+        vec![
+            "+------+", "| expr |", "+------+", "|      |", "| 2    |", "|      |",
+            "+------+",
+        ],
+    );
+
+    // `index` takes an Int64 position, while `list_position` returns UInt64
+    let position = cast(list_position(col("list"), lit("three")), DataType::Int64);
+    evaluate_expr_test(
+        col("list").index(position),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "|       |",
+            "| three |",
+            "|       |",
+            "+-------+",
+        ],
+    );
+
+    // searching for NULL finds nothing
+    evaluate_expr_test(
+        list_position(col("list"), lit(ScalarValue::Utf8(None))),
+        vec![
+            "+------+", "| expr |", "+------+", "|      |", "|      |", "|      |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_map_from_arrays() {
+    let map = map_from_arrays(col("list"), col("list"));
+    evaluate_expr_test(
+        map.clone().map_extract(lit("three")),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "|       |",
+            "| three |",
+            "|       |",
+            "+-------+",
+        ],
+    );
+
+    // a missing key is NULL
+    evaluate_expr_test(
+        map.map_extract(lit("six")),
+        vec![
+            "+------+", "| expr |", "+------+", "|      |", "|      |", "|      |",
+            "+------+",
+        ],
+    );
+
+    // the last value of a duplicate key wins
+    let map = map_from_arrays(
+        array_append(col("list"), lit("one")),
+        array_append(col("list"), col("id")),
+    );
+    evaluate_expr_test(
+        map.map_extract(lit("one")),
+        vec![
+            "+------+", "| expr |", "+------+", "| 1    |", "| 2    |", "| 3    |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_array_zip() {
+    // list has 1, 3 and 1 elements and the other array has 2, so the
+    // shorter array is padded with nulls in every row
+    let expr = array_zip(col("list"), make_array(vec![col("id"), col("i")]));
+    evaluate_expr_test(
+        expr,
+        vec![
+            "+---------------------------------------------------+",
+            "| expr                                              |",
+            "+---------------------------------------------------+",
+            "| [{0: one, 1: 1}, {0: , 1: 10}]                    |",
+            "| [{0: two, 1: 2}, {0: three, 1: }, {0: four, 1: }] |",
+            "| [{0: five, 1: 3}, {0: , 1: 5}]                    |",
+            "+---------------------------------------------------+",
+        ],
+    );
+}
+
+#[test]
+fn test_jaccard_similarity() {
+    // list is ["one"], ["two", "three", "four"] and ["five"]
+    let other = make_array(vec![lit("one"), lit("two"), lit("two")]);
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        jaccard_similarity(col("list"), other),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 0.5  |",
+            "| 0.25 |",
+            "| 0.0  |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_reduce() {
+    let lambda = binary_expr(
+        col(LIST_REDUCE_ACC),
+        Operator::StringConcat,
+        col(LIST_REDUCE_ELEM),
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        list_reduce(col("list"), lit(""), lambda),
+        vec![
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| one          |",
+            "| twothreefour |",
+            "| five         |",
+            "+--------------+",
+        ],
+    );
+
+    // NULL elements are skipped
+    let lambda = col(LIST_REDUCE_ACC) + col(LIST_REDUCE_ELEM);
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        list_reduce(make_array(vec![col("i"), lit(1i64)]), lit(0i64), lambda),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 11   |",
+            "| 1    |",
+            "| 6    |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_list_reduce_lambda_type() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let err = SessionContext::new()
+        .create_physical_expr(
+            list_reduce(col("list"), lit(0i64), col(LIST_REDUCE_ELEM)),
+            &df_schema,
+        )
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "list_reduce lambda must return the type of the initial value Int64, got Utf8"
+    );
+}
+
+#[test]
+fn test_list_take_while() {
+    // length returns Int32 for Utf8
+    let lambda = character_length(col(LIST_TAKE_WHILE_ELEM)).eq(lit(3i32));
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        list_take_while(col("list"), lambda),
+        vec![
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| [one] |",
+            "| [two] |",
+            "| []    |",
+            "+-------+",
+        ],
+    );
+
+    // a list whose elements all pass is kept, and a NULL predicate ends the
+    // prefix like a false one
+    let lambda = col(LIST_TAKE_WHILE_ELEM).gt(lit(0i64));
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        list_take_while(make_array(vec![col("i"), lit(1i64)]), lambda.clone()),
+        vec![
+            "+---------+",
+            "| expr    |",
+            "+---------+",
+            "| [10, 1] |",
+            "| []      |",
+            "| [5, 1]  |",
+            "+---------+",
+        ],
+    );
+    // an empty list stays empty
+    let empty = cast(
+        make_array(vec![]),
+        DataType::new_list(DataType::Int64, true),
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        list_take_while(empty, lambda),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| []   |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_array_flatten_n() -> Result<()> {
+    // [[[1, 2], [3]], [[4]]], [[[5]], NULL, [NULL, [6]]] and NULL
+    let mut builder =
+        ListBuilder::new(ListBuilder::new(ListBuilder::new(Int64Builder::new())));
+    builder.append_value([
+        Some(vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(3)])]),
+        Some(vec![Some(vec![Some(4)])]),
+    ]);
+    builder.append_value([
+        Some(vec![Some(vec![Some(5)])]),
+        None,
+        Some(vec![None, Some(vec![Some(6)])]),
+    ]);
+    builder.append_null();
+    let nested: ArrayRef = Arc::new(builder.finish());
+    let batch = RecordBatch::try_from_iter(vec![("nested", nested)])?;
+
+    // the NULL list of lists is dropped
+    let result = evaluate_batch(array_flatten_n(col("nested"), lit(1i64)), &batch)?;
+    #[rustfmt::skip]
+    assert_eq!(
+        pretty_format_columns("expr", &[result])?.to_string(),
+        [
+            "+--------------------+",
+            "| expr               |",
+            "+--------------------+",
+            "| [[1, 2], [3], [4]] |",
+            "| [[5], , [6]]       |",
+            "|                    |",
+            "+--------------------+",
+        ]
+        .join("\n")
+    );
+
+    // and so are the NULL lists
+    let result = evaluate_batch(array_flatten_n(col("nested"), lit(2i64)), &batch)?;
+    #[rustfmt::skip]
+    assert_eq!(
+        pretty_format_columns("expr", &[result])?.to_string(),
+        [
+            "+--------------+",
+            "| expr         |",
+            "+--------------+",
+            "| [1, 2, 3, 4] |",
+            "| [5, 6]       |",
+            "|              |",
+            "+--------------+",
+        ]
+        .join("\n")
+    );
+
+    // flattening deeper than the nesting flattens fully
+    let deeper = evaluate_batch(array_flatten_n(col("nested"), lit(5i64)), &batch)?;
+    let result = evaluate_batch(array_flatten_n(col("nested"), lit(2i64)), &batch)?;
+    assert_eq!(&deeper, &result);
+    Ok(())
+}
+
+#[test]
+fn test_array_sort_by() {
+    let values = make_array(vec![lit("a"), lit("b"), lit("c")]);
+    // i is 10, NULL and 5
+    let keys = make_array(vec![lit(7i64), col("i"), lit(6i64)]);
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        array_sort_by(values.clone(), keys.clone(), lit("ASC"), lit("NULLS LAST")),
+        vec![
+            "+-----------+",
+            "| expr      |",
+            "+-----------+",
+            "| [c, a, b] |",
+            "| [c, a, b] |",
+            "| [b, c, a] |",
+            "+-----------+",
+        ],
+    );
+
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        array_sort_by(values, keys, lit("DESC"), lit("NULLS FIRST")),
+        vec![
+            "+-----------+",
+            "| expr      |",
+            "+-----------+",
+            "| [b, a, c] |",
+            "| [b, a, c] |",
+            "| [a, c, b] |",
+            "+-----------+",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn test_grouped_aggregate_ext_order_by() {
+    let agg = first_value_udaf()
+        .call(vec![col("props")])
+        .order_by(vec![col("i").sort(false, true)])
+        .build()
+        .unwrap()
+        .alias("first_props");
+
+    evaluate_grouped_agg_test(
+        TEST_BATCH.clone(),
+        vec![col("id")],
+        agg,
+        vec![
+            "+----+-----------------+",
+            "| id | first_props     |",
+            "+----+-----------------+",
+            "| 1  | {a: 2021-02-01} |",
+            "| 2  | {a: 2021-02-02} |",
+            "| 3  | {a: 2021-02-03} |",
+            "+----+-----------------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_unnest_list() {
+    // ["one"] ["two", "three", "four"] ["five"], with an empty list appended
+    let empty = string_list_batch(vec![vec![]]);
+    let batch = RecordBatch::try_from_iter(vec![
+        ("id", Arc::new(StringArray::from(vec!["4"])) as ArrayRef),
+        ("list", Arc::clone(empty.column(0))),
+    ])
+    .unwrap();
+    let df = SessionContext::new()
+        .read_batch(TEST_BATCH.clone())
+        .unwrap()
+        .select_columns(&["id", "list"])
+        .unwrap()
+        .union(SessionContext::new().read_batch(batch).unwrap())
+        .unwrap();
+
+    // the elements are in order, with the other columns repeated, and the
+    // empty list is dropped
+    let results = df.clone().unnest_column("list").unwrap().collect().await;
+    assert_eq!(
+        pretty_format_batches(&results.unwrap())
+            .unwrap()
+            .to_string(),
+        [
+            "+----+-------+",
+            "| id | list  |",
+            "+----+-------+",
+            "| 1  | one   |",
+            "| 2  | two   |",
+            "| 2  | three |",
+            "| 2  | four  |",
+            "| 3  | five  |",
+            "+----+-------+",
+        ]
+        .join("\n")
+    );
+
+    // unless empty lists are preserved
+    let options = UnnestOptions::new().with_preserve_empty_lists(true);
+    let results = df
+        .unnest_column_with_options("list", options)
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(
+        pretty_format_batches(&results.unwrap())
+            .unwrap()
+            .to_string(),
+        [
+            "+----+-------+",
+            "| id | list  |",
+            "+----+-------+",
+            "| 1  | one   |",
+            "| 2  | two   |",
+            "| 2  | three |",
+            "| 2  | four  |",
+            "| 3  | five  |",
+            "| 4  |       |",
+            "+----+-------+",
+        ]
+        .join("\n")
+    );
+}
+
+#[tokio::test]
+async fn test_grouped_aggregate_empty_groups() {
+    evaluate_grouped_agg_test(
+        TEST_BATCH.slice(0, 0),
+        vec![col("id")],
+        sum_udaf().call(vec![col("i")]).alias("sum_i"),
+        vec![
+            "+----+-------+",
+            "| id | sum_i |",
+            "+----+-------+",
+            "+----+-------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_grouped_aggregate_sorted_by_key() {
+    // groups are returned in key order, with NULL keys first
+    evaluate_grouped_agg_test(
+        TEST_BATCH.clone(),
+        vec![col("i").gt(lit(5)).alias("big")],
+        count_udaf().call(vec![col("id")]).alias("count"),
+        vec![
+            "+-------+-------+",
+            "| big   | count |",
+            "+-------+-------+",
+            "|       | 1     |",
+            "| false | 1     |",
+            "| true  | 1     |",
+            "+-------+-------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_grouped_aggregate_date_bin_gapfill() {
+    // there is no data for the 02:00 bucket, nor for most buckets of tag b
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "tag",
+            Arc::new(StringArray::from(vec!["a", "a", "b", "a"])) as ArrayRef,
+        ),
+        (
+            "time",
+            Arc::new(TimestampNanosecondArray::from(vec![
+                Some(600_000_000_000),
+                Some(4_800_000_000_000),
+                Some(6_000_000_000_000),
+                Some(11_100_000_000_000),
+            ])) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+    let one_hour = lit(ScalarValue::new_interval_mdn(0, 0, 3_600_000_000_000));
+
+    evaluate_grouped_agg_test(
+        batch.clone(),
+        vec![
+            col("tag"),
+            date_bin_gapfill(one_hour.clone(), col("time"), lit("1970-01-01T00:00:00"))
+                .alias("hour"),
+        ],
+        count_udaf().call(vec![col("time")]).alias("count"),
+        vec![
+            "+-----+---------------------+-------+",
+            "| tag | hour                | count |",
+            "+-----+---------------------+-------+",
+            "| a   | 1970-01-01T00:00:00 | 1     |",
+            "| a   | 1970-01-01T01:00:00 | 1     |",
+            "| a   | 1970-01-01T02:00:00 |       |",
+            "| a   | 1970-01-01T03:00:00 | 1     |",
+            "| b   | 1970-01-01T00:00:00 |       |",
+            "| b   | 1970-01-01T01:00:00 | 1     |",
+            "| b   | 1970-01-01T02:00:00 |       |",
+            "| b   | 1970-01-01T03:00:00 |       |",
+            "+-----+---------------------+-------+",
+        ],
+    )
+    .await;
+
+    // date_bin_gapfill requires a grouping context
+    let err = SessionContext::new()
+        .read_batch(batch)
+        .unwrap()
+        .select(vec![date_bin_gapfill(
+            one_hour,
+            col("time"),
+            lit("1970-01-01T00:00:00"),
+        )])
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+    assert_contains!(
+        err.strip_backtrace(),
+        "date_bin_gapfill can only be used as a GROUP BY expression of an aggregate"
+    );
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_order_by() {
+    let agg = first_value_udaf().call(vec![col("props")]);
+
+    // ORDER BY id ASC
+    let agg_asc = agg
+        .clone()
+        .order_by(vec![col("id").sort(true, true)])
+        .build()
+        .unwrap()
+        .alias("asc");
+
+    // ORDER BY id DESC
+    let agg_desc = agg
+        .order_by(vec![col("id").sort(false, true)])
+        .build()
+        .unwrap()
+        .alias("desc");
+
+    evaluate_agg_test(
+        agg_asc,
+        vec![
+            "+-----------------+",
+            "| asc             |",
+            "+-----------------+",
+            "| {a: 2021-02-01} |",
+            "+-----------------+",
+        ],
+    )
+    .await;
+
+    evaluate_agg_test(
+        agg_desc,
+        vec![
+            "+-----------------+",
+            "| desc            |",
+            "+-----------------+",
+            "| {a: 2021-02-03} |",
+            "+-----------------+",
+        ],
+    )
+    .await;
+}
+
+#[test]
+fn test_semantic_eq() -> Result<()> {
+    let agg = first_value_udaf().call(vec![col("props")]);
+    let agg_asc = agg
+        .clone()
+        .order_by(vec![col("id").sort(true, true)])
+        .build()?;
+    let agg_desc = agg.order_by(vec![col("id").sort(false, true)]).build()?;
+
+    // the aliases alone do not matter
+    assert!(agg_asc.clone().alias("asc").semantic_eq(&agg_asc));
+    assert!(agg_asc
+        .clone()
+        .alias("asc")
+        .semantic_eq(&agg_asc.clone().alias("other")));
+    // but the order direction does
+    assert!(!agg_asc.clone().alias("asc").semantic_eq(&agg_desc.clone()));
+    assert!(!agg_asc
+        .clone()
+        .alias("x")
+        .semantic_eq(&agg_desc.clone().alias("x")));
+
+    // nested aliases and their metadata are ignored too
+    let metadata = std::collections::HashMap::from([("k".into(), "v".into())]);
+    let expr = (col("i").alias_with_metadata("x", Some(metadata)) + lit(1i64))
+        .gt(col("i").alias("y"))
+        .alias("z");
+    assert!(expr.semantic_eq(&(col("i") + lit(1i64)).gt(col("i"))));
+    assert!(!expr.semantic_eq(&(col("i") + lit(2i64)).gt(col("i"))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schema_name() -> Result<()> {
+    let first_props = first_value_udaf()
+        .call(vec![col("t.props")])
+        .order_by(vec![col("t.id").sort(true, false)])
+        .build()?;
+    let aggs = vec![
+        first_props.clone(),
+        first_props.alias("first_props"),
+        sum_udaf()
+            .call(vec![cast(col("t.i"), DataType::Float64)])
+            .filter(col("t.i").gt(lit(5)))
+            .build()?,
+        count_udaf().call(vec![col("t.id")]).distinct().build()?,
+    ];
+
+    let names: Vec<_> = aggs.iter().map(|e| e.schema_name().to_string()).collect();
+    assert_eq!(
+        names,
+        [
+            "first_value(t.props) ORDER BY [t.id ASC NULLS LAST]",
+            "first_props",
+            "sum(t.i) FILTER (WHERE t.i > Int32(5))",
+            "count(DISTINCT t.id)",
+        ]
+    );
+
+    // the names are the ones used in the schema of the plan
+    let ctx = SessionContext::new();
+    ctx.register_batch("t", TEST_BATCH.clone())?;
+    let df = ctx.table("t").await?.aggregate(vec![], aggs)?;
+    let field_names: Vec<_> = df.schema().fields().iter().map(|f| f.name()).collect();
+    assert_eq!(names.iter().collect::<Vec<_>>(), field_names);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_alias_qualified() -> Result<()> {
+    // the same name can be used with different qualifiers
+    let df = SessionContext::new()
+        .read_batch(TEST_BATCH.clone())?
+        .select(vec![
+            col("id").with_alias_qualified("t1", "id")?,
+            col("i").with_alias_qualified("t2", "id")?,
+        ])?;
+    let columns = df.schema().columns();
+    assert_eq!(columns[0].to_string(), "t1.id");
+    assert_eq!(columns[1].to_string(), "t2.id");
+
+    // the qualified columns can be referenced by later plans
+    let df = df.select(vec![col("t2.id")])?;
+    assert_eq!(df.schema().field(0).name(), "id");
+    let result = df.collect().await?;
+    datafusion::assert_batches_eq!(
+        ["+----+", "| id |", "+----+", "| 10 |", "|    |", "| 5  |", "+----+"],
+        &result
+    );
+
+    let err = col("id")
+        .alias_qualified(Some("t1"), "x")
+        .with_alias_qualified("t2", "x")
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Can not alias id AS t1.x with qualifier t2"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_order_by_expr() {
+    let agg = first_value_udaf().call(vec![col("props")]);
+    // "1-10", NULL, "3-5"
+    let key = binary_expr(
+        binary_expr(col("id"), Operator::StringConcat, lit("-")),
+        Operator::StringConcat,
+        col("i"),
+    );
+
+    // ORDER BY id || '-' || i ASC NULLS FIRST
+    let agg_nulls_first = agg
+        .clone()
+        .order_by(vec![key.clone().sort(true, true)])
+        .build()
+        .unwrap()
+        .alias("nulls_first");
+
+    // ORDER BY id || '-' || i DESC NULLS LAST
+    let agg_desc = agg
+        .order_by(vec![key.sort(false, false)])
+        .build()
+        .unwrap()
+        .alias("desc");
+
+    evaluate_agg_test(
+        agg_nulls_first,
+        vec![
+            "+-----------------+",
+            "| nulls_first     |",
+            "+-----------------+",
+            "| {a: 2021-02-02} |",
+            "+-----------------+",
+        ],
+    )
+    .await;
+
+    evaluate_agg_test(
+        agg_desc,
+        vec![
+            "+-----------------+",
+            "| desc            |",
+            "+-----------------+",
+            "| {a: 2021-02-03} |",
+            "+-----------------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_order_by_with_nulls() {
+    let agg = first_value_udaf().call(vec![col("i")]);
+
+    // ORDER BY i ASC NULLS FIRST
+    let agg_nulls_first = agg
+        .clone()
+        .order_by_with_nulls(vec![(col("i"), true, NullsPlacement::First)])
+        .build()
+        .unwrap()
+        .alias("nulls_first");
+
+    // ORDER BY i ASC NULLS LAST
+    let agg_nulls_last = agg
+        .order_by_with_nulls(vec![(col("i"), true, NullsPlacement::Last)])
+        .build()
+        .unwrap()
+        .alias("nulls_last");
+
+    evaluate_agg_test(
+        agg_nulls_first,
+        vec![
+            "+-------------+",
+            "| nulls_first |",
+            "+-------------+",
+            "|             |",
+            "+-------------+",
+        ],
+    )
+    .await;
+
+    evaluate_agg_test(
+        agg_nulls_last,
+        vec![
+            "+------------+",
+            "| nulls_last |",
+            "+------------+",
+            "| 5          |",
+            "+------------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_order_by_using() -> Result<()> {
+    // ORDER BY i USING > NULLS LAST
+    let agg = array_agg_udaf()
+        .call(vec![col("i")])
+        .order_by(vec![col("i").sort_using(Operator::Gt, false)?])
+        .build()?
+        .alias("using_gt");
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-----------+",
+            "| using_gt  |",
+            "+-----------+",
+            "| [10, 5, ] |",
+            "+-----------+",
+        ],
+    )
+    .await;
+
+    // ORDER BY i USING < NULLS FIRST
+    let agg = array_agg_udaf()
+        .call(vec![col("i")])
+        .order_by(vec![col("i").sort_using(Operator::Lt, true)?])
+        .build()?
+        .alias("using_lt");
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-----------+",
+            "| using_lt  |",
+            "+-----------+",
+            "| [, 5, 10] |",
+            "+-----------+",
+        ],
+    )
+    .await;
+
+    let err = col("i").sort_using(Operator::GtEq, false).unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "ORDER BY USING requires an ordering operator, < or >, but got >="
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_filter() {
+    let agg = first_value_udaf()
+        .call(vec![col("i")])
+        .order_by(vec![col("i").sort(true, true)])
+        .filter(col("i").is_not_null())
+        .build()
+        .unwrap()
+        .alias("val");
+
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-----+",
+            "| val |",
+            "+-----+",
+            "| 5   |",
+            "+-----+",
+        ],
+    )
+        .await;
+}
+
+#[tokio::test]
+async fn test_bitwise_aggregates() -> Result<()> {
+    let run = |agg: Expr, expected: &'static str| async move {
+        let expected = format!("| {expected:<4} |");
+        evaluate_agg_test(
+            agg.alias("bits"),
+            vec!["+------+", "| bits |", "+------+", &expected, "+------+"],
+        )
+        .await
+    };
+
+    // i is [10, NULL, 5], and NULLs are ignored
+    run(bit_and(col("i")), "0").await;
+    run(bit_or(col("i")), "15").await;
+    run(bit_xor(col("i")), "15").await;
+
+    run(bit_and(col("i")).filter(col("i").gt(lit(5))).build()?, "10").await;
+    run(bit_or(col("i")).filter(col("i").lt(lit(10))).build()?, "5").await;
+    run(bit_xor(col("i")).filter(col("i").gt(lit(10))).build()?, "").await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_geometric_and_harmonic_mean() -> Result<()> {
+    let batch = RecordBatch::try_from_iter(vec![(
+        "v",
+        Arc::new(Int64Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(4),
+            Some(8),
+        ])) as ArrayRef,
+    )])?;
+    let results = SessionContext::new()
+        .read_batch(batch)?
+        .aggregate(
+            vec![],
+            vec![geometric_mean(col("v")), harmonic_mean(col("v"))],
+        )?
+        .collect()
+        .await?;
+    let means = results[0]
+        .columns()
+        .iter()
+        .map(|column| as_float64_array(column).map(|means| means.value(0)))
+        .collect::<Result<Vec<_>>>()?;
+
+    // the NULL is ignored, so the means are 64 ^ (1/4) and 4 / (15/8)
+    let expected = [64f64.powf(0.25), 4.0 / 1.875];
+    for (mean, expected) in means.into_iter().zip(expected) {
+        assert!((mean - expected).abs() < 1e-9, "{mean} != {expected}");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_distinct() {
+    let agg = sum_udaf()
+        .call(vec![lit(5)])
+        // distinct sum should be 5, not 15
+        .distinct()
+        .build()
+        .unwrap()
+        .alias("distinct");
+
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+----------+",
+            "| distinct |",
+            "+----------+",
+            "| 5        |",
+            "+----------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_distinct_order_by() {
+    // i is [10, NULL, 5], so the values are [10, 5, 5]
+    let value = coalesce(vec![col("i"), lit(5)]);
+    let agg = array_agg_udaf()
+        .call(vec![value.clone()])
+        .distinct()
+        .order_by(vec![value.clone().sort(true, true)])
+        .build()
+        .unwrap()
+        .alias("distinct_asc");
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+--------------+",
+            "| distinct_asc |",
+            "+--------------+",
+            "| [5, 10]      |",
+            "+--------------+",
+        ],
+    )
+    .await;
+
+    let agg = array_agg_udaf()
+        .call(vec![value.clone()])
+        .order_by(vec![value.sort(false, true)])
+        .distinct()
+        .build()
+        .unwrap()
+        .alias("distinct_desc");
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+---------------+",
+            "| distinct_desc |",
+            "+---------------+",
+            "| [10, 5]       |",
+            "+---------------+",
+        ],
+    )
+    .await;
+
+    // the order of distinct values is only defined by their own value
+    let err = array_agg_udaf()
+        .call(vec![col("i")])
+        .distinct()
+        .order_by(vec![col("id").sort(true, true)])
+        .build()
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "In an aggregate with DISTINCT, ORDER BY expressions must appear in argument list"
+    );
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_distinct_sorted() {
+    // i is [10, NULL, 5], so the values are [10, 5, 5]
+    let agg = array_agg_udaf()
+        .call(vec![coalesce(vec![col("i"), lit(5)])])
+        .distinct()
+        .build()
+        .unwrap()
+        .alias("distinct");
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+----------+",
+            "| distinct |",
+            "+----------+",
+            "| [5, 10]  |",
+            "+----------+",
+        ],
+    )
+    .await;
+
+    // without an ORDER BY, distinct values are sorted with NULLs last
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "g",
+            Arc::new(StringArray::from(vec!["a", "b", "a", "a", "b", "a"])) as ArrayRef,
+        ),
+        (
+            "v",
+            Arc::new(StringArray::from(vec![
+                Some("z"),
+                Some("x"),
+                None,
+                Some("z"),
+                Some("x"),
+                Some("y"),
+            ])) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+    let agg = array_agg_udaf()
+        .call(vec![col("v")])
+        .distinct()
+        .build()
+        .unwrap()
+        .alias("distinct");
+    let expected = vec![
+        "+---+----------+",
+        "| g | distinct |",
+        "+---+----------+",
+        "| a | [y, z, ] |",
+        "| b | [x]      |",
+        "+---+----------+",
+    ];
+    evaluate_grouped_agg_test(
+        batch.clone(),
+        vec![col("g")],
+        agg.clone(),
+        expected.clone(),
+    )
+    .await;
+
+    // the result does not depend on how the input is partitioned
+    let rows = (0..batch.num_rows())
+        .map(|i| vec![batch.slice(i, 1)])
+        .collect::<Vec<_>>();
+    for partitions in [rows.clone(), rows.into_iter().rev().collect()] {
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new().with_target_partitions(4),
+        );
+        let table = datafusion::datasource::MemTable::try_new(batch.schema(), partitions)
+            .unwrap();
+        let results = ctx
+            .read_table(Arc::new(table))
+            .unwrap()
+            .aggregate(vec![col("g")], vec![agg.clone()])
+            .unwrap()
+            .sort(vec![col("g").sort(true, true)])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        datafusion::assert_batches_eq!(expected, &results);
+    }
+}
+
+#[tokio::test]
+async fn test_flatten_agg() -> Result<()> {
+    // list is [["one"], ["two", "three", "four"], ["five"]]
+    let agg = flatten_agg(col("list")).alias("flat");
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-------------------------------+",
+            "| flat                          |",
+            "+-------------------------------+",
+            "| [one, two, three, four, five] |",
+            "+-------------------------------+",
+        ],
+    )
+    .await;
+
+    let agg = flatten_agg(col("list"))
+        .order_by(vec![col("id").sort(false, true)])
+        .build()?
+        .alias("flat");
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-------------------------------+",
+            "| flat                          |",
+            "+-------------------------------+",
+            "| [five, two, three, four, one] |",
+            "+-------------------------------+",
+        ],
+    )
+    .await;
+
+    let agg = flatten_agg(col("list"))
+        .filter(col("i").is_not_null())
+        .build()?
+        .alias("flat");
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-------------+",
+            "| flat        |",
+            "+-------------+",
+            "| [one, five] |",
+            "+-------------+",
+        ],
+    )
+    .await;
+
+    // NULL lists contribute no elements
+    let null_list = ScalarValue::try_new_null(&DataType::new_list(DataType::Utf8, true))?;
+    let agg = flatten_agg(lit(null_list)).alias("flat");
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+------+",
+            "| flat |",
+            "+------+",
+            "| []   |",
+            "+------+",
+        ],
+    )
+    .await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_string_agg_list() -> Result<()> {
+    // list is [["one"], ["two", "three", "four"], ["five"]]
+    let agg = string_agg_list(col("list"), lit(",")).alias("joined");
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-------------------------+",
+            "| joined                  |",
+            "+-------------------------+",
+            "| one,two,three,four,five |",
+            "+-------------------------+",
+        ],
+    )
+    .await;
+
+    let agg = string_agg_list(col("list"), lit(","))
+        .order_by(vec![col("id").sort(false, true)])
+        .build()?
+        .alias("joined");
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+-------------------------+",
+            "| joined                  |",
+            "+-------------------------+",
+            "| five,two,three,four,one |",
+            "+-------------------------+",
+        ],
+    )
+    .await;
+
+    // NULL elements and lists are skipped
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    builder.append_value([Some("a"), None]);
+    builder.append_null();
+    builder.append_value([None, Some("b")]);
+    let batch =
+        RecordBatch::try_from_iter(vec![("l", Arc::new(builder.finish()) as ArrayRef)])?;
+    #[rustfmt::skip]
+    evaluate_grouped_agg_test(
+        batch,
+        vec![],
+        string_agg_list(col("l"), lit(" | ")).alias("joined"),
+        vec![
+            "+--------+",
+            "| joined |",
+            "+--------+",
+            "| a | b  |",
+            "+--------+",
+        ],
+    )
+    .await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mode() -> Result<()> {
+    // id is ["1", "2", "3"], so every value is equally frequent
+    #[rustfmt::skip]
+    evaluate_agg_test(
+        mode(col("id")).alias("mode"),
+        vec![
+            "+------+",
+            "| mode |",
+            "+------+",
+            "| 1    |",
+            "+------+",
+        ],
+    )
+    .await;
+
+    // v has a clear mode of 3, but NULL is even more frequent. Every value in
+    // w is equally frequent, and 2 is seen first
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "v",
+            Arc::new(Int64Array::from(vec![
+                Some(2),
+                Some(3),
+                Some(3),
+                None,
+                None,
+                None,
+                None,
+                Some(1),
+                Some(2),
+                Some(3),
+            ])) as ArrayRef,
+        ),
+        (
+            "w",
+            Arc::new(Int64Array::from(vec![
+                Some(2),
+                Some(3),
+                Some(1),
+                Some(3),
+                Some(1),
+                Some(2),
+                None,
+                None,
+                None,
+                None,
+            ])) as ArrayRef,
+        ),
+    ])?;
+    let run = |agg: Expr, expected: &'static str| {
+        let batch = batch.clone();
+        async move {
+            let expected = format!("| {expected:<4} |");
+            evaluate_grouped_agg_test(
+                batch,
+                vec![],
+                agg.alias("mode"),
+                vec!["+------+", "| mode |", "+------+", &expected, "+------+"],
+            )
+            .await
+        }
+    };
+
+    run(mode(col("v")), "3").await;
+    run(mode(col("v")).filter(col("v").lt(lit(3))).build()?, "2").await;
+    run(
+        mode(col("v"))
+            .null_treatment(NullTreatment::RespectNulls)
+            .build()?,
+        "",
+    )
+    .await;
+
+    for (tie_break, expected) in [
+        (ModeTieBreak::Smallest, "1"),
+        (ModeTieBreak::Largest, "3"),
+        (ModeTieBreak::FirstSeen, "2"),
+    ] {
+        let mode = AggregateUDF::new_from_impl(Mode::new().with_tie_break(tie_break));
+        run(mode.call(vec![col("w")]), expected).await;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_covariance_and_correlation_null_pairs() -> Result<()> {
+    // the pair (NULL, 50) in group a and the pair (4, NULL) in group b are
+    // ignored, which leaves the pairs (1, 2) and (3, 6) in group a
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "g",
+            Arc::new(StringArray::from(vec!["a", "a", "a", "b"])) as ArrayRef,
+        ),
+        (
+            "i",
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(3), Some(4)])) as ArrayRef,
+        ),
+        (
+            "j",
+            Arc::new(Int64Array::from(vec![Some(2), Some(50), Some(6), None]))
+                as ArrayRef,
+        ),
+    ])?;
+
+    // the deviations from the means 2 and 4 are (-1, -2) and (1, 2), so the
+    // sum of their products is 4, divided by n - 1 = 1 for the sample
+    // covariance and by n = 2 for the population covariance
+    for (agg, expected) in [
+        (covar_samp(col("i"), col("j")), "4.0"),
+        (covar_pop(col("i"), col("j")), "2.0"),
+        (corr(col("i"), col("j")), "1.0"),
+    ] {
+        let expected = format!("| a | {expected:<3} |");
+        evaluate_grouped_agg_test(
+            batch.clone(),
+            vec![col("g")],
+            agg.alias("s"),
+            vec![
+                "+---+-----+",
+                "| g | s   |",
+                "+---+-----+",
+                &expected,
+                "| b |     |",
+                "+---+-----+",
+            ],
+        )
+        .await;
+    }
+
+    // a single pair has a population covariance, but no sample covariance
+    let batch = batch.slice(0, 2);
+    #[rustfmt::skip]
+    evaluate_grouped_agg_test(
+        batch.clone(),
+        vec![],
+        covar_pop(col("i"), col("j")).alias("s"),
+        vec!["+-----+", "| s   |", "+-----+", "| 0.0 |", "+-----+"],
+    )
+    .await;
+    #[rustfmt::skip]
+    evaluate_grouped_agg_test(
+        batch,
+        vec![],
+        covar_samp(col("i"), col("j")).alias("s"),
+        vec!["+---+", "| s |", "+---+", "|   |", "+---+"],
+    )
+    .await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sum_overflow() -> Result<()> {
+    // the sum of "a" fits in an Int64, "b" is below i64::MIN and "c" above i64::MAX
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "g",
+            Arc::new(StringArray::from(vec!["a", "a", "a", "b", "b", "c", "c"]))
+                as ArrayRef,
+        ),
+        (
+            "v",
+            Arc::new(Int64Array::from(vec![
+                i64::MAX - 10,
+                20,
+                -15,
+                i64::MIN,
+                -1,
+                i64::MAX,
+                1,
+            ])) as ArrayRef,
+        ),
+    ])?;
+    let sum = |overflow: OverflowMode| {
+        sum_udaf()
+            .call(vec![col("v")])
+            .with_overflow(overflow)
+            .alias("s")
+    };
+
+    // by default the sums wrap around
+    evaluate_grouped_agg_test(
+        batch.clone(),
+        vec![col("g")],
+        sum(OverflowMode::Wrapping),
+        vec![
+            "+---+----------------------+",
+            "| g | s                    |",
+            "+---+----------------------+",
+            "| a | 9223372036854775802  |",
+            "| b | 9223372036854775807  |",
+            "| c | -9223372036854775808 |",
+            "+---+----------------------+",
+        ],
+    )
+    .await;
+
+    // only the final sums are clamped, so the result does not depend on the
+    // order in which the values are added or on how the input is partitioned
+    let expected = vec![
+        "+---+----------------------+",
+        "| g | s                    |",
+        "+---+----------------------+",
+        "| a | 9223372036854775802  |",
+        "| b | -9223372036854775808 |",
+        "| c | 9223372036854775807  |",
+        "+---+----------------------+",
+    ];
+    evaluate_grouped_agg_test(
+        batch.clone(),
+        vec![col("g")],
+        sum(OverflowMode::Saturating),
+        expected.clone(),
+    )
+    .await;
+    let rows = (0..batch.num_rows())
+        .map(|i| vec![batch.slice(i, 1)])
+        .collect::<Vec<_>>();
+    for partitions in [rows.clone(), rows.into_iter().rev().collect()] {
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new().with_target_partitions(4),
+        );
+        let table =
+            datafusion::datasource::MemTable::try_new(batch.schema(), partitions)?;
+        let results = ctx
+            .read_table(Arc::new(table))?
+            .aggregate(vec![col("g")], vec![sum(OverflowMode::Saturating)])?
+            .sort(vec![col("g").sort(true, true)])?
+            .collect()
+            .await?;
+        datafusion::assert_batches_eq!(expected, &results);
+    }
+
+    let err = SessionContext::new()
+        .read_batch(batch)?
+        .filter(col("g").eq(lit("a")))?
+        .aggregate(vec![], vec![sum(OverflowMode::Checked)])?
+        .collect()
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Execution error: Sum overflowed Int64 adding 20 to the sum so far of about 9223372036854775797"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_with_precision() {
+    let agg = approx_distinct_udaf()
+        .call(vec![col("id")])
+        .with_precision(14)
+        .build()
+        .unwrap()
+        .alias("approx");
+
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+--------+",
+            "| approx |",
+            "+--------+",
+            "| 3      |",
+            "+--------+",
+        ],
+    )
+    .await;
+
+    // composes with filter, and out of range precisions are clamped
+    let agg = approx_distinct_udaf()
+        .call(vec![col("id")])
+        .filter(col("i").is_not_null())
+        .with_precision(30)
+        .build()
+        .unwrap()
+        .alias("approx");
+
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+--------+",
+            "| approx |",
+            "+--------+",
+            "| 2      |",
+            "+--------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_within_group() {
+    // median of i, which is 10, NULL and 5. The result has the type of i, so
+    // 7.5 is truncated
+    let agg = approx_percentile_cont_udaf()
+        .call(vec![lit(0.5)])
+        .within_group(vec![col("i").sort(true, true)])
+        .build()
+        .unwrap()
+        .alias("median");
+
+    evaluate_agg_test(
+        agg,
+        vec![
+            "+--------+",
+            "| median |",
+            "+--------+",
+            "| 7      |",
+            "+--------+",
+        ],
+    )
+    .await;
+
+    let err = sum_udaf()
+        .call(vec![col("i")])
+        .within_group(vec![col("i").sort(true, true)])
+        .build()
+        .unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "WITHIN GROUP is only permitted for ordered-set aggregate functions, but sum is not one"
+    );
+}
+
+#[tokio::test]
+async fn test_bitmap_agg_contains() {
+    // i is 10, NULL and 5
+    let df = SessionContext::new()
+        .read_batch(TEST_BATCH.clone())
+        .unwrap()
+        .aggregate(vec![], vec![bitmap_agg(col("i")).alias("bitmap")])
+        .unwrap()
+        .select(vec![
+            bitmap_contains(col("bitmap"), lit(5)).alias("has_5"),
+            bitmap_contains(col("bitmap"), lit(7)).alias("has_7"),
+            bitmap_contains(col("bitmap"), lit(ScalarValue::Int64(None)))
+                .alias("has_null"),
+        ])
+        .unwrap();
+
+    let result = pretty_format_batches(&df.collect().await.unwrap())
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        result.lines().collect::<Vec<_>>(),
+        vec![
+            "+-------+-------+----------+",
+            "| has_5 | has_7 | has_null |",
+            "+-------+-------+----------+",
+            "| true  | false |          |",
+            "+-------+-------+----------+",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_null_treatment() {
+    let agg = first_value_udaf()
+        .call(vec![col("i")])
+        .order_by(vec![col("i").sort(true, true)]);
+
+    let agg_respect = agg
+        .clone()
+        .null_treatment(NullTreatment::RespectNulls)
+        .build()
+        .unwrap()
+        .alias("respect");
+
+    let agg_ignore = agg
+        .null_treatment(NullTreatment::IgnoreNulls)
+        .build()
+        .unwrap()
+        .alias("ignore");
+
+    evaluate_agg_test(
+        agg_respect,
+        vec![
+            "+---------+",
+            "| respect |",
+            "+---------+",
+            "|         |",
+            "+---------+",
+        ],
+    )
+    .await;
+
+    evaluate_agg_test(
+        agg_ignore,
+        vec![
+            "+--------+",
+            "| ignore |",
+            "+--------+",
+            "| 5      |",
+            "+--------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_null_treatment_sum_avg() {
+    // sum and avg already skip nulls, so IGNORE NULLS is accepted
+    let agg_sum = sum_udaf()
+        .call(vec![col("i")])
+        .null_treatment(NullTreatment::IgnoreNulls)
+        .build()
+        .unwrap()
+        .alias("ignore");
+
+    evaluate_agg_test(
+        agg_sum,
+        vec![
+            "+--------+",
+            "| ignore |",
+            "+--------+",
+            "| 15     |",
+            "+--------+",
+        ],
+    )
+    .await;
+
+    let agg_avg = avg_udaf()
+        .call(vec![col("i")])
+        .null_treatment(NullTreatment::IgnoreNulls)
+        .build()
+        .unwrap()
+        .alias("ignore");
+
+    evaluate_agg_test(
+        agg_avg,
+        vec![
+            "+--------+",
+            "| ignore |",
+            "+--------+",
+            "| 7.5    |",
+            "+--------+",
+        ],
+    )
+    .await;
+
+    // but RESPECT NULLS is not supported, rather than being silently ignored
+    for udaf in [sum_udaf(), avg_udaf()] {
+        let err = udaf
+            .call(vec![col("i")])
+            .null_treatment(NullTreatment::RespectNulls)
+            .build()
+            .unwrap_err();
+        assert_contains!(err.to_string(), "RESPECT NULLS is not permitted for");
+    }
+}
+
+#[tokio::test]
+async fn test_aggregate_ext_null_treatment_median() {
+    // the median of the non-null values 10 and 5 is interpolated for integers
+    let agg_ignore = median_udaf()
+        .call(vec![col("i")])
+        .null_treatment(NullTreatment::IgnoreNulls)
+        .build()
+        .unwrap()
+        .alias("ignore");
+
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    assert_eq!(agg_ignore.get_type(&df_schema).unwrap(), DataType::Float64);
+
+    evaluate_agg_test(
+        agg_ignore,
+        vec![
+            "+--------+",
+            "| ignore |",
+            "+--------+",
+            "| 7.5    |",
+            "+--------+",
+        ],
+    )
+    .await;
+
+    let err = median_udaf()
+        .call(vec![col("i")])
+        .null_treatment(NullTreatment::RespectNulls)
+        .build()
+        .unwrap_err();
+    assert_contains!(err.to_string(), "RESPECT NULLS is not permitted for");
+}
+
+#[tokio::test]
+async fn test_create_physical_expr() {
+    // create_physical_expr does not simplify the expression
+    // 1 + 1
+    create_expr_test(lit(1i32) + lit(2i32), "1 + 2");
+    // However, you can run the simplifier before creating the physical
+    // expression. This mimics what delta.rs and other non-sql libraries do to
+    // create predicates
+    //
+    // 1 + 1
+    create_simplified_expr_test(lit(1i32) + lit(2i32), "3");
+}
+
+#[test]
+fn test_physical_expr_data_type() {
+    // the output type is known before evaluating, and is checked against the
+    // evaluated array in evaluate_expr_test
+    let schema = TEST_BATCH.schema();
+    let df_schema = DFSchema::try_from(Arc::clone(&schema)).unwrap();
+    let ctx = SessionContext::new();
+    for (expr, expected) in [
+        (col("i") + lit(1i32), DataType::Int64),
+        (col("id").eq(lit(1i32)), DataType::Boolean),
+        (col("props").field("a"), DataType::Utf8),
+        (
+            col("list"),
+            DataType::List(Arc::new(Field::new_list_field(DataType::Utf8, true))),
+        ),
+    ] {
+        let physical_expr = ctx.create_physical_expr(expr, &df_schema).unwrap();
+        assert_eq!(physical_expr.data_type(&schema).unwrap(), expected);
+    }
+}
+
+#[tokio::test]
+async fn test_create_physical_expr_coercion() {
+    // create_physical_expr does apply type coercion and unwrapping in cast
+    //
+    // expect the cast on the literals
+    // compare string function to int  `id = 1`
+    create_expr_test(col("id").eq(lit(1i32)), "id@0 = CAST(1 AS Utf8)");
+    create_expr_test(lit(1i32).eq(col("id")), "CAST(1 AS Utf8) = id@0");
+    // compare int col to string literal `i = '202410'`
+    // Note this casts the column (not the field)
+    create_expr_test(col("i").eq(lit("202410")), "CAST(i@1 AS Utf8) = 202410");
+    create_expr_test(lit("202410").eq(col("i")), "202410 = CAST(i@1 AS Utf8)");
+    // however, when simplified the casts on i should removed
+    // https://github.com/apache/datafusion/issues/14944
+    create_simplified_expr_test(col("i").eq(lit("202410")), "CAST(i@1 AS Utf8) = 202410");
+    create_simplified_expr_test(lit("202410").eq(col("i")), "CAST(i@1 AS Utf8) = 202410");
+    // casts to the type i already has are removed when simplified
+    create_simplified_expr_test(cast(col("i"), DataType::Int64), "i@1");
+    create_simplified_expr_test(
+        try_cast(col("i"), DataType::Int64).gt(lit(5i64)),
+        "i@1 > 5",
+    );
+}
+
+#[test]
+fn test_rewrite_cast_to_try_cast() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let ctx = SessionContext::new();
+
+    // the JSON payloads can not be cast to integers
+    let expr = cast(col("id"), DataType::Int64) + cast(col("payload"), DataType::Int64);
+    let err = ctx
+        .create_physical_expr(expr.clone(), &df_schema)
+        .unwrap()
+        .evaluate(&TEST_BATCH)
+        .unwrap_err();
+    assert_contains!(err.to_string(), "Cannot cast string");
+
+    // the rewritten expression has the same type, but returns NULL instead
+    let rewritten = expr.rewrite_cast_to_try_cast();
+    assert_eq!(
+        rewritten.get_type(&df_schema).unwrap(),
+        expr.get_type(&df_schema).unwrap()
+    );
+    create_expr_test(
+        rewritten.clone(),
+        "TRY_CAST(id@0 AS Int64) + TRY_CAST(payload@4 AS Int64)",
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        rewritten,
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "|      |",
+            "|      |",
+            "+------+",
+        ],
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        cast(col("id"), DataType::Int64).rewrite_cast_to_try_cast(),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 1    |",
+            "| 2    |",
+            "| 3    |",
+            "+------+",
+        ],
+    );
+}
+
+#[test]
+fn test_greatest_least() {
+    // the arguments are coerced like the operands of `=`
+    create_expr_test(
+        greatest(vec![col("i"), lit(3i32)]),
+        "greatest(i@1, CAST(3 AS Int64))",
+    );
+    create_expr_test(
+        least(vec![col("i"), lit(3i32), lit("4")]),
+        "least(CAST(i@1 AS Utf8), CAST(3 AS Utf8), 4)",
+    );
+
+    // NULLs are ignored
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        greatest(vec![col("i"), lit(7i64)]),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 10   |",
+            "| 7    |",
+            "| 7    |",
+            "+------+",
+        ],
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        least(vec![col("i"), lit(7i64)]),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 7    |",
+            "| 7    |",
+            "| 5    |",
+            "+------+",
+        ],
+    );
+
+    // unless they are configured to be propagated
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        greatest_propagating_nulls(vec![col("i"), lit(7i64)]),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "| 10   |",
+            "|      |",
+            "| 7    |",
+            "+------+",
+        ],
+    );
+    #[rustfmt::skip]
+    evaluate_expr_test(
+        least_propagating_nulls(vec![lit(ScalarValue::Int64(None)), col("i")]),
+        vec![
+            "+------+",
+            "| expr |",
+            "+------+",
+            "|      |",
+            "+------+",
+        ],
+    );
+    assert_ne!(
+        greatest_propagating_nulls(vec![col("i")]),
+        greatest(vec![col("i")])
+    );
+}
+
+#[test]
+fn test_create_physical_expr_no_coercion() {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let ctx = SessionContext::new();
+    let create = |expr| ctx.create_physical_expr_no_coercion(expr, &df_schema);
+
+    // already type-correct expressions are used as is
+    let expr = create(col("id").eq(lit("1"))).unwrap();
+    assert_eq!(expr.to_string(), "id@0 = 1");
+    let expr = create(cast(col("id"), DataType::Int64).gt(col("i"))).unwrap();
+    assert_eq!(expr.to_string(), "CAST(id@0 AS Int64) > i@1");
+
+    // expressions that create_physical_expr would add casts to are errors
+    let err = create(col("id").eq(lit(1i32))).unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Expression id = Int32(1) does not have the types required by its operators and functions, it would need to be coerced to id = CAST(Int32(1) AS Utf8)"
+    );
+    let err = create(col("i") + lit(1i32)).unwrap_err();
+    assert_contains!(err.to_string(), "i + CAST(Int32(1) AS Int64)");
+}
+
+#[test]
+fn test_create_physical_expr_with_coercion() {
+    /// Never coerces integers to strings
+    #[derive(Debug)]
+    struct NoIntToString;
+
+    impl CoercionRules for NoIntToString {
+        fn coerce(
+            &self,
+            expr: Expr,
+            from: &DataType,
+            to: &DataType,
+            schema: &dyn ExprSchema,
+        ) -> Result<Expr> {
+            if from.is_integer() && to == &DataType::Utf8 {
+                return plan_err!("Can not coerce {expr} from {from} to {to}");
+            }
+            expr.cast_to(to, schema)
+        }
+    }
+
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema()).unwrap();
+    let ctx = SessionContext::new();
+
+    // the default rules are the same as create_physical_expr
+    let expr = ctx
+        .create_physical_expr_with_coercion(
+            col("id").eq(lit(1i32)),
+            &df_schema,
+            &DefaultCoercionRules,
+        )
+        .unwrap();
+    assert_eq!(expr.to_string(), "id@0 = CAST(1 AS Utf8)");
+
+    let create =
+        |expr| ctx.create_physical_expr_with_coercion(expr, &df_schema, &NoIntToString);
+    let err = create(col("id").eq(lit(1i32))).unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Can not coerce Int32(1) from Int32 to Utf8"
+    );
+    let err = create(col("i").eq(lit("202410"))).unwrap_err();
+    assert_contains!(err.to_string(), "Can not coerce i from Int64 to Utf8");
+    let err = create(col("id").in_list(vec![lit("1"), lit(2i32)], false)).unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Can not coerce Int32(2) from Int32 to Utf8"
+    );
+    let err = create(
+        when(col("i").gt(lit(1i64)), col("i"))
+            .otherwise(lit("a"))
+            .unwrap(),
+    )
+    .unwrap_err();
+    assert_contains!(err.to_string(), "Can not coerce i from Int64 to Utf8");
+
+    // other coercions are unchanged
+    let expr = create(col("i") + lit(1i32)).unwrap();
+    assert_eq!(expr.to_string(), "i@1 + CAST(1 AS Int64)");
+    let expr = create(col("id").eq(lit("1"))).unwrap();
+    assert_eq!(expr.to_string(), "id@0 = 1");
+}
+
+#[test]
+fn test_create_physical_sort_expr() {
+    let batch = &TEST_BATCH;
+    let df_schema = DFSchema::try_from(batch.schema()).unwrap();
+    let ctx = SessionContext::new();
+
+    // the sorted expression is coerced, and the sort options are kept
+    let sort_expr = ctx
+        .create_physical_sort_expr((col("i") + lit(1i32)).sort(false, true), &df_schema)
+        .unwrap();
+    assert_eq!(sort_expr.to_string(), "i@1 + CAST(1 AS Int64) DESC");
+    assert_eq!(
+        sort_expr.options,
+        SortOptions {
+            descending: true,
+            nulls_first: true,
+        }
+    );
+
+    // i is 10, NULL and 5
+    let sort_column = sort_expr.evaluate_to_sort_column(batch).unwrap();
+    let indices = lexsort_to_indices(&[sort_column], None).unwrap();
+    assert_eq!(indices.values(), &[1, 0, 2]);
+
+    let sort_expr = ctx
+        .create_physical_sort_expr(col("id").sort(true, false), &df_schema)
+        .unwrap();
+    assert_eq!(sort_expr.to_string(), "id@0 ASC NULLS LAST");
+}
+
+#[test]
+fn test_create_physical_exprs() -> Result<()> {
+    let batch = &TEST_BATCH;
+    let df_schema = DFSchema::try_from(batch.schema())?;
+    let ctx = SessionContext::new();
+
+    // the expressions are planned in order, like one at a time
+    let exprs = vec![
+        col("i").gt(lit(5i32)),
+        col("id").eq(lit("2")),
+        col("i") + lit(1u8),
+    ];
+    let physical_exprs = ctx.create_physical_exprs(exprs.clone(), &df_schema)?;
+    assert_eq!(physical_exprs.len(), exprs.len());
+    for (physical_expr, expr) in physical_exprs.iter().zip(exprs) {
+        let expected = ctx.create_physical_expr(expr, &df_schema)?;
+        assert_eq!(physical_expr.to_string(), expected.to_string());
+    }
+
+    let results = physical_exprs
+        .iter()
+        .map(|expr| expr.evaluate(batch)?.into_array(batch.num_rows()))
+        .collect::<Result<Vec<_>>>()?;
+    #[rustfmt::skip]
+    assert_eq!(
+        pretty_format_columns("expr", &results)?.to_string(),
+        [
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| true  |",
+            "|       |",
+            "| false |",
+            "| false |",
+            "| true  |",
+            "| false |",
+            "| 11    |",
+            "|       |",
+            "| 6     |",
+            "+-------+",
+        ]
+        .join("\n")
+    );
+
+    // the error says which expression could not be planned
+    let exprs = vec![col("i").gt(lit(5i32)), col("missing"), col("id")];
+    let err = ctx.create_physical_exprs(exprs, &df_schema).unwrap_err();
+    assert_contains!(err.to_string(), "Failed to create physical expression 1");
+    assert_contains!(err.to_string(), "missing");
+    Ok(())
+}
+
+#[test]
+fn test_create_physical_expr_with_props() {
+    #[derive(Debug)]
+    struct ConstantVarProvider {}
+
+    impl VarProvider for ConstantVarProvider {
+        fn get_value(&self, _var_names: Vec<String>) -> Result<ScalarValue> {
+            Ok(ScalarValue::Int64(Some(42)))
+        }
+
+        fn get_type(&self, _: &[String]) -> Option<DataType> {
+            Some(DataType::Int64)
+        }
+    }
+
+    let batch = &TEST_BATCH;
+    let df_schema = DFSchema::try_from(batch.schema()).unwrap();
+    let mut props = ExecutionProps::new();
+    props.add_var_provider(VarType::UserDefined, Arc::new(ConstantVarProvider {}));
+
+    // @x + i
+    let expr = Expr::ScalarVariable(DataType::Int64, vec!["@x".to_string()]) + col("i");
+    let ctx = SessionContext::new();
+
+    // the session's execution props have no variable providers
+    assert!(ctx.create_physical_expr(expr.clone(), &df_schema).is_err());
+
+    let physical_expr = ctx
+        .create_physical_expr_with_props(expr, &df_schema, &props)
+        .unwrap();
+    let array = physical_expr
+        .evaluate(batch)
+        .unwrap()
+        .into_array(1)
+        .unwrap();
+    let result = pretty_format_columns("expr", &[array]).unwrap().to_string();
+    let actual_lines = result.lines().collect::<Vec<_>>();
+
+    #[rustfmt::skip]
+    let expected_lines = vec![
+        "+------+",
+        "| expr |",
+        "+------+",
+        "| 52   |",
+        "|      |",
+        "| 47   |",
+        "+------+",
+    ];
+    assert_eq!(
+        expected_lines, actual_lines,
+        "\n\nexpected:\n\n{expected_lines:#?}\nactual:\n\n{actual_lines:#?}\n\n"
+    );
+}
+
+#[tokio::test]
+async fn test_window_row_number() {
+    // partitions: {1, 3} where i is not null and {2} where i is null
+    let expr = row_number()
+        .partition_by(vec![col("i").is_null()])
+        .order_by(vec![col("id").sort(true, true)])
+        .build()
+        .unwrap();
+    evaluate_window_test(
+        expr,
+        vec![
+            "+----+------+",
+            "| id | expr |",
+            "+----+------+",
+            "| 1  | 1    |",
+            "| 2  | 1    |",
+            "| 3  | 2    |",
+            "+----+------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_window_preserving_order() {
+    // the window function orders the rows by descending id, and the result
+    // is in the order of the input
+    let expr = row_number()
+        .partition_by(vec![col("i").is_null()])
+        .order_by(vec![col("id").sort(false, true)])
+        .build()
+        .unwrap();
+    evaluate_window_test(
+        expr,
+        vec![
+            "+----+------+",
+            "| id | expr |",
+            "+----+------+",
+            "| 1  | 2    |",
+            "| 2  | 1    |",
+            "| 3  | 1    |",
+            "+----+------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_window_lag_lead() {
+    let expr = lag(col("i"), None, None)
+        .order_by(vec![col("id").sort(true, true)])
+        .build()
+        .unwrap();
+    evaluate_window_test(
+        expr,
+        vec![
+            "+----+------+",
+            "| id | expr |",
+            "+----+------+",
+            "| 1  |      |",
+            "| 2  | 10   |",
+            "| 3  |      |",
+            "+----+------+",
+        ],
+    )
+    .await;
+
+    let expr = lead(col("id"), Some(1), Some(ScalarValue::from("none")))
+        .partition_by(vec![col("i").is_null()])
+        .order_by(vec![col("id").sort(true, true)])
+        .build()
+        .unwrap();
+    evaluate_window_test(
+        expr,
+        vec![
+            "+----+------+",
+            "| id | expr |",
+            "+----+------+",
+            "| 1  | 3    |",
+            "| 2  | none |",
+            "| 3  | none |",
+            "+----+------+",
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_window_filter() {
+    // the row with a NULL i is not counted, but still produces the count of
+    // the rows before it
+    let expr = Expr::WindowFunction(WindowFunction::new(count_udaf(), vec![col("id")]))
+        .filter(col("i").is_not_null())
+        .order_by(vec![col("id").sort(true, true)])
+        .build()
+        .unwrap();
+    evaluate_window_test(
+        expr,
+        vec![
+            "+----+------+",
+            "| id | expr |",
+            "+----+------+",
+            "| 1  | 1    |",
+            "| 2  | 1    |",
+            "| 3  | 2    |",
+            "+----+------+",
+        ],
+    )
+    .await;
+
+    // in a sliding frame, a filtered out row is not retracted either when it
+    // leaves the frame
+    let expr = Expr::WindowFunction(WindowFunction::new(count_udaf(), vec![col("id")]))
+        .filter(col("id").not_eq(lit("1")))
+        .order_by(vec![col("id").sort(true, true)])
+        .window_frame(WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            WindowFrameBound::CurrentRow,
+        ))
+        .build()
+        .unwrap();
+    evaluate_window_test(
+        expr,
+        vec![
+            "+----+------+",
+            "| id | expr |",
+            "+----+------+",
+            "| 1  | 0    |",
+            "| 2  | 1    |",
+            "| 3  | 2    |",
+            "+----+------+",
+        ],
+    )
+    .await;
+
+    let err = row_number()
+        .filter(col("i").is_not_null())
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Error during planning: FILTER is not supported for non-aggregate window function row_number"
+    );
+}
+
+#[tokio::test]
+async fn test_window_empty_input() -> Result<()> {
+    let expr = row_number().partition_by(vec![col("id")]).build().unwrap();
+    let result = SessionContext::new()
+        .read_batch(TEST_BATCH.clone())?
+        .filter(col("id").eq(lit("missing")))?
+        .window(vec![expr.alias("expr")])?
+        .collect()
+        .await?;
+    assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_evaluate_scalar() -> Result<()> {
+    // 1 + 2
+    assert_eq!(
+        (lit(1i32) + lit(2i32)).evaluate_scalar()?,
+        ScalarValue::Int32(Some(3))
+    );
+    // lower('FOO')
+    assert_eq!(
+        lower(lit("FOO")).evaluate_scalar()?,
+        ScalarValue::Utf8(Some("foo".to_string()))
+    );
+    // expressions with columns or volatile functions can not be evaluated
+    assert!((col("i") + lit(1i64)).evaluate_scalar().is_err());
+    assert!(random().evaluate_scalar().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_simplify_boolean_constants() -> Result<()> {
+    let batch = &TEST_BATCH;
+    let df_schema = DFSchema::try_from(batch.schema())?;
+    let evaluate = |expr: Expr| -> Result<ArrayRef> {
+        SessionContext::new()
+            .create_physical_expr(expr, &df_schema)?
+            .evaluate(batch)?
+            .into_array(batch.num_rows())
+    };
+
+    // i is [10, NULL, 5], so `b` is [true, NULL, false]
+    let b = || col("i").gt(lit(5i64));
+    let cases = [
+        (b().and(lit(true)), b()),
+        (b().or(lit(false)), b()),
+        (b().and(lit(false)), lit(false)),
+        (b().or(lit(true)), lit(true)),
+        (!!b(), b()),
+    ];
+    let props = ExecutionProps::new();
+    let simplifier = ExprSimplifier::new(
+        SimplifyContext::new(&props).with_schema(df_schema.clone().into()),
+    );
+    for (expr, expected) in cases {
+        let simplified = expr.clone().simplify_boolean_constants();
+        assert_eq!(simplified, expected, "{expr}");
+        // the simplifier applies the same rules
+        assert_eq!(simplifier.simplify(expr.clone())?, expected, "{expr}");
+        // and they hold for the NULL value of `b`
+        assert_eq!(
+            evaluate(simplified)?.as_ref(),
+            evaluate(expr.clone())?.as_ref(),
+            "{expr}"
+        );
+    }
+
+    // `b AND true` keeps the nullability of `b`
+    assert!(b()
+        .and(lit(true))
+        .simplify_boolean_constants()
+        .nullable(&df_schema)?);
+    Ok(())
+}
+
+#[test]
+fn test_assert_not_null() -> Result<()> {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema())?;
+    assert!(col("i").nullable(&df_schema)?);
+    assert!(!col("i").assert_not_null().nullable(&df_schema)?);
+
     #[rustfmt::skip]
     evaluate_expr_test(
-        lit(ScalarValue::Null).field("a"),
+        col("id").assert_not_null(),
         vec![
             "+------+",
             "| expr |",
             "+------+",
-            "|      |",
+            "| 1    |",
+            "| 2    |",
+            "| 3    |",
             "+------+",
         ],
     );
+
+    // the second value of `i` is NULL
+    let physical_expr = SessionContext::new()
+        .create_physical_expr(col("i").assert_not_null(), &df_schema)?;
+    let err = physical_expr.evaluate(&TEST_BATCH).unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Execution error: assert_not_null found a NULL value at row 1"
+    );
+    Ok(())
 }
 
 #[test]
-fn test_nested_get_field() {
-    evaluate_expr_test(
-        col("props")
-            .field("a")
-            .eq(lit("2021-02-02"))
-            .or(col("id").eq(lit(1))),
-        vec![
-            "+-------+",
-            "| expr  |",
-            "+-------+",
-            "| true  |",
-            "| true  |",
-            "| false |",
-            "+-------+",
-        ],
-    );
+fn test_placeholder_types() -> Result<()> {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema())?;
+
+    // i = $1
+    let types = col("i")
+        .eq(placeholder("$1"))
+        .placeholder_types(&df_schema)?;
+    assert_eq!(types["$1"], DataType::Int64);
+
+    // id IN ($1, $2) AND $3 IS NOT NULL
+    let expr = col("id")
+        .in_list(vec![placeholder("$1"), placeholder("$2")], false)
+        .and(placeholder("$3").is_not_null());
+    let types = expr.placeholder_types(&df_schema)?;
+    assert_eq!(types.len(), 3);
+    assert_eq!(types["$1"], DataType::Utf8);
+    assert_eq!(types["$2"], DataType::Utf8);
+    assert_eq!(types["$3"], DataType::Null);
+    Ok(())
 }
 
 #[test]
-fn test_list_index() {
+fn test_replace_placeholders_with_schema_defaults() -> Result<()> {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema())?;
+
+    // $i + $step, where $i defaults to the column i and $step has no default
+    let expr = placeholder("$i") + placeholder("$step");
+    let (expr, unbound) = expr.replace_placeholders_with_schema_defaults(&df_schema)?;
+    assert_eq!(expr, col("i") + placeholder("$step"));
+    assert_eq!(unbound, vec!["$step"]);
+
     #[rustfmt::skip]
     evaluate_expr_test(
-        col("list").index(lit(1i64)),
+        expr.with_param_values(vec![("step", ScalarValue::from(1i64))])?,
         vec![
             "+------+",
             "| expr |",
             "+------+",
-            "| one  |",
-            "| two  |",
-            "| five |",
+            "| 11   |",
+            "|      |",
+            "| 6    |",
             "+------+",
         ],
     );
+    Ok(())
 }
 
 #[test]
-fn test_list_range() {
+fn test_substitute() {
+    // both accesses to props.a are replaced, but not the access to props.b
+    // or the other columns
+    let predicate = col("props")
+        .field("a")
+        .eq(lit("2021-02-01"))
+        .or(col("props").field("a").gt(col("id")))
+        .and(col("props").field("b").is_null());
+    let expr = predicate.substitute(&col("props").field("a"), &lit("X"));
+    let expected = lit("X")
+        .eq(lit("2021-02-01"))
+        .or(lit("X").gt(col("id")))
+        .and(col("props").field("b").is_null());
+    assert_eq!(expr, expected);
+
+    // the replacement may contain the target
+    let expr = (col("i") * col("i")).substitute(&col("i"), &(col("i") + lit(1i64)));
+    assert_eq!(expr, (col("i") + lit(1i64)) * (col("i") + lit(1i64)));
+
+    // an expression without the target is unchanged
+    let expr = col("i").gt(lit(5i64));
+    assert_eq!(expr.substitute(&col("id"), &lit("X")), expr);
+}
+
+#[test]
+fn test_negate_predicate() -> Result<()> {
+    let predicate = col("i")
+        .gt(lit(5i64))
+        .and(col("id").eq(lit("1")))
+        .or(col("i")
+            .is_null()
+            .and(!col("id").in_list(vec![lit("2"), lit("3")], false)));
+    let expected = col("i")
+        .lt_eq(lit(5i64))
+        .or(col("id").not_eq(lit("1")))
+        .and(
+            col("i")
+                .is_not_null()
+                .or(col("id").in_list(vec![lit("2"), lit("3")], false)),
+        );
+    assert_eq!(predicate.negate_predicate(), expected);
+    let negated = evaluate_batch(predicate.negate_predicate(), &TEST_BATCH)?;
+    assert_eq!(
+        negated.as_ref(),
+        evaluate_batch(!predicate, &TEST_BATCH)?.as_ref()
+    );
+
+    // i is NULL in the second row, so the negation is NULL there rather than
+    // true, and IS TRUE is negated to IS NOT TRUE rather than IS FALSE
+    let predicate = col("i").gt(lit(5i64)).is_true().or(col("i").lt(lit(7i64)));
+    let expected = col("i")
+        .gt(lit(5i64))
+        .is_not_true()
+        .and(col("i").gt_eq(lit(7i64)));
+    assert_eq!(predicate.negate_predicate(), expected);
+    #[rustfmt::skip]
     evaluate_expr_test(
-        col("list").range(lit(1i64), lit(2i64)),
+        predicate.negate_predicate(),
         vec![
-            "+--------------+",
-            "| expr         |",
-            "+--------------+",
-            "| [one]        |",
-            "| [two, three] |",
-            "| [five]       |",
-            "+--------------+",
+            "+-------+",
+            "| expr  |",
+            "+-------+",
+            "| false |",
+            "|       |",
+            "| false |",
+            "+-------+",
         ],
     );
+    let negated = evaluate_batch(predicate.negate_predicate(), &TEST_BATCH)?;
+    assert_eq!(
+        negated.as_ref(),
+        evaluate_batch(!predicate, &TEST_BATCH)?.as_ref()
+    );
+
+    // negating twice pushes the inner NOT down without negating the predicate
+    let predicate = !(col("id")
+        .like(lit("1%"))
+        .or(col("i").between(lit(1i64), lit(5i64))));
+    let expected = col("id")
+        .not_like(lit("1%"))
+        .and(col("i").not_between(lit(1i64), lit(5i64)));
+    assert_eq!(predicate.negate_predicate().negate_predicate(), expected);
+
+    // predicates that can not absorb the negation are wrapped in NOT
+    let predicate = col("props").field("a").is_not_null().and(col("b"));
+    let expected = col("props").field("a").is_null().or(!col("b"));
+    assert_eq!(predicate.negate_predicate(), expected);
+    Ok(())
 }
 
-#[tokio::test]
-async fn test_aggregate_ext_order_by() {
-    let agg = first_value_udaf().call(vec![col("props")]);
+#[test]
+fn test_rewrite_in_list_to_or() -> Result<()> {
+    let values = vec![lit("1"), lit("3"), lit("5")];
+    for negated in [false, true] {
+        let in_list = col("id").in_list(values.clone(), negated);
+        let chain = if negated {
+            col("id")
+                .not_eq(lit("1"))
+                .and(col("id").not_eq(lit("3")))
+                .and(col("id").not_eq(lit("5")))
+        } else {
+            col("id")
+                .eq(lit("1"))
+                .or(col("id").eq(lit("3")))
+                .or(col("id").eq(lit("5")))
+        };
+        assert_eq!(in_list.rewrite_in_list_to_or(), chain);
+        assert_eq!(chain.rewrite_or_to_in_list(), in_list);
+        assert_eq!(
+            in_list.rewrite_in_list_to_or().rewrite_or_to_in_list(),
+            in_list
+        );
 
-    // ORDER BY id ASC
-    let agg_asc = agg
-        .clone()
-        .order_by(vec![col("id").sort(true, true)])
-        .build()
-        .unwrap()
-        .alias("asc");
+        let rewritten = evaluate_batch(in_list.rewrite_in_list_to_or(), &TEST_BATCH)?;
+        assert_eq!(
+            rewritten.as_ref(),
+            evaluate_batch(in_list, &TEST_BATCH)?.as_ref()
+        );
+    }
 
-    // ORDER BY id DESC
-    let agg_desc = agg
-        .order_by(vec![col("id").sort(false, true)])
-        .build()
-        .unwrap()
-        .alias("desc");
+    // IN lists are rewritten at any depth
+    let expr = col("i")
+        .gt(lit(1i64))
+        .and(col("id").in_list(vec![lit("1"), lit("2")], false));
+    let expected = col("i")
+        .gt(lit(1i64))
+        .and(col("id").eq(lit("1")).or(col("id").eq(lit("2"))));
+    assert_eq!(expr.rewrite_in_list_to_or(), expected);
+    assert_eq!(expected.rewrite_or_to_in_list(), expr);
 
-    evaluate_agg_test(
-        agg_asc,
-        vec![
-            "+-----------------+",
-            "| asc             |",
-            "+-----------------+",
-            "| {a: 2021-02-01} |",
-            "+-----------------+",
-        ],
-    )
-    .await;
+    // chains comparing different expressions, or with other operators, are
+    // kept
+    let expr = col("id").eq(lit("1")).or(col("i").eq(lit(1i64)));
+    assert_eq!(expr.rewrite_or_to_in_list(), expr);
+    let expr = col("id").eq(lit("1")).or(col("id").not_eq(lit("2")));
+    assert_eq!(expr.rewrite_or_to_in_list(), expr);
 
-    evaluate_agg_test(
-        agg_desc,
-        vec![
-            "+-----------------+",
-            "| desc            |",
-            "+-----------------+",
-            "| {a: 2021-02-03} |",
-            "+-----------------+",
-        ],
-    )
-    .await;
+    // volatile expressions are evaluated once by an IN list but once per
+    // comparison by a chain, so neither is rewritten
+    let expr = random().in_list(vec![col("a"), col("b")], false);
+    assert_eq!(expr.rewrite_in_list_to_or(), expr);
+    let expr = random().eq(col("a")).or(random().eq(col("b")));
+    assert_eq!(expr.rewrite_or_to_in_list(), expr);
+    Ok(())
 }
 
-#[tokio::test]
-async fn test_aggregate_ext_filter() {
-    let agg = first_value_udaf()
-        .call(vec![col("i")])
-        .order_by(vec![col("i").sort(true, true)])
-        .filter(col("i").is_not_null())
-        .build()
-        .unwrap()
-        .alias("val");
+#[test]
+fn test_as_boolean_filter() -> Result<()> {
+    let df_schema = DFSchema::try_from(TEST_BATCH.schema())?;
 
-    #[rustfmt::skip]
-    evaluate_agg_test(
-        agg,
-        vec![
-            "+-----+",
-            "| val |",
-            "+-----+",
-            "| 5   |",
-            "+-----+",
-        ],
-    )
-        .await;
-}
+    let predicate = col("id").eq(lit("2"));
+    assert_eq!(predicate.as_boolean_filter(&df_schema)?, predicate);
 
-#[tokio::test]
-async fn test_aggregate_ext_distinct() {
-    let agg = sum_udaf()
-        .call(vec![lit(5)])
-        // distinct sum should be 5, not 15
-        .distinct()
-        .build()
-        .unwrap()
-        .alias("distinct");
+    let err = col("i").as_boolean_filter(&df_schema).unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Error during planning: Cannot use non-boolean expression 'i' returning Int64 as a filter"
+    );
 
-    evaluate_agg_test(
-        agg,
-        vec![
-            "+----------+",
-            "| distinct |",
-            "+----------+",
-            "| 5        |",
-            "+----------+",
-        ],
-    )
-    .await;
+    // NULL is a missing boolean
+    let predicate = lit(ScalarValue::Null).as_boolean_filter(&df_schema)?;
+    assert_eq!(predicate, cast(lit(ScalarValue::Null), DataType::Boolean));
+    Ok(())
 }
 
-#[tokio::test]
-async fn test_aggregate_ext_null_treatment() {
-    let agg = first_value_udaf()
-        .call(vec![col("i")])
-        .order_by(vec![col("i").sort(true, true)]);
-
-    let agg_respect = agg
-        .clone()
-        .null_treatment(NullTreatment::RespectNulls)
-        .build()
-        .unwrap()
-        .alias("respect");
+/// Evaluates the specified expr as an aggregate and compares the result to the
+/// expected result.
+async fn evaluate_agg_test(expr: Expr, expected_lines: Vec<&str>) {
+    evaluate_grouped_agg_test(TEST_BATCH.clone(), vec![], expr, expected_lines).await
+}
 
-    let agg_ignore = agg
-        .null_treatment(NullTreatment::IgnoreNulls)
-        .build()
+/// Evaluates the specified expr as an aggregate of `batch` grouped by
+/// `group_expr` and compares the result, sorted by the group keys, to the
+/// expected result.
+///
+/// If there are no groups, the expected result is just the header.
+async fn evaluate_grouped_agg_test(
+    batch: RecordBatch,
+    group_expr: Vec<Expr>,
+    expr: Expr,
+    expected_lines: Vec<&str>,
+) {
+    let ctx = SessionContext::new();
+    let num_group_exprs = group_expr.len();
+    let df = ctx
+        .read_batch(batch)
         .unwrap()
-        .alias("ignore");
+        .aggregate(group_expr, vec![expr])
+        .unwrap();
 
-    evaluate_agg_test(
-        agg_respect,
-        vec![
-            "+---------+",
-            "| respect |",
-            "+---------+",
-            "|         |",
-            "+---------+",
-        ],
-    )
-    .await;
+    // The group keys are the leading columns of the aggregate output
+    let sort_expr = df
+        .schema()
+        .columns()
+        .into_iter()
+        .take(num_group_exprs)
+        .map(|column| col(column).sort(true, true))
+        .collect::<Vec<_>>();
+    let df = if sort_expr.is_empty() {
+        df
+    } else {
+        df.sort(sort_expr).unwrap()
+    };
 
-    evaluate_agg_test(
-        agg_ignore,
-        vec![
-            "+--------+",
-            "| ignore |",
-            "+--------+",
-            "| 5      |",
-            "+--------+",
-        ],
-    )
-    .await;
-}
+    let schema = Arc::clone(df.schema().inner());
+    let mut result = df.collect().await.unwrap();
+    if result.is_empty() {
+        result.push(RecordBatch::new_empty(schema));
+    }
 
-#[tokio::test]
-async fn test_create_physical_expr() {
-    // create_physical_expr does not simplify the expression
-    // 1 + 1
-    create_expr_test(lit(1i32) + lit(2i32), "1 + 2");
-    // However, you can run the simplifier before creating the physical
-    // expression. This mimics what delta.rs and other non-sql libraries do to
-    // create predicates
-    //
-    // 1 + 1
-    create_simplified_expr_test(lit(1i32) + lit(2i32), "3");
-}
+    let result = pretty_format_batches(&result).unwrap().to_string();
+    let actual_lines = result.lines().collect::<Vec<_>>();
 
-#[tokio::test]
-async fn test_create_physical_expr_coercion() {
-    // create_physical_expr does apply type coercion and unwrapping in cast
-    //
-    // expect the cast on the literals
-    // compare string function to int  `id = 1`
-    create_expr_test(col("id").eq(lit(1i32)), "id@0 = CAST(1 AS Utf8)");
-    create_expr_test(lit(1i32).eq(col("id")), "CAST(1 AS Utf8) = id@0");
-    // compare int col to string literal `i = '202410'`
-    // Note this casts the column (not the field)
-    create_expr_test(col("i").eq(lit("202410")), "CAST(i@1 AS Utf8) = 202410");
-    create_expr_test(lit("202410").eq(col("i")), "202410 = CAST(i@1 AS Utf8)");
-    // however, when simplified the casts on i should removed
-    // https://github.com/apache/datafusion/issues/14944
-    create_simplified_expr_test(col("i").eq(lit("202410")), "CAST(i@1 AS Utf8) = 202410");
-    create_simplified_expr_test(lit("202410").eq(col("i")), "CAST(i@1 AS Utf8) = 202410");
+    assert_eq!(
+        expected_lines, actual_lines,
+        "\n\nexpected:\n\n{expected_lines:#?}\nactual:\n\n{actual_lines:#?}\n\n"
+    );
 }
 
-/// Evaluates the specified expr as an aggregate and compares the result to the
-/// expected result.
-async fn evaluate_agg_test(expr: Expr, expected_lines: Vec<&str>) {
+/// Evaluates the specified expr as a window function and compares the result,
+/// in the order of the rows of `TEST_BATCH`, to the expected result.
+async fn evaluate_window_test(expr: Expr, expected_lines: Vec<&str>) {
     let ctx = SessionContext::new();
-    let group_expr = vec![];
-    let agg_expr = vec![expr];
     let result = ctx
         .read_batch(TEST_BATCH.clone())
         .unwrap()
-        .aggregate(group_expr, agg_expr)
+        .window_preserving_order(vec![expr.alias("expr")])
+        .unwrap()
+        .select_columns(&["id", "expr"])
         .unwrap()
         .collect()
         .await
@@ -371,8 +4127,17 @@ fn evaluate_expr_test(expr: Expr, expected_lines: Vec<&str>) {
         .create_physical_expr(expr, &df_schema)
         .unwrap();
 
+    // The output type must be known before evaluating
+    let data_type = physical_expr.data_type(&batch.schema()).unwrap();
+    let nullable = physical_expr.nullable(&batch.schema()).unwrap();
+
     let result = physical_expr.evaluate(batch).unwrap();
     let array = result.into_array(1).unwrap();
+    assert_eq!(&data_type, array.data_type());
+    if !nullable {
+        assert_eq!(array.null_count(), 0, "non nullable expr produced nulls");
+    }
+
     let result = pretty_format_columns("expr", &[array]).unwrap().to_string();
     let actual_lines = result.lines().collect::<Vec<_>>();
 
@@ -382,6 +4147,24 @@ fn evaluate_expr_test(expr: Expr, expected_lines: Vec<&str>) {
     );
 }
 
+/// Returns a batch with a single `List<Utf8>` column named "list"
+fn string_list_batch(lists: Vec<Vec<&str>>) -> RecordBatch {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for list in lists {
+        builder.append_value(list.into_iter().map(Some));
+    }
+    let list_array: ArrayRef = Arc::new(builder.finish());
+    RecordBatch::try_from_iter(vec![("list", list_array)]).unwrap()
+}
+
+fn evaluate_batch(expr: Expr, batch: &RecordBatch) -> Result<ArrayRef> {
+    let df_schema = DFSchema::try_from(batch.schema())?;
+    SessionContext::new()
+        .create_physical_expr(expr, &df_schema)?
+        .evaluate(batch)?
+        .into_array(batch.num_rows())
+}
+
 /// Creates the physical expression from Expr and compares the Debug expression
 /// to the expected result.
 fn create_expr_test(expr: Expr, expected_expr: &str) {
@@ -408,12 +4191,15 @@ fn create_simplified_expr_test(expr: Expr, expected_expr: &str) {
     create_expr_test(simplified, expected_expr);
 }
 
-/// Returns a Batch with 3 rows and 4 columns:
+/// Returns a Batch with 3 rows and 7 columns:
 ///
 /// id: Utf8
 /// i: Int64
 /// props: Struct
 /// list: List<String>
+/// payload: Utf8 (JSON)
+/// date: Date32
+/// json_values: Utf8 (JSON)
 static TEST_BATCH: LazyLock<RecordBatch> = LazyLock::new(|| {
     let string_array: ArrayRef = Arc::new(StringArray::from(vec!["1", "2", "3"]));
     let int_array: ArrayRef =
@@ -436,11 +4222,31 @@ static TEST_BATCH: LazyLock<RecordBatch> = LazyLock::new(|| {
     builder.append_value([Some("five")]);
     let list_array: ArrayRef = Arc::new(builder.finish());
 
+    // 2021-02-01 NULL 2021-02-03
+    let date_array: ArrayRef =
+        Arc::new(Date32Array::from(vec![Some(18659), None, Some(18661)]));
+
+    let json_array: ArrayRef = Arc::new(StringArray::from(vec![
+        r#"{"a": {"b": "x"}, "tags": ["t1", "t2"]}"#,
+        r#"{"a": {"b": [1, {"c": 2}]}, "tags": []}"#,
+        "not json",
+    ]));
+
+    // an array, an object, and a JSON value that is neither
+    let json_values_array: ArrayRef = Arc::new(StringArray::from(vec![
+        r#"[1, "two", [3, 4]]"#,
+        r#"{"z": null, "y": [1]}"#,
+        "42",
+    ]));
+
     RecordBatch::try_from_iter(vec![
         ("id", string_array),
         ("i", int_array),
         ("props", struct_array),
         ("list", list_array),
+        ("payload", json_array),
+        ("date", date_array),
+        ("json_values", json_values_array),
     ])
     .unwrap()
 });
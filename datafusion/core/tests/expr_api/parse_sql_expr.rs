@@ -16,13 +16,14 @@
 // under the License.
 
 use arrow::datatypes::{DataType, Field, Schema};
-use datafusion::prelude::{CsvReadOptions, SessionContext};
+use datafusion::prelude::{CsvReadOptions, SessionConfig, SessionContext};
 use datafusion_common::DFSchema;
 use datafusion_common::{DFSchemaRef, Result, ToDFSchema};
 use datafusion_expr::col;
 use datafusion_expr::lit;
 use datafusion_expr::Expr;
-use datafusion_sql::unparser::Unparser;
+use datafusion_sql::sqlparser::dialect::{Dialect, GenericDialect, MySqlDialect};
+use datafusion_sql::unparser::{ExprToSqlExt, Unparser};
 /// A schema like:
 ///
 /// a: Int32 (possibly with nulls)
@@ -87,6 +88,56 @@ async fn round_trip_dataframe(sql: &str) -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn round_trip_to_sql_string() -> Result<()> {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Int32, false),
+        Field::new("Total Price", DataType::Float64, false),
+        Field::new("select", DataType::Utf8, false),
+    ])
+    .to_dfschema_ref()?;
+    let tests = [
+        "a + b * 2",
+        "(a + b) * 2",
+        "a - (b - 1) > 0 OR NOT (a = 1 AND b = 2)",
+        r#""Total Price" / (a * b) > 1.5"#,
+        r#""select" = 'it''s' AND a IS NOT NULL"#,
+        "CASE WHEN a BETWEEN 1 AND 10 THEN b ELSE -b END IN (1, 2)",
+        "abs(a - b) + CAST(b AS DOUBLE)",
+    ];
+
+    for (dialect, parser_dialect) in [
+        ("generic", &GenericDialect {} as &dyn Dialect),
+        ("mysql", &MySqlDialect {}),
+    ] {
+        let config =
+            SessionConfig::new().set_str("datafusion.sql_parser.dialect", dialect);
+        let ctx = SessionContext::new_with_config(config);
+        for test in tests {
+            let sql = if dialect == "mysql" {
+                test.replace('"', "`")
+            } else {
+                test.to_string()
+            };
+            let expr = ctx.parse_sql_expr(&sql, &schema)?;
+            let sql2 = expr.to_sql_string(parser_dialect)?;
+            let expr2 = ctx.parse_sql_expr(&sql2, &schema)?;
+            assert_eq!(expr, expr2, "{sql} was rendered as {sql2}");
+        }
+    }
+
+    // only the parentheses and quotes that are needed are kept
+    let ctx = SessionContext::new();
+    let expr = ctx.parse_sql_expr(r#"(("Total Price" + (a)) * b)"#, &schema)?;
+    assert_eq!(
+        expr.to_sql_string(&GenericDialect {})?,
+        r#"("Total Price" + a) * b"#
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_qualified_schema() -> Result<()> {
     let sql = "a < 5 OR a = 8";
@@ -99,6 +150,51 @@ async fn roundtrip_qualified_schema() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn parse_unresolved_sql_expr() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    let expr = ctx.parse_unresolved_sql_expr("a + b * 2")?;
+    assert_eq!(expr, col("a") + col("b") * lit(2_i64));
+
+    // qualified identifiers stay qualified
+    let expr = ctx.parse_unresolved_sql_expr("t.a < 5 OR t.a = 8")?;
+    assert_eq!(
+        expr,
+        col("t.a").lt(lit(5_i64)).or(col("t.a").eq(lit(8_i64)))
+    );
+
+    // function calls and aggregates are planned without type information
+    let tests = vec![
+        "(abs(a) > 1)",
+        "(min(a) + (max(b) * sum(c)))",
+        "a IN (1, 2)",
+    ];
+    for test in tests {
+        let expr = ctx.parse_unresolved_sql_expr(test)?;
+        assert_eq!(unparse_sql_expr(&expr)?, test);
+    }
+
+    // the referenced columns can be collected before binding to a table
+    let expr = ctx.parse_unresolved_sql_expr("upper(name) = 'X' AND id > 10")?;
+    let mut columns = expr
+        .column_refs()
+        .into_iter()
+        .map(|c| c.flat_name())
+        .collect::<Vec<_>>();
+    columns.sort();
+    assert_eq!(columns, vec!["id", "name"]);
+
+    Ok(())
+}
+
+#[test]
+fn parse_unresolved_sql_expr_error() {
+    let ctx = SessionContext::new();
+    let err = ctx.parse_unresolved_sql_expr("a +").unwrap_err();
+    assert!(err.to_string().contains("SQL error"), "{err}");
+}
+
 fn unparse_sql_expr(expr: &Expr) -> Result<String> {
     let unparser = Unparser::default();
 
@@ -511,6 +511,7 @@ fn expr_test_schema() -> DFSchemaRef {
         Field::new("c3", DataType::Int64, true),
         Field::new("c4", DataType::UInt32, true),
         Field::new("c5", DataType::Utf8View, true),
+        Field::new("c6", DataType::LargeUtf8, true),
         Field::new("c1_non_null", DataType::Utf8, false),
         Field::new("c2_non_null", DataType::Boolean, false),
         Field::new("c3_non_null", DataType::Int64, false),
@@ -729,3 +730,75 @@ fn test_simplify_cycles() {
     let expected = lit(true);
     test_simplify_with_cycle_count(expr, expected, 3);
 }
+
+fn test_simplify_prefix_range(input_expr: Expr, expected_expr: Expr) {
+    let info: MyInfo = MyInfo {
+        schema: expr_test_schema(),
+        execution_props: ExecutionProps::new(),
+    };
+    let simplifier = ExprSimplifier::new(info).with_prefix_range_rewrite(true);
+    let simplified_expr = simplifier
+        .simplify(input_expr.clone())
+        .expect("successfully evaluated");
+
+    assert_eq!(
+        simplified_expr, expected_expr,
+        "Mismatch evaluating {input_expr}\n  Expected:{expected_expr}\n  Got:{simplified_expr}"
+    );
+}
+
+#[test]
+fn test_simplify_starts_with_to_range() {
+    // starts_with(c1, 'abc') ===> c1 >= 'abc' AND c1 < 'abd'
+    test_simplify_prefix_range(
+        starts_with(col("c1"), lit("abc")),
+        col("c1").gt_eq(lit("abc")).and(col("c1").lt(lit("abd"))),
+    );
+
+    // The bound keeps the type of the column
+    let large = |s: &str| lit(ScalarValue::LargeUtf8(Some(s.to_string())));
+    test_simplify_prefix_range(
+        starts_with(col("c6"), large("ab")),
+        col("c6").gt_eq(large("ab")).and(col("c6").lt(large("ac"))),
+    );
+
+    // Trailing maximum code points are dropped before incrementing
+    test_simplify_prefix_range(
+        starts_with(col("c1"), lit("a\u{10FFFF}")),
+        col("c1")
+            .gt_eq(lit("a\u{10FFFF}"))
+            .and(col("c1").lt(lit("b"))),
+    );
+
+    // There is no upper bound for a prefix of only maximum code points
+    test_simplify_prefix_range(
+        starts_with(col("c1"), lit("\u{10FFFF}")),
+        col("c1").gt_eq(lit("\u{10FFFF}")),
+    );
+}
+
+#[test]
+fn test_simplify_starts_with_to_range_unchanged() {
+    // Without the rewrite, starts_with is simplified to LIKE
+    test_simplify(
+        starts_with(col("c1"), lit("abc")),
+        col("c1").like(lit("abc%")),
+    );
+
+    // Utf8View columns are not rewritten
+    test_simplify_prefix_range(
+        starts_with(
+            col("c5"),
+            lit(ScalarValue::Utf8View(Some("abc".to_string()))),
+        ),
+        col("c5").like(lit(ScalarValue::Utf8View(Some("abc%".to_string())))),
+    );
+
+    // ends_with and contains can't be turned into ranges
+    for expr in [
+        ends_with(col("c1"), lit("abc")),
+        contains(col("c1"), lit("abc")),
+    ] {
+        test_simplify_prefix_range(expr.clone(), expr);
+    }
+}
@@ -254,6 +254,7 @@ pub fn bounded_window_exec(
         Arc::new(WindowFrame::new(Some(false))),
         schema.as_ref(),
         false,
+        None,
     )
     .unwrap();
 
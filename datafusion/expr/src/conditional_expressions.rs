@@ -17,16 +17,41 @@
 
 //! Conditional expressions
 use crate::expr::Case;
+use crate::type_coercion::other::get_coerce_type_for_case_expression;
 use crate::{expr_schema::ExprSchemable, Expr};
 use arrow::datatypes::DataType;
-use datafusion_common::{plan_err, DFSchema, HashSet, Result};
+use datafusion_common::{plan_err, DFSchema, DFSchemaRef, HashSet, Result};
 
 /// Helper struct for building [Expr::Case]
+///
+/// By default, only the types of literal `THEN` and `ELSE` values are checked.
+/// If a schema is provided with [`Self::with_schema`], the types of all the
+/// values are checked, and they are cast to their common type.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use datafusion_common::{DFSchema, Result};
+/// # use datafusion_expr::{col, lit, when};
+/// # fn main() -> Result<()> {
+/// let schema = Schema::new(vec![Field::new("i", DataType::Int64, true)]);
+/// let schema = Arc::new(DFSchema::try_from(schema)?);
+/// let expr = when(col("i").is_null(), lit(-1i32))
+///     .with_schema(schema)
+///     .otherwise(col("i"))?;
+/// assert_eq!(
+///     expr.to_string(),
+///     "CASE WHEN i IS NULL THEN CAST(Int32(-1) AS Int64) ELSE i END"
+/// );
+/// # Ok(())
+/// # }
+/// ```
 pub struct CaseBuilder {
     expr: Option<Box<Expr>>,
     when_expr: Vec<Expr>,
     then_expr: Vec<Expr>,
     else_expr: Option<Box<Expr>>,
+    schema: Option<DFSchemaRef>,
 }
 
 impl CaseBuilder {
@@ -41,6 +66,7 @@ impl CaseBuilder {
             when_expr,
             then_expr,
             else_expr,
+            schema: None,
         }
     }
     pub fn when(&mut self, when: Expr, then: Expr) -> CaseBuilder {
@@ -51,8 +77,23 @@ impl CaseBuilder {
             when_expr: self.when_expr.clone(),
             then_expr: self.then_expr.clone(),
             else_expr: self.else_expr.clone(),
+            schema: self.schema.clone(),
         }
     }
+
+    /// Check the types of the `THEN` and `ELSE` values against `schema` when
+    /// the expression is built, and cast them to their common type.
+    pub fn with_schema(&mut self, schema: DFSchemaRef) -> CaseBuilder {
+        self.schema = Some(schema);
+        CaseBuilder {
+            expr: self.expr.clone(),
+            when_expr: self.when_expr.clone(),
+            then_expr: self.then_expr.clone(),
+            else_expr: self.else_expr.clone(),
+            schema: self.schema.clone(),
+        }
+    }
+
     pub fn otherwise(&mut self, else_expr: Expr) -> Result<Expr> {
         self.else_expr = Some(Box::new(else_expr));
         self.build()
@@ -63,6 +104,10 @@ impl CaseBuilder {
     }
 
     fn build(&self) -> Result<Expr> {
+        if let Some(schema) = &self.schema {
+            return self.build_coerced(schema);
+        }
+
         // Collect all "then" expressions
         let mut then_expr = self.then_expr.clone();
         if let Some(e) = &self.else_expr {
@@ -98,12 +143,75 @@ impl CaseBuilder {
             self.else_expr.clone(),
         )))
     }
+
+    /// Builds the expression with the `THEN` and `ELSE` values cast to their
+    /// common type
+    fn build_coerced(&self, schema: &DFSchema) -> Result<Expr> {
+        if self.then_expr.is_empty() {
+            return plan_err!("CASE expression must have at least one 'when' value");
+        }
+        let then_types = self
+            .then_expr
+            .iter()
+            .map(|e| e.get_type(schema))
+            .collect::<Result<Vec<_>>>()?;
+        let else_type = self
+            .else_expr
+            .as_ref()
+            .map(|e| e.get_type(schema))
+            .transpose()?;
+        let Some(result_type) =
+            get_coerce_type_for_case_expression(&then_types, else_type.as_ref())
+        else {
+            let mut types = then_types;
+            types.extend(else_type);
+            return plan_err!(
+                "CASE expression 'then' and 'else' values have incompatible data types: {types:?}"
+            );
+        };
+
+        let when_then_expr = self
+            .when_expr
+            .iter()
+            .zip(self.then_expr.iter())
+            .map(|(w, t)| {
+                Ok((
+                    Box::new(w.clone()),
+                    Box::new(t.clone().cast_to(&result_type, schema)?),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let else_expr = self
+            .else_expr
+            .as_ref()
+            .map(|e| {
+                e.as_ref()
+                    .clone()
+                    .cast_to(&result_type, schema)
+                    .map(Box::new)
+            })
+            .transpose()?;
+        Ok(Expr::Case(Case::new(
+            self.expr.clone(),
+            when_then_expr,
+            else_expr,
+        )))
+    }
+}
+
+impl Default for CaseBuilder {
+    /// A `CASE` expression without an operand or any `WHEN` values
+    fn default() -> Self {
+        Self::new(None, vec![], vec![], None)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{col, lit, when};
+    use arrow::datatypes::{Field, Schema};
+    use std::sync::Arc;
 
     #[test]
     fn case_when_same_literal_then_types() -> Result<()> {
@@ -113,6 +221,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn case_when_with_schema() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("i", DataType::Int64, true),
+            Field::new("s", DataType::Utf8, true),
+        ]);
+        let schema = Arc::new(DFSchema::try_from(schema)?);
+
+        let expr = CaseBuilder::default()
+            .when(col("i").is_null(), lit(-1i32))
+            .when(col("i").lt(lit(0)), lit(0u8))
+            .with_schema(Arc::clone(&schema))
+            .otherwise(col("i"))?;
+        assert_eq!(expr.get_type(&schema)?, DataType::Int64);
+        assert_eq!(
+            expr.to_string(),
+            "CASE WHEN i IS NULL THEN CAST(Int32(-1) AS Int64) WHEN i < Int32(0) THEN CAST(UInt8(0) AS Int64) ELSE i END"
+        );
+
+        // without a schema, only literals are checked
+        assert!(when(col("i").is_null(), lit(true))
+            .otherwise(col("i"))
+            .is_ok());
+        let err = when(col("i").is_null(), lit(true))
+            .with_schema(schema)
+            .otherwise(col("i"))
+            .unwrap_err();
+        assert!(err.to_string().contains(
+            "CASE expression 'then' and 'else' values have incompatible data types: [Boolean, Int64]"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn case_when_different_literal_then_types() {
         let maybe_expr = when(col("state").eq(lit("CO")), lit(303))
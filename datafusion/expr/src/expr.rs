@@ -19,12 +19,13 @@
 
 use std::collections::HashSet;
 use std::fmt::{self, Display, Formatter, Write};
-use std::hash::{Hash, Hasher};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::mem;
 use std::sync::Arc;
 
 use crate::expr_fn::binary_expr;
 use crate::logical_plan::Subquery;
+use crate::utils::split_binary;
 use crate::Volatility;
 use crate::{udaf, ExprSchemable, Operator, Signature, WindowFrame, WindowUDF};
 
@@ -34,7 +35,8 @@ use datafusion_common::tree_node::{
     Transformed, TransformedResult, TreeNode, TreeNodeContainer, TreeNodeRecursion,
 };
 use datafusion_common::{
-    Column, DFSchema, HashMap, Result, ScalarValue, Spans, TableReference,
+    plan_err, Column, DFSchema, ExprSchema, HashMap, ParamValues, Result, ScalarValue,
+    Spans, TableReference,
 };
 use datafusion_functions_window_common::field::WindowUDFFieldArgs;
 use sqlparser::ast::{
@@ -728,6 +730,34 @@ impl Sort {
         }
     }
 
+    /// Create a new Sort expression that orders by the comparison operator
+    /// `op`, as in `ORDER BY expr USING op`
+    ///
+    /// Only the ordering operators are supported: [`Operator::Lt`] sorts in
+    /// ascending order and [`Operator::Gt`] in descending order, so the
+    /// ordering is the same as one created with [`Sort::new`].
+    ///
+    /// ```
+    /// # use datafusion_expr::{col, expr::Sort, Operator};
+    /// let sort = Sort::try_new_using(col("foo"), Operator::Gt, true).unwrap();
+    /// assert_eq!(sort, col("foo").sort(false, true));
+    ///
+    /// // `=` does not define an ordering
+    /// assert!(Sort::try_new_using(col("foo"), Operator::Eq, true).is_err());
+    /// ```
+    pub fn try_new_using(expr: Expr, op: Operator, nulls_first: bool) -> Result<Self> {
+        let asc = match op {
+            Operator::Lt => true,
+            Operator::Gt => false,
+            _ => {
+                return plan_err!(
+                    "ORDER BY USING requires an ordering operator, < or >, but got {op}"
+                )
+            }
+        };
+        Ok(Self::new(expr, asc, nulls_first))
+    }
+
     /// Create a new Sort expression with the opposite sort direction
     pub fn reverse(&self) -> Self {
         Self {
@@ -747,6 +777,32 @@ impl Sort {
     }
 }
 
+/// Where NULL values are placed relative to non-NULL values in a [`Sort`]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Hash, Debug)]
+pub enum NullsPlacement {
+    /// `NULLS FIRST`: NULL values sort before all other values
+    First,
+    /// `NULLS LAST`: NULL values sort after all other values
+    Last,
+}
+
+impl NullsPlacement {
+    /// Returns true if this is [`NullsPlacement::First`], matching the
+    /// `nulls_first` flag of [`Sort`]
+    pub fn nulls_first(&self) -> bool {
+        matches!(self, Self::First)
+    }
+}
+
+impl Display for NullsPlacement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First => write!(f, "NULLS FIRST"),
+            Self::Last => write!(f, "NULLS LAST"),
+        }
+    }
+}
+
 impl Display for Sort {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.expr)?;
@@ -927,6 +983,9 @@ pub struct WindowFunctionParams {
     pub order_by: Vec<Sort>,
     /// Window frame
     pub window_frame: WindowFrame,
+    /// Optional filter expression; rows for which it is not true do not
+    /// contribute to the aggregate, but still produce an output row
+    pub filter: Option<Box<Expr>>,
     /// Specifies how NULL value is treated: ignore or respect
     pub null_treatment: Option<NullTreatment>,
 }
@@ -942,6 +1001,7 @@ impl WindowFunction {
                 partition_by: Vec::default(),
                 order_by: Vec::default(),
                 window_frame: WindowFrame::new(None),
+                filter: None,
                 null_treatment: None,
             },
         }
@@ -1192,14 +1252,29 @@ impl Expr {
     /// 1. [`Expr::Alias`], which shows only the alias itself
     /// 2. [`Expr::Cast`] / [`Expr::TryCast`], which only displays the expression
     ///
+    /// Plan builders qualify the columns of an expression before computing its
+    /// name, so the name matches the plan's only if the columns in the
+    /// expression are qualified the same way.
+    ///
     /// # Example
     /// ```
-    /// # use datafusion_expr::{col, lit};
+    /// # use arrow::datatypes::DataType;
+    /// # use datafusion_expr::{cast, col, lit, ExprFunctionExt};
+    /// # use datafusion_expr::test::function_stub::sum;
     /// let expr = col("foo").eq(lit(42));
     /// assert_eq!("foo = Int32(42)", expr.schema_name().to_string());
     ///
     /// let expr = col("foo").alias("bar").eq(lit(11));
     /// assert_eq!("bar = Int32(11)", expr.schema_name().to_string());
+    ///
+    /// let expr = cast(col("t.foo"), DataType::Float64).alias("bar");
+    /// assert_eq!("bar", expr.schema_name().to_string());
+    ///
+    /// let expr = sum(col("t.foo")).filter(col("t.foo").gt(lit(1))).build().unwrap();
+    /// assert_eq!(
+    ///     "sum(t.foo) FILTER (WHERE t.foo > Int32(1))",
+    ///     expr.schema_name().to_string()
+    /// );
     /// ```
     ///
     /// [`Schema`]: arrow::datatypes::Schema
@@ -1326,6 +1401,22 @@ impl Expr {
         binary_expr(self, Operator::LtEq, other)
     }
 
+    /// Return `self IS DISTINCT FROM other`
+    ///
+    /// Unlike [`Self::not_eq`], NULLs are compared as ordinary values, so the
+    /// result is never NULL.
+    pub fn is_distinct_from(self, other: Expr) -> Expr {
+        binary_expr(self, Operator::IsDistinctFrom, other)
+    }
+
+    /// Return `self IS NOT DISTINCT FROM other`
+    ///
+    /// Unlike [`Self::eq`], NULLs are compared as ordinary values, so the
+    /// result is never NULL.
+    pub fn is_not_distinct_from(self, other: Expr) -> Expr {
+        binary_expr(self, Operator::IsNotDistinctFrom, other)
+    }
+
     /// Return `self && other`
     pub fn and(self, other: Expr) -> Expr {
         binary_expr(self, Operator::And, other)
@@ -1374,6 +1465,43 @@ impl Expr {
         Expr::Like(Like::new(true, Box::new(self), Box::new(other), None, true))
     }
 
+    /// Return `self LIKE other ESCAPE escape_char`
+    ///
+    /// In `other`, `%` and `_` preceded by `escape_char` match themselves
+    /// rather than any characters. Returns an error if `escape_char` is not a
+    /// single character.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// // matches `100%`, but not `1000`
+    /// let expr = col("id").like_with_escape(lit("100$%"), "$").unwrap();
+    /// assert_eq!(expr.to_string(), "id LIKE Utf8(\"100$%\") ESCAPE '$'");
+    /// assert!(col("id").like_with_escape(lit("100$%"), "$$").is_err());
+    /// ```
+    pub fn like_with_escape(self, other: Expr, escape_char: &str) -> Result<Expr> {
+        Ok(Expr::Like(Like::new(
+            false,
+            Box::new(self),
+            Box::new(other),
+            Some(single_escape_char(escape_char)?),
+            false,
+        )))
+    }
+
+    /// Return `self ILIKE other ESCAPE escape_char`
+    ///
+    /// See [`Self::like_with_escape`] for details
+    pub fn ilike_with_escape(self, other: Expr, escape_char: &str) -> Result<Expr> {
+        Ok(Expr::Like(Like::new(
+            false,
+            Box::new(self),
+            Box::new(other),
+            Some(single_escape_char(escape_char)?),
+            true,
+        )))
+    }
+
     /// Return the name to use for the specific Expr
     pub fn name_for_alias(&self) -> Result<String> {
         Ok(self.schema_name().to_string())
@@ -1425,6 +1553,54 @@ impl Expr {
         Expr::Alias(Alias::new(self, relation, name.into()))
     }
 
+    /// Return `self AS relation.name`, replacing any existing alias on `self`
+    /// but keeping its metadata.
+    ///
+    /// Unlike [`Self::alias_qualified`], this returns an error if `self` is
+    /// already aliased with a different qualifier.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_common::TableReference;
+    /// # use datafusion_expr::col;
+    /// let expr = col("foo").with_alias_qualified("t", "bar").unwrap();
+    /// let t = Some(TableReference::bare("t"));
+    /// assert_eq!(expr.qualified_name(), (t.clone(), "bar".to_string()));
+    ///
+    /// // the existing alias is replaced
+    /// let expr = expr.with_alias_qualified("t", "baz").unwrap();
+    /// assert_eq!(expr.qualified_name(), (t, "baz".to_string()));
+    ///
+    /// // but its qualifier can not be changed
+    /// assert!(expr.with_alias_qualified("u", "baz").is_err());
+    /// ```
+    pub fn with_alias_qualified(
+        self,
+        relation: impl Into<TableReference>,
+        name: impl Into<String>,
+    ) -> Result<Expr> {
+        let relation = relation.into();
+        let expr = match self {
+            Expr::Alias(Alias {
+                expr,
+                relation: Some(existing),
+                name: existing_name,
+                ..
+            }) if existing != relation => {
+                return plan_err!(
+                    "Can not alias {expr} AS {existing}.{existing_name} with qualifier {relation}"
+                );
+            }
+            Expr::Alias(Alias { expr, metadata, .. }) => {
+                return Ok(Expr::Alias(
+                    Alias::new(*expr, Some(relation), name).with_metadata(metadata),
+                ));
+            }
+            expr => expr,
+        };
+        Ok(expr.alias_qualified(Some(relation), name))
+    }
+
     /// Return `self AS name` alias expression with a specific qualifier and metadata
     ///
     /// The metadata will be attached to the Arrow Schema field when the expression
@@ -1476,8 +1652,10 @@ impl Expr {
 
     /// Recursively removed potentially multiple aliases from an expression.
     ///
-    /// This method removes nested aliases and returns [`Transformed`]
-    /// to signal if the expression was changed.
+    /// This method removes nested aliases at every depth, except within
+    /// subqueries, and returns [`Transformed`] to signal if the expression
+    /// was changed. Use it to compare expressions that differ only in their
+    /// aliases.
     ///
     /// # Example
     /// ```
@@ -1523,6 +1701,25 @@ impl Expr {
         .unwrap()
     }
 
+    /// Returns true if this expression computes the same thing as `other`,
+    /// that is if they are equal after removing their aliases with
+    /// [`Self::unalias_nested`].
+    ///
+    /// Aliases, and the field metadata they carry, are ignored at every
+    /// depth except within subqueries. Everything else is compared as with
+    /// `==`, so for example `a + b` is not semantically equal to `b + a`.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = (col("a").alias("x") + lit(1)).alias("y");
+    /// assert!(expr.semantic_eq(&(col("a") + lit(1))));
+    /// assert!(!expr.semantic_eq(&(col("a") + lit(2))));
+    /// ```
+    pub fn semantic_eq(&self, other: &Expr) -> bool {
+        self.clone().unalias_nested().data == other.clone().unalias_nested().data
+    }
+
     /// Return `self IN <list>` if `negated` is false, otherwise
     /// return `self NOT IN <list>`.a
     pub fn in_list(self, list: Vec<Expr>, negated: bool) -> Expr {
@@ -1549,6 +1746,19 @@ impl Expr {
         Sort::new(self, asc, nulls_first)
     }
 
+    /// Create a sort configuration that orders by the comparison operator
+    /// `op`, as in `ORDER BY foo USING >`.
+    ///
+    /// Returns an error if `op` is not `<` or `>`, see [`Sort::try_new_using`].
+    ///
+    /// ```
+    /// # use datafusion_expr::{col, Operator};
+    /// let sort_expr = col("foo").sort_using(Operator::Gt, false).unwrap(); // SORT DESC NULLS_LAST
+    /// ```
+    pub fn sort_using(self, op: Operator, nulls_first: bool) -> Result<Sort> {
+        Sort::try_new_using(self, op, nulls_first)
+    }
+
     /// Return `IsTrue(Box(self))`
     pub fn is_true(self) -> Expr {
         Expr::IsTrue(Box::new(self))
@@ -1715,6 +1925,199 @@ impl Expr {
             .expect("exists closure is infallible")
     }
 
+    /// Return each distinct column referenced in this expression, in the
+    /// order they first appear from left to right.
+    ///
+    /// Unlike [`Self::column_refs`], the order is deterministic. Columns are
+    /// compared with their qualifiers, so `t.a` and `a` are both returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_common::Column;
+    /// # use datafusion_expr::col;
+    /// // For an expression `b + (a * b)`
+    /// let expr = col("b") + (col("a") * col("b"));
+    /// assert_eq!(
+    ///     expr.columns(),
+    ///     vec![Column::new_unqualified("b"), Column::new_unqualified("a")]
+    /// );
+    /// ```
+    pub fn columns(&self) -> Vec<Column> {
+        let mut seen = HashSet::new();
+        let mut columns = vec![];
+        self.apply(|expr| {
+            if let Expr::Column(col) = expr {
+                if seen.insert(col) {
+                    columns.push(col.clone());
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("traversal is infallible");
+        columns
+    }
+
+    /// Return a copy of this expression with every column reference replaced
+    /// by the result of calling `f` on it.
+    ///
+    /// All other nodes, including aliases and nested field accesses, are left
+    /// unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_common::Column;
+    /// # use datafusion_expr::col;
+    /// // For an expression `(i + j) AS total`
+    /// let expr = (col("i") + col("j")).alias("total");
+    /// // qualify every column with `metrics`
+    /// let expr = expr.rewrite_column_refs(|c| Column::new(Some("metrics"), &c.name));
+    /// assert_eq!(expr, (col("metrics.i") + col("metrics.j")).alias("total"));
+    /// ```
+    pub fn rewrite_column_refs(&self, f: impl Fn(&Column) -> Column) -> Expr {
+        self.clone()
+            .transform(|expr| match expr {
+                Expr::Column(c) => Ok(Transformed::yes(Expr::Column(f(&c)))),
+                _ => Ok(Transformed::no(expr)),
+            })
+            .data()
+            .expect("rewrite closure is infallible")
+    }
+
+    /// Return references to every subexpression, including this expression,
+    /// for which `predicate` returns true.
+    ///
+    /// The expression is traversed in pre-order, so a node is returned before
+    /// the nodes it contains, and children are visited from left to right.
+    /// All children are visited, including the arguments, filters and
+    /// `ORDER BY` / `PARTITION BY` expressions of aggregate and window
+    /// functions. Subqueries are not traversed.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit, Expr};
+    /// // For an expression `a + 1 > b * 2`
+    /// let expr = (col("a") + lit(1)).gt(col("b") * lit(2));
+    /// let literals = expr.find_subexpr(|e| matches!(e, Expr::Literal(_)));
+    /// assert_eq!(literals, vec![&lit(1), &lit(2)]);
+    /// ```
+    pub fn find_subexpr(&self, predicate: impl Fn(&Expr) -> bool) -> Vec<&Expr> {
+        let mut found = vec![];
+        self.apply(|expr| {
+            if predicate(expr) {
+                found.push(expr);
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("traversal is infallible");
+        found
+    }
+
+    /// Return the number of nodes in the expression tree, including this
+    /// expression.
+    ///
+    /// Every subexpression traversed by [`Self::find_subexpr`] is counted,
+    /// such as field accesses, function arguments and the filters and
+    /// `ORDER BY` / `PARTITION BY` expressions of aggregate and window
+    /// functions. The plans of subqueries are not traversed.
+    ///
+    /// This can be used to reject overly large expressions, for example
+    /// before creating a physical expression from user supplied SQL.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// // `a + 1 > b` has the nodes `>`, `+`, `a`, `1` and `b`
+    /// let expr = (col("a") + lit(1)).gt(col("b"));
+    /// assert_eq!(expr.count_nodes(), 5);
+    /// ```
+    pub fn count_nodes(&self) -> usize {
+        let mut count = 0;
+        self.apply(|_| {
+            count += 1;
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("traversal is infallible");
+        count
+    }
+
+    /// Return an equivalent expression in which the operands of commutative
+    /// binary operators, such as `+`, `*`, `=`, `AND` and `OR`, are in a
+    /// deterministic order.
+    ///
+    /// Expressions that only differ in the order of such operands, like
+    /// `a = b` and `b = a`, are equal after being canonicalized, which is
+    /// useful to deduplicate structurally equivalent predicates. The operands
+    /// are ordered by their hash, so the canonical form is deterministic for a
+    /// given build of DataFusion but should not be persisted. Chains of the
+    /// same operator are not reassociated: `(a + b) + c` and `a + (b + c)`
+    /// remain different.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = col("id").eq(lit("2"));
+    /// let swapped = lit("2").eq(col("id"));
+    /// assert_ne!(expr, swapped);
+    /// assert_eq!(expr.canonicalize(), swapped.canonicalize());
+    ///
+    /// // `-` is not commutative
+    /// let expr = col("a") - col("b");
+    /// assert_eq!(expr.canonicalize(), expr);
+    /// ```
+    pub fn canonicalize(&self) -> Expr {
+        fn stable_hash(expr: &Expr) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            expr.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        self.clone()
+            .transform_up(|expr| match expr {
+                Expr::BinaryExpr(BinaryExpr { left, op, right })
+                    if is_commutative(op) && stable_hash(&left) > stable_hash(&right) =>
+                {
+                    Ok(Transformed::yes(Expr::BinaryExpr(BinaryExpr::new(
+                        right, op, left,
+                    ))))
+                }
+                expr => Ok(Transformed::no(expr)),
+            })
+            .data()
+            .expect("rewrite is infallible")
+    }
+
+    /// Return an equivalent expression in which every `CAST` is replaced by a
+    /// `TRY_CAST`, which returns `NULL` instead of an error when a value
+    /// can not be cast.
+    ///
+    /// This is useful, for example, to push predicates down to sources that
+    /// can not tolerate cast failures. The rewritten expression has the same
+    /// data type, but may be nullable where the original was not. Only the
+    /// casts already in the expression are rewritten: the casts added by type
+    /// coercion, for example when creating a physical expression, are not.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::DataType;
+    /// # use datafusion_expr::{cast, col, lit, try_cast};
+    /// let expr = cast(col("a"), DataType::Int64).eq(lit(1));
+    /// assert_eq!(
+    ///     expr.rewrite_cast_to_try_cast(),
+    ///     try_cast(col("a"), DataType::Int64).eq(lit(1))
+    /// );
+    /// ```
+    pub fn rewrite_cast_to_try_cast(&self) -> Expr {
+        self.clone()
+            .transform_up(|expr| match expr {
+                Expr::Cast(Cast { expr, data_type }) => Ok(Transformed::yes(
+                    Expr::TryCast(TryCast::new(expr, data_type)),
+                )),
+                expr => Ok(Transformed::no(expr)),
+            })
+            .data()
+            .expect("rewrite is infallible")
+    }
+
     /// Return true if the expression contains out reference(correlated) expressions.
     pub fn contains_outer(&self) -> bool {
         self.exists(|expr| Ok(matches!(expr, Expr::OuterReferenceColumn { .. })))
@@ -1742,6 +2145,93 @@ impl Expr {
             .expect("exists closure is infallible")
     }
 
+    /// Returns the [`DataType`] this expression produces when evaluated
+    /// against `schema`.
+    ///
+    /// This is the type [`ExprSchemable::get_type`] returns, which is also the
+    /// type of the physical expression created from this expression.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion_common::DFSchema;
+    /// # use datafusion_expr::{col, lit};
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let schema = DFSchema::try_from(schema).unwrap();
+    /// let expr = col("a") + lit(1_i64);
+    /// assert_eq!(expr.data_type(&schema).unwrap(), DataType::Int64);
+    /// assert!(expr.nullable_in(&schema).unwrap());
+    /// ```
+    pub fn data_type(&self, schema: &DFSchema) -> Result<DataType> {
+        self.get_type(schema)
+    }
+
+    /// Returns true if this expression may produce NULL when evaluated
+    /// against `input_schema`, see [`Self::data_type`].
+    ///
+    /// This is the same as [`ExprSchemable::nullable`], without having to
+    /// import the trait.
+    pub fn nullable_in(&self, input_schema: &dyn ExprSchema) -> Result<bool> {
+        ExprSchemable::nullable(self, input_schema)
+    }
+
+    /// Returns this expression if it can be used to filter rows of `schema`,
+    /// that is if it returns [`DataType::Boolean`], or an error naming the
+    /// type it returns otherwise.
+    ///
+    /// Expressions returning `NULL`, or dictionaries of booleans, are cast
+    /// to [`DataType::Boolean`].
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion_common::DFSchema;
+    /// # use datafusion_expr::{col, lit};
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    /// let schema = DFSchema::try_from(schema).unwrap();
+    /// let expr = col("a").gt(lit(1));
+    /// assert_eq!(expr.as_boolean_filter(&schema).unwrap(), expr);
+    /// assert!(col("a").as_boolean_filter(&schema).is_err());
+    /// ```
+    pub fn as_boolean_filter(&self, schema: &DFSchema) -> Result<Expr> {
+        match self.get_type(schema)? {
+            DataType::Boolean => Ok(self.clone()),
+            DataType::Null => self.clone().cast_to(&DataType::Boolean, schema),
+            DataType::Dictionary(_, value_type) if *value_type == DataType::Boolean => {
+                self.clone().cast_to(&DataType::Boolean, schema)
+            }
+            data_type => plan_err!(
+                "Cannot use non-boolean expression '{self}' returning {data_type} as a filter"
+            ),
+        }
+    }
+
+    /// Returns true if the expression always produces the same result when
+    /// evaluated with the same input, so the result can be cached.
+    ///
+    /// The expression is not deterministic if any scalar function in it,
+    /// including in the arguments of aggregate and window functions and in
+    /// the plans of subqueries, is [`Volatility::Volatile`], such as
+    /// `random()`.
+    ///
+    /// [`Volatility::Stable`] functions such as `now()` are considered
+    /// deterministic: they return the same value for every evaluation within
+    /// a query, but may return a different value in the next query. A result
+    /// that is cached across queries should therefore also check for stable
+    /// functions.
+    pub fn is_deterministic(&self) -> bool {
+        !self
+            .exists(|expr| {
+                Ok(match expr {
+                    Expr::Exists(Exists { subquery, .. })
+                    | Expr::InSubquery(InSubquery { subquery, .. })
+                    | Expr::ScalarSubquery(subquery) => subquery_is_volatile(subquery)?,
+                    _ => expr.is_volatile_node(),
+                })
+            })
+            .expect("exists closure is infallible")
+    }
+
     /// Recursively find all [`Expr::Placeholder`] expressions, and
     /// to infer their [`DataType`] from the context of their use.
     ///
@@ -1792,6 +2282,346 @@ impl Expr {
         .map(|data| (data, has_placeholder))
     }
 
+    /// Returns the [`DataType`] of each [`Expr::Placeholder`] in this
+    /// expression, keyed by placeholder id.
+    ///
+    /// Types are inferred from the context of their use, as in
+    /// [`Self::infer_placeholder_types`]. Occurrences of a placeholder whose
+    /// type can not be inferred are ignored if another occurrence has a type.
+    /// A placeholder without any typed occurrence, or that is inferred to
+    /// have different types in different places, is reported as
+    /// [`DataType::Null`].
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion_common::DFSchema;
+    /// # use datafusion_expr::{col, placeholder};
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+    /// let schema = DFSchema::try_from(schema).unwrap();
+    ///
+    /// // a = $1 AND $2 IS NULL
+    /// let expr = col("a").eq(placeholder("$1")).and(placeholder("$2").is_null());
+    /// let types = expr.placeholder_types(&schema).unwrap();
+    /// assert_eq!(types["$1"], DataType::Int64);
+    /// assert_eq!(types["$2"], DataType::Null);
+    /// ```
+    pub fn placeholder_types(
+        &self,
+        schema: &DFSchema,
+    ) -> Result<HashMap<String, DataType>> {
+        let (expr, _) = self.clone().infer_placeholder_types(schema)?;
+        // `None` until a typed occurrence of the placeholder is found
+        let mut types: HashMap<String, Option<DataType>> = HashMap::new();
+        expr.apply(|expr| {
+            if let Expr::Placeholder(Placeholder { id, data_type }) = expr {
+                let prev = types.entry(id.clone()).or_default();
+                match (prev, data_type) {
+                    (_, None) => {}
+                    (prev @ None, Some(data_type)) => *prev = Some(data_type.clone()),
+                    (Some(prev), Some(data_type)) if prev != data_type => {
+                        *prev = DataType::Null;
+                    }
+                    (Some(_), Some(_)) => {}
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(types
+            .into_iter()
+            .map(|(id, data_type)| (id, data_type.unwrap_or(DataType::Null)))
+            .collect())
+    }
+
+    /// Replaces each [`Expr::Literal`] in this expression with a placeholder
+    /// (`$1`, `$2`, ...), and returns the new expression together with the
+    /// replaced values, in placeholder order.
+    ///
+    /// Expressions that differ only in their constants are replaced with
+    /// the same expression, which makes it useful as a key for caching plans.
+    /// The original expression can be reconstructed by passing the values to
+    /// [`Self::with_param_values`].
+    ///
+    /// Existing placeholders are left unchanged, so their ids may clash with
+    /// the new ones.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_common::ScalarValue;
+    /// # use datafusion_expr::{col, lit, placeholder};
+    /// let expr = col("id").eq(lit("2"));
+    /// let (parameterized, values) = expr.replace_literals();
+    /// assert_eq!(parameterized, col("id").eq(placeholder("$1")));
+    /// assert_eq!(values, vec![ScalarValue::from("2")]);
+    ///
+    /// // binding the values reconstructs the original expression
+    /// assert_eq!(parameterized.with_param_values(values).unwrap(), expr);
+    /// ```
+    pub fn replace_literals(&self) -> (Expr, Vec<ScalarValue>) {
+        let mut values = vec![];
+        let expr = self
+            .clone()
+            .transform(|expr| match expr {
+                Expr::Literal(value) => {
+                    values.push(value);
+                    Ok(Transformed::yes(Expr::Placeholder(Placeholder::new(
+                        format!("${}", values.len()),
+                        None,
+                    ))))
+                }
+                _ => Ok(Transformed::no(expr)),
+            })
+            .data()
+            .expect("transform closure is infallible");
+        (expr, values)
+    }
+
+    /// Returns this expression with all placeholders (e.g `$1`, `$2`, ...)
+    /// replaced with the corresponding values in `param_values`.
+    ///
+    /// Returns an error if there is no value for a placeholder.
+    ///
+    /// See [`LogicalPlan::with_param_values`] to replace the placeholders of a
+    /// plan, and [`Self::replace_literals`] for an example.
+    ///
+    /// [`LogicalPlan::with_param_values`]: crate::LogicalPlan::with_param_values
+    pub fn with_param_values(self, param_values: impl Into<ParamValues>) -> Result<Expr> {
+        let param_values = param_values.into();
+        self.transform(|expr| match expr {
+            Expr::Placeholder(Placeholder { id, .. }) => {
+                let value = param_values.get_placeholders_with_values(&id)?;
+                Ok(Transformed::yes(Expr::Literal(value)))
+            }
+            _ => Ok(Transformed::no(expr)),
+        })
+        .data()
+    }
+
+    /// Replaces each placeholder `$name` in this expression with a reference
+    /// to the column `name` of `schema`, if there is one, so that an omitted
+    /// value defaults to the value of the column.
+    ///
+    /// Returns the new expression together with the ids of the placeholders
+    /// that have no matching column, which are left unchanged, in the order
+    /// in which they first appear. Returns an error if the name of a
+    /// placeholder matches columns of several relations.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow::datatypes::{DataType, Field, Schema};
+    /// # use datafusion_common::DFSchema;
+    /// # use datafusion_expr::{col, placeholder};
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+    /// let schema = DFSchema::try_from(schema).unwrap();
+    ///
+    /// let expr = placeholder("$a").gt(placeholder("$b"));
+    /// let (expr, unbound) = expr
+    ///     .replace_placeholders_with_schema_defaults(&schema)
+    ///     .unwrap();
+    /// assert_eq!(expr, col("a").gt(placeholder("$b")));
+    /// assert_eq!(unbound, vec!["$b"]);
+    /// ```
+    pub fn replace_placeholders_with_schema_defaults(
+        self,
+        schema: &DFSchema,
+    ) -> Result<(Expr, Vec<String>)> {
+        let mut unbound: Vec<String> = vec![];
+        let expr = self
+            .transform(|expr| {
+                let Expr::Placeholder(Placeholder { id, .. }) = &expr else {
+                    return Ok(Transformed::no(expr));
+                };
+                let name = id.strip_prefix('$').unwrap_or(id);
+                let mut columns = schema.columns_with_unqualified_name(name);
+                match columns.len() {
+                    0 => {
+                        if !unbound.contains(id) {
+                            unbound.push(id.clone());
+                        }
+                        Ok(Transformed::no(expr))
+                    }
+                    1 => Ok(Transformed::yes(Expr::Column(columns.remove(0)))),
+                    _ => plan_err!(
+                        "Placeholder {id} matches several columns: {}",
+                        columns
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                }
+            })
+            .data()?;
+        Ok((expr, unbound))
+    }
+
+    /// Returns this expression with every subexpression equal to `target`
+    /// replaced with `replacement`.
+    ///
+    /// Subexpressions are compared structurally with `==`, so for example
+    /// `a + b` does not match `b + a`. The replacements are not searched for
+    /// further occurrences of `target`, so `replacement` may contain `target`.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = (col("a") + lit(1)).gt(col("a") + lit(1)).and(col("b"));
+    /// let expr = expr.substitute(&(col("a") + lit(1)), &col("c"));
+    /// assert_eq!(expr, col("c").gt(col("c")).and(col("b")));
+    /// ```
+    pub fn substitute(&self, target: &Expr, replacement: &Expr) -> Expr {
+        self.clone()
+            .transform_down(|expr| {
+                if &expr == target {
+                    Ok(Transformed::new(
+                        replacement.clone(),
+                        true,
+                        TreeNodeRecursion::Jump,
+                    ))
+                } else {
+                    Ok(Transformed::no(expr))
+                }
+            })
+            .data()
+            .expect("transform closure is infallible")
+    }
+
+    /// Returns the negation of this predicate in negation normal form, where
+    /// `NOT` is pushed down through `AND` and `OR` with De Morgan's laws and
+    /// absorbed by the predicates below them.
+    ///
+    /// For example `NOT (a AND b)` becomes `NOT a OR NOT b`, and comparisons
+    /// are flipped, so `a < 5` becomes `a >= 5`. The result is equivalent to
+    /// `NOT self` under SQL's three-valued logic, so it is NULL exactly when
+    /// `NOT self` is NULL: `a IS NULL` becomes `a IS NOT NULL`, and `a IS TRUE`
+    /// becomes `a IS NOT TRUE` rather than `a IS FALSE`. Predicates that can
+    /// not absorb the negation, such as columns and function calls, are
+    /// wrapped in `NOT`, and any `NOT` within this predicate is pushed down
+    /// as well.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = col("a").lt(lit(5)).and(col("b").is_null().or(col("c")));
+    /// assert_eq!(
+    ///     expr.negate_predicate(),
+    ///     col("a")
+    ///         .gt_eq(lit(5))
+    ///         .or(col("b").is_not_null().and(!col("c")))
+    /// );
+    /// ```
+    pub fn negate_predicate(&self) -> Expr {
+        negation_normal_form(self, true)
+    }
+
+    /// Returns this expression with every `IN` list rewritten to a chain of
+    /// equality comparisons, for sources that can not evaluate `IN` lists.
+    ///
+    /// `x IN (a, b)` becomes `x = a OR x = b`, and `x NOT IN (a, b)` becomes
+    /// `x != a AND x != b`, which are equivalent under SQL's three-valued
+    /// logic. Empty `IN` lists are kept, and so are `IN` lists comparing a
+    /// [volatile](Self::is_volatile) expression, such as `random()`, which
+    /// would be evaluated once per comparison. See
+    /// [`Self::rewrite_or_to_in_list`] for the inverse rewrite.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = col("a").in_list(vec![lit(1), lit(2)], false);
+    /// assert_eq!(
+    ///     expr.rewrite_in_list_to_or(),
+    ///     col("a").eq(lit(1)).or(col("a").eq(lit(2)))
+    /// );
+    /// ```
+    pub fn rewrite_in_list_to_or(&self) -> Expr {
+        self.clone()
+            .transform_up(|expr| match expr {
+                Expr::InList(InList {
+                    expr,
+                    list,
+                    negated,
+                }) if !list.is_empty() && !expr.is_volatile() => {
+                    let (op, chain_op) = if negated {
+                        (Operator::NotEq, Operator::And)
+                    } else {
+                        (Operator::Eq, Operator::Or)
+                    };
+                    let chain = list
+                        .into_iter()
+                        .map(|value| binary_expr(expr.as_ref().clone(), op, value))
+                        .reduce(|chain, expr| binary_expr(chain, chain_op, expr))
+                        .expect("list is not empty");
+                    Ok(Transformed::yes(chain))
+                }
+                _ => Ok(Transformed::no(expr)),
+            })
+            .data()
+            .expect("transform closure is infallible")
+    }
+
+    /// Returns this expression with every chain of equality comparisons of
+    /// the same expression rewritten to an `IN` list, for sources that
+    /// evaluate `IN` lists more efficiently.
+    ///
+    /// `x = a OR x = b` becomes `x IN (a, b)`, and `x != a AND x != b`
+    /// becomes `x NOT IN (a, b)`. The compared expression must be the left
+    /// operand of every comparison, and chains with any other operand are
+    /// kept. Chains comparing a [volatile](Self::is_volatile) expression are
+    /// kept too, since each comparison evaluates it again. This is the
+    /// inverse of [`Self::rewrite_in_list_to_or`].
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion_expr::{col, lit};
+    /// let expr = col("a").not_eq(lit(1)).and(col("a").not_eq(lit(2)));
+    /// assert_eq!(
+    ///     expr.rewrite_or_to_in_list(),
+    ///     col("a").in_list(vec![lit(1), lit(2)], true)
+    /// );
+    /// ```
+    pub fn rewrite_or_to_in_list(&self) -> Expr {
+        self.clone()
+            .transform_down(|expr| {
+                let (chain_op, op, negated) = match &expr {
+                    Expr::BinaryExpr(BinaryExpr {
+                        op: Operator::Or, ..
+                    }) => (Operator::Or, Operator::Eq, false),
+                    Expr::BinaryExpr(BinaryExpr {
+                        op: Operator::And, ..
+                    }) => (Operator::And, Operator::NotEq, true),
+                    _ => return Ok(Transformed::no(expr)),
+                };
+
+                let mut compared = None;
+                let mut list = vec![];
+                for operand in split_binary(&expr, chain_op) {
+                    match operand {
+                        Expr::BinaryExpr(BinaryExpr {
+                            left,
+                            op: operand_op,
+                            right,
+                        }) if *operand_op == op
+                            && !left.is_volatile()
+                            && compared.is_none_or(|compared| compared == left) =>
+                        {
+                            compared = Some(left);
+                            list.push(right.as_ref().clone());
+                        }
+                        _ => return Ok(Transformed::no(expr)),
+                    }
+                }
+                let in_list = match compared {
+                    Some(compared) => {
+                        Expr::InList(InList::new(compared.clone(), list, negated))
+                    }
+                    None => return Ok(Transformed::no(expr)),
+                };
+                Ok(Transformed::yes(in_list))
+            })
+            .data()
+            .expect("transform closure is infallible")
+    }
+
     /// Returns true if some of this `exprs` subexpressions may not be evaluated
     /// and thus any side effects (like divide by zero) may not be encountered
     pub fn short_circuits(&self) -> bool {
@@ -1850,6 +2680,24 @@ impl Expr {
     }
 }
 
+/// Returns true if swapping the operands of `op` does not change the result
+fn is_commutative(op: Operator) -> bool {
+    matches!(
+        op,
+        Operator::Plus
+            | Operator::Multiply
+            | Operator::BitwiseAnd
+            | Operator::BitwiseOr
+            | Operator::BitwiseXor
+            | Operator::Eq
+            | Operator::NotEq
+            | Operator::IsDistinctFrom
+            | Operator::IsNotDistinctFrom
+            | Operator::And
+            | Operator::Or
+    )
+}
+
 impl Normalizeable for Expr {
     fn can_normalize(&self) -> bool {
         #[allow(clippy::match_like_matches_macro)]
@@ -2102,6 +2950,7 @@ impl NormalizeEq for Expr {
                         window_frame: self_window_frame,
                         partition_by: self_partition_by,
                         order_by: self_order_by,
+                        filter: self_filter,
                         null_treatment: self_null_treatment,
                     },
                     WindowFunctionParams {
@@ -2109,6 +2958,7 @@ impl NormalizeEq for Expr {
                         window_frame: other_window_frame,
                         partition_by: other_partition_by,
                         order_by: other_order_by,
+                        filter: other_filter,
                         null_treatment: other_null_treatment,
                     },
                 ) = (self_params, other_params);
@@ -2121,6 +2971,13 @@ impl NormalizeEq for Expr {
                         .iter()
                         .zip(other_args.iter())
                         .all(|(a, b)| a.normalize_eq(b))
+                    && match (self_filter, other_filter) {
+                        (Some(self_filter), Some(other_filter)) => {
+                            self_filter.normalize_eq(other_filter)
+                        }
+                        (None, None) => true,
+                        _ => false,
+                    }
                     && self_partition_by
                         .iter()
                         .zip(other_partition_by.iter())
@@ -2362,6 +3219,7 @@ impl HashNode for Expr {
                     partition_by: _,
                     order_by: _,
                     window_frame,
+                    filter: _,
                     null_treatment,
                 } = params;
                 fun.hash(state);
@@ -2414,6 +3272,90 @@ impl HashNode for Expr {
     }
 }
 
+/// Returns true if any expression in the plan of `subquery`, or of the
+/// subqueries it contains, is volatile
+fn subquery_is_volatile(subquery: &Subquery) -> Result<bool> {
+    let mut is_volatile = false;
+    subquery.subquery.apply_with_subqueries(|plan| {
+        plan.apply_expressions(|expr| {
+            is_volatile = expr.is_volatile();
+            Ok(if is_volatile {
+                TreeNodeRecursion::Stop
+            } else {
+                TreeNodeRecursion::Continue
+            })
+        })
+    })?;
+    Ok(is_volatile)
+}
+
+/// Returns the only character of the `ESCAPE` string of a `LIKE` expression
+fn single_escape_char(escape_char: &str) -> Result<char> {
+    let mut chars = escape_char.chars();
+    match (chars.next(), chars.next()) {
+        (Some(char), None) => Ok(char),
+        _ => plan_err!("Invalid escape character in LIKE expression"),
+    }
+}
+
+/// Returns `expr`, or its negation if `negated`, with the `NOT`s pushed down
+/// to the predicates that can not absorb them
+fn negation_normal_form(expr: &Expr, negated: bool) -> Expr {
+    match expr {
+        Expr::Not(inner) => negation_normal_form(inner, !negated),
+        Expr::BinaryExpr(BinaryExpr { left, op, right })
+            if matches!(op, Operator::And | Operator::Or) =>
+        {
+            // De Morgan's laws also hold under three-valued logic
+            let op = match (op, negated) {
+                (Operator::And, true) => Operator::Or,
+                (Operator::Or, true) => Operator::And,
+                (op, _) => *op,
+            };
+            binary_expr(
+                negation_normal_form(left, negated),
+                op,
+                negation_normal_form(right, negated),
+            )
+        }
+        _ if !negated => expr.clone(),
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => match op.negate() {
+            Some(op) => binary_expr(left.as_ref().clone(), op, right.as_ref().clone()),
+            None => Expr::Not(Box::new(expr.clone())),
+        },
+        Expr::Like(_) | Expr::SimilarTo(_) => !expr.clone(),
+        Expr::Between(between) => Expr::Between(Between {
+            negated: !between.negated,
+            ..between.clone()
+        }),
+        Expr::InList(in_list) => Expr::InList(InList {
+            negated: !in_list.negated,
+            ..in_list.clone()
+        }),
+        Expr::Exists(exists) => Expr::Exists(Exists {
+            negated: !exists.negated,
+            ..exists.clone()
+        }),
+        Expr::InSubquery(in_subquery) => Expr::InSubquery(InSubquery {
+            negated: !in_subquery.negated,
+            ..in_subquery.clone()
+        }),
+        Expr::IsNull(inner) => Expr::IsNotNull(inner.clone()),
+        Expr::IsNotNull(inner) => Expr::IsNull(inner.clone()),
+        Expr::IsTrue(inner) => Expr::IsNotTrue(inner.clone()),
+        Expr::IsNotTrue(inner) => Expr::IsTrue(inner.clone()),
+        Expr::IsFalse(inner) => Expr::IsNotFalse(inner.clone()),
+        Expr::IsNotFalse(inner) => Expr::IsFalse(inner.clone()),
+        Expr::IsUnknown(inner) => Expr::IsNotUnknown(inner.clone()),
+        Expr::IsNotUnknown(inner) => Expr::IsUnknown(inner.clone()),
+        // the negation of NULL is NULL
+        Expr::Literal(ScalarValue::Boolean(value)) => {
+            Expr::Literal(ScalarValue::Boolean(value.map(|value| !value)))
+        }
+        _ => Expr::Not(Box::new(expr.clone())),
+    }
+}
+
 // Modifies expr if it is a placeholder with datatype of right
 fn rewrite_placeholder(expr: &mut Expr, other: &Expr, schema: &DFSchema) -> Result<()> {
     if let Expr::Placeholder(Placeholder { id: _, data_type }) = expr {
@@ -2663,6 +3605,7 @@ impl Display for SchemaDisplay<'_> {
                         partition_by,
                         order_by,
                         window_frame,
+                        filter,
                         null_treatment,
                     } = params;
 
@@ -2677,6 +3620,10 @@ impl Display for SchemaDisplay<'_> {
                         write!(f, " {null_treatment}")?;
                     }
 
+                    if let Some(filter) = filter {
+                        write!(f, " FILTER (WHERE {filter})")?;
+                    }
+
                     if !partition_by.is_empty() {
                         write!(
                             f,
@@ -3043,6 +3990,7 @@ impl Display for Expr {
                         partition_by,
                         order_by,
                         window_frame,
+                        filter,
                         null_treatment,
                     } = params;
 
@@ -3052,6 +4000,10 @@ impl Display for Expr {
                         write!(f, "{nt}")?;
                     }
 
+                    if let Some(fe) = filter {
+                        write!(f, " FILTER (WHERE {fe})")?;
+                    }
+
                     if !partition_by.is_empty() {
                         write!(f, " PARTITION BY [{}]", expr_vec_fmt!(partition_by))?;
                     }
@@ -3201,10 +4153,14 @@ pub fn physical_name(expr: &Expr) -> Result<String> {
 
 #[cfg(test)]
 mod test {
-    use crate::expr_fn::col;
+    use crate::expr_fn::{
+        cast, col, in_subquery, placeholder, scalar_subquery, try_cast,
+    };
+    use crate::test::function_stub::{sum, sum_udaf};
     use crate::{
         case, lit, qualified_wildcard, wildcard, wildcard_with_options, ColumnarValue,
-        ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Volatility,
+        ExprFunctionExt, LogicalPlanBuilder, ScalarFunctionArgs, ScalarUDF,
+        ScalarUDFImpl, Volatility,
     };
     use arrow::datatypes::{Field, Schema};
     use sqlparser::ast;
@@ -3317,6 +4273,120 @@ mod test {
         }
     }
 
+    #[test]
+    fn columns() {
+        // (b + a) * b > t.a
+        let expr = ((col("b") + col("a")) * col("b")).gt(col("t.a"));
+        assert_eq!(
+            expr.columns(),
+            vec![
+                Column::new_unqualified("b"),
+                Column::new_unqualified("a"),
+                Column::new(Some("t"), "a"),
+            ]
+        );
+
+        // columns inside of nested expressions are included
+        let expr = case(col("c"))
+            .when(lit(1), col("d").alias("x"))
+            .otherwise(col("c"))
+            .unwrap();
+        assert_eq!(
+            expr.columns(),
+            vec![Column::new_unqualified("c"), Column::new_unqualified("d")]
+        );
+
+        assert!(lit(1).columns().is_empty());
+    }
+
+    #[test]
+    fn with_alias_qualified() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let df_schema = DFSchema::try_from(schema)?;
+
+        let expr = col("a").with_alias_qualified("t", "b")?;
+        let (qualifier, field) = expr.to_field(&df_schema)?;
+        assert_eq!(qualifier, Some(TableReference::bare("t")));
+        assert_eq!(field.name(), "b");
+
+        // an unqualified alias is replaced, keeping its metadata
+        let metadata = std::collections::HashMap::from([("k".into(), "v".into())]);
+        let expr = col("a")
+            .alias_with_metadata("x", Some(metadata.clone()))
+            .with_alias_qualified("t", "b")?;
+        assert_eq!(
+            expr,
+            Expr::Alias(
+                Alias::new(col("a"), Some("t"), "b").with_metadata(Some(metadata))
+            )
+        );
+
+        // a qualified alias can only be replaced with the same qualifier
+        let expr = col("a").alias_qualified(Some("t"), "x");
+        assert_eq!(
+            expr.clone().with_alias_qualified("t", "b")?,
+            col("a").alias_qualified(Some("t"), "b")
+        );
+        let err = expr.with_alias_qualified("u", "b").unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Can not alias a AS t.x with qualifier u"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn placeholder_types() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("id", DataType::Int32, true),
+        ]));
+        let df_schema = DFSchema::try_from(schema).unwrap();
+
+        // id = $1 AND name LIKE $2 AND $3 = $4
+        let expr = col("id")
+            .eq(placeholder("$1"))
+            .and(col("name").like(placeholder("$2")))
+            .and(placeholder("$3").eq(placeholder("$4")));
+        let types = expr.placeholder_types(&df_schema).unwrap();
+        assert_eq!(
+            types,
+            HashMap::from([
+                ("$1".to_string(), DataType::Int32),
+                ("$2".to_string(), DataType::Utf8),
+                ("$3".to_string(), DataType::Null),
+                ("$4".to_string(), DataType::Null),
+            ])
+        );
+
+        // a placeholder used with different types is ambiguous
+        let expr = col("id")
+            .eq(placeholder("$1"))
+            .or(col("name").eq(placeholder("$1")));
+        let types = expr.placeholder_types(&df_schema).unwrap();
+        assert_eq!(types["$1"], DataType::Null);
+
+        // a placeholder used with the same type is not ambiguous
+        let expr = col("id")
+            .eq(placeholder("$1"))
+            .or(col("id").gt(placeholder("$1")));
+        let types = expr.placeholder_types(&df_schema).unwrap();
+        assert_eq!(types["$1"], DataType::Int32);
+
+        // occurrences without an inferred type do not make a placeholder
+        // ambiguous
+        let expr = col("id")
+            .eq(placeholder("$1"))
+            .and(placeholder("$1").is_not_null());
+        let types = expr.placeholder_types(&df_schema).unwrap();
+        assert_eq!(types["$1"], DataType::Int32);
+        let expr = placeholder("$1")
+            .is_null()
+            .or(col("id").eq(placeholder("$1")));
+        let types = expr.placeholder_types(&df_schema).unwrap();
+        assert_eq!(types["$1"], DataType::Int32);
+    }
+
     #[test]
     #[allow(deprecated)]
     fn format_case_when() -> Result<()> {
@@ -3462,6 +4532,174 @@ mod test {
         assert_eq!(udf.signature().volatility, Volatility::Volatile);
     }
 
+    #[test]
+    fn test_is_deterministic() {
+        #[derive(Debug)]
+        struct TestScalarUDF {
+            signature: Signature,
+        }
+        impl ScalarUDFImpl for TestScalarUDF {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+            fn name(&self) -> &str {
+                "TestScalarUDF"
+            }
+            fn signature(&self) -> &Signature {
+                &self.signature
+            }
+            fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+                Ok(DataType::Float64)
+            }
+            fn invoke_with_args(
+                &self,
+                _args: ScalarFunctionArgs,
+            ) -> Result<ColumnarValue> {
+                Ok(ColumnarValue::Scalar(ScalarValue::from(1.0)))
+            }
+        }
+        let udf = |volatility| {
+            ScalarUDF::from(TestScalarUDF {
+                signature: Signature::variadic_any(volatility),
+            })
+        };
+        let volatile = udf(Volatility::Volatile).call(vec![lit(1)]);
+        let stable = udf(Volatility::Stable).call(vec![col("a")]);
+
+        assert!(col("a").is_deterministic());
+        assert!((col("a") + stable.clone()).is_deterministic());
+        assert!(!volatile.is_deterministic());
+        assert!(!(col("a") + volatile.clone()).is_deterministic());
+
+        // nested in other expressions
+        let nested = udf(Volatility::Stable).call(vec![lit(1), volatile.clone()]);
+        assert!(!nested.is_deterministic());
+        assert!(!case(col("a"))
+            .when(lit(1), volatile.clone().alias("v"))
+            .otherwise(lit(2.0))
+            .unwrap()
+            .is_deterministic());
+        assert!(
+            !Expr::Cast(Cast::new(Box::new(volatile.clone()), DataType::Utf8))
+                .is_deterministic()
+        );
+
+        // in aggregate arguments and filters
+        assert!(sum(stable.clone()).is_deterministic());
+        assert!(!sum(volatile.clone()).is_deterministic());
+        let filtered = sum(col("a"))
+            .filter(col("a").gt(volatile.clone()))
+            .build()
+            .unwrap();
+        assert!(!filtered.is_deterministic());
+
+        // in subqueries
+        let plan = |expr: Expr| {
+            Arc::new(
+                LogicalPlanBuilder::empty(true)
+                    .project(vec![expr])
+                    .unwrap()
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let stable_literal = udf(Volatility::Stable).call(vec![lit(1)]);
+        assert!(scalar_subquery(plan(stable_literal)).is_deterministic());
+        assert!(!scalar_subquery(plan(volatile.clone())).is_deterministic());
+        let nested = in_subquery(col("a"), plan(scalar_subquery(plan(volatile))));
+        assert!(!nested.is_deterministic());
+    }
+
+    #[test]
+    fn test_replace_literals() -> Result<()> {
+        let expr = col("a")
+            .eq(lit(1))
+            .and(col("b").between(lit("x"), lit("y")))
+            .or(col("c").is_null());
+        let (parameterized, values) = expr.replace_literals();
+        assert_eq!(
+            parameterized,
+            col("a")
+                .eq(placeholder("$1"))
+                .and(col("b").between(placeholder("$2"), placeholder("$3")))
+                .or(col("c").is_null())
+        );
+        assert_eq!(
+            values,
+            vec![
+                ScalarValue::Int32(Some(1)),
+                ScalarValue::from("x"),
+                ScalarValue::from("y")
+            ]
+        );
+        assert_eq!(parameterized.clone().with_param_values(values)?, expr);
+
+        // structurally identical expressions share the same parameterization
+        let other = col("a")
+            .eq(lit(2))
+            .and(col("b").between(lit("p"), lit("q")))
+            .or(col("c").is_null());
+        assert_eq!(other.replace_literals().0, parameterized);
+
+        // no literals
+        let (no_literals, values) = col("a").is_null().replace_literals();
+        assert_eq!(no_literals, col("a").is_null());
+        assert!(values.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_param_values_missing_value() {
+        let expr = col("a")
+            .eq(placeholder("$1"))
+            .and(col("b").eq(placeholder("$2")));
+        let err = expr
+            .with_param_values(vec![ScalarValue::from(1)])
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: No value found for placeholder with id $2"
+        );
+    }
+
+    #[test]
+    fn test_replace_placeholders_with_schema_defaults() -> Result<()> {
+        let schema = DFSchema::try_from_qualified_schema(
+            "t",
+            &Schema::new(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+            ]),
+        )?;
+        let expr = col("a")
+            .eq(placeholder("$a"))
+            .and(placeholder("$c").is_null())
+            .or(placeholder("$1").eq(placeholder("$c")));
+        let (expr, unbound) = expr.replace_placeholders_with_schema_defaults(&schema)?;
+        assert_eq!(
+            expr,
+            col("a")
+                .eq(col("t.a"))
+                .and(placeholder("$c").is_null())
+                .or(placeholder("$1").eq(placeholder("$c")))
+        );
+        assert_eq!(unbound, vec!["$c", "$1"]);
+
+        // a name shared by columns of several relations is ambiguous
+        let schema = schema.join(&DFSchema::try_from_qualified_schema(
+            "u",
+            &Schema::new(vec![Field::new("a", DataType::Int64, true)]),
+        )?)?;
+        let err = placeholder("$a")
+            .replace_placeholders_with_schema_defaults(&schema)
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Placeholder $a matches several columns: t.a, u.a"
+        );
+        Ok(())
+    }
+
     use super::*;
 
     #[test]
@@ -3553,6 +4791,190 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_rewrite_column_refs() {
+        let rename = |c: &Column| {
+            if c.name == "a" {
+                Column::from_name("renamed")
+            } else {
+                c.clone()
+            }
+        };
+
+        let expr = (col("a") + col("b")).alias("a");
+        let expected = (col("renamed") + col("b")).alias("a");
+        assert_eq!(expr.rewrite_column_refs(rename), expected);
+
+        // columns in nested expressions are rewritten too
+        let expr = case(col("a"))
+            .when(lit(1), col("a").is_null())
+            .otherwise(col("c"))
+            .unwrap();
+        let expected = case(col("renamed"))
+            .when(lit(1), col("renamed").is_null())
+            .otherwise(col("c"))
+            .unwrap();
+        assert_eq!(expr.rewrite_column_refs(rename), expected);
+    }
+
+    #[test]
+    fn test_unalias_nested() {
+        let expr = sum(col("a").alias("x") + lit(1).alias("one").alias("uno"))
+            .filter(col("b").alias("y").gt(lit(0)))
+            .build()
+            .unwrap()
+            .alias("total");
+        let expected = sum(col("a") + lit(1))
+            .filter(col("b").gt(lit(0)))
+            .build()
+            .unwrap();
+        let unaliased = expr.unalias_nested();
+        assert!(unaliased.transformed);
+        assert_eq!(unaliased.data, expected);
+
+        // expressions without aliases are unchanged
+        let unaliased = expected.clone().unalias_nested();
+        assert!(!unaliased.transformed);
+        assert_eq!(unaliased.data, expected);
+
+        // aliases in subqueries are kept
+        let plan = Arc::new(
+            LogicalPlanBuilder::empty(true)
+                .project(vec![lit(1).alias("one")])
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+        let expr = scalar_subquery(plan).alias("s");
+        assert_eq!(expr.clone().unalias_nested().data, expr.unalias());
+    }
+
+    #[test]
+    fn test_find_subexpr() {
+        let agg = sum(col("a") + lit(1))
+            .filter(col("b").gt(lit(0)))
+            .order_by(vec![col("c").sort(true, true)])
+            .build()
+            .unwrap();
+        let window = Expr::WindowFunction(WindowFunction::new(
+            WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+            vec![col("d")],
+        ))
+        .partition_by(vec![col("e")])
+        .order_by(vec![col("f").sort(false, true)])
+        .build()
+        .unwrap();
+        let expr = (agg.clone() + window.clone()).alias("total");
+
+        let columns = expr.find_subexpr(|e| matches!(e, Expr::Column(_)));
+        let expected = ["a", "b", "c", "d", "e", "f"].map(col);
+        assert_eq!(columns, expected.iter().collect::<Vec<_>>());
+
+        // a node is returned before the nodes it contains
+        let functions = expr.find_subexpr(|e| {
+            matches!(e, Expr::AggregateFunction(_) | Expr::WindowFunction(_))
+                || e == &col("a")
+        });
+        assert_eq!(functions, vec![&agg, &col("a"), &window]);
+
+        // the expression itself is included
+        assert_eq!(
+            expr.find_subexpr(|e| matches!(e, Expr::Alias(_))),
+            vec![&expr]
+        );
+        assert!(expr.find_subexpr(|e| e == &col("g")).is_empty());
+    }
+
+    #[test]
+    fn test_count_nodes() {
+        assert_eq!(col("a").count_nodes(), 1);
+        assert_eq!((col("a") + lit(1)).alias("b").count_nodes(), 4);
+
+        // sum, a + 1, a, 1, b > 0, b, 0, c
+        let agg = sum(col("a") + lit(1))
+            .filter(col("b").gt(lit(0)))
+            .order_by(vec![col("c").sort(true, true)])
+            .build()
+            .unwrap();
+        assert_eq!(agg.count_nodes(), 8);
+
+        // sum, d, e, f
+        let window = Expr::WindowFunction(WindowFunction::new(
+            WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+            vec![col("d")],
+        ))
+        .partition_by(vec![col("e")])
+        .order_by(vec![col("f").sort(false, true)])
+        .build()
+        .unwrap();
+        assert_eq!(window.count_nodes(), 4);
+
+        assert_eq!((agg + window).count_nodes(), 13);
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let a = || col("a");
+        let b = || col("b");
+        let pairs = vec![
+            (a() + b(), b() + a()),
+            (a() * b(), b() * a()),
+            (a().eq(b()), b().eq(a())),
+            (a().and(b()), b().and(a())),
+            (a().or(b()), b().or(a())),
+            (col("id").eq(lit("2")), lit("2").eq(col("id"))),
+            // nested operands are canonicalized too
+            (
+                (a() + lit(1)).eq(b() * lit(2)),
+                (lit(2) * b()).eq(lit(1) + a()),
+            ),
+            (
+                a().gt(lit(1)).and(b().is_null().or(lit(true))),
+                lit(true).or(b().is_null()).and(a().gt(lit(1))),
+            ),
+        ];
+        for (expr, swapped) in pairs {
+            assert_ne!(expr, swapped);
+            assert_eq!(expr.canonicalize(), swapped.canonicalize(), "{expr}");
+            // canonicalizing is idempotent
+            assert_eq!(expr.canonicalize().canonicalize(), expr.canonicalize());
+        }
+
+        // the operands of non-commutative operators are not swapped
+        for expr in [
+            a() - b(),
+            b() - a(),
+            a() / b(),
+            b() / a(),
+            a().lt(b()),
+            b().lt(a()),
+        ] {
+            assert_eq!(expr.canonicalize(), expr);
+        }
+    }
+
+    #[test]
+    fn test_rewrite_cast_to_try_cast() {
+        let int = DataType::Int64;
+        assert_eq!(
+            cast(col("a"), int.clone()).rewrite_cast_to_try_cast(),
+            try_cast(col("a"), int.clone())
+        );
+
+        // nested casts, and casts in the operands of other expressions
+        let expr = cast(cast(col("a"), DataType::Utf8), int.clone())
+            .gt(lit(1))
+            .and(col("b").is_null());
+        let expected = try_cast(try_cast(col("a"), DataType::Utf8), int.clone())
+            .gt(lit(1))
+            .and(col("b").is_null());
+        assert_eq!(expr.rewrite_cast_to_try_cast(), expected);
+
+        // expressions without casts are unchanged
+        let expr = try_cast(col("a"), int) + col("b");
+        assert_eq!(expr.rewrite_cast_to_try_cast(), expr);
+    }
+
     #[test]
     fn test_schema_display_alias_with_relation() {
         assert_eq!(
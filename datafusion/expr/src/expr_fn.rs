@@ -27,12 +27,14 @@ use crate::function::{
 };
 use crate::select_expr::SelectExpr;
 use crate::{
-    conditional_expressions::CaseBuilder, expr::Sort, logical_plan::Subquery,
-    AggregateUDF, Expr, LogicalPlan, Operator, PartitionEvaluator, ScalarFunctionArgs,
-    ScalarFunctionImplementation, ScalarUDF, Signature, Volatility,
+    conditional_expressions::CaseBuilder, expr::NullsPlacement, expr::Sort, lit,
+    logical_plan::Subquery, AggregateUDF, Expr, LogicalPlan, Operator,
+    PartitionEvaluator, ScalarFunctionArgs, ScalarFunctionImplementation, ScalarUDF,
+    Signature, Volatility,
 };
 use crate::{
-    AggregateUDFImpl, ColumnarValue, ScalarUDFImpl, WindowFrame, WindowUDF, WindowUDFImpl,
+    AggregateUDFImpl, ColumnarValue, ScalarUDFImpl, WindowFrame,
+    WindowFunctionDefinition, WindowUDF, WindowUDFImpl,
 };
 use arrow::compute::kernels::cast_utils::{
     parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
@@ -746,9 +748,26 @@ pub fn interval_month_day_nano_lit(value: &str) -> Expr {
 pub trait ExprFunctionExt {
     /// Add `ORDER BY <order_by>`
     fn order_by(self, order_by: Vec<Sort>) -> ExprFuncBuilder;
+    /// Add `ORDER BY <expr> [ASC | DESC] NULLS [FIRST | LAST]` where each
+    /// sort key is an `(expr, asc, nulls)` tuple with explicit [`NullsPlacement`].
+    ///
+    /// This can not be combined with [`Self::order_by`] on the same function;
+    /// doing so results in an error when the function is built.
+    fn order_by_with_nulls(
+        self,
+        order_by: Vec<(Expr, bool, NullsPlacement)>,
+    ) -> ExprFuncBuilder;
     /// Add `FILTER <filter>`
+    ///
+    /// For aggregate window functions, rows for which `filter` is not true
+    /// are skipped when accumulating, but each of them still produces the
+    /// aggregate of the rows accumulated so far in its frame. Non-aggregate
+    /// window functions do not support a filter.
     fn filter(self, filter: Expr) -> ExprFuncBuilder;
     /// Add `DISTINCT`
+    ///
+    /// When combined with [`Self::order_by`], every `ORDER BY` expression must
+    /// also be an argument of the aggregate, otherwise building it fails.
     fn distinct(self) -> ExprFuncBuilder;
     /// Add `RESPECT NULLS` or `IGNORE NULLS`
     fn null_treatment(
@@ -759,12 +778,26 @@ pub trait ExprFunctionExt {
     fn partition_by(self, partition_by: Vec<Expr>) -> ExprFuncBuilder;
     /// Add appropriate window frame conditions
     fn window_frame(self, window_frame: WindowFrame) -> ExprFuncBuilder;
+    /// Add a precision argument to an approximate aggregate function that
+    /// accepts one, such as `approx_distinct`.
+    ///
+    /// The precision is passed as an additional trailing argument, so an
+    /// aggregate that does not take a precision fails to plan.
+    fn with_precision(self, precision: u8) -> ExprFuncBuilder;
+    /// Add `WITHIN GROUP (ORDER BY <within_group>)` to an ordered-set
+    /// aggregate function, such as `approx_percentile_cont`.
+    ///
+    /// Like in SQL, the sort expression is also passed as the first argument
+    /// of the aggregate. Building fails if the aggregate is not an ordered-set
+    /// aggregate, if there is not exactly one sort expression, or if
+    /// [`Self::order_by`] is also used.
+    fn within_group(self, within_group: Vec<Sort>) -> ExprFuncBuilder;
 }
 
 #[derive(Debug, Clone)]
 pub enum ExprFuncKind {
     Aggregate(AggregateFunction),
-    Window(WindowFunction),
+    Window(Box<WindowFunction>),
 }
 
 /// Implementation of [`ExprFunctionExt`].
@@ -774,11 +807,14 @@ pub enum ExprFuncKind {
 pub struct ExprFuncBuilder {
     fun: Option<ExprFuncKind>,
     order_by: Option<Vec<Sort>>,
+    order_by_with_nulls: Option<Vec<Sort>>,
     filter: Option<Expr>,
     distinct: bool,
     null_treatment: Option<NullTreatment>,
     partition_by: Option<Vec<Expr>>,
     window_frame: Option<WindowFrame>,
+    precision: Option<u8>,
+    within_group: Option<Vec<Sort>>,
 }
 
 impl ExprFuncBuilder {
@@ -787,11 +823,14 @@ impl ExprFuncBuilder {
         Self {
             fun,
             order_by: None,
+            order_by_with_nulls: None,
             filter: None,
             distinct: false,
             null_treatment: None,
             partition_by: None,
             window_frame: None,
+            precision: None,
+            within_group: None,
         }
     }
 
@@ -800,16 +839,26 @@ impl ExprFuncBuilder {
     /// # Errors:
     ///
     /// Returns an error if this builder  [`ExprFunctionExt`] was used with an
-    /// `Expr` variant other than [`Expr::AggregateFunction`] or [`Expr::WindowFunction`]
+    /// `Expr` variant other than [`Expr::AggregateFunction`] or [`Expr::WindowFunction`],
+    /// if both [`ExprFunctionExt::order_by`] and
+    /// [`ExprFunctionExt::order_by_with_nulls`] were used, if
+    /// [`ExprFunctionExt::with_precision`] or [`ExprFunctionExt::within_group`]
+    /// was used with a window function, if an aggregate function does not
+    /// support the requested [`ExprFunctionExt::null_treatment`], if
+    /// [`ExprFunctionExt::filter`] was used with a non-aggregate window
+    /// function, or if [`ExprFunctionExt::within_group`] was used incorrectly
     pub fn build(self) -> Result<Expr> {
         let Self {
             fun,
             order_by,
+            order_by_with_nulls,
             filter,
             distinct,
             null_treatment,
             partition_by,
             window_frame,
+            precision,
+            within_group,
         } = self;
 
         let Some(fun) = fun else {
@@ -818,18 +867,86 @@ impl ExprFuncBuilder {
             );
         };
 
+        let order_by = match (order_by, order_by_with_nulls) {
+            (Some(_), Some(_)) => {
+                return plan_err!(
+                    "ExprFunctionExt::order_by and ExprFunctionExt::order_by_with_nulls can not be used together"
+                );
+            }
+            (order_by, order_by_with_nulls) => order_by.or(order_by_with_nulls),
+        };
+
         let fun_expr = match fun {
             ExprFuncKind::Aggregate(mut udaf) => {
+                if let Some(null_treatment) = null_treatment {
+                    if !udaf.func.supports_null_treatment(null_treatment) {
+                        return plan_err!(
+                            "{null_treatment} is not permitted for {}",
+                            udaf.func.name()
+                        );
+                    }
+                }
+                // Like Postgres, DISTINCT and ORDER BY can only be mixed if
+                // every ORDER BY expression is also an argument, otherwise the
+                // order of the deduplicated values is not well defined
+                if distinct {
+                    if let Some(sort) = order_by
+                        .iter()
+                        .flatten()
+                        .find(|sort| !udaf.params.args.contains(&sort.expr))
+                    {
+                        return plan_err!(
+                            "In an aggregate with DISTINCT, ORDER BY expressions must appear in argument list, but {} does not",
+                            sort.expr
+                        );
+                    }
+                }
+                let order_by = match (order_by, within_group) {
+                    (order_by, None) => order_by,
+                    (None, Some(within_group)) => {
+                        let within_group =
+                            ordered_set_within_group(&udaf.func, within_group)?;
+                        udaf.params.args.insert(0, within_group.expr.clone());
+                        Some(vec![within_group])
+                    }
+                    (Some(_), Some(_)) => {
+                        return plan_err!(
+                            "ExprFunctionExt::order_by and ExprFunctionExt::within_group can not be used together"
+                        );
+                    }
+                };
                 udaf.params.order_by = order_by;
                 udaf.params.filter = filter.map(Box::new);
                 udaf.params.distinct = distinct;
                 udaf.params.null_treatment = null_treatment;
+                if let Some(precision) = precision {
+                    udaf.params.args.push(lit(i64::from(precision)));
+                }
                 Expr::AggregateFunction(udaf)
             }
-            ExprFuncKind::Window(WindowFunction {
-                fun,
-                params: WindowFunctionParams { args, .. },
-            }) => {
+            ExprFuncKind::Window(_) if precision.is_some() => {
+                return plan_err!(
+                    "ExprFunctionExt::with_precision can only be used with Expr::AggregateFunction"
+                );
+            }
+            ExprFuncKind::Window(_) if within_group.is_some() => {
+                return plan_err!(
+                    "ExprFunctionExt::within_group can only be used with Expr::AggregateFunction"
+                );
+            }
+            ExprFuncKind::Window(window_fun) => {
+                let WindowFunction {
+                    fun,
+                    params: WindowFunctionParams { args, .. },
+                } = *window_fun;
+                if let (Some(_), WindowFunctionDefinition::WindowUDF(udwf)) =
+                    (&filter, &fun)
+                {
+                    return plan_err!(
+                        "FILTER is not supported for non-aggregate window function {}",
+                        udwf.name()
+                    );
+                }
                 let has_order_by = order_by.as_ref().map(|o| !o.is_empty());
                 Expr::WindowFunction(WindowFunction {
                     fun,
@@ -839,6 +956,7 @@ impl ExprFuncBuilder {
                         order_by: order_by.unwrap_or_default(),
                         window_frame: window_frame
                             .unwrap_or_else(|| WindowFrame::new(has_order_by)),
+                        filter: filter.map(Box::new),
                         null_treatment,
                     },
                 })
@@ -849,6 +967,34 @@ impl ExprFuncBuilder {
     }
 }
 
+/// Returns the single sort expression of a `WITHIN GROUP` clause of `func`,
+/// checking that `func` is an ordered-set aggregate
+fn ordered_set_within_group(
+    func: &AggregateUDF,
+    mut within_group: Vec<Sort>,
+) -> Result<Sort> {
+    if !func.is_ordered_set_aggregate() {
+        return plan_err!(
+            "WITHIN GROUP is only permitted for ordered-set aggregate functions, but {} is not one",
+            func.name()
+        );
+    }
+    if within_group.len() != 1 {
+        return plan_err!(
+            "Only a single ordering expression is permitted in a WITHIN GROUP clause"
+        );
+    }
+    Ok(within_group.remove(0))
+}
+
+/// Converts `(expr, asc, nulls)` sort keys into [`Sort`] expressions
+fn sorts_with_nulls(order_by: Vec<(Expr, bool, NullsPlacement)>) -> Vec<Sort> {
+    order_by
+        .into_iter()
+        .map(|(expr, asc, nulls)| expr.sort(asc, nulls.nulls_first()))
+        .collect()
+}
+
 impl ExprFunctionExt for ExprFuncBuilder {
     /// Add `ORDER BY <order_by>`
     fn order_by(mut self, order_by: Vec<Sort>) -> ExprFuncBuilder {
@@ -856,6 +1002,15 @@ impl ExprFunctionExt for ExprFuncBuilder {
         self
     }
 
+    /// Add `ORDER BY <expr> [ASC | DESC] NULLS [FIRST | LAST]`
+    fn order_by_with_nulls(
+        mut self,
+        order_by: Vec<(Expr, bool, NullsPlacement)>,
+    ) -> ExprFuncBuilder {
+        self.order_by_with_nulls = Some(sorts_with_nulls(order_by));
+        self
+    }
+
     /// Add `FILTER <filter>`
     fn filter(mut self, filter: Expr) -> ExprFuncBuilder {
         self.filter = Some(filter);
@@ -886,6 +1041,18 @@ impl ExprFunctionExt for ExprFuncBuilder {
         self.window_frame = Some(window_frame);
         self
     }
+
+    /// Add a precision argument
+    fn with_precision(mut self, precision: u8) -> ExprFuncBuilder {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Add `WITHIN GROUP (ORDER BY <within_group>)`
+    fn within_group(mut self, within_group: Vec<Sort>) -> ExprFuncBuilder {
+        self.within_group = Some(within_group);
+        self
+    }
 }
 
 impl ExprFunctionExt for Expr {
@@ -895,7 +1062,7 @@ impl ExprFunctionExt for Expr {
                 ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)))
             }
             Expr::WindowFunction(udwf) => {
-                ExprFuncBuilder::new(Some(ExprFuncKind::Window(udwf)))
+                ExprFuncBuilder::new(Some(ExprFuncKind::Window(Box::new(udwf))))
             }
             _ => ExprFuncBuilder::new(None),
         };
@@ -904,16 +1071,38 @@ impl ExprFunctionExt for Expr {
         }
         builder
     }
+    fn order_by_with_nulls(
+        self,
+        order_by: Vec<(Expr, bool, NullsPlacement)>,
+    ) -> ExprFuncBuilder {
+        let mut builder = match self {
+            Expr::AggregateFunction(udaf) => {
+                ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)))
+            }
+            Expr::WindowFunction(udwf) => {
+                ExprFuncBuilder::new(Some(ExprFuncKind::Window(Box::new(udwf))))
+            }
+            _ => ExprFuncBuilder::new(None),
+        };
+        if builder.fun.is_some() {
+            builder.order_by_with_nulls = Some(sorts_with_nulls(order_by));
+        }
+        builder
+    }
     fn filter(self, filter: Expr) -> ExprFuncBuilder {
-        match self {
+        let mut builder = match self {
             Expr::AggregateFunction(udaf) => {
-                let mut builder =
-                    ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)));
-                builder.filter = Some(filter);
-                builder
+                ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)))
+            }
+            Expr::WindowFunction(udwf) => {
+                ExprFuncBuilder::new(Some(ExprFuncKind::Window(Box::new(udwf))))
             }
             _ => ExprFuncBuilder::new(None),
+        };
+        if builder.fun.is_some() {
+            builder.filter = Some(filter);
         }
+        builder
     }
     fn distinct(self) -> ExprFuncBuilder {
         match self {
@@ -935,7 +1124,7 @@ impl ExprFunctionExt for Expr {
                 ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)))
             }
             Expr::WindowFunction(udwf) => {
-                ExprFuncBuilder::new(Some(ExprFuncKind::Window(udwf)))
+                ExprFuncBuilder::new(Some(ExprFuncKind::Window(Box::new(udwf))))
             }
             _ => ExprFuncBuilder::new(None),
         };
@@ -948,7 +1137,8 @@ impl ExprFunctionExt for Expr {
     fn partition_by(self, partition_by: Vec<Expr>) -> ExprFuncBuilder {
         match self {
             Expr::WindowFunction(udwf) => {
-                let mut builder = ExprFuncBuilder::new(Some(ExprFuncKind::Window(udwf)));
+                let mut builder =
+                    ExprFuncBuilder::new(Some(ExprFuncKind::Window(Box::new(udwf))));
                 builder.partition_by = Some(partition_by);
                 builder
             }
@@ -959,19 +1149,122 @@ impl ExprFunctionExt for Expr {
     fn window_frame(self, window_frame: WindowFrame) -> ExprFuncBuilder {
         match self {
             Expr::WindowFunction(udwf) => {
-                let mut builder = ExprFuncBuilder::new(Some(ExprFuncKind::Window(udwf)));
+                let mut builder =
+                    ExprFuncBuilder::new(Some(ExprFuncKind::Window(Box::new(udwf))));
                 builder.window_frame = Some(window_frame);
                 builder
             }
             _ => ExprFuncBuilder::new(None),
         }
     }
+
+    fn with_precision(self, precision: u8) -> ExprFuncBuilder {
+        match self {
+            Expr::AggregateFunction(udaf) => {
+                let mut builder =
+                    ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)));
+                builder.precision = Some(precision);
+                builder
+            }
+            _ => ExprFuncBuilder::new(None),
+        }
+    }
+
+    fn within_group(self, within_group: Vec<Sort>) -> ExprFuncBuilder {
+        match self {
+            Expr::AggregateFunction(udaf) => {
+                let mut builder =
+                    ExprFuncBuilder::new(Some(ExprFuncKind::Aggregate(udaf)));
+                builder.within_group = Some(within_group);
+                builder
+            }
+            _ => ExprFuncBuilder::new(None),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn order_by_with_nulls() {
+        let agg = crate::test::function_stub::count(col("a"));
+        let expected = agg
+            .clone()
+            .order_by(vec![col("b").sort(true, false), col("c").sort(false, true)])
+            .build()
+            .unwrap();
+        let actual = agg
+            .order_by_with_nulls(vec![
+                (col("b"), true, NullsPlacement::Last),
+                (col("c"), false, NullsPlacement::First),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn order_by_with_nulls_mixed() {
+        let err = crate::test::function_stub::count(col("a"))
+            .order_by(vec![col("b").sort(true, false)])
+            .order_by_with_nulls(vec![(col("c"), true, NullsPlacement::First)])
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains(
+            "order_by and ExprFunctionExt::order_by_with_nulls can not be used together"
+        ));
+    }
+
+    #[test]
+    fn distinct_with_order_by() {
+        let agg = crate::test::function_stub::count(col("a"))
+            .distinct()
+            .order_by(vec![col("a").sort(false, true)])
+            .build()
+            .unwrap();
+        let Expr::AggregateFunction(agg) = agg else {
+            panic!("expected aggregate function, got {agg}");
+        };
+        assert!(agg.params.distinct);
+        assert_eq!(agg.params.order_by, Some(vec![col("a").sort(false, true)]));
+
+        let err = crate::test::function_stub::count(col("a"))
+            .order_by(vec![col("a").sort(true, true), col("b").sort(true, true)])
+            .distinct()
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains(
+            "ORDER BY expressions must appear in argument list, but b does not"
+        ));
+    }
+
+    #[test]
+    fn with_precision() {
+        let agg = crate::test::function_stub::count(col("a"))
+            .filter(col("b").gt(lit(5)))
+            .with_precision(10)
+            .build()
+            .unwrap();
+        let Expr::AggregateFunction(agg) = agg else {
+            panic!("expected aggregate function, got {agg}");
+        };
+        assert_eq!(agg.params.args, vec![col("a"), lit(10i64)]);
+        assert_eq!(agg.params.filter, Some(Box::new(col("b").gt(lit(5)))));
+    }
+
+    #[test]
+    fn within_group_not_ordered_set() {
+        let err = crate::test::function_stub::count(col("a"))
+            .within_group(vec![col("a").sort(true, true)])
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains(
+            "WITHIN GROUP is only permitted for ordered-set aggregate functions"
+        ));
+    }
+
     #[test]
     fn filter_is_null_and_is_not_null() {
         let col_null = col("col1");
@@ -86,7 +86,7 @@ pub use datafusion_expr_common::signature::{
 pub use datafusion_expr_common::type_coercion::binary;
 pub use expr::{
     Between, BinaryExpr, Case, Cast, Expr, GetFieldAccess, GroupingSet, Like,
-    Sort as SortExpr, TryCast, WindowFunctionDefinition,
+    NullsPlacement, Sort as SortExpr, TryCast, WindowFunctionDefinition,
 };
 pub use expr_fn::*;
 pub use expr_schema::ExprSchemable;
@@ -17,7 +17,11 @@
 
 //! Literal module contains foundational types that are used to represent literals in DataFusion.
 
+use std::fmt::Display;
+use std::sync::Arc;
+
 use crate::Expr;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
 use datafusion_common::ScalarValue;
 
 /// Create a literal expression
@@ -77,6 +81,94 @@ impl Literal for ScalarValue {
     }
 }
 
+/// literal expression containing a `Date32` of the days since the UNIX epoch
+impl Literal for NaiveDate {
+    fn lit(&self) -> Expr {
+        Expr::Literal(date_to_scalar(Some(*self)))
+    }
+}
+
+/// literal expression containing a `Date32`, or a `Date32` NULL
+impl Literal for Option<NaiveDate> {
+    fn lit(&self) -> Expr {
+        Expr::Literal(date_to_scalar(*self))
+    }
+}
+
+/// literal expression containing a `Timestamp` without a timezone, see
+/// [`timestamp_to_scalar`] for its precision
+impl Literal for NaiveDateTime {
+    fn lit(&self) -> Expr {
+        Expr::Literal(timestamp_to_scalar(Some(*self), None))
+    }
+}
+
+/// literal expression containing a `Timestamp` without a timezone, or a
+/// nanosecond `Timestamp` NULL
+impl Literal for Option<NaiveDateTime> {
+    fn lit(&self) -> Expr {
+        Expr::Literal(timestamp_to_scalar(*self, None))
+    }
+}
+
+/// literal expression containing a nanosecond `Timestamp` with the timezone
+/// of the value's offset, such as `UTC` or `+05:00`. Values outside of the
+/// nanosecond range are microsecond `Timestamp`s.
+impl<Tz: TimeZone> Literal for DateTime<Tz>
+where
+    Tz::Offset: Display,
+{
+    fn lit(&self) -> Expr {
+        Expr::Literal(timestamp_to_scalar(
+            Some(self.naive_utc()),
+            Some(self.offset().to_string().into()),
+        ))
+    }
+}
+
+/// literal expression containing a `Time64` of the nanoseconds since midnight
+impl Literal for NaiveTime {
+    fn lit(&self) -> Expr {
+        Expr::Literal(ScalarValue::Time64Nanosecond(Some(time_nanos(self))))
+    }
+}
+
+/// literal expression containing a `Time64`, or a `Time64` NULL
+impl Literal for Option<NaiveTime> {
+    fn lit(&self) -> Expr {
+        Expr::Literal(ScalarValue::Time64Nanosecond(self.as_ref().map(time_nanos)))
+    }
+}
+
+fn date_to_scalar(date: Option<NaiveDate>) -> ScalarValue {
+    let days = date.map(|date| {
+        date.signed_duration_since(DateTime::UNIX_EPOCH.date_naive())
+            .num_days() as i32
+    });
+    ScalarValue::Date32(days)
+}
+
+/// Returns a nanosecond `Timestamp` of the UTC `timestamp`, or a microsecond
+/// `Timestamp` if it is outside of the nanosecond range (about the years 1677
+/// to 2262)
+fn timestamp_to_scalar(
+    timestamp: Option<NaiveDateTime>,
+    tz: Option<Arc<str>>,
+) -> ScalarValue {
+    let Some(timestamp) = timestamp else {
+        return ScalarValue::TimestampNanosecond(None, tz);
+    };
+    let timestamp = timestamp.and_utc();
+    match timestamp.timestamp_nanos_opt() {
+        Some(nanos) => ScalarValue::TimestampNanosecond(Some(nanos), tz),
+        None => ScalarValue::TimestampMicrosecond(Some(timestamp.timestamp_micros()), tz),
+    }
+}
+
+fn time_nanos(time: &NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64
+}
+
 macro_rules! make_literal {
     ($TYPE:ty, $SCALAR:ident, $DOC: expr) => {
         #[doc = $DOC]
@@ -203,4 +295,55 @@ mod test {
         let expr = col("time").eq(lit_timestamp_nano(i));
         assert_eq!(expr, expected);
     }
+
+    #[test]
+    fn test_lit_chrono() {
+        let date = NaiveDate::from_ymd_opt(2021, 2, 1).unwrap();
+        assert_eq!(lit(date), lit(ScalarValue::Date32(Some(18659))));
+        assert_eq!(lit(Some(date)), lit(date));
+        assert_eq!(lit(None::<NaiveDate>), lit(ScalarValue::Date32(None)));
+        let before_epoch = NaiveDate::from_ymd_opt(1969, 12, 31).unwrap();
+        assert_eq!(lit(before_epoch), lit(ScalarValue::Date32(Some(-1))));
+
+        let datetime = date.and_hms_nano_opt(1, 2, 3, 4).unwrap();
+        let nanos = 1_612_141_323_000_000_004;
+        assert_eq!(
+            lit(datetime),
+            lit(ScalarValue::TimestampNanosecond(Some(nanos), None))
+        );
+        assert_eq!(
+            lit(datetime.and_utc()),
+            lit(ScalarValue::TimestampNanosecond(
+                Some(nanos),
+                Some("UTC".into())
+            ))
+        );
+        // the timestamp is of the UTC time, with the offset as timezone
+        let offset = chrono::FixedOffset::east_opt(5 * 3600).unwrap();
+        assert_eq!(
+            lit(offset.from_utc_datetime(&datetime)),
+            lit(ScalarValue::TimestampNanosecond(
+                Some(nanos),
+                Some("+05:00".into())
+            ))
+        );
+
+        let far_future = NaiveDate::from_ymd_opt(3000, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(
+            lit(far_future),
+            lit(ScalarValue::TimestampMicrosecond(
+                Some(32_503_680_000_000_000),
+                None
+            ))
+        );
+
+        let time = datetime.time();
+        assert_eq!(
+            lit(time),
+            lit(ScalarValue::Time64Nanosecond(Some(3_723_000_000_004)))
+        );
+    }
 }
@@ -39,7 +39,45 @@ pub mod other;
 
 pub use datafusion_expr_common::type_coercion::binary;
 
+use std::fmt::Debug;
+
 use arrow::datatypes::DataType;
+use datafusion_common::{ExprSchema, Result};
+
+use crate::{Expr, ExprSchemable};
+
+/// Customizes the casts that type coercion adds to expressions.
+///
+/// When an argument of an operator or function does not have the type it
+/// requires, type coercion casts the argument to that type. Implementations
+/// can instead rewrite the argument differently, or return an error to reject
+/// the coercion, for example to never coerce integers to strings.
+///
+/// See [`DefaultCoercionRules`] for the default behavior.
+pub trait CoercionRules: Debug + Send + Sync {
+    /// Coerces `expr`, of type `from`, to the type `to` that is required by
+    /// the operator or function that `expr` is an argument of.
+    ///
+    /// The returned expression must have the type `to`. By default `expr` is
+    /// cast to `to`.
+    fn coerce(
+        &self,
+        expr: Expr,
+        _from: &DataType,
+        to: &DataType,
+        schema: &dyn ExprSchema,
+    ) -> Result<Expr> {
+        expr.cast_to(to, schema)
+    }
+}
+
+/// The [`CoercionRules`] that DataFusion uses by default, which cast the
+/// arguments to the required types
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultCoercionRules;
+
+impl CoercionRules for DefaultCoercionRules {}
+
 /// Determine whether the given data type `dt` represents signed numeric values.
 pub fn is_signed_numeric(dt: &DataType) -> bool {
     matches!(
@@ -28,6 +28,7 @@ use arrow::datatypes::{DataType, Field};
 
 use datafusion_common::{exec_err, not_impl_err, Result, ScalarValue, Statistics};
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use sqlparser::ast::NullTreatment;
 
 use crate::expr::{
     schema_name_from_exprs, schema_name_from_exprs_comma_separated_without_space,
@@ -327,6 +328,16 @@ impl AggregateUDF {
         self.inner.supports_null_handling_clause()
     }
 
+    /// See [`AggregateUDFImpl::supports_null_treatment`] for more details.
+    pub fn supports_null_treatment(&self, null_treatment: NullTreatment) -> bool {
+        self.inner.supports_null_treatment(null_treatment)
+    }
+
+    /// See [`AggregateUDFImpl::default_null_treatment`] for more details.
+    pub fn default_null_treatment(&self) -> NullTreatment {
+        self.inner.default_null_treatment()
+    }
+
     /// See [`AggregateUDFImpl::is_ordered_set_aggregate`] for more details.
     pub fn is_ordered_set_aggregate(&self) -> bool {
         self.inner.is_ordered_set_aggregate()
@@ -545,6 +556,7 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
             partition_by,
             order_by,
             window_frame,
+            filter,
             null_treatment,
         } = params;
 
@@ -559,6 +571,10 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
             schema_name.write_fmt(format_args!(" {null_treatment}"))?;
         }
 
+        if let Some(filter) = filter {
+            schema_name.write_fmt(format_args!(" FILTER (WHERE {filter})"))?;
+        }
+
         if !partition_by.is_empty() {
             schema_name.write_fmt(format_args!(
                 " PARTITION BY [{}]",
@@ -628,7 +644,7 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
     ///
     /// Different from `display_name` in that it is used for window aggregate function
     ///
-    /// Defaults to `function_name([DISTINCT] column1, column2, ..) [null_treatment] [partition by [..]] [order_by [..]]`
+    /// Defaults to `function_name([DISTINCT] column1, column2, ..) [null_treatment] [filter] [partition by [..]] [order_by [..]]`
     fn window_function_display_name(
         &self,
         params: &WindowFunctionParams,
@@ -638,6 +654,7 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
             partition_by,
             order_by,
             window_frame,
+            filter,
             null_treatment,
         } = params;
 
@@ -653,6 +670,10 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
             display_name.write_fmt(format_args!(" {null_treatment}"))?;
         }
 
+        if let Some(filter) = filter {
+            display_name.write_fmt(format_args!(" FILTER (WHERE {filter})"))?;
+        }
+
         if !partition_by.is_empty() {
             display_name.write_fmt(format_args!(
                 " PARTITION BY [{}]",
@@ -952,6 +973,28 @@ pub trait AggregateUDFImpl: Debug + Send + Sync {
         true
     }
 
+    /// If this function supports the given `null_treatment`, return true
+    ///
+    /// Defaults to [`Self::supports_null_handling_clause`]. Functions that
+    /// always skip nulls, such as `sum`, can override this to accept
+    /// `IGNORE NULLS` but reject `RESPECT NULLS`, rather than silently
+    /// ignoring a treatment they do not implement.
+    fn supports_null_treatment(&self, _null_treatment: NullTreatment) -> bool {
+        self.supports_null_handling_clause()
+    }
+
+    /// The null treatment used when neither `IGNORE NULLS` nor
+    /// `RESPECT NULLS` is specified.
+    ///
+    /// Defaults to [`NullTreatment::RespectNulls`]. Functions that skip nulls
+    /// unless asked not to can override this to return
+    /// [`NullTreatment::IgnoreNulls`], so that
+    /// [`AccumulatorArgs::ignore_nulls`] is only false when `RESPECT NULLS`
+    /// is specified.
+    fn default_null_treatment(&self) -> NullTreatment {
+        NullTreatment::RespectNulls
+    }
+
     /// If this function is ordered-set aggregate function, return true
     /// If the function is not, return false
     fn is_ordered_set_aggregate(&self) -> bool {
@@ -1118,6 +1161,18 @@ impl AggregateUDFImpl for AliasedAggregateUDFImpl {
         self.inner.is_descending()
     }
 
+    fn supports_null_handling_clause(&self) -> bool {
+        self.inner.supports_null_handling_clause()
+    }
+
+    fn supports_null_treatment(&self, null_treatment: NullTreatment) -> bool {
+        self.inner.supports_null_treatment(null_treatment)
+    }
+
+    fn default_null_treatment(&self) -> NullTreatment {
+        self.inner.default_null_treatment()
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.inner.documentation()
     }
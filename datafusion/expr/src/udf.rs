@@ -849,6 +849,7 @@ pub mod scalar_doc_sections {
             DOC_SECTION_ARRAY,
             DOC_SECTION_STRUCT,
             DOC_SECTION_MAP,
+            DOC_SECTION_JSON,
             DOC_SECTION_HASHING,
             DOC_SECTION_UNION,
             DOC_SECTION_OTHER,
@@ -866,6 +867,7 @@ pub mod scalar_doc_sections {
             DOC_SECTION_ARRAY,
             DOC_SECTION_STRUCT,
             DOC_SECTION_MAP,
+            DOC_SECTION_JSON,
             DOC_SECTION_HASHING,
             DOC_SECTION_UNION,
             DOC_SECTION_OTHER,
@@ -931,6 +933,14 @@ The following regular expression functions are supported:"#,
         description: None,
     };
 
+    pub const DOC_SECTION_JSON: DocSection = DocSection {
+        include: true,
+        label: "JSON Functions",
+        description: Some(
+            "Functions to extract values from strings containing JSON documents",
+        ),
+    };
+
     pub const DOC_SECTION_HASHING: DocSection = DocSection {
         include: true,
         label: "Hashing Functions",
@@ -0,0 +1,372 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A compact set of integers, used by the `bitmap_agg` and `bitmap_contains`
+//! functions.
+//!
+//! Like a [roaring bitmap], values are grouped by their upper 48 bits into
+//! containers holding their lower 16 bits. A container with at most
+//! [`MAX_ARRAY_CONTAINER_LEN`] values stores them as a sorted array, and a
+//! larger container stores them as a bitset of all 65536 possible values.
+//!
+//! A serialized bitmap consists of, in little endian:
+//!
+//! * the number of containers, as a `u32`
+//! * for each container in ascending order of their upper bits
+//!   * the upper bits of its values, as a `u64`
+//!   * the number of values, as a `u32`
+//!   * if there are at most [`MAX_ARRAY_CONTAINER_LEN`] values, the lower
+//!     bits of each value in ascending order, as `u16`s, and otherwise the
+//!     bitset as [`BITSET_WORDS`] `u64`s
+//!
+//! [roaring bitmap]: https://roaringbitmap.org/
+
+use std::collections::BTreeMap;
+use std::mem::size_of;
+
+use datafusion_common::{exec_err, Result};
+
+/// The maximum number of values a container stores as a sorted array
+pub const MAX_ARRAY_CONTAINER_LEN: usize = 4096;
+
+/// The number of `u64` words in the bitset of a container
+pub const BITSET_WORDS: usize = 1024;
+
+/// A set of `u64` values, see the [module documentation](self)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bitmap {
+    containers: BTreeMap<u64, Container>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Container {
+    /// The sorted lower bits of the values
+    Array(Vec<u16>),
+    /// A bit for each possible lower bits, and the number of bits that are set
+    Bitset(Box<[u64; BITSET_WORDS]>, usize),
+}
+
+impl Container {
+    fn len(&self) -> usize {
+        match self {
+            Container::Array(values) => values.len(),
+            Container::Bitset(_, len) => *len,
+        }
+    }
+
+    fn insert(&mut self, low: u16) -> bool {
+        match self {
+            Container::Array(values) => {
+                let Err(index) = values.binary_search(&low) else {
+                    return false;
+                };
+                values.insert(index, low);
+                if values.len() > MAX_ARRAY_CONTAINER_LEN {
+                    let mut bitset = Box::new([0; BITSET_WORDS]);
+                    for &low in values.iter() {
+                        bitset[low as usize / 64] |= 1 << (low % 64);
+                    }
+                    *self = Container::Bitset(bitset, values.len());
+                }
+                true
+            }
+            Container::Bitset(bitset, len) => {
+                let (word, bit) = (low as usize / 64, 1 << (low % 64));
+                if bitset[word] & bit != 0 {
+                    return false;
+                }
+                bitset[word] |= bit;
+                *len += 1;
+                true
+            }
+        }
+    }
+
+    fn contains(&self, low: u16) -> bool {
+        match self {
+            Container::Array(values) => values.binary_search(&low).is_ok(),
+            Container::Bitset(bitset, _) => {
+                bitset[low as usize / 64] & (1 << (low % 64)) != 0
+            }
+        }
+    }
+
+    /// Returns the lower bits of the values in ascending order
+    fn values(&self) -> Box<dyn Iterator<Item = u16> + '_> {
+        match self {
+            Container::Array(values) => Box::new(values.iter().copied()),
+            Container::Bitset(bitset, _) => Box::new(
+                (0..=u16::MAX)
+                    .filter(|&low| bitset[low as usize / 64] & (1 << (low % 64)) != 0),
+            ),
+        }
+    }
+}
+
+impl Bitmap {
+    /// Creates an empty bitmap
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `value` to the bitmap, returning true if it was not already
+    /// present
+    pub fn insert(&mut self, value: u64) -> bool {
+        let (high, low) = split(value);
+        self.containers
+            .entry(high)
+            .or_insert_with(|| Container::Array(vec![]))
+            .insert(low)
+    }
+
+    /// Returns true if the bitmap contains `value`
+    pub fn contains(&self, value: u64) -> bool {
+        let (high, low) = split(value);
+        self.containers
+            .get(&high)
+            .is_some_and(|container| container.contains(low))
+    }
+
+    /// Returns the number of values in the bitmap
+    pub fn len(&self) -> usize {
+        self.containers.values().map(Container::len).sum()
+    }
+
+    /// Returns true if the bitmap contains no values
+    pub fn is_empty(&self) -> bool {
+        self.containers.is_empty()
+    }
+
+    /// Adds all the values of `other` to the bitmap
+    pub fn union(&mut self, other: &Bitmap) {
+        for (&high, container) in &other.containers {
+            for low in container.values() {
+                self.insert(high << 16 | low as u64);
+            }
+        }
+    }
+
+    /// Returns the approximate size of the bitmap in memory, in bytes
+    pub fn size(&self) -> usize {
+        size_of::<Self>()
+            + self
+                .containers
+                .values()
+                .map(|container| {
+                    size_of::<u64>()
+                        + size_of::<Container>()
+                        + match container {
+                            Container::Array(values) => {
+                                values.capacity() * size_of::<u16>()
+                            }
+                            Container::Bitset(..) => BITSET_WORDS * size_of::<u64>(),
+                        }
+                })
+                .sum::<usize>()
+    }
+
+    /// Returns the bitmap in the serialized format described in the
+    /// [module documentation](self)
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend((self.containers.len() as u32).to_le_bytes());
+        for (high, container) in &self.containers {
+            bytes.extend(high.to_le_bytes());
+            bytes.extend((container.len() as u32).to_le_bytes());
+            match container {
+                Container::Array(values) => values
+                    .iter()
+                    .for_each(|low| bytes.extend(low.to_le_bytes())),
+                Container::Bitset(bitset, _) => bitset
+                    .iter()
+                    .for_each(|word| bytes.extend(word.to_le_bytes())),
+            }
+        }
+        bytes
+    }
+
+    /// Parses a bitmap serialized by [`Self::serialize`]
+    pub fn deserialize(bytes: &[u8]) -> Result<Self> {
+        let mut bitmap = Self::new();
+        for container in SerializedContainers::try_new(bytes)? {
+            let (high, len, data) = container?;
+            let container = if len <= MAX_ARRAY_CONTAINER_LEN {
+                Container::Array(
+                    data.chunks_exact(size_of::<u16>())
+                        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+                        .collect(),
+                )
+            } else {
+                let mut bitset = Box::new([0; BITSET_WORDS]);
+                for (word, chunk) in
+                    bitset.iter_mut().zip(data.chunks_exact(size_of::<u64>()))
+                {
+                    *word = u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                Container::Bitset(bitset, len)
+            };
+            bitmap.containers.insert(high, container);
+        }
+        Ok(bitmap)
+    }
+
+    /// Returns true if the serialized bitmap `bytes` contains `value`,
+    /// without deserializing it
+    pub fn serialized_contains(bytes: &[u8], value: u64) -> Result<bool> {
+        let (high, low) = split(value);
+        for container in SerializedContainers::try_new(bytes)? {
+            let (container_high, len, data) = container?;
+            if container_high < high {
+                continue;
+            }
+            if container_high > high {
+                break;
+            }
+            return Ok(if len <= MAX_ARRAY_CONTAINER_LEN {
+                data.chunks_exact(size_of::<u16>())
+                    .any(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]) == low)
+            } else {
+                let start = low as usize / 64 * size_of::<u64>();
+                let word = u64::from_le_bytes(
+                    data[start..start + size_of::<u64>()].try_into().unwrap(),
+                );
+                word & (1 << (low % 64)) != 0
+            });
+        }
+        Ok(false)
+    }
+}
+
+/// Splits `value` into its upper 48 and lower 16 bits
+fn split(value: u64) -> (u64, u16) {
+    (value >> 16, value as u16)
+}
+
+/// Iterates over the containers of a serialized bitmap, returning their upper
+/// bits, number of values and serialized values
+struct SerializedContainers<'a> {
+    bytes: &'a [u8],
+    remaining: u32,
+}
+
+impl<'a> SerializedContainers<'a> {
+    fn try_new(bytes: &'a [u8]) -> Result<Self> {
+        let Some((remaining, bytes)) = bytes.split_first_chunk::<4>() else {
+            return exec_err!("Invalid bitmap: missing number of containers");
+        };
+        Ok(Self {
+            bytes,
+            remaining: u32::from_le_bytes(*remaining),
+        })
+    }
+
+    fn next_container(&mut self) -> Result<(u64, usize, &'a [u8])> {
+        let Some((high, rest)) = self.bytes.split_first_chunk::<8>() else {
+            return exec_err!("Invalid bitmap: truncated container header");
+        };
+        let Some((len, rest)) = rest.split_first_chunk::<4>() else {
+            return exec_err!("Invalid bitmap: truncated container header");
+        };
+        let len = u32::from_le_bytes(*len) as usize;
+        let data_len = if len <= MAX_ARRAY_CONTAINER_LEN {
+            len * size_of::<u16>()
+        } else {
+            BITSET_WORDS * size_of::<u64>()
+        };
+        if rest.len() < data_len {
+            return exec_err!("Invalid bitmap: truncated container values");
+        }
+        let (data, rest) = rest.split_at(data_len);
+        self.bytes = rest;
+        Ok((u64::from_le_bytes(*high), len, data))
+    }
+}
+
+impl<'a> Iterator for SerializedContainers<'a> {
+    type Item = Result<(u64, usize, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let container = self.next_container();
+        if container.is_err() {
+            // stop after the first error
+            self.remaining = 0;
+        }
+        Some(container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmap() -> Result<()> {
+        // a sparse container, a dense container and a container for a value
+        // whose upper bits are all set
+        let values = [0, 1, 70_000, u64::MAX]
+            .into_iter()
+            .chain((1 << 20..(1 << 20) + 10_000).step_by(2));
+
+        let mut bitmap = Bitmap::new();
+        for value in values.clone() {
+            assert!(bitmap.insert(value));
+        }
+        assert!(!bitmap.insert(70_000));
+        assert_eq!(bitmap.len(), 5004);
+
+        let bytes = bitmap.serialize();
+        let deserialized = Bitmap::deserialize(&bytes)?;
+        assert_eq!(deserialized, bitmap);
+
+        for value in values {
+            assert!(bitmap.contains(value));
+            assert!(Bitmap::serialized_contains(&bytes, value)?);
+        }
+        for value in [2, 69_999, (1 << 20) + 1, u64::MAX - 1] {
+            assert!(!bitmap.contains(value));
+            assert!(!Bitmap::serialized_contains(&bytes, value)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitmap_union() {
+        let mut bitmap1 = Bitmap::new();
+        bitmap1.insert(1);
+        bitmap1.insert(100_000);
+        let mut bitmap2 = Bitmap::new();
+        bitmap2.insert(1);
+        bitmap2.insert(2);
+
+        bitmap1.union(&bitmap2);
+        assert_eq!(bitmap1.len(), 3);
+        assert!([1, 2, 100_000].iter().all(|&value| bitmap1.contains(value)));
+    }
+
+    #[test]
+    fn test_bitmap_invalid() {
+        assert!(Bitmap::deserialize(&[]).is_err());
+        // one container, with a truncated header
+        assert!(Bitmap::deserialize(&[1, 0, 0, 0, 5]).is_err());
+        // empty bitmap
+        assert!(Bitmap::deserialize(&[0, 0, 0, 0]).unwrap().is_empty());
+    }
+}
@@ -33,6 +33,7 @@
 
 pub mod accumulator;
 pub mod aggregate;
+pub mod bitmap;
 pub mod merge_arrays;
 pub mod order;
 pub mod stats;
@@ -17,11 +17,12 @@
 
 //! Defines physical expressions that can evaluated at runtime during query execution
 
-use crate::hyperloglog::HyperLogLog;
-use arrow::array::{BinaryArray, StringViewArray};
+use crate::hyperloglog::{HyperLogLog, HLL_MAX_P, HLL_MIN_P, HLL_P};
+use arrow::array::{BinaryArray, RecordBatch, StringViewArray};
 use arrow::array::{
     GenericBinaryArray, GenericStringArray, OffsetSizeTrait, PrimitiveArray,
 };
+use arrow::datatypes::Schema;
 use arrow::datatypes::{
     ArrowPrimitiveType, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
     UInt32Type, UInt64Type, UInt8Type,
@@ -34,13 +35,17 @@ use datafusion_common::{
 use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::{
-    Accumulator, AggregateUDFImpl, Documentation, Signature, Volatility,
+    Accumulator, AggregateUDFImpl, ColumnarValue, Documentation, Signature,
+    TypeSignature, Volatility,
 };
 use datafusion_macros::user_doc;
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use log::warn;
 use std::any::Any;
 use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 make_udaf_expr_and_func!(
     ApproxDistinct,
@@ -60,12 +65,11 @@ impl<T: Hash> From<&HyperLogLog<T>> for ScalarValue {
 impl<T: Hash> TryFrom<&[u8]> for HyperLogLog<T> {
     type Error = DataFusionError;
     fn try_from(v: &[u8]) -> Result<HyperLogLog<T>> {
-        let arr: [u8; 16384] = v.try_into().map_err(|_| {
-            DataFusionError::Internal(
-                "Impossibly got invalid binary array from states".into(),
-            )
-        })?;
-        Ok(HyperLogLog::<T>::new_with_registers(arr))
+        let precision = v.len().trailing_zeros() as usize;
+        if !v.len().is_power_of_two() || !(HLL_MIN_P..=HLL_MAX_P).contains(&precision) {
+            return internal_err!("Impossibly got invalid binary array from states");
+        }
+        Ok(HyperLogLog::<T>::new_with_registers(v.to_vec()))
     }
 }
 
@@ -96,10 +100,10 @@ where
     T: ArrowPrimitiveType,
     T::Native: Hash,
 {
-    /// new approx_distinct accumulator
-    pub fn new() -> Self {
+    /// new approx_distinct accumulator with `2^precision` registers
+    pub fn new(precision: usize) -> Self {
         Self {
-            hll: HyperLogLog::new(),
+            hll: HyperLogLog::new_with_precision(precision),
         }
     }
 }
@@ -117,10 +121,10 @@ impl<T> StringHLLAccumulator<T>
 where
     T: OffsetSizeTrait,
 {
-    /// new approx_distinct accumulator
-    pub fn new() -> Self {
+    /// new approx_distinct accumulator with `2^precision` registers
+    pub fn new(precision: usize) -> Self {
         Self {
-            hll: HyperLogLog::new(),
+            hll: HyperLogLog::new_with_precision(precision),
             phantom_data: PhantomData,
         }
     }
@@ -139,9 +143,9 @@ impl<T> StringViewHLLAccumulator<T>
 where
     T: OffsetSizeTrait,
 {
-    pub fn new() -> Self {
+    pub fn new(precision: usize) -> Self {
         Self {
-            hll: HyperLogLog::new(),
+            hll: HyperLogLog::new_with_precision(precision),
             phantom_data: PhantomData,
         }
     }
@@ -160,10 +164,10 @@ impl<T> BinaryHLLAccumulator<T>
 where
     T: OffsetSizeTrait,
 {
-    /// new approx_distinct accumulator
-    pub fn new() -> Self {
+    /// new approx_distinct accumulator with `2^precision` registers
+    pub fn new(precision: usize) -> Self {
         Self {
-            hll: HyperLogLog::new(),
+            hll: HyperLogLog::new_with_precision(precision),
             phantom_data: PhantomData,
         }
     }
@@ -196,8 +200,8 @@ macro_rules! default_accumulator_impl {
         }
 
         fn size(&self) -> usize {
-            // HLL has static size
-            std::mem::size_of_val(self)
+            // HLL has a fixed number of registers, set by its precision
+            std::mem::size_of_val(self) + self.hll.as_ref().len()
         }
     };
 }
@@ -264,6 +268,38 @@ where
     default_accumulator_impl!();
 }
 
+/// Returns the HyperLogLog precision given by `expr`, clamped to the supported
+/// range
+fn validate_input_precision_expr(expr: &Arc<dyn PhysicalExpr>) -> Result<usize> {
+    let empty_schema = Arc::new(Schema::empty());
+    let batch = RecordBatch::new_empty(empty_schema);
+    let precision = match expr.evaluate(&batch) {
+        Ok(ColumnarValue::Scalar(sv)) if sv.data_type().is_integer() => {
+            match sv.cast_to(&DataType::Int64)? {
+                ScalarValue::Int64(Some(precision)) => precision,
+                _ => {
+                    return not_impl_err!(
+                        "Precision value for 'APPROX_DISTINCT' must not be NULL"
+                    )
+                }
+            }
+        }
+        _ => {
+            return not_impl_err!(
+                "Precision value for 'APPROX_DISTINCT' must be an integer literal, got: {expr}"
+            )
+        }
+    };
+
+    let clamped = precision.clamp(HLL_MIN_P as i64, HLL_MAX_P as i64);
+    if clamped != precision {
+        warn!(
+            "Precision value {precision} for 'APPROX_DISTINCT' is out of range, using {clamped}"
+        );
+    }
+    Ok(clamped as usize)
+}
+
 impl Debug for ApproxDistinct {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ApproxDistinct")
@@ -282,7 +318,7 @@ impl Default for ApproxDistinct {
 #[user_doc(
     doc_section(label = "Approximate Functions"),
     description = "Returns the approximate number of distinct input values calculated using the HyperLogLog algorithm.",
-    syntax_example = "approx_distinct(expression)",
+    syntax_example = "approx_distinct(expression[, precision])",
     sql_example = r#"```sql
 > SELECT approx_distinct(column_name) FROM table_name;
 +-----------------------------------+
@@ -291,7 +327,11 @@ impl Default for ApproxDistinct {
 | 42                                |
 +-----------------------------------+
 ```"#,
-    standard_argument(name = "expression",)
+    standard_argument(name = "expression",),
+    argument(
+        name = "precision",
+        description = "Optional. The precision `p` of the HyperLogLog sketch, which uses `2^p` registers. Higher values are more accurate but use more memory. Must be a constant integer, and is clamped to be between 4 and 18. Defaults to 14."
+    )
 )]
 pub struct ApproxDistinct {
     signature: Signature,
@@ -300,7 +340,10 @@ pub struct ApproxDistinct {
 impl ApproxDistinct {
     pub fn new() -> Self {
         Self {
-            signature: Signature::any(1, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
         }
     }
 }
@@ -332,24 +375,46 @@ impl AggregateUDFImpl for ApproxDistinct {
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
         let data_type = acc_args.exprs[0].data_type(acc_args.schema)?;
+        let precision = match acc_args.exprs.get(1) {
+            Some(expr) => validate_input_precision_expr(expr)?,
+            None => HLL_P,
+        };
 
         let accumulator: Box<dyn Accumulator> = match data_type {
             // TODO u8, i8, u16, i16 shall really be done using bitmap, not HLL
             // TODO support for boolean (trivial case)
             // https://github.com/apache/datafusion/issues/1109
-            DataType::UInt8 => Box::new(NumericHLLAccumulator::<UInt8Type>::new()),
-            DataType::UInt16 => Box::new(NumericHLLAccumulator::<UInt16Type>::new()),
-            DataType::UInt32 => Box::new(NumericHLLAccumulator::<UInt32Type>::new()),
-            DataType::UInt64 => Box::new(NumericHLLAccumulator::<UInt64Type>::new()),
-            DataType::Int8 => Box::new(NumericHLLAccumulator::<Int8Type>::new()),
-            DataType::Int16 => Box::new(NumericHLLAccumulator::<Int16Type>::new()),
-            DataType::Int32 => Box::new(NumericHLLAccumulator::<Int32Type>::new()),
-            DataType::Int64 => Box::new(NumericHLLAccumulator::<Int64Type>::new()),
-            DataType::Utf8 => Box::new(StringHLLAccumulator::<i32>::new()),
-            DataType::LargeUtf8 => Box::new(StringHLLAccumulator::<i64>::new()),
-            DataType::Utf8View => Box::new(StringViewHLLAccumulator::<i32>::new()),
-            DataType::Binary => Box::new(BinaryHLLAccumulator::<i32>::new()),
-            DataType::LargeBinary => Box::new(BinaryHLLAccumulator::<i64>::new()),
+            DataType::UInt8 => {
+                Box::new(NumericHLLAccumulator::<UInt8Type>::new(precision))
+            }
+            DataType::UInt16 => {
+                Box::new(NumericHLLAccumulator::<UInt16Type>::new(precision))
+            }
+            DataType::UInt32 => {
+                Box::new(NumericHLLAccumulator::<UInt32Type>::new(precision))
+            }
+            DataType::UInt64 => {
+                Box::new(NumericHLLAccumulator::<UInt64Type>::new(precision))
+            }
+            DataType::Int8 => Box::new(NumericHLLAccumulator::<Int8Type>::new(precision)),
+            DataType::Int16 => {
+                Box::new(NumericHLLAccumulator::<Int16Type>::new(precision))
+            }
+            DataType::Int32 => {
+                Box::new(NumericHLLAccumulator::<Int32Type>::new(precision))
+            }
+            DataType::Int64 => {
+                Box::new(NumericHLLAccumulator::<Int64Type>::new(precision))
+            }
+            DataType::Utf8 => Box::new(StringHLLAccumulator::<i32>::new(precision)),
+            DataType::LargeUtf8 => Box::new(StringHLLAccumulator::<i64>::new(precision)),
+            DataType::Utf8View => {
+                Box::new(StringViewHLLAccumulator::<i32>::new(precision))
+            }
+            DataType::Binary => Box::new(BinaryHLLAccumulator::<i32>::new(precision)),
+            DataType::LargeBinary => {
+                Box::new(BinaryHLLAccumulator::<i64>::new(precision))
+            }
             other => {
                 return not_impl_err!(
                 "Support for 'approx_distinct' for data type {other} is not implemented"
@@ -51,6 +51,7 @@ make_udaf_expr_and_func!(
 #[user_doc(
     doc_section(label = "General Functions"),
     description = r#"Returns an array created from the expression elements. If ordering is required, elements are inserted in the specified order.
+With DISTINCT and without ORDER BY, the distinct elements are sorted in ascending order with NULLs last.
 This aggregation function can only mix DISTINCT and ORDER BY if the ordering expression is exactly the same as the argument expression."#,
     syntax_example = "array_agg(expression [ORDER BY expression])",
     sql_example = r#"
@@ -380,6 +381,10 @@ impl Accumulator for ArrayAggAccumulator {
     }
 }
 
+/// Accumulator for a `ARRAY_AGG(DISTINCT ...)` aggregation. Without an
+/// `ORDER BY`, the distinct values are sorted in ascending order with NULLs
+/// last, so that the result does not depend on the order of the input nor on
+/// how it is partitioned.
 #[derive(Debug)]
 struct DistinctArrayAggAccumulator {
     values: HashSet<ScalarValue>,
@@ -454,26 +459,28 @@ impl Accumulator for DistinctArrayAggAccumulator {
             return Ok(ScalarValue::new_null_list(self.datatype.clone(), true, 1));
         }
 
-        if let Some(opts) = self.sort_options {
-            values.sort_by(|a, b| {
-                if a.is_null() {
-                    return match opts.nulls_first {
-                        true => Ordering::Less,
-                        false => Ordering::Greater,
-                    };
-                }
-                if b.is_null() {
-                    return match opts.nulls_first {
-                        true => Ordering::Greater,
-                        false => Ordering::Less,
-                    };
-                }
-                match opts.descending {
-                    true => b.partial_cmp(a).unwrap_or(Ordering::Equal),
-                    false => a.partial_cmp(b).unwrap_or(Ordering::Equal),
-                }
-            });
-        };
+        let opts = self.sort_options.unwrap_or(SortOptions {
+            descending: false,
+            nulls_first: false,
+        });
+        values.sort_by(|a, b| {
+            if a.is_null() {
+                return match opts.nulls_first {
+                    true => Ordering::Less,
+                    false => Ordering::Greater,
+                };
+            }
+            if b.is_null() {
+                return match opts.nulls_first {
+                    true => Ordering::Greater,
+                    false => Ordering::Less,
+                };
+            }
+            match opts.descending {
+                true => b.partial_cmp(a).unwrap_or(Ordering::Equal),
+                false => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            }
+        });
 
         let arr = ScalarValue::new_list(&values, &self.datatype, true);
         Ok(ScalarValue::List(arr))
@@ -808,6 +815,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn duplicates_distinct_sorted_across_partitions() -> Result<()> {
+        let partitions = [
+            data(["c", "a", "c"]),
+            data(["b", "a", "d", "b"]),
+            data([None, Some("e"), Some("a")]),
+        ];
+
+        // the result is the same whatever the order in which the partitions are merged
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0]] {
+            let mut acc = ArrayAggAccumulatorBuilder::string().distinct().build()?;
+            for i in order {
+                let mut partial =
+                    ArrayAggAccumulatorBuilder::string().distinct().build()?;
+                partial.update_batch(&[Arc::clone(&partitions[i])])?;
+                acc = merge(acc, partial)?;
+            }
+
+            let result = print_nulls(str_arr(acc.evaluate()?)?);
+            assert_eq!(result, vec!["a", "b", "c", "d", "e", "NULL"]);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn no_duplicates_distinct_sort_asc() -> Result<()> {
         let (mut acc1, mut acc2) = ArrayAggAccumulatorBuilder::string()
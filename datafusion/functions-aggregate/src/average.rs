@@ -32,6 +32,7 @@ use datafusion_common::{
     exec_err, not_impl_err, utils::take_function_args, Result, ScalarValue,
 };
 use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::sqlparser::ast::NullTreatment;
 use datafusion_expr::type_coercion::aggregates::{avg_return_type, coerce_avg_type};
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::Volatility::Immutable;
@@ -303,6 +304,11 @@ impl AggregateUDFImpl for Avg {
         coerce_avg_type(self.name(), std::slice::from_ref(args))
     }
 
+    fn supports_null_treatment(&self, null_treatment: NullTreatment) -> bool {
+        // `avg` always skips nulls, so only `IGNORE NULLS` is supported
+        null_treatment == NullTreatment::IgnoreNulls
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`BitmapAgg`] accumulator for the `bitmap_agg` function
+
+use arrow::array::{ArrayRef, AsArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Int64Type};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, Signature, Volatility,
+};
+use datafusion_functions_aggregate_common::bitmap::Bitmap;
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::mem::size_of_val;
+
+make_udaf_expr_and_func!(
+    BitmapAgg,
+    bitmap_agg,
+    expression,
+    "Returns a bitmap of the distinct non-null integer values, for use with bitmap_contains",
+    bitmap_agg_udaf
+);
+
+#[user_doc(
+    doc_section(label = "General Functions"),
+    description = "Returns a compact serialized bitmap of the distinct integer values, which can be tested for membership with `bitmap_contains`. \
+NULL values are ignored, so the result is an empty bitmap if there are no non-null input values.",
+    syntax_example = "bitmap_agg(expression)",
+    sql_example = r#"```sql
+> SELECT bitmap_contains(bitmap_agg(column1), 5) FROM (VALUES (5), (NULL), (10));
++-----------------------------------------------+
+| bitmap_contains(bitmap_agg(column1),Int64(5)) |
++-----------------------------------------------+
+| true                                          |
++-----------------------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Integer expression to build the bitmap of. Can be a constant, column, or function, and any combination of operators."
+    ),
+    related_udf(name = "bitmap_contains")
+)]
+/// BITMAP_AGG aggregate expression
+#[derive(Debug)]
+pub struct BitmapAgg {
+    signature: Signature,
+}
+
+impl BitmapAgg {
+    /// Create a new BitmapAgg aggregate function
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for BitmapAgg {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggregateUDFImpl for BitmapAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "bitmap_agg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [arg_type] = take_function_args(self.name(), arg_types)?;
+        if arg_type.is_integer() || arg_type == &DataType::Null {
+            Ok(vec![DataType::Int64])
+        } else {
+            plan_err!("bitmap_agg expects an integer argument, got {arg_type}")
+        }
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn is_nullable(&self) -> bool {
+        false
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        Ok(vec![Field::new(
+            format_state_name(args.name, "bitmap"),
+            DataType::Binary,
+            true,
+        )])
+    }
+
+    fn accumulator(&self, _acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(BitmapAggAccumulator::default()))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Accumulates the values into a [`Bitmap`], storing negative values by
+/// their two's complement bits
+#[derive(Debug, Default)]
+struct BitmapAggAccumulator {
+    bitmap: Bitmap,
+}
+
+impl Accumulator for BitmapAggAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let [values] = take_function_args("bitmap_agg", values)?;
+        let values = cast(values, &DataType::Int64)?;
+        values
+            .as_primitive::<Int64Type>()
+            .iter()
+            .flatten()
+            .for_each(|value| {
+                self.bitmap.insert(value as u64);
+            });
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(ScalarValue::Binary(Some(self.bitmap.serialize())))
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self) - size_of_val(&self.bitmap) + self.bitmap.size()
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.evaluate()?])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let [states] = take_function_args("bitmap_agg", states)?;
+        for state in states.as_binary::<i32>().iter().flatten() {
+            self.bitmap.union(&Bitmap::deserialize(state)?);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, Int64Array};
+    use std::sync::Arc;
+
+    fn evaluate_bitmap(acc: &mut BitmapAggAccumulator) -> Result<Bitmap> {
+        let ScalarValue::Binary(Some(bytes)) = acc.evaluate()? else {
+            panic!("expected a binary value");
+        };
+        Bitmap::deserialize(&bytes)
+    }
+
+    #[test]
+    fn bitmap_agg() -> Result<()> {
+        let mut acc1 = BitmapAggAccumulator::default();
+        let mut acc2 = BitmapAggAccumulator::default();
+        acc1.update_batch(&[Arc::new(Int64Array::from(vec![Some(1), None, Some(-1)]))])?;
+        acc2.update_batch(&[Arc::new(Int32Array::from(vec![Some(1), Some(70_000)]))])?;
+        acc1.merge_batch(
+            &acc2
+                .state()?
+                .into_iter()
+                .map(|s| s.to_array())
+                .collect::<Result<Vec<_>>>()?,
+        )?;
+
+        let bitmap = evaluate_bitmap(&mut acc1)?;
+        assert_eq!(bitmap.len(), 3);
+        assert!(bitmap.contains(1));
+        assert!(bitmap.contains(-1_i64 as u64));
+        assert!(bitmap.contains(70_000));
+        Ok(())
+    }
+
+    #[test]
+    fn bitmap_agg_no_values() -> Result<()> {
+        let mut acc = BitmapAggAccumulator::default();
+        acc.update_batch(&[Arc::new(Int64Array::from(vec![None]))])?;
+        assert!(evaluate_bitmap(&mut acc)?.is_empty());
+        Ok(())
+    }
+}
@@ -58,15 +58,16 @@ make_udaf_expr_and_func!(
 
 #[user_doc(
     doc_section(label = "Statistical Functions"),
-    description = "Returns the coefficient of correlation between two numeric values.",
+    description = "Returns the coefficient of correlation between two numeric values: their population covariance divided by the product of their population standard deviations. \
+Pairs in which either value is NULL are ignored, and NULL is returned if no pairs remain.",
     syntax_example = "corr(expression1, expression2)",
     sql_example = r#"```sql
-> SELECT corr(column1, column2) FROM table_name;
-+--------------------------------+
-| corr(column1, column2)         |
-+--------------------------------+
-| 0.85                           |
-+--------------------------------+
+> SELECT corr(column1, column2) FROM (VALUES (1, 2), (2, NULL), (3, 6));
++-----------------------+
+| corr(column1,column2) |
++-----------------------+
+| 1.0                   |
++-----------------------+
 ```"#,
     standard_argument(name = "expression1", prefix = "First"),
     standard_argument(name = "expression2", prefix = "Second")
@@ -18,6 +18,7 @@
 use ahash::RandomState;
 use datafusion_common::stats::Precision;
 use datafusion_expr::expr::WindowFunction;
+use datafusion_expr::sqlparser::ast::NullTreatment;
 use datafusion_functions_aggregate_common::aggregate::count_distinct::BytesViewDistinctCountAccumulator;
 use datafusion_macros::user_doc;
 use datafusion_physical_expr::expressions;
@@ -398,6 +399,11 @@ impl AggregateUDFImpl for Count {
         None
     }
 
+    fn supports_null_treatment(&self, null_treatment: NullTreatment) -> bool {
+        // `count` always skips nulls, so only `IGNORE NULLS` is supported
+        null_treatment == NullTreatment::IgnoreNulls
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
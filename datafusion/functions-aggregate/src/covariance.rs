@@ -57,15 +57,16 @@ make_udaf_expr_and_func!(
 
 #[user_doc(
     doc_section(label = "Statistical Functions"),
-    description = "Returns the sample covariance of a set of number pairs.",
+    description = "Returns the sample covariance of a set of number pairs: the sum of the products of the deviations of the two numbers of each pair from their means, divided by `n - 1`, where `n` is the number of pairs. \
+Pairs in which either number is NULL are ignored, and NULL is returned if fewer than two pairs remain.",
     syntax_example = "covar_samp(expression1, expression2)",
     sql_example = r#"```sql
-> SELECT covar_samp(column1, column2) FROM table_name;
-+-----------------------------------+
-| covar_samp(column1, column2)      |
-+-----------------------------------+
-| 8.25                              |
-+-----------------------------------+
+> SELECT covar_samp(column1, column2) FROM (VALUES (1, 2), (2, NULL), (3, 6));
++-----------------------------+
+| covar_samp(column1,column2) |
++-----------------------------+
+| 4.0                         |
++-----------------------------+
 ```"#,
     standard_argument(name = "expression1", prefix = "First"),
     standard_argument(name = "expression2", prefix = "Second")
@@ -149,15 +150,16 @@ impl AggregateUDFImpl for CovarianceSample {
 
 #[user_doc(
     doc_section(label = "Statistical Functions"),
-    description = "Returns the sample covariance of a set of number pairs.",
-    syntax_example = "covar_samp(expression1, expression2)",
+    description = "Returns the population covariance of a set of number pairs: the sum of the products of the deviations of the two numbers of each pair from their means, divided by the number of pairs `n`. \
+Pairs in which either number is NULL are ignored, and NULL is returned if no pairs remain.",
+    syntax_example = "covar_pop(expression1, expression2)",
     sql_example = r#"```sql
-> SELECT covar_samp(column1, column2) FROM table_name;
-+-----------------------------------+
-| covar_samp(column1, column2)      |
-+-----------------------------------+
-| 8.25                              |
-+-----------------------------------+
+> SELECT covar_pop(column1, column2) FROM (VALUES (1, 2), (2, NULL), (3, 6));
++----------------------------+
+| covar_pop(column1,column2) |
++----------------------------+
+| 2.0                        |
++----------------------------+
 ```"#,
     standard_argument(name = "expression1", prefix = "First"),
     standard_argument(name = "expression2", prefix = "Second")
@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extension methods for Expr.
+
+use std::sync::Arc;
+
+use datafusion_expr::expr::AggregateFunction;
+use datafusion_expr::{AggregateUDF, Expr};
+
+use crate::sum::{OverflowMode, Sum};
+
+/// Set how a call to `sum` handles a sum that overflows its type, see
+/// [`Sum::with_overflow`]
+///
+/// Expressions other than calls to `sum` are returned unchanged.
+///
+/// ## Example: Return an error if the sum of column "c1" overflows
+///
+/// ```
+/// # use datafusion_expr::col;
+/// # use datafusion_functions_aggregate::expr_ext::SumOverflow;
+/// # use datafusion_functions_aggregate::sum::{sum_udaf, OverflowMode};
+/// let expr = sum_udaf()
+///     .call(vec![col("c1")])
+///     .with_overflow(OverflowMode::Checked);
+/// assert_eq!(expr.schema_name().to_string(), "sum(c1)");
+/// ```
+pub trait SumOverflow {
+    fn with_overflow(self, overflow: OverflowMode) -> Expr;
+}
+
+impl SumOverflow for Expr {
+    fn with_overflow(self, overflow: OverflowMode) -> Expr {
+        match self {
+            Expr::AggregateFunction(AggregateFunction { func, params })
+                if func.inner().as_any().is::<Sum>() =>
+            {
+                let func =
+                    AggregateUDF::new_from_impl(Sum::new().with_overflow(overflow));
+                Expr::AggregateFunction(AggregateFunction {
+                    func: Arc::new(func),
+                    params,
+                })
+            }
+            expr => expr,
+        }
+    }
+}
@@ -0,0 +1,279 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`FlattenAgg`] accumulator for the `flatten_agg` function
+
+use crate::array_agg::ArrayAgg;
+use arrow::array::{new_empty_array, Array, ArrayRef, AsArray};
+use arrow::compute::concat;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::utils::{take_function_args, SingleRowListArrayBuilder};
+use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, ReversedUDAF, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::mem::size_of_val;
+
+make_udaf_expr_and_func!(
+    FlattenAgg,
+    flatten_agg,
+    expression,
+    "Concatenates the elements of all input lists into a single list",
+    flatten_agg_udaf
+);
+
+#[user_doc(
+    doc_section(label = "General Functions"),
+    description = "Returns a list created by concatenating the elements of the input lists. \
+If ordering is required, the lists are concatenated in the specified order. \
+With DISTINCT, duplicate lists are removed before they are concatenated. \
+NULL and empty lists contribute no elements, so the result is an empty list if there are no non-empty input lists.",
+    syntax_example = "flatten_agg([DISTINCT] expression [ORDER BY expression])",
+    sql_example = r#"```sql
+> SELECT flatten_agg(column1 ORDER BY column2) FROM (VALUES ([1, 2], 2), ([3], 1), (NULL, 3));
++--------------------------------------------------------+
+| flatten_agg(column1) ORDER BY [column2 ASC NULLS LAST] |
++--------------------------------------------------------+
+| [3, 1, 2]                                              |
++--------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "The list expression whose elements to concatenate. Can be a column or any valid list expression."
+    )
+)]
+/// FLATTEN_AGG aggregate expression
+#[derive(Debug)]
+pub struct FlattenAgg {
+    signature: Signature,
+    array_agg: ArrayAgg,
+    aliases: Vec<String>,
+}
+
+impl FlattenAgg {
+    /// Create a new FlattenAgg aggregate function
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            array_agg: Default::default(),
+            aliases: vec![String::from("list_agg_concat")],
+        }
+    }
+}
+
+impl Default for FlattenAgg {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggregateUDFImpl for FlattenAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "flatten_agg"
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [arg_type] = take_function_args(self.name(), arg_types)?;
+        match arg_type {
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _) => {
+                Ok(vec![DataType::new_list(field.data_type().clone(), true)])
+            }
+            _ => plan_err!("flatten_agg expects a list argument, got {arg_type}"),
+        }
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [DataType::List(field)] = arg_types else {
+            return plan_err!("flatten_agg expects a list argument, got {arg_types:?}");
+        };
+        Ok(DataType::new_list(field.data_type().clone(), true))
+    }
+
+    fn is_nullable(&self) -> bool {
+        false
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        self.array_agg.state_fields(args)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let DataType::List(field) = acc_args.return_field.data_type() else {
+            return internal_err!("flatten_agg should return a list");
+        };
+
+        let array_agg_acc = self.array_agg.accumulator(AccumulatorArgs {
+            return_field: &Field::new(
+                "f",
+                DataType::new_list(acc_args.return_field.data_type().clone(), true),
+                true,
+            ),
+            ..acc_args
+        })?;
+
+        Ok(Box::new(FlattenAggAccumulator {
+            array_agg_acc,
+            value_type: field.data_type().clone(),
+        }))
+    }
+
+    fn reverse_expr(&self) -> ReversedUDAF {
+        ReversedUDAF::Reversed(flatten_agg_udaf())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[derive(Debug)]
+struct FlattenAggAccumulator {
+    array_agg_acc: Box<dyn Accumulator>,
+    value_type: DataType,
+}
+
+impl Accumulator for FlattenAggAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.array_agg_acc.update_batch(values)
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let scalar = self.array_agg_acc.evaluate()?;
+        let ScalarValue::List(list) = scalar else {
+            return internal_err!("Expected a DataType::List while evaluating underlying ArrayAggAccumulator, but got {}", scalar.data_type());
+        };
+
+        // array_agg returns NULL when there are no input rows
+        let lists = if list.is_null(0) {
+            vec![]
+        } else {
+            let rows = list.value(0);
+            let rows = rows.as_list::<i32>();
+            rows.iter().flatten().collect::<Vec<_>>()
+        };
+
+        let values = if lists.is_empty() {
+            new_empty_array(&self.value_type)
+        } else {
+            concat(&lists.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?
+        };
+        Ok(SingleRowListArrayBuilder::new(values).build_list_scalar())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+            - size_of_val(&self.array_agg_acc)
+            - size_of_val(&self.value_type)
+            + self.array_agg_acc.size()
+            + self.value_type.size()
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.array_agg_acc.state()
+    }
+
+    fn merge_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.array_agg_acc.merge_batch(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, ListArray};
+    use arrow::datatypes::{Int32Type, Schema};
+    use datafusion_physical_expr::expressions::Column;
+    use datafusion_physical_expr_common::sort_expr::LexOrdering;
+    use std::sync::Arc;
+
+    fn list_type() -> DataType {
+        DataType::new_list(DataType::Int32, true)
+    }
+
+    fn accumulator() -> Result<Box<dyn Accumulator>> {
+        let schema = Schema::new(vec![Field::new("a", list_type(), true)]);
+        FlattenAgg::new().accumulator(AccumulatorArgs {
+            return_field: &Field::new("f", list_type(), false),
+            schema: &schema,
+            ignore_nulls: false,
+            ordering_req: &LexOrdering::default(),
+            is_reversed: false,
+            name: "",
+            is_distinct: false,
+            exprs: &[Arc::new(Column::new("a", 0))],
+        })
+    }
+
+    fn lists(rows: Vec<Option<Vec<Option<i32>>>>) -> ArrayRef {
+        Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(rows))
+    }
+
+    fn evaluate_values(acc: &mut Box<dyn Accumulator>) -> Result<Int32Array> {
+        let ScalarValue::List(list) = acc.evaluate()? else {
+            return internal_err!("expected a list");
+        };
+        Ok(list.value(0).as_primitive::<Int32Type>().clone())
+    }
+
+    #[test]
+    fn flatten_agg() -> Result<()> {
+        let mut acc1 = accumulator()?;
+        let mut acc2 = accumulator()?;
+        acc1.update_batch(&[lists(vec![Some(vec![Some(1), None]), None, Some(vec![])])])?;
+        acc2.update_batch(&[lists(vec![Some(vec![Some(3)])])])?;
+        acc1.merge_batch(
+            &acc2
+                .state()?
+                .into_iter()
+                .map(|s| s.to_array())
+                .collect::<Result<Vec<_>>>()?,
+        )?;
+
+        let values = evaluate_values(&mut acc1)?;
+        assert_eq!(values, Int32Array::from(vec![Some(1), None, Some(3)]));
+        Ok(())
+    }
+
+    #[test]
+    fn flatten_agg_empty() -> Result<()> {
+        let mut acc = accumulator()?;
+        assert_eq!(evaluate_values(&mut acc)?.len(), 0);
+
+        acc.update_batch(&[lists(vec![None, None])])?;
+        let result = acc.evaluate()?;
+        assert!(!result.is_null());
+        assert_eq!(evaluate_values(&mut acc)?.len(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,376 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`GeometricMean`]: geometric mean aggregation.
+//! [`HarmonicMean`]: harmonic mean aggregation.
+
+use arrow::array::{ArrayRef, Float64Array, UInt64Array};
+use arrow::compute::{cast, sum};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{downcast_value, exec_err, not_impl_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::mem::size_of_val;
+
+make_udaf_expr_and_func!(
+    GeometricMean,
+    geometric_mean,
+    expression,
+    "Computes the geometric mean of positive values.",
+    geometric_mean_udaf
+);
+
+make_udaf_expr_and_func!(
+    HarmonicMean,
+    harmonic_mean,
+    expression,
+    "Computes the harmonic mean of positive values.",
+    harmonic_mean_udaf
+);
+
+#[user_doc(
+    doc_section(label = "General Functions"),
+    description = "Returns the geometric mean of a set of positive numbers, the `n`th root of their product. \
+The product is computed as a sum of logarithms, so it does not overflow. NULL values are ignored, \
+and a zero or negative value is an error.",
+    syntax_example = "geometric_mean(expression)",
+    sql_example = r#"```sql
+> SELECT geometric_mean(column1) FROM (VALUES (1), (4), (NULL), (16));
++-------------------------+
+| geometric_mean(column1) |
++-------------------------+
+| 4.0                     |
++-------------------------+
+```"#,
+    standard_argument(name = "expression", prefix = "Numeric")
+)]
+#[derive(Debug)]
+pub struct GeometricMean {
+    signature: Signature,
+}
+
+impl Default for GeometricMean {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GeometricMean {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::numeric(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for GeometricMean {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "geometric_mean"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        mean_state_fields(args.name, "log_sum")
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        if acc_args.is_distinct {
+            return not_impl_err!(
+                "GEOMETRIC_MEAN(DISTINCT) aggregations are not available"
+            );
+        }
+        Ok(Box::new(MeanAccumulator::new(MeanKind::Geometric)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "General Functions"),
+    description = "Returns the harmonic mean of a set of positive numbers, the number of values divided by the sum of their reciprocals. \
+NULL values are ignored, and a zero or negative value is an error.",
+    syntax_example = "harmonic_mean(expression)",
+    sql_example = r#"```sql
+> SELECT harmonic_mean(column1) FROM (VALUES (1), (4), (NULL), (4));
++------------------------+
+| harmonic_mean(column1) |
++------------------------+
+| 2.0                    |
++------------------------+
+```"#,
+    standard_argument(name = "expression", prefix = "Numeric")
+)]
+#[derive(Debug)]
+pub struct HarmonicMean {
+    signature: Signature,
+}
+
+impl Default for HarmonicMean {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HarmonicMean {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::numeric(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for HarmonicMean {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "harmonic_mean"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        mean_state_fields(args.name, "reciprocal_sum")
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        if acc_args.is_distinct {
+            return not_impl_err!(
+                "HARMONIC_MEAN(DISTINCT) aggregations are not available"
+            );
+        }
+        Ok(Box::new(MeanAccumulator::new(MeanKind::Harmonic)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn mean_state_fields(name: &str, sum_name: &str) -> Result<Vec<Field>> {
+    Ok(vec![
+        Field::new(format_state_name(name, "count"), DataType::UInt64, true),
+        Field::new(format_state_name(name, sum_name), DataType::Float64, true),
+    ])
+}
+
+/// The mean computed by a [`MeanAccumulator`]
+#[derive(Debug, Clone, Copy)]
+enum MeanKind {
+    Geometric,
+    Harmonic,
+}
+
+impl MeanKind {
+    fn name(&self) -> &'static str {
+        match self {
+            MeanKind::Geometric => "geometric_mean",
+            MeanKind::Harmonic => "harmonic_mean",
+        }
+    }
+
+    /// The term a value adds to the sum
+    fn term(&self, value: f64) -> f64 {
+        match self {
+            MeanKind::Geometric => value.ln(),
+            MeanKind::Harmonic => value.recip(),
+        }
+    }
+
+    /// The mean of `count` values whose terms add up to `sum`
+    fn mean(&self, count: u64, sum: f64) -> f64 {
+        match self {
+            MeanKind::Geometric => (sum / count as f64).exp(),
+            MeanKind::Harmonic => count as f64 / sum,
+        }
+    }
+}
+
+/// Accumulates the count and the sum of the terms of the non-null values,
+/// the logarithms for a geometric mean and the reciprocals for a harmonic
+/// mean
+#[derive(Debug)]
+struct MeanAccumulator {
+    kind: MeanKind,
+    count: u64,
+    sum: f64,
+}
+
+impl MeanAccumulator {
+    fn new(kind: MeanKind) -> Self {
+        Self {
+            kind,
+            count: 0,
+            sum: 0.0,
+        }
+    }
+}
+
+impl Accumulator for MeanAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = cast(&values[0], &DataType::Float64)?;
+        for value in downcast_value!(values, Float64Array).iter().flatten() {
+            // also rejects NaN
+            if value.partial_cmp(&0.0) != Some(Ordering::Greater) {
+                return exec_err!(
+                    "{} requires positive values, got {value}",
+                    self.kind.name()
+                );
+            }
+            self.count += 1;
+            self.sum += self.kind.term(value);
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let counts = downcast_value!(states[0], UInt64Array);
+        let sums = downcast_value!(states[1], Float64Array);
+        self.count += sum(counts).unwrap_or_default();
+        self.sum += sum(sums).unwrap_or_default();
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            ScalarValue::from(self.count),
+            ScalarValue::from(self.sum),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        if self.count == 0 {
+            return Ok(ScalarValue::Float64(None));
+        }
+        Ok(ScalarValue::from(self.kind.mean(self.count, self.sum)))
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use std::sync::Arc;
+
+    fn evaluate_mean(acc: &mut MeanAccumulator) -> Result<Option<f64>> {
+        let ScalarValue::Float64(mean) = acc.evaluate()? else {
+            panic!("expected a Float64 value");
+        };
+        Ok(mean)
+    }
+
+    fn merged_mean(kind: MeanKind, batches: &[ArrayRef]) -> Result<Option<f64>> {
+        let mut acc = MeanAccumulator::new(kind);
+        for batch in batches {
+            let mut partial = MeanAccumulator::new(kind);
+            partial.update_batch(&[Arc::clone(batch)])?;
+            let state = partial
+                .state()?
+                .into_iter()
+                .map(|s| s.to_array())
+                .collect::<Result<Vec<_>>>()?;
+            acc.merge_batch(&state)?;
+        }
+        evaluate_mean(&mut acc)
+    }
+
+    #[test]
+    fn geometric_mean() -> Result<()> {
+        let batches: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![Some(2), None, Some(8)])),
+            Arc::new(Float64Array::from(vec![4.0])),
+        ];
+        let mean = merged_mean(MeanKind::Geometric, &batches)?.unwrap();
+        assert!((mean - 4.0).abs() < 1e-12, "{mean}");
+
+        // the product of the values overflows, but not the sum of logarithms
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![1e300, 1e300, 1e300]));
+        let mean = merged_mean(MeanKind::Geometric, &[values])?.unwrap();
+        assert!((mean / 1e300 - 1.0).abs() < 1e-12, "{mean}");
+        Ok(())
+    }
+
+    #[test]
+    fn harmonic_mean() -> Result<()> {
+        let batches: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(2)])),
+            Arc::new(Float64Array::from(vec![4.0])),
+        ];
+        // 3 / (1 + 1/2 + 1/4)
+        let mean = merged_mean(MeanKind::Harmonic, &batches)?.unwrap();
+        assert!((mean - 12.0 / 7.0).abs() < 1e-12, "{mean}");
+        Ok(())
+    }
+
+    #[test]
+    fn mean_no_values() -> Result<()> {
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![None]));
+        assert_eq!(
+            merged_mean(MeanKind::Geometric, &[Arc::clone(&values)])?,
+            None
+        );
+        assert_eq!(merged_mean(MeanKind::Harmonic, &[values])?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn mean_non_positive_values() {
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![1.0, 0.0]));
+        let err = merged_mean(MeanKind::Geometric, &[Arc::clone(&values)]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: geometric_mean requires positive values, got 0"
+        );
+
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![-2.0]));
+        let err = merged_mean(MeanKind::Harmonic, &[values]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: harmonic_mean requires positive values, got -2"
+        );
+    }
+}
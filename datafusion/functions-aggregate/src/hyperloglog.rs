@@ -38,20 +38,23 @@ use ahash::RandomState;
 use std::hash::Hash;
 use std::marker::PhantomData;
 
-/// The greater is P, the smaller the error.
-const HLL_P: usize = 14_usize;
-/// The number of bits of the hash value used determining the number of leading zeros
-const HLL_Q: usize = 64_usize - HLL_P;
-const NUM_REGISTERS: usize = 1_usize << HLL_P;
-/// Mask to obtain index into the registers
-const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
+/// The default precision. The greater is P, the smaller the error.
+pub(crate) const HLL_P: usize = 14_usize;
+/// The smallest supported precision
+pub(crate) const HLL_MIN_P: usize = 4_usize;
+/// The largest supported precision
+pub(crate) const HLL_MAX_P: usize = 18_usize;
+/// The number of bits of the hash value used determining the number of
+/// leading zeros, for the largest number of bits supported
+const HLL_MAX_Q: usize = 64_usize - HLL_MIN_P;
 
 #[derive(Clone, Debug)]
 pub(crate) struct HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    registers: [u8; NUM_REGISTERS],
+    /// `2^p` registers, where `p` is the precision
+    registers: Vec<u8>,
     phantom: PhantomData<T>,
 }
 
@@ -81,22 +84,46 @@ impl<T> HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    /// Creates a new, empty HyperLogLog.
+    /// Creates a new, empty HyperLogLog with the default precision.
     pub fn new() -> Self {
-        let registers = [0; NUM_REGISTERS];
-        Self::new_with_registers(registers)
+        Self::new_with_precision(HLL_P)
+    }
+
+    /// Creates a new, empty HyperLogLog with `2^precision` registers.
+    ///
+    /// `precision` must be between [`HLL_MIN_P`] and [`HLL_MAX_P`]
+    pub fn new_with_precision(precision: usize) -> Self {
+        assert!(
+            (HLL_MIN_P..=HLL_MAX_P).contains(&precision),
+            "unexpected HyperLogLog precision {precision}"
+        );
+        Self::new_with_registers(vec![0; 1_usize << precision])
     }
 
     /// Creates a HyperLogLog from already populated registers
     /// note that this method should not be invoked in untrusted environment
     /// because the internal structure of registers are not examined.
-    pub(crate) fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
+    ///
+    /// The number of registers must be `2^p` for a supported precision `p`
+    pub(crate) fn new_with_registers(registers: Vec<u8>) -> Self {
+        debug_assert!(
+            registers.len().is_power_of_two()
+                && (HLL_MIN_P..=HLL_MAX_P)
+                    .contains(&(registers.len().trailing_zeros() as usize)),
+            "unexpected HyperLogLog register count {}",
+            registers.len()
+        );
         Self {
             registers,
             phantom: PhantomData,
         }
     }
 
+    /// The precision `p` of this HyperLogLog, which has `2^p` registers
+    pub fn precision(&self) -> usize {
+        self.registers.len().trailing_zeros() as usize
+    }
+
     /// choice of hash function: ahash is already an dependency
     /// and it fits the requirements of being a 64bit hash with
     /// reasonable performance.
@@ -108,19 +135,22 @@ where
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
         let hash = self.hash_value(obj);
-        let index = (hash & HLL_P_MASK) as usize;
-        let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
+        let precision = self.precision();
+        // Mask to obtain index into the registers
+        let index = (hash & (self.registers.len() as u64 - 1)) as usize;
+        let q = 64 - precision;
+        let p = ((hash >> precision) | (1_u64 << q)).trailing_zeros() + 1;
         self.registers[index] = self.registers[index].max(p as u8);
     }
 
     /// Get the register histogram (each value in register index into
-    /// the histogram; u32 is enough because we only have 2**14=16384 registers
+    /// the histogram; u32 is enough because we have at most 2**18 registers
     #[inline]
-    fn get_histogram(&self) -> [u32; HLL_Q + 2] {
-        let mut histogram = [0; HLL_Q + 2];
+    fn get_histogram(&self) -> [u32; HLL_MAX_Q + 2] {
+        let mut histogram = [0; HLL_MAX_Q + 2];
         // hopefully this can be unrolled
-        for r in self.registers {
-            histogram[r as usize] += 1;
+        for r in &self.registers {
+            histogram[*r as usize] += 1;
         }
         histogram
     }
@@ -141,9 +171,10 @@ where
     /// Guess the number of unique elements seen by the HyperLogLog.
     pub fn count(&self) -> usize {
         let histogram = self.get_histogram();
-        let m = NUM_REGISTERS as f64;
-        let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
-        for i in histogram[1..=HLL_Q].iter().rev() {
+        let q = 64 - self.precision();
+        let m = self.registers.len() as f64;
+        let mut z = m * hll_tau((m - histogram[q + 1] as f64) / m);
+        for i in histogram[1..=q].iter().rev() {
             z += *i as f64;
             z *= 0.5;
         }
@@ -233,16 +264,20 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{HyperLogLog, NUM_REGISTERS};
+    use super::{HyperLogLog, HLL_MAX_P, HLL_MIN_P, HLL_P};
 
     fn compare_with_delta(got: usize, expected: usize) {
+        compare_with_delta_for_precision(HLL_P, got, expected)
+    }
+
+    fn compare_with_delta_for_precision(precision: usize, got: usize, expected: usize) {
         let expected = expected as f64;
         let diff = (got as f64) - expected;
         let diff = diff.abs() / expected;
         // times 6 because we want the tests to be stable
         // so we allow a rather large margin of error
         // this is adopted from redis's unit test version as well
-        let margin = 1.04 / ((NUM_REGISTERS as f64).sqrt()) * 6.0;
+        let margin = 1.04 / (((1_usize << precision) as f64).sqrt()) * 6.0;
         assert!(
             diff <= margin,
             "{} is not near {} percent of {} which is ({}, {})",
@@ -366,4 +401,27 @@ mod tests {
         }
         compare_with_delta(hll.count(), 1000);
     }
+
+    #[test]
+    fn test_precision() {
+        for precision in HLL_MIN_P..=HLL_MAX_P {
+            let mut hll = HyperLogLog::<u64>::new_with_precision(precision);
+            assert_eq!(hll.precision(), precision);
+            assert_eq!(hll.as_ref().len(), 1 << precision);
+            hll.extend(0..10_000_u64);
+            compare_with_delta_for_precision(precision, hll.count(), 10_000);
+        }
+    }
+
+    #[test]
+    fn test_merge_precision() {
+        let mut hll = HyperLogLog::<u64>::new_with_precision(HLL_MIN_P);
+        hll.extend(0..500_u64);
+
+        let mut other = HyperLogLog::<u64>::new_with_precision(HLL_MIN_P);
+        other.extend(500..1000_u64);
+
+        hll.merge(&other);
+        compare_with_delta_for_precision(HLL_MIN_P, hll.count(), 1000);
+    }
 }
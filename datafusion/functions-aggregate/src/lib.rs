@@ -71,19 +71,25 @@ pub mod approx_percentile_cont_with_weight;
 pub mod array_agg;
 pub mod average;
 pub mod bit_and_or_xor;
+pub mod bitmap_agg;
 pub mod bool_and_or;
 pub mod correlation;
 pub mod count;
 pub mod covariance;
+pub mod expr_ext;
 pub mod first_last;
+pub mod flatten_agg;
+pub mod geometric_harmonic_mean;
 pub mod grouping;
 pub mod hyperloglog;
 pub mod median;
 pub mod min_max;
+pub mod mode;
 pub mod nth_value;
 pub mod regr;
 pub mod stddev;
 pub mod string_agg;
+pub mod string_agg_list;
 pub mod sum;
 pub mod variance;
 
@@ -108,6 +114,7 @@ pub mod expr_fn {
     pub use super::bit_and_or_xor::bit_and;
     pub use super::bit_and_or_xor::bit_or;
     pub use super::bit_and_or_xor::bit_xor;
+    pub use super::bitmap_agg::bitmap_agg;
     pub use super::bool_and_or::bool_and;
     pub use super::bool_and_or::bool_or;
     pub use super::correlation::corr;
@@ -117,10 +124,14 @@ pub mod expr_fn {
     pub use super::covariance::covar_samp;
     pub use super::first_last::first_value;
     pub use super::first_last::last_value;
+    pub use super::flatten_agg::flatten_agg;
+    pub use super::geometric_harmonic_mean::geometric_mean;
+    pub use super::geometric_harmonic_mean::harmonic_mean;
     pub use super::grouping::grouping;
     pub use super::median::median;
     pub use super::min_max::max;
     pub use super::min_max::min;
+    pub use super::mode::mode;
     pub use super::nth_value::nth_value;
     pub use super::regr::regr_avgx;
     pub use super::regr::regr_avgy;
@@ -133,6 +144,7 @@ pub mod expr_fn {
     pub use super::regr::regr_syy;
     pub use super::stddev::stddev;
     pub use super::stddev::stddev_pop;
+    pub use super::string_agg_list::string_agg_list;
     pub use super::sum::sum;
     pub use super::variance::var_pop;
     pub use super::variance::var_sample;
@@ -150,6 +162,7 @@ pub fn all_default_aggregate_functions() -> Vec<Arc<AggregateUDF>> {
         sum::sum_udaf(),
         min_max::max_udaf(),
         min_max::min_udaf(),
+        mode::mode_udaf(),
         median::median_udaf(),
         count::count_udaf(),
         regr::regr_slope_udaf(),
@@ -163,6 +176,8 @@ pub fn all_default_aggregate_functions() -> Vec<Arc<AggregateUDF>> {
         regr::regr_sxy_udaf(),
         variance::var_samp_udaf(),
         variance::var_pop_udaf(),
+        geometric_harmonic_mean::geometric_mean_udaf(),
+        geometric_harmonic_mean::harmonic_mean_udaf(),
         stddev::stddev_udaf(),
         stddev::stddev_pop_udaf(),
         approx_median::approx_median_udaf(),
@@ -170,9 +185,12 @@ pub fn all_default_aggregate_functions() -> Vec<Arc<AggregateUDF>> {
         approx_percentile_cont_udaf(),
         approx_percentile_cont_with_weight_udaf(),
         string_agg::string_agg_udaf(),
+        string_agg_list::string_agg_list_udaf(),
+        flatten_agg::flatten_agg_udaf(),
         bit_and_or_xor::bit_and_udaf(),
         bit_and_or_xor::bit_or_udaf(),
         bit_and_or_xor::bit_xor_udaf(),
+        bitmap_agg::bitmap_agg_udaf(),
         bool_and_or::bool_and_udaf(),
         bool_and_or::bool_or_udaf(),
         average::avg_udaf(),
@@ -37,10 +37,13 @@ use arrow::array::Array;
 use arrow::array::ArrowNativeTypeOp;
 use arrow::datatypes::{ArrowNativeType, ArrowPrimitiveType};
 
+use datafusion_common::utils::take_function_args;
 use datafusion_common::{
-    internal_datafusion_err, internal_err, DataFusionError, HashSet, Result, ScalarValue,
+    internal_datafusion_err, internal_err, plan_err, DataFusionError, HashSet, Result,
+    ScalarValue,
 };
 use datafusion_expr::function::StateFieldsArgs;
+use datafusion_expr::sqlparser::ast::NullTreatment;
 use datafusion_expr::{
     function::AccumulatorArgs, utils::format_state_name, Accumulator, AggregateUDFImpl,
     Documentation, Signature, Volatility,
@@ -61,7 +64,8 @@ make_udaf_expr_and_func!(
 
 #[user_doc(
     doc_section(label = "General Functions"),
-    description = "Returns the median value in the specified column.",
+    description = "Returns the median value in the specified column. If there is an even number of values, the median is the mean of the two middle values. \
+Integer values are converted to `Float64`, so the median of `1` and `2` is `1.5`. NULL values are ignored, so `IGNORE NULLS` has no effect.",
     syntax_example = "median(expression)",
     sql_example = r#"```sql
 > SELECT median(column_name) FROM table_name;
@@ -103,7 +107,7 @@ impl Default for Median {
 impl Median {
     pub fn new() -> Self {
         Self {
-            signature: Signature::numeric(1, Volatility::Immutable),
+            signature: Signature::user_defined(Volatility::Immutable),
         }
     }
 }
@@ -121,6 +125,22 @@ impl AggregateUDFImpl for Median {
         &self.signature
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [arg_type] = take_function_args(self.name(), arg_types)?;
+        // Integers are converted to floats so that the mean of the two middle
+        // values is not truncated
+        match arg_type {
+            DataType::Float16
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _) => Ok(vec![arg_type.clone()]),
+            DataType::Null => Ok(vec![DataType::Float64]),
+            arg_type if arg_type.is_integer() => Ok(vec![DataType::Float64]),
+            arg_type => plan_err!("{} does not support type {arg_type}", self.name()),
+        }
+    }
+
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         Ok(arg_types[0].clone())
     }
@@ -217,6 +237,11 @@ impl AggregateUDFImpl for Median {
         &[]
     }
 
+    fn supports_null_treatment(&self, null_treatment: NullTreatment) -> bool {
+        // `median` always skips nulls, so only `IGNORE NULLS` is supported
+        null_treatment == NullTreatment::IgnoreNulls
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -0,0 +1,379 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`Mode`] accumulator for the `mode` function
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem::{size_of, size_of_val};
+use std::sync::Arc;
+
+use arrow::array::{new_empty_array, Array, ArrayRef, AsArray, Int64Array};
+use arrow::datatypes::{DataType, Field, Int64Type};
+use datafusion_common::utils::SingleRowListArrayBuilder;
+use datafusion_common::{internal_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::sqlparser::ast::NullTreatment;
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+
+make_udaf_expr_and_func!(
+    Mode,
+    mode,
+    expression,
+    "Returns the most frequent value",
+    mode_udaf
+);
+
+/// How [`Mode`] chooses between values that occur equally often
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModeTieBreak {
+    /// Return the smallest of the most frequent values
+    #[default]
+    Smallest,
+    /// Return the largest of the most frequent values
+    Largest,
+    /// Return the most frequent value that was seen first. The order in which
+    /// the values are seen depends on the order of the input, which is only
+    /// deterministic for a single partition.
+    FirstSeen,
+}
+
+#[user_doc(
+    doc_section(label = "General Functions"),
+    description = "Returns the most frequent value in the specified column. \
+If several values are equally frequent, the smallest one is returned. \
+NULL values are ignored unless `RESPECT NULLS` is specified.",
+    syntax_example = "mode(expression)",
+    sql_example = r#"```sql
+> SELECT mode(column_name) FROM table_name;
++------------------------+
+| mode(column_name)      |
++------------------------+
+| 12                     |
++------------------------+
+```"#,
+    standard_argument(name = "expression",)
+)]
+/// MODE aggregate expression
+#[derive(Debug)]
+pub struct Mode {
+    signature: Signature,
+    tie_break: ModeTieBreak,
+}
+
+impl Mode {
+    /// Create a new MODE aggregate function
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+            tie_break: ModeTieBreak::default(),
+        }
+    }
+
+    /// Set how values that occur equally often are chosen between
+    pub fn with_tie_break(mut self, tie_break: ModeTieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggregateUDFImpl for Mode {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "mode"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        let value_type = args.input_fields[0].data_type().clone();
+        Ok(vec![
+            Field::new(
+                format_state_name(args.name, "mode_values"),
+                DataType::List(Arc::new(Field::new_list_field(value_type, true))),
+                true,
+            ),
+            Field::new(
+                format_state_name(args.name, "mode_counts"),
+                DataType::List(Arc::new(Field::new_list_field(DataType::Int64, true))),
+                true,
+            ),
+        ])
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(ModeAccumulator::new(
+            acc_args.return_field.data_type().clone(),
+            self.tie_break,
+            acc_args.ignore_nulls,
+        )))
+    }
+
+    fn supports_null_handling_clause(&self) -> bool {
+        true
+    }
+
+    fn default_null_treatment(&self) -> NullTreatment {
+        NullTreatment::IgnoreNulls
+    }
+
+    fn equals(&self, other: &dyn AggregateUDFImpl) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.tie_break == other.tie_break)
+    }
+
+    fn hash_value(&self) -> u64 {
+        let hasher = &mut DefaultHasher::new();
+        self.name().hash(hasher);
+        self.tie_break.hash(hasher);
+        hasher.finish()
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Counts the occurrences of each distinct value, in the order in which the
+/// values were first seen
+#[derive(Debug)]
+struct ModeAccumulator {
+    data_type: DataType,
+    tie_break: ModeTieBreak,
+    ignore_nulls: bool,
+    /// Index of each value in `values` and `counts`
+    indices: HashMap<ScalarValue, usize>,
+    values: Vec<ScalarValue>,
+    counts: Vec<i64>,
+}
+
+impl ModeAccumulator {
+    fn new(data_type: DataType, tie_break: ModeTieBreak, ignore_nulls: bool) -> Self {
+        Self {
+            data_type,
+            tie_break,
+            ignore_nulls,
+            indices: HashMap::new(),
+            values: vec![],
+            counts: vec![],
+        }
+    }
+
+    fn add(&mut self, value: ScalarValue, count: i64) {
+        if self.ignore_nulls && value.is_null() {
+            return;
+        }
+        match self.indices.get(&value) {
+            Some(&idx) => self.counts[idx] += count,
+            None => {
+                self.indices.insert(value.clone(), self.values.len());
+                self.values.push(value);
+                self.counts.push(count);
+            }
+        }
+    }
+
+    /// Compares two equally frequent values, where the greater one wins
+    fn cmp_ties(&self, a: usize, b: usize) -> Ordering {
+        match self.tie_break {
+            ModeTieBreak::Smallest => self.values[b]
+                .partial_cmp(&self.values[a])
+                .unwrap_or(Ordering::Equal),
+            ModeTieBreak::Largest => self.values[a]
+                .partial_cmp(&self.values[b])
+                .unwrap_or(Ordering::Equal),
+            ModeTieBreak::FirstSeen => b.cmp(&a),
+        }
+    }
+}
+
+impl Accumulator for ModeAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let array = &values[0];
+        for idx in 0..array.len() {
+            self.add(ScalarValue::try_from_array(array, idx)?, 1);
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let [values, counts] = states else {
+            return internal_err!("mode expects two state arrays, got {}", states.len());
+        };
+        let values = values.as_list::<i32>();
+        let counts = counts.as_list::<i32>();
+        for (values, counts) in values.iter().zip(counts.iter()) {
+            let (Some(values), Some(counts)) = (values, counts) else {
+                continue;
+            };
+            let counts = counts.as_primitive::<Int64Type>();
+            for idx in 0..values.len() {
+                self.add(
+                    ScalarValue::try_from_array(&values, idx)?,
+                    counts.value(idx),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = if self.values.is_empty() {
+            new_empty_array(&self.data_type)
+        } else {
+            ScalarValue::iter_to_array(self.values.iter().cloned())?
+        };
+        let counts = Arc::new(Int64Array::from(self.counts.clone()));
+        Ok(vec![
+            SingleRowListArrayBuilder::new(values).build_list_scalar(),
+            SingleRowListArrayBuilder::new(counts).build_list_scalar(),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let winner = (0..self.values.len()).max_by(|&a, &b| {
+            self.counts[a]
+                .cmp(&self.counts[b])
+                .then_with(|| self.cmp_ties(a, b))
+        });
+        match winner {
+            Some(idx) => Ok(self.values[idx].clone()),
+            None => ScalarValue::try_from(&self.data_type),
+        }
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self) - size_of_val(&self.data_type)
+            + self.data_type.size()
+            + self.indices.capacity() * (size_of::<ScalarValue>() + size_of::<usize>())
+            + ScalarValue::size_of_vec(&self.values)
+            - size_of_val(&self.values)
+            + self.counts.capacity() * size_of::<i64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::StringArray;
+
+    fn mode_of(
+        batches: Vec<ArrayRef>,
+        tie_break: ModeTieBreak,
+        ignore_nulls: bool,
+    ) -> Result<ScalarValue> {
+        let data_type = batches[0].data_type().clone();
+        let mut merged = ModeAccumulator::new(data_type.clone(), tie_break, ignore_nulls);
+        for batch in batches {
+            let mut acc =
+                ModeAccumulator::new(data_type.clone(), tie_break, ignore_nulls);
+            acc.update_batch(&[batch])?;
+            let state = acc
+                .state()?
+                .into_iter()
+                .map(|s| s.to_array())
+                .collect::<Result<Vec<_>>>()?;
+            merged.merge_batch(&state)?;
+        }
+        merged.evaluate()
+    }
+
+    fn ints(values: Vec<Option<i64>>) -> ArrayRef {
+        Arc::new(Int64Array::from(values))
+    }
+
+    #[test]
+    fn mode_clear_winner() -> Result<()> {
+        let batches = vec![
+            ints(vec![Some(1), Some(2), Some(2)]),
+            ints(vec![Some(3), Some(2), Some(1)]),
+        ];
+        for tie_break in [
+            ModeTieBreak::Smallest,
+            ModeTieBreak::Largest,
+            ModeTieBreak::FirstSeen,
+        ] {
+            assert_eq!(
+                mode_of(batches.clone(), tie_break, true)?,
+                ScalarValue::Int64(Some(2))
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn mode_tie_break() -> Result<()> {
+        let batches = vec![
+            Arc::new(StringArray::from(vec!["b", "c"])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["a", "c", "b", "a"])),
+        ];
+        let utf8 = |s: &str| ScalarValue::Utf8(Some(s.to_string()));
+        assert_eq!(
+            mode_of(batches.clone(), ModeTieBreak::Smallest, true)?,
+            utf8("a")
+        );
+        assert_eq!(
+            mode_of(batches.clone(), ModeTieBreak::Largest, true)?,
+            utf8("c")
+        );
+        assert_eq!(mode_of(batches, ModeTieBreak::FirstSeen, true)?, utf8("b"));
+        Ok(())
+    }
+
+    #[test]
+    fn mode_nulls() -> Result<()> {
+        let batches = vec![ints(vec![None, Some(1), None, Some(1), None])];
+        assert_eq!(
+            mode_of(batches.clone(), ModeTieBreak::Smallest, true)?,
+            ScalarValue::Int64(Some(1))
+        );
+        assert_eq!(
+            mode_of(batches, ModeTieBreak::Smallest, false)?,
+            ScalarValue::Int64(None)
+        );
+
+        let batches = vec![ints(vec![None, None])];
+        assert_eq!(
+            mode_of(batches, ModeTieBreak::Smallest, true)?,
+            ScalarValue::Int64(None)
+        );
+        Ok(())
+    }
+}
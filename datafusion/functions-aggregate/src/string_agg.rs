@@ -30,8 +30,10 @@ use datafusion_expr::{
 use datafusion_functions_aggregate_common::accumulator::StateFieldsArgs;
 use datafusion_macros::user_doc;
 use datafusion_physical_expr::expressions::Literal;
+use datafusion_physical_expr::PhysicalExpr;
 use std::any::Any;
 use std::mem::size_of_val;
+use std::sync::Arc;
 
 make_udaf_expr_and_func!(
     StringAgg,
@@ -134,24 +136,7 @@ impl AggregateUDFImpl for StringAgg {
     }
 
     fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
-        let Some(lit) = acc_args.exprs[1].as_any().downcast_ref::<Literal>() else {
-            return not_impl_err!(
-                "The second argument of the string_agg function must be a string literal"
-            );
-        };
-
-        let delimiter = if lit.value().is_null() {
-            // If the second argument (the delimiter that joins strings) is NULL, join
-            // on an empty string. (e.g. [a, b, c] => "abc").
-            ""
-        } else if let Some(lit_string) = lit.value().try_as_str() {
-            lit_string.unwrap_or("")
-        } else {
-            return not_impl_err!(
-                "StringAgg not supported for delimiter \"{}\"",
-                lit.value()
-            );
-        };
+        let delimiter = literal_delimiter(self.name(), &acc_args.exprs[1])?;
 
         let array_agg_acc = self.array_agg.accumulator(AccumulatorArgs {
             return_field: &Field::new(
@@ -242,6 +227,32 @@ impl Accumulator for StringAggAccumulator {
     }
 }
 
+/// Returns the delimiter that joins the strings of `function_name`, which must
+/// be a string literal
+pub(crate) fn literal_delimiter<'a>(
+    function_name: &str,
+    expr: &'a Arc<dyn PhysicalExpr>,
+) -> Result<&'a str> {
+    let Some(lit) = expr.as_any().downcast_ref::<Literal>() else {
+        return not_impl_err!(
+            "The second argument of the {function_name} function must be a string literal"
+        );
+    };
+
+    if lit.value().is_null() {
+        // If the second argument (the delimiter that joins strings) is NULL, join
+        // on an empty string. (e.g. [a, b, c] => "abc").
+        Ok("")
+    } else if let Some(lit_string) = lit.value().try_as_str() {
+        Ok(lit_string.unwrap_or(""))
+    } else {
+        not_impl_err!(
+            "{function_name} not supported for delimiter \"{}\"",
+            lit.value()
+        )
+    }
+}
+
 fn filter_index<T: Clone>(values: &[T], index: usize) -> Vec<T> {
     values
         .iter()
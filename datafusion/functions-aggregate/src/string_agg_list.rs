@@ -0,0 +1,237 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StringAggList`] accumulator for the `string_agg_list` function
+
+use crate::flatten_agg::FlattenAgg;
+use crate::string_agg::{literal_delimiter, StringAggAccumulator};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, Result};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+make_udaf_expr_and_func!(
+    StringAggList,
+    string_agg_list,
+    expr delimiter,
+    "Concatenates the elements of all input lists and places separator values between them",
+    string_agg_list_udaf
+);
+
+#[user_doc(
+    doc_section(label = "General Functions"),
+    description = "Concatenates the elements of all input lists, converted to strings, and places separator values between them. \
+If ordering is required, the lists are concatenated in the specified order. \
+NULL elements are skipped, and the result is NULL if there are no non-null elements.",
+    syntax_example = "string_agg_list(expression, delimiter [ORDER BY expression])",
+    sql_example = r#"```sql
+> SELECT string_agg_list(column1, ', ' ORDER BY column2) AS joined
+  FROM (VALUES (['b', 'c'], 2), (['a', NULL], 1));
++---------+
+| joined  |
++---------+
+| a, b, c |
++---------+
+```"#,
+    argument(
+        name = "expression",
+        description = "The list expression whose elements to concatenate. Can be a column or any valid list expression."
+    ),
+    argument(
+        name = "delimiter",
+        description = "A literal string used as a separator between the concatenated elements."
+    )
+)]
+/// STRING_AGG_LIST aggregate expression
+#[derive(Debug)]
+pub struct StringAggList {
+    signature: Signature,
+    flatten_agg: FlattenAgg,
+    aliases: Vec<String>,
+}
+
+impl StringAggList {
+    /// Create a new StringAggList aggregate function
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            flatten_agg: FlattenAgg::new(),
+            aliases: vec![String::from("array_to_string_agg")],
+        }
+    }
+}
+
+impl Default for StringAggList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggregateUDFImpl for StringAggList {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "string_agg_list"
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [list_type, delimiter_type] = take_function_args(self.name(), arg_types)?;
+        let list_type = match list_type {
+            DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _) => DataType::new_list(DataType::Utf8, true),
+            _ => {
+                return plan_err!(
+                    "string_agg_list expects a list argument, got {list_type}"
+                )
+            }
+        };
+        match delimiter_type {
+            DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Null => Ok(vec![list_type, delimiter_type.clone()]),
+            _ => plan_err!(
+                "string_agg_list expects a string delimiter, got {delimiter_type}"
+            ),
+        }
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::LargeUtf8)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
+        self.flatten_agg.state_fields(args)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let delimiter = literal_delimiter(self.name(), &acc_args.exprs[1])?;
+
+        let flatten_agg_acc = self.flatten_agg.accumulator(AccumulatorArgs {
+            return_field: &Field::new(
+                "f",
+                DataType::new_list(DataType::Utf8, true),
+                true,
+            ),
+            exprs: &acc_args.exprs[..1],
+            ..acc_args
+        })?;
+
+        Ok(Box::new(StringAggAccumulator::new(
+            flatten_agg_acc,
+            delimiter,
+        )))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, ListBuilder, StringArray, StringBuilder};
+    use arrow::datatypes::Schema;
+    use datafusion_common::ScalarValue;
+    use datafusion_physical_expr::expressions::{Column, Literal};
+    use datafusion_physical_expr_common::sort_expr::LexOrdering;
+    use std::sync::Arc;
+
+    fn accumulator() -> Result<Box<dyn Accumulator>> {
+        let list_type = DataType::new_list(DataType::Utf8, true);
+        let schema = Schema::new(vec![Field::new("l", list_type, true)]);
+        StringAggList::new().accumulator(AccumulatorArgs {
+            return_field: &Field::new("f", DataType::LargeUtf8, true),
+            schema: &schema,
+            ignore_nulls: false,
+            ordering_req: &LexOrdering::default(),
+            is_reversed: false,
+            name: "",
+            is_distinct: false,
+            exprs: &[
+                Arc::new(Column::new("l", 0)),
+                Arc::new(Literal::new(ScalarValue::from("-"))),
+            ],
+        })
+    }
+
+    fn lists(rows: Vec<Option<Vec<Option<&str>>>>) -> ArrayRef {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        for row in rows {
+            builder.append_option(row);
+        }
+        Arc::new(builder.finish())
+    }
+
+    fn delimiters(len: usize) -> ArrayRef {
+        Arc::new(StringArray::from(vec!["-"; len]))
+    }
+
+    #[test]
+    fn string_agg_list() -> Result<()> {
+        let mut acc1 = accumulator()?;
+        let mut acc2 = accumulator()?;
+        acc1.update_batch(&[
+            lists(vec![Some(vec![Some("a"), None]), None, Some(vec![])]),
+            delimiters(3),
+        ])?;
+        acc2.update_batch(&[
+            lists(vec![Some(vec![Some("b"), Some("c")])]),
+            delimiters(1),
+        ])?;
+        acc1.merge_batch(
+            &acc2
+                .state()?
+                .into_iter()
+                .map(|s| s.to_array())
+                .collect::<Result<Vec<_>>>()?,
+        )?;
+
+        assert_eq!(
+            acc1.evaluate()?,
+            ScalarValue::LargeUtf8(Some("a-b-c".into()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn string_agg_list_empty() -> Result<()> {
+        let mut acc = accumulator()?;
+        assert_eq!(acc.evaluate()?, ScalarValue::LargeUtf8(None));
+
+        acc.update_batch(&[lists(vec![Some(vec![None]), None]), delimiters(2)])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::LargeUtf8(None));
+        Ok(())
+    }
+}
@@ -18,14 +18,17 @@
 //! Defines `SUM` and `SUM DISTINCT` aggregate accumulators
 
 use ahash::RandomState;
+use datafusion_expr::sqlparser::ast::NullTreatment;
 use datafusion_expr::utils::AggregateOrderSensitivity;
 use std::any::Any;
+use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::mem::{size_of, size_of_val};
 
 use arrow::array::Array;
 use arrow::array::ArrowNativeTypeOp;
-use arrow::array::{ArrowNumericType, AsArray};
+use arrow::array::{ArrowNumericType, AsArray, PrimitiveArray};
 use arrow::datatypes::ArrowNativeType;
 use arrow::datatypes::ArrowPrimitiveType;
 use arrow::datatypes::{
@@ -55,6 +58,21 @@ make_udaf_expr_and_func!(
     sum_udaf
 );
 
+/// How [`Sum`] handles a sum that does not fit in its integer or decimal type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowMode {
+    /// Wrap around, so the sum of `i64::MAX` and `1` is `i64::MIN`
+    #[default]
+    Wrapping,
+    /// Return an error naming the sum accumulated before the overflow
+    Checked,
+    /// Clamp the sum to the largest or smallest value of its type
+    ///
+    /// Only the final sum is clamped, so for example the sum of `i64::MAX`,
+    /// `1` and `-1` is `i64::MAX` in whichever order the values are added.
+    Saturating,
+}
+
 /// Sum only supports a subset of numeric types, instead relying on type coercion
 ///
 /// This macro is similar to [downcast_primitive](arrow::array::downcast_primitive)
@@ -107,14 +125,33 @@ macro_rules! downcast_sum {
 #[derive(Debug)]
 pub struct Sum {
     signature: Signature,
+    overflow: OverflowMode,
 }
 
 impl Sum {
     pub fn new() -> Self {
         Self {
             signature: Signature::user_defined(Volatility::Immutable),
+            overflow: OverflowMode::default(),
         }
     }
+
+    /// Set how a sum that overflows its type is handled.
+    ///
+    /// Integers are summed as `Int64` or `UInt64`, so for example the sum of
+    /// an `Int8` column only overflows when it does not fit in an `Int64`.
+    /// Floating point sums never overflow, and a sum with
+    /// [`OverflowMode::Saturating`] can not be used in a window frame that
+    /// does not start at `UNBOUNDED PRECEDING`.
+    pub fn with_overflow(mut self, overflow: OverflowMode) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns how a sum that overflows its type is handled
+    pub fn overflow(&self) -> OverflowMode {
+        self.overflow
+    }
 }
 
 impl Default for Sum {
@@ -187,14 +224,20 @@ impl AggregateUDFImpl for Sum {
         if args.is_distinct {
             macro_rules! helper {
                 ($t:ty, $dt:expr) => {
-                    Ok(Box::new(DistinctSumAccumulator::<$t>::try_new(&$dt)?))
+                    Ok(Box::new(DistinctSumAccumulator::<$t>::try_new(
+                        &$dt,
+                        self.overflow,
+                    )?))
                 };
             }
             downcast_sum!(args, helper)
         } else {
             macro_rules! helper {
                 ($t:ty, $dt:expr) => {
-                    Ok(Box::new(SumAccumulator::<$t>::new($dt.clone())))
+                    Ok(Box::new(SumAccumulator::<$t>::new(
+                        $dt.clone(),
+                        self.overflow,
+                    )))
                 };
             }
             downcast_sum!(args, helper)
@@ -210,11 +253,19 @@ impl AggregateUDFImpl for Sum {
                 false,
             )])
         } else {
-            Ok(vec![Field::new(
+            let mut fields = vec![Field::new(
                 format_state_name(args.name, "sum"),
                 args.return_type().clone(),
                 true,
-            )])
+            )];
+            if self.overflow == OverflowMode::Saturating {
+                fields.push(Field::new(
+                    format_state_name(args.name, "sum overflows"),
+                    DataType::Int64,
+                    true,
+                ));
+            }
+            Ok(fields)
         }
     }
 
@@ -223,7 +274,8 @@ impl AggregateUDFImpl for Sum {
     }
 
     fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
-        !args.is_distinct
+        // the groups accumulator can not report overflows
+        !args.is_distinct && self.overflow == OverflowMode::Wrapping
     }
 
     fn create_groups_accumulator(
@@ -245,9 +297,18 @@ impl AggregateUDFImpl for Sum {
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn Accumulator>> {
+        // the sliding accumulator does not count how often its sum overflows
+        if self.overflow == OverflowMode::Saturating {
+            return not_impl_err!(
+                "Sum with saturating overflow can not be used as a sliding accumulator"
+            );
+        }
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(SlidingSumAccumulator::<$t>::new($dt.clone())))
+                Ok(Box::new(SlidingSumAccumulator::<$t>::new(
+                    $dt.clone(),
+                    self.overflow,
+                )))
             };
         }
         downcast_sum!(args, helper)
@@ -261,6 +322,25 @@ impl AggregateUDFImpl for Sum {
         AggregateOrderSensitivity::Insensitive
     }
 
+    fn supports_null_treatment(&self, null_treatment: NullTreatment) -> bool {
+        // `sum` always skips nulls, so only `IGNORE NULLS` is supported
+        null_treatment == NullTreatment::IgnoreNulls
+    }
+
+    fn equals(&self, other: &dyn AggregateUDFImpl) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.overflow == other.overflow)
+    }
+
+    fn hash_value(&self) -> u64 {
+        let hasher = &mut DefaultHasher::new();
+        self.name().hash(hasher);
+        self.overflow.hash(hasher);
+        hasher.finish()
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -278,10 +358,56 @@ impl AggregateUDFImpl for Sum {
     }
 }
 
+/// Returns `sum + value`, or an error if it overflows
+fn add_checked<T: ArrowPrimitiveType>(
+    sum: T::Native,
+    value: T::Native,
+    data_type: &DataType,
+) -> Result<T::Native> {
+    match sum.add_checked(value) {
+        Ok(sum) => Ok(sum),
+        Err(_) => exec_err!(
+            "Sum overflowed {data_type} adding {} to the sum so far of about {}",
+            ScalarValue::new_primitive::<T>(Some(value), data_type)?,
+            ScalarValue::new_primitive::<T>(Some(sum), data_type)?
+        ),
+    }
+}
+
+/// Returns `sum + value`, wrapping around on overflow
+///
+/// `overflows` counts how many times the sum wrapped past the largest (+1)
+/// or the smallest (-1) value of its type, so together with the returned sum
+/// it represents the exact sum no matter in which order values are added.
+fn add_counting_overflows<T: ArrowPrimitiveType>(
+    sum: T::Native,
+    value: T::Native,
+    overflows: &mut i64,
+) -> T::Native {
+    if sum.add_checked(value).is_err() {
+        *overflows += if value.is_lt(T::Native::ZERO) { -1 } else { 1 };
+    }
+    sum.add_wrapping(value)
+}
+
+/// Returns the exact sum counted by [`add_counting_overflows`] clamped to the
+/// range of its type
+fn saturate<T: ArrowPrimitiveType>(sum: T::Native, overflows: i64) -> T::Native {
+    // the total orders of integers are the range of the type
+    match overflows.cmp(&0) {
+        Ordering::Less => T::Native::MIN_TOTAL_ORDER,
+        Ordering::Equal => sum,
+        Ordering::Greater => T::Native::MAX_TOTAL_ORDER,
+    }
+}
+
 /// This accumulator computes SUM incrementally
 struct SumAccumulator<T: ArrowNumericType> {
     sum: Option<T::Native>,
+    /// See [`add_counting_overflows`], only used by [`OverflowMode::Saturating`]
+    overflows: i64,
     data_type: DataType,
+    overflow: OverflowMode,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
@@ -291,34 +417,67 @@ impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(data_type: DataType, overflow: OverflowMode) -> Self {
         Self {
             sum: None,
+            overflows: 0,
             data_type,
+            overflow,
         }
     }
 }
 
 impl<T: ArrowNumericType> Accumulator for SumAccumulator<T> {
     fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        if self.overflow == OverflowMode::Saturating {
+            // the sum is only clamped once all the partial sums are merged
+            return Ok(vec![
+                ScalarValue::new_primitive::<T>(self.sum, &self.data_type)?,
+                ScalarValue::Int64(Some(self.overflows)),
+            ]);
+        }
         Ok(vec![self.evaluate()?])
     }
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            let v = self.sum.get_or_insert(T::Native::usize_as(0));
-            *v = v.add_wrapping(x);
+        match self.overflow {
+            OverflowMode::Wrapping => {
+                if let Some(x) = arrow::compute::sum(values) {
+                    let v = self.sum.get_or_insert(T::Native::usize_as(0));
+                    *v = v.add_wrapping(x);
+                }
+            }
+            // add the values one at a time to find where the sum overflows
+            OverflowMode::Checked => {
+                for x in values.iter().flatten() {
+                    let v = self.sum.get_or_insert(T::Native::usize_as(0));
+                    *v = add_checked::<T>(*v, x, &self.data_type)?;
+                }
+            }
+            OverflowMode::Saturating => {
+                for x in values.iter().flatten() {
+                    let v = self.sum.get_or_insert(T::Native::usize_as(0));
+                    *v = add_counting_overflows::<T>(*v, x, &mut self.overflows);
+                }
+            }
         }
         Ok(())
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
-        self.update_batch(states)
+        self.update_batch(&states[..1])?;
+        if self.overflow == OverflowMode::Saturating {
+            if let Some(x) = arrow::compute::sum(states[1].as_primitive::<Int64Type>()) {
+                self.overflows += x;
+            }
+        }
+        Ok(())
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
-        ScalarValue::new_primitive::<T>(self.sum, &self.data_type)
+        let sum = self.sum.map(|sum| saturate::<T>(sum, self.overflows));
+        ScalarValue::new_primitive::<T>(sum, &self.data_type)
     }
 
     fn size(&self) -> usize {
@@ -333,6 +492,7 @@ struct SlidingSumAccumulator<T: ArrowNumericType> {
     sum: T::Native,
     count: u64,
     data_type: DataType,
+    overflow: OverflowMode,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
@@ -342,12 +502,26 @@ impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SlidingSumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(data_type: DataType, overflow: OverflowMode) -> Self {
         Self {
             sum: T::Native::usize_as(0),
             count: 0,
             data_type,
+            overflow,
+        }
+    }
+
+    fn add(&mut self, values: &PrimitiveArray<T>) -> Result<()> {
+        if self.overflow == OverflowMode::Wrapping {
+            if let Some(x) = arrow::compute::sum(values) {
+                self.sum = self.sum.add_wrapping(x)
+            }
+            return Ok(());
         }
+        for x in values.iter().flatten() {
+            self.sum = add_checked::<T>(self.sum, x, &self.data_type)?;
+        }
+        Ok(())
     }
 }
 
@@ -359,17 +533,11 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
         self.count += (values.len() - values.null_count()) as u64;
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.add_wrapping(x)
-        }
-        Ok(())
+        self.add(values)
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
-        let values = states[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.add_wrapping(x)
-        }
+        self.add(states[0].as_primitive::<T>())?;
         if let Some(x) = arrow::compute::sum(states[1].as_primitive::<UInt64Type>()) {
             self.count += x;
         }
@@ -387,8 +555,24 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
 
     fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.sub_wrapping(x)
+        if self.overflow == OverflowMode::Wrapping {
+            if let Some(x) = arrow::compute::sum(values) {
+                self.sum = self.sum.sub_wrapping(x)
+            }
+        } else {
+            for x in values.iter().flatten() {
+                self.sum = match self.sum.sub_checked(x) {
+                    Ok(sum) => sum,
+                    Err(_) => {
+                        return exec_err!(
+                            "Sum overflowed {} subtracting {} from the sum so far of about {}",
+                            self.data_type,
+                            ScalarValue::new_primitive::<T>(Some(x), &self.data_type)?,
+                            ScalarValue::new_primitive::<T>(Some(self.sum), &self.data_type)?
+                        )
+                    }
+                };
+            }
         }
         self.count -= (values.len() - values.null_count()) as u64;
         Ok(())
@@ -402,6 +586,7 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
 struct DistinctSumAccumulator<T: ArrowPrimitiveType> {
     values: HashSet<Hashable<T::Native>, RandomState>,
     data_type: DataType,
+    overflow: OverflowMode,
 }
 
 impl<T: ArrowPrimitiveType> std::fmt::Debug for DistinctSumAccumulator<T> {
@@ -411,10 +596,11 @@ impl<T: ArrowPrimitiveType> std::fmt::Debug for DistinctSumAccumulator<T> {
 }
 
 impl<T: ArrowPrimitiveType> DistinctSumAccumulator<T> {
-    pub fn try_new(data_type: &DataType) -> Result<Self> {
+    pub fn try_new(data_type: &DataType, overflow: OverflowMode) -> Result<Self> {
         Ok(Self {
             values: HashSet::default(),
             data_type: data_type.clone(),
+            overflow,
         })
     }
 }
@@ -468,10 +654,19 @@ impl<T: ArrowPrimitiveType> Accumulator for DistinctSumAccumulator<T> {
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
         let mut acc = T::Native::usize_as(0);
+        let mut overflows = 0;
         for distinct_value in self.values.iter() {
-            acc = acc.add_wrapping(distinct_value.0)
+            acc = match self.overflow {
+                OverflowMode::Wrapping => acc.add_wrapping(distinct_value.0),
+                OverflowMode::Checked => {
+                    add_checked::<T>(acc, distinct_value.0, &self.data_type)?
+                }
+                OverflowMode::Saturating => {
+                    add_counting_overflows::<T>(acc, distinct_value.0, &mut overflows)
+                }
+            };
         }
-        let v = (!self.values.is_empty()).then_some(acc);
+        let v = (!self.values.is_empty()).then(|| saturate::<T>(acc, overflows));
         ScalarValue::new_primitive::<T>(v, &self.data_type)
     }
 
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for `array_append`, `array_prepend` and `array_concat` functions.
+//! [`ScalarUDFImpl`] definitions for `array_append`, `array_prepend`, `array_concat` and `concat_list` functions.
 
 use std::any::Any;
 use std::sync::Arc;
@@ -23,8 +23,8 @@ use std::sync::Arc;
 use crate::make_array::make_array_inner;
 use crate::utils::{align_array_dimensions, check_datatypes, make_scalar_function};
 use arrow::array::{
-    Array, ArrayRef, Capacities, GenericListArray, MutableArrayData, NullArray,
-    NullBufferBuilder, OffsetSizeTrait,
+    new_empty_array, Array, ArrayRef, Capacities, GenericListArray, LargeListArray,
+    ListArray, MutableArrayData, NullArray, NullBufferBuilder, OffsetSizeTrait,
 };
 use arrow::buffer::OffsetBuffer;
 use arrow::datatypes::{DataType, Field};
@@ -34,7 +34,7 @@ use datafusion_common::utils::{
 use datafusion_common::Result;
 use datafusion_common::{
     cast::as_generic_list_array,
-    exec_err, plan_err,
+    exec_err, internal_err, plan_err,
     utils::{list_ndims, take_function_args},
 };
 use datafusion_expr::binary::type_union_resolution;
@@ -350,6 +350,177 @@ impl ScalarUDFImpl for ArrayConcat {
     }
 }
 
+make_udf_expr_and_func!(
+    ConcatList,
+    concat_list,
+    "Concatenates lists and elements into a single list.",
+    concat_list_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Concatenates lists and elements into a single list. The arguments with the most dimensions are lists whose elements are appended in order, and the arguments with one dimension less (or NULL) are appended as single elements. The element types of all arguments are unified.",
+    syntax_example = "concat_list(value[, ..., value_n])",
+    sql_example = r#"```sql
+> select concat_list([1, 2], 3, [4]);
++--------------------------------------------------------------------------+
+| concat_list(make_array(Int64(1),Int64(2)),Int64(3),make_array(Int64(4))) |
++--------------------------------------------------------------------------+
+| [1, 2, 3, 4]                                                             |
++--------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "value",
+        description = "Array or element expression. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "value_n",
+        description = "Subsequent array or element to concatenate."
+    )
+)]
+#[derive(Debug)]
+pub struct ConcatList {
+    signature: Signature,
+}
+
+impl Default for ConcatList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConcatList {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ConcatList {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "concat_list"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        if arg_types.is_empty() {
+            return plan_err!("{} expects at least one argument", self.name());
+        }
+
+        // the arguments with the most dimensions are the lists to concatenate
+        let max_dims = arg_types.iter().map(list_ndims).max().unwrap_or(0).max(1);
+        let mut large_list = false;
+        let mut base_types = Vec::with_capacity(arg_types.len());
+        for arg_type in arg_types {
+            let dims = list_ndims(arg_type);
+            if dims == max_dims {
+                large_list |= matches!(arg_type, DataType::LargeList(_));
+            } else if dims != max_dims - 1 && !arg_type.is_null() {
+                return plan_err!(
+                    "{} argument of type {arg_type} is neither a list with {max_dims} dimensions nor an element of one",
+                    self.name()
+                );
+            }
+            base_types.push(base_type(arg_type));
+        }
+
+        let Some(mut return_type) = type_union_resolution(&base_types) else {
+            return plan_err!(
+                "Failed to unify argument types of {}: {arg_types:?}",
+                self.name()
+            );
+        };
+        for _ in 1..max_dims {
+            return_type = DataType::new_list(return_type, true)
+        }
+        if large_list {
+            Ok(DataType::new_large_list(return_type, true))
+        } else {
+            Ok(DataType::new_list(return_type, true))
+        }
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        let return_type = args.return_field.data_type().clone();
+        make_scalar_function(move |args| concat_list_inner(args, &return_type))(
+            &args.args,
+        )
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        // lists are coerced to the return type and elements to its element type
+        let return_type = self.return_type(arg_types)?;
+        let max_dims = list_ndims(&return_type);
+        let element_type = match &return_type {
+            DataType::List(field) | DataType::LargeList(field) => field.data_type(),
+            _ => return internal_err!("{} must return a list", self.name()),
+        };
+        let arg_types = arg_types.iter().map(|arg_type| {
+            if list_ndims(arg_type) == max_dims {
+                return_type.clone()
+            } else {
+                element_type.clone()
+            }
+        });
+
+        Ok(arg_types.collect())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// concat_list SQL function
+///
+/// Each argument whose type is not `return_type` is an element, which is
+/// turned into a list of that single element before concatenating
+fn concat_list_inner(args: &[ArrayRef], return_type: &DataType) -> Result<ArrayRef> {
+    let args = args
+        .iter()
+        .map(|arg| {
+            if arg.data_type() == return_type {
+                return Ok(Arc::clone(arg));
+            }
+            let field = Arc::new(Field::new_list_field(arg.data_type().clone(), true));
+            let lengths = std::iter::repeat_n(1, arg.len());
+            let list: ArrayRef = match return_type {
+                DataType::List(_) => Arc::new(ListArray::try_new(
+                    field,
+                    OffsetBuffer::from_lengths(lengths),
+                    Arc::clone(arg),
+                    None,
+                )?),
+                DataType::LargeList(_) => Arc::new(LargeListArray::try_new(
+                    field,
+                    OffsetBuffer::from_lengths(lengths),
+                    Arc::clone(arg),
+                    None,
+                )?),
+                _ => return exec_err!("concat_list does not support type {return_type}"),
+            };
+            Ok(list)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if matches!(return_type, DataType::LargeList(_)) {
+        concat_internal::<i64>(&args)
+    } else {
+        concat_internal::<i32>(&args)
+    }
+}
+
 /// Array_concat/Array_cat SQL function
 pub(crate) fn array_concat_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.is_empty() {
@@ -427,11 +598,17 @@ fn concat_internal<O: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
         .iter()
         .map(|a| a.as_ref())
         .collect::<Vec<&dyn Array>>();
+    // every row is null
+    let values = if elements.is_empty() {
+        new_empty_array(&data_type)
+    } else {
+        arrow::compute::concat(elements.as_slice())?
+    };
 
     let list_arr = GenericListArray::<O>::new(
         Arc::new(Field::new_list_field(data_type, true)),
         OffsetBuffer::from_lengths(array_lengths),
-        Arc::new(arrow::compute::concat(elements.as_slice())?),
+        values,
         valid.finish(),
     );
 
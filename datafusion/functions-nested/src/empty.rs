@@ -15,14 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for array_empty function.
+//! [`ScalarUDFImpl`] definitions for array_empty and nullif_list_empty functions.
 
 use crate::utils::make_scalar_function;
-use arrow::array::{Array, ArrayRef, BooleanArray, OffsetSizeTrait};
-use arrow::buffer::BooleanBuffer;
+use arrow::array::{make_array, Array, ArrayRef, BooleanArray, OffsetSizeTrait};
+use arrow::buffer::{BooleanBuffer, NullBuffer};
 use arrow::datatypes::{
     DataType,
-    DataType::{Boolean, FixedSizeList, LargeList, List},
+    DataType::{Boolean, FixedSizeList, LargeList, List, Null},
 };
 use datafusion_common::cast::as_generic_list_array;
 use datafusion_common::{exec_err, utils::take_function_args, Result};
@@ -110,6 +110,80 @@ impl ScalarUDFImpl for ArrayEmpty {
     }
 }
 
+make_udf_expr_and_func!(
+    NullIfListEmpty,
+    nullif_list_empty,
+    array,
+    "returns NULL for an empty array, or the array itself otherwise.",
+    nullif_list_empty_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns NULL for an empty array, or the array unchanged if it is not empty.",
+    syntax_example = "nullif_list_empty(array)",
+    sql_example = r#"```sql
+> select nullif_list_empty([]), nullif_list_empty([1]);
++---------------------------------+-----------------------------------------+
+| nullif_list_empty(make_array()) | nullif_list_empty(make_array(Int64(1))) |
++---------------------------------+-----------------------------------------+
+| NULL                            | [1]                                     |
++---------------------------------+-----------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug)]
+pub struct NullIfListEmpty {
+    signature: Signature,
+}
+
+impl Default for NullIfListEmpty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NullIfListEmpty {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::arrays(1, None, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for NullIfListEmpty {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "nullif_list_empty"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [array_type] = take_function_args(self.name(), arg_types)?;
+        Ok(array_type.clone())
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(nullif_list_empty_inner)(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
 /// Array_empty SQL function
 pub fn array_empty_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
     let [array] = take_function_args("array_empty", args)?;
@@ -135,3 +209,33 @@ fn general_array_empty<O: OffsetSizeTrait>(array: &ArrayRef) -> Result<ArrayRef>
         .collect::<BooleanArray>();
     Ok(Arc::new(result))
 }
+
+/// nullif_list_empty SQL function
+pub fn nullif_list_empty_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array] = take_function_args("nullif_list_empty", args)?;
+    let non_empty = match array.data_type() {
+        Null => return Ok(Arc::clone(array)),
+        List(_) => general_non_empty::<i32>(array)?,
+        LargeList(_) => general_non_empty::<i64>(array)?,
+        FixedSizeList(_, size) => {
+            if *size == 0 {
+                BooleanBuffer::new_unset(array.len())
+            } else {
+                BooleanBuffer::new_set(array.len())
+            }
+        }
+        arg_type => {
+            return exec_err!("nullif_list_empty does not support type {arg_type}")
+        }
+    };
+
+    // only the validity changes, so the type is exactly the same
+    let nulls = NullBuffer::union(array.nulls(), Some(&NullBuffer::new(non_empty)));
+    let data = array.to_data().into_builder().nulls(nulls).build()?;
+    Ok(make_array(data))
+}
+
+fn general_non_empty<O: OffsetSizeTrait>(array: &ArrayRef) -> Result<BooleanBuffer> {
+    let lengths = as_generic_list_array::<O>(array)?.offsets().lengths();
+    Ok(lengths.map(|len| len > 0).collect())
+}
@@ -22,7 +22,7 @@ use arrow::array::{cast::AsArray, Array, ArrayRef, GenericListArray, OffsetSizeT
 use arrow::buffer::OffsetBuffer;
 use arrow::datatypes::{DataType, FieldRef};
 use arrow::row::{RowConverter, SortField};
-use datafusion_common::utils::take_function_args;
+use datafusion_common::utils::{take_function_args, ListCoercion};
 use datafusion_common::{internal_err, HashSet, Result};
 use datafusion_expr::{
     ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
@@ -41,7 +41,7 @@ make_udf_expr_and_func!(
 
 #[user_doc(
     doc_section(label = "Array Functions"),
-    description = "Returns an array of the elements that appear in the first array but not in the second.",
+    description = "Returns an array of the elements that appear in `array1` but not in `array2`, without duplicates, in the order in which they first appear in `array1`. NULL elements are considered equal to each other.",
     syntax_example = "array_except(array1, array2)",
     sql_example = r#"```sql
 > select array_except([1, 2, 3, 4], [5, 6, 3, 4]);
@@ -81,7 +81,11 @@ impl Default for ArrayExcept {
 impl ArrayExcept {
     pub fn new() -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::arrays(
+                2,
+                Some(ListCoercion::FixedSizedListToList),
+                Volatility::Immutable,
+            ),
             aliases: vec!["list_except".to_string()],
         }
     }
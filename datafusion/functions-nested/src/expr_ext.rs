@@ -17,9 +17,10 @@
 
 //! Extension methods for Expr.
 
-use datafusion_expr::Expr;
+use datafusion_expr::{lit, Expr};
 
 use crate::extract::{array_element, array_slice};
+use crate::map_extract::map_extract;
 
 /// Return access to the element field. Example `expr["name"]`
 ///
@@ -40,6 +41,20 @@ use crate::extract::{array_element, array_slice};
 ///    .index(lit(3));
 /// assert_eq!(expr.schema_name().to_string(), "c1[Int32(3)]");
 /// ```
+///
+/// ## Example Access the last element from column "c1"
+///
+/// Negative indexes count backwards from the end of the list, so `-1` is the
+/// last element and `-2` the second to last. Indexes that are out of bounds
+/// in either direction return `NULL`.
+///
+/// ```
+/// # use datafusion_expr::{lit, col, Expr};
+/// # use datafusion_functions_nested::expr_ext::IndexAccessor;
+/// let expr = col("c1")
+///    .index(lit(-1));
+/// assert_eq!(expr.schema_name().to_string(), "c1[Int32(-1)]");
+/// ```
 pub trait IndexAccessor {
     fn index(self, key: Expr) -> Expr;
 }
@@ -70,21 +85,79 @@ impl IndexAccessor for Expr {
 ///    .range(lit(2), lit(4));
 /// assert_eq!(expr.schema_name().to_string(), "c1[Int32(2):Int32(4)]");
 /// ```
+///
+/// ## Example: Access every other element from column "c1"
+///
+/// [`SliceAccessor::range_with_stride`] takes every `stride`th element
+/// between `start` and `stop`. A negative `stride` walks the list backwards,
+/// so `start` must be greater than `stop`, and a `stride` of `0` is an error.
+///
+/// You can access the value `[10, 30]` with
+///
+/// ```
+/// # use datafusion_expr::{lit, col};
+/// # use datafusion_functions_nested::expr_ext::SliceAccessor;
+/// let expr = col("c1")
+///    .range_with_stride(lit(1), lit(3), lit(2));
+/// assert_eq!(expr.schema_name().to_string(), "c1[Int32(1):Int32(3):Int32(2)]");
+/// ```
 pub trait SliceAccessor {
     fn range(self, start: Expr, stop: Expr) -> Expr;
+
+    fn range_with_stride(self, start: Expr, stop: Expr, stride: Expr) -> Expr;
 }
 
 impl SliceAccessor for Expr {
     fn range(self, start: Expr, stop: Expr) -> Expr {
         array_slice(self, start, stop, None)
     }
+
+    fn range_with_stride(self, start: Expr, stop: Expr, stride: Expr) -> Expr {
+        array_slice(self, start, stop, Some(stride))
+    }
+}
+
+/// Return the value for a key of a map, for example `expr['name']`
+///
+/// Unlike the [`map_extract`] function, which returns a list holding the
+/// value, [`MapAccessor::map_extract`] returns the value itself, or `NULL`
+/// if the map does not contain the key.
+///
+/// ## Example: Access the value for key "b" from column "c1"
+///
+/// For example if column "c1" holds maps like this
+///
+/// ```json
+/// {"a": 1, "b": 2}
+/// ```
+///
+/// You can access the value `2` with
+///
+/// ```
+/// # use datafusion_expr::{lit, col};
+/// # use datafusion_functions_nested::expr_ext::MapAccessor;
+/// let expr = col("c1")
+///    .map_extract(lit("b"));
+/// assert_eq!(
+///     expr.schema_name().to_string(),
+///     "map_extract(c1,Utf8(\"b\"))[Int64(1)]"
+/// );
+/// ```
+pub trait MapAccessor {
+    fn map_extract(self, key: Expr) -> Expr;
+}
+
+impl MapAccessor for Expr {
+    fn map_extract(self, key: Expr) -> Expr {
+        array_element(map_extract(self, key), lit(1i64))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use datafusion_expr::{col, lit};
+    use datafusion_expr::col;
 
     #[test]
     fn test_index() {
@@ -99,4 +172,18 @@ mod tests {
         let expr2 = array_slice(col("a"), lit(1), lit(2), None);
         assert_eq!(expr1, expr2);
     }
+
+    #[test]
+    fn test_range_with_stride() {
+        let expr1 = col("a").range_with_stride(lit(1), lit(3), lit(2));
+        let expr2 = array_slice(col("a"), lit(1), lit(3), Some(lit(2)));
+        assert_eq!(expr1, expr2);
+    }
+
+    #[test]
+    fn test_map_extract() {
+        let expr1 = col("a").map_extract(lit("b"));
+        let expr2 = array_element(map_extract(col("a"), lit("b")), lit(1i64));
+        assert_eq!(expr1, expr2);
+    }
 }
@@ -15,21 +15,25 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for flatten function.
+//! [`ScalarUDFImpl`] definitions for flatten and array_flatten_n functions.
 
 use crate::utils::make_scalar_function;
-use arrow::array::{Array, ArrayRef, GenericListArray, OffsetSizeTrait};
+use arrow::array::{Array, ArrayRef, GenericListArray, OffsetSizeTrait, UInt64Array};
 use arrow::buffer::OffsetBuffer;
+use arrow::compute::take;
 use arrow::datatypes::{
     DataType,
     DataType::{FixedSizeList, LargeList, List, Null},
+    Field, FieldRef,
 };
-use datafusion_common::cast::{as_large_list_array, as_list_array};
+use datafusion_common::cast::{as_int64_array, as_large_list_array, as_list_array};
 use datafusion_common::utils::ListCoercion;
-use datafusion_common::{exec_err, utils::take_function_args, Result};
+use datafusion_common::{
+    exec_err, internal_err, plan_err, utils::take_function_args, Result, ScalarValue,
+};
 use datafusion_expr::{
     ArrayFunctionArgument, ArrayFunctionSignature, ColumnarValue, Documentation,
-    ScalarUDFImpl, Signature, TypeSignature, Volatility,
+    ReturnFieldArgs, ScalarUDFImpl, Signature, TypeSignature, Volatility,
 };
 use datafusion_macros::user_doc;
 use std::any::Any;
@@ -43,6 +47,14 @@ make_udf_expr_and_func!(
     flatten_udf
 );
 
+make_udf_expr_and_func!(
+    ArrayFlattenN,
+    array_flatten_n,
+    array depth,
+    "flattens the nested arrays of an array up to the given depth.",
+    array_flatten_n_udf
+);
+
 #[user_doc(
     doc_section(label = "Array Functions"),
     description = "Converts an array of arrays to a flat array.\n\n- Applies to any depth of nested arrays\n- Does not change arrays that are already flat\n\nThe flattened array contains all the elements from all source arrays.",
@@ -211,6 +223,227 @@ pub fn flatten_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Flattens the nested arrays of an array up to the given depth.\n\n- A depth of 1 removes one level of nesting, a depth of 2 two levels, and so on\n- A depth greater than the nesting of the array returns the fully flattened array\n- A depth of 0 returns the array as is\n- NULL nested arrays are dropped",
+    syntax_example = "array_flatten_n(array, depth)",
+    sql_example = r#"```sql
+> select array_flatten_n([[[1, 2], [3]], [[4]]], 1);
++----------------------------------------------------+
+| array_flatten_n(List([[1,2],[3]], [[4]]),Int64(1)) |
++----------------------------------------------------+
+| [[1, 2], [3], [4]]                                 |
++----------------------------------------------------+
+> select array_flatten_n([[[1, 2], [3]], [[4]]], 2);
++----------------------------------------------------+
+| array_flatten_n(List([[1,2],[3]], [[4]]),Int64(2)) |
++----------------------------------------------------+
+| [1, 2, 3, 4]                                       |
++----------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "depth",
+        description = "Constant number of levels of nesting to flatten."
+    )
+)]
+#[derive(Debug)]
+pub struct ArrayFlattenN {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayFlattenN {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayFlattenN {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature {
+                type_signature: TypeSignature::ArraySignature(
+                    ArrayFunctionSignature::Array {
+                        arguments: vec![
+                            ArrayFunctionArgument::Array,
+                            ArrayFunctionArgument::Index,
+                        ],
+                        array_coercion: Some(ListCoercion::FixedSizedListToList),
+                    },
+                ),
+                volatility: Volatility::Immutable,
+            },
+            aliases: vec![String::from("list_flatten_n")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayFlattenN {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_flatten_n"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let [array, _] = take_function_args(self.name(), args.arg_fields)?;
+        // the depth decides the return type, so it must be known when planning
+        let depth = match args.scalar_arguments.get(1).copied().flatten() {
+            Some(ScalarValue::Int64(Some(depth))) => flatten_depth(*depth)?,
+            _ => {
+                return plan_err!(
+                    "{} requires its depth argument to be a constant integer",
+                    self.name()
+                )
+            }
+        };
+        Ok(Field::new(
+            self.name(),
+            flatten_n_type(array.data_type(), depth)?,
+            array.is_nullable(),
+        ))
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(array_flatten_n_inner)(&args.args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Checks that the depth of `array_flatten_n` is not negative
+fn flatten_depth(depth: i64) -> Result<usize> {
+    match usize::try_from(depth) {
+        Ok(depth) => Ok(depth),
+        Err(_) => plan_err!("array_flatten_n depth must not be negative, got {depth}"),
+    }
+}
+
+/// The type of `data_type` flattened `depth` times, stopping at the
+/// innermost array
+fn flatten_n_type(data_type: &DataType, depth: usize) -> Result<DataType> {
+    let mut field = match data_type {
+        List(field) | LargeList(field) | FixedSizeList(field, _) => field,
+        Null => return Ok(Null),
+        _ => return exec_err!("array_flatten_n does not support type '{data_type}'"),
+    };
+    for _ in 0..depth {
+        match field.data_type() {
+            List(inner) | LargeList(inner) | FixedSizeList(inner, _) => field = inner,
+            _ => break,
+        }
+    }
+    Ok(match data_type {
+        LargeList(_) => LargeList(Arc::clone(field)),
+        _ => List(Arc::clone(field)),
+    })
+}
+
+/// array_flatten_n SQL function
+pub fn array_flatten_n_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array, depth] = take_function_args("array_flatten_n", args)?;
+    let depth = as_int64_array(depth)?;
+    if depth.is_empty() {
+        return Ok(Arc::clone(array));
+    }
+    if depth.null_count() > 0 || depth.values().iter().any(|d| *d != depth.value(0)) {
+        return exec_err!("array_flatten_n requires its depth to be a constant integer");
+    }
+
+    let mut array = cast_fsl_to_list(Arc::clone(array))?;
+    for _ in 0..flatten_depth(depth.value(0))? {
+        let flattened = match array.data_type() {
+            List(_) => flatten_one_level(as_list_array(&array)?)?,
+            LargeList(_) => flatten_one_level(as_large_list_array(&array)?)?,
+            Null => None,
+            _ => {
+                return exec_err!(
+                    "array_flatten_n does not support type '{}'",
+                    array.data_type()
+                )
+            }
+        };
+        // stop once the elements are no longer arrays
+        let Some(flattened) = flattened else { break };
+        array = flattened;
+    }
+    Ok(array)
+}
+
+/// Concatenates the nested arrays of each array in `list`, dropping the NULL
+/// nested arrays, or returns `None` if the elements of `list` are not arrays
+fn flatten_one_level<O: OffsetSizeTrait>(
+    list: &GenericListArray<O>,
+) -> Result<Option<ArrayRef>> {
+    let values = cast_fsl_to_list(Arc::clone(list.values()))?;
+    match values.data_type() {
+        List(field) => {
+            concat_nested(list, as_list_array(&values)?, Arc::clone(field)).map(Some)
+        }
+        LargeList(field) => {
+            concat_nested(list, as_large_list_array(&values)?, Arc::clone(field))
+                .map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn concat_nested<O: OffsetSizeTrait, P: OffsetSizeTrait>(
+    list: &GenericListArray<O>,
+    nested: &GenericListArray<P>,
+    field: FieldRef,
+) -> Result<ArrayRef> {
+    let mut indices = vec![];
+    let mut lengths = Vec::with_capacity(list.len());
+    for (row, offsets) in list.value_offsets().windows(2).enumerate() {
+        let start = indices.len();
+        if list.is_valid(row) {
+            for idx in offsets[0].as_usize()..offsets[1].as_usize() {
+                if nested.is_null(idx) {
+                    continue;
+                }
+                let nested_offsets = &nested.value_offsets()[idx..idx + 2];
+                indices.extend(
+                    (nested_offsets[0].as_usize()..nested_offsets[1].as_usize())
+                        .map(|i| i as u64),
+                );
+            }
+        }
+        lengths.push(indices.len() - start);
+    }
+
+    let values = take(nested.values(), &UInt64Array::from(indices), None)?;
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
 // Create new offsets that are equivalent to `flatten` the array.
 fn get_offsets_for_flatten<O: OffsetSizeTrait>(
     offsets: OffsetBuffer<O>,
@@ -245,3 +478,96 @@ fn cast_fsl_to_list(array: ArrayRef) -> Result<ArrayRef> {
         _ => Ok(array),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::ListArray;
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::Int64Type;
+
+    /// Wraps `values` in a list with lists of the given lengths
+    fn wrap(values: ArrayRef, lengths: &[usize], nulls: Option<Vec<bool>>) -> ArrayRef {
+        Arc::new(ListArray::new(
+            Arc::new(Field::new_list_field(values.data_type().clone(), true)),
+            OffsetBuffer::from_lengths(lengths.iter().copied()),
+            values,
+            nulls.map(NullBuffer::from),
+        ))
+    }
+
+    fn flatten_n(array: &ArrayRef, depth: i64) -> Result<ArrayRef> {
+        let depth = Arc::new(arrow::array::Int64Array::from(vec![depth; array.len()]));
+        array_flatten_n_inner(&[Arc::clone(array), depth])
+    }
+
+    #[test]
+    fn test_array_flatten_n() -> Result<()> {
+        // [[[1, 2], NULL, [3]]], NULL, [[[4]], NULL, [[5, NULL]]], []
+        let lists: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(3)]),
+                Some(vec![Some(4)]),
+                Some(vec![Some(5), None]),
+            ]));
+        let array = wrap(
+            wrap(
+                Arc::clone(&lists),
+                &[3, 1, 0, 1],
+                Some(vec![true, true, false, true]),
+            ),
+            &[1, 0, 3, 0],
+            Some(vec![true, false, true, true]),
+        );
+
+        // the NULL list of lists is dropped
+        let expected = wrap(lists, &[3, 0, 2, 0], Some(vec![true, false, true, true]));
+        let result = flatten_n(&array, 1)?;
+        assert_eq!(&result, &expected);
+        assert_eq!(result.data_type(), &flatten_n_type(array.data_type(), 1)?);
+
+        let expected = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4), Some(5), None]),
+            Some(vec![]),
+        ])) as ArrayRef;
+        for depth in [2, 3, 10] {
+            let result = flatten_n(&array, depth)?;
+            assert_eq!(&result, &expected);
+            assert_eq!(
+                result.data_type(),
+                &flatten_n_type(array.data_type(), depth as usize)?
+            );
+        }
+
+        assert_eq!(&flatten_n(&array, 0)?, &array);
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_flatten_n_sliced() -> Result<()> {
+        let lists = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2), Some(3)]),
+            Some(vec![Some(4)]),
+        ]);
+        let array = wrap(Arc::new(lists.slice(1, 2)), &[2], None);
+        let expected = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(2), Some(3), Some(4)]),
+        ])) as ArrayRef;
+        assert_eq!(&flatten_n(&array, 1)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_flatten_n_negative_depth() {
+        let err = flatten_depth(-1).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: array_flatten_n depth must not be negative, got -1"
+        );
+    }
+}
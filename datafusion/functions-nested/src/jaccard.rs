@@ -0,0 +1,247 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for jaccard_similarity function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{
+    new_null_array, Array, ArrayRef, Float64Array, GenericListArray, OffsetSizeTrait,
+};
+use arrow::datatypes::DataType;
+use arrow::datatypes::DataType::{LargeList, List, Null};
+use arrow::row::{RowConverter, SortField};
+use datafusion_common::cast::{as_large_list_array, as_list_array};
+use datafusion_common::utils::{take_function_args, ListCoercion};
+use datafusion_common::{exec_err, internal_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    JaccardSimilarity,
+    jaccard_similarity,
+    array1 array2,
+    "returns the size of the intersection of the distinct elements of array1 and array2 divided by the size of their union.",
+    jaccard_similarity_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns the Jaccard similarity of two arrays: the number of distinct elements in both arrays divided by the number of distinct elements in either array, between 0.0 and 1.0. Duplicate elements are counted once, and NULL elements are considered equal to each other. Two empty arrays have a similarity of 1.0.",
+    syntax_example = "jaccard_similarity(array1, array2)",
+    sql_example = r#"```sql
+> select jaccard_similarity([1, 2, 2, 3], [2, 3, 4]);
++---------------------------------------------------+
+| jaccard_similarity(List([1,2,2,3]),List([2,3,4])) |
++---------------------------------------------------+
+| 0.5                                               |
++---------------------------------------------------+
+```"#,
+    argument(
+        name = "array1",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "array2",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug)]
+pub struct JaccardSimilarity {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for JaccardSimilarity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JaccardSimilarity {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::arrays(
+                2,
+                Some(ListCoercion::FixedSizedListToList),
+                Volatility::Immutable,
+            ),
+            aliases: vec![String::from("jaccard")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for JaccardSimilarity {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "jaccard_similarity"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(jaccard_similarity_inner)(&args.args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn jaccard_similarity_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array1, array2] = take_function_args("jaccard_similarity", args)?;
+    match (array1.data_type(), array2.data_type()) {
+        (Null, _) | (_, Null) => Ok(new_null_array(&DataType::Float64, array1.len())),
+        (List(_), List(_)) => {
+            general_jaccard_similarity(as_list_array(array1)?, as_list_array(array2)?)
+        }
+        (LargeList(_), LargeList(_)) => general_jaccard_similarity(
+            as_large_list_array(array1)?,
+            as_large_list_array(array2)?,
+        ),
+        (arg_type1, arg_type2) => exec_err!(
+            "jaccard_similarity does not support types {arg_type1} and {arg_type2}"
+        ),
+    }
+}
+
+fn general_jaccard_similarity<OffsetSize: OffsetSizeTrait>(
+    l: &GenericListArray<OffsetSize>,
+    r: &GenericListArray<OffsetSize>,
+) -> Result<ArrayRef> {
+    // the elements of lists of NULLs, such as `[]`, can not be compared to the
+    // other side, but there are only NULL elements to compare
+    let (l_values, r_values) = match (l.value_type(), r.value_type()) {
+        (Null, Null) => (Arc::clone(l.values()), Arc::clone(r.values())),
+        (Null, dt) => (new_null_array(&dt, l.values().len()), Arc::clone(r.values())),
+        (dt, Null) => (Arc::clone(l.values()), new_null_array(&dt, r.values().len())),
+        (l_type, r_type) if l_type == r_type => {
+            (Arc::clone(l.values()), Arc::clone(r.values()))
+        }
+        (l_type, r_type) => {
+            return internal_err!(
+                "jaccard_similarity expects arrays of the same type, got {l_type} and {r_type}"
+            )
+        }
+    };
+
+    let converter =
+        RowConverter::new(vec![SortField::new(l_values.data_type().clone())])?;
+    // nulls are encoded as equal rows, so NULL elements are equal to each other
+    let l_rows = converter.convert_columns(&[l_values])?;
+    let r_rows = converter.convert_columns(&[r_values])?;
+
+    let mut l_set = HashSet::new();
+    let mut union = HashSet::new();
+    let result = l
+        .offsets()
+        .windows(2)
+        .zip(r.offsets().windows(2))
+        .enumerate()
+        .map(|(i, (l_w, r_w))| {
+            if l.is_null(i) || r.is_null(i) {
+                return None;
+            }
+            l_set.clear();
+            l_set.extend((l_w[0].as_usize()..l_w[1].as_usize()).map(|j| l_rows.row(j)));
+            union.clear();
+            union.extend(l_set.iter().copied());
+            let mut intersection = 0;
+            for j in r_w[0].as_usize()..r_w[1].as_usize() {
+                let row = r_rows.row(j);
+                if union.insert(row) {
+                    continue;
+                }
+                // `row` is in `l` or repeated in `r`, and elements of `l` are
+                // removed from `l_set` to only count them once
+                if l_set.remove(&row) {
+                    intersection += 1;
+                }
+            }
+            if union.is_empty() {
+                // two empty sets are equal
+                return Some(1.0);
+            }
+            Some(intersection as f64 / union.len() as f64)
+        })
+        .collect::<Float64Array>();
+
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::ListArray;
+    use arrow::datatypes::Int64Type;
+
+    #[test]
+    fn test_jaccard_similarity() -> Result<()> {
+        let l = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(2), Some(3)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            Some(vec![None, Some(1)]),
+            None,
+            Some(vec![Some(1)]),
+        ]);
+        let r = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3), Some(2), Some(4), Some(4)]),
+            Some(vec![Some(2), Some(1), Some(1)]),
+            Some(vec![Some(3)]),
+            Some(vec![]),
+            Some(vec![None]),
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        let result = jaccard_similarity_inner(&[Arc::new(l), Arc::new(r)])?;
+        assert_eq!(
+            result.as_ref(),
+            &Float64Array::from(vec![
+                Some(0.5),
+                Some(1.0),
+                Some(0.0),
+                Some(1.0),
+                Some(0.5),
+                None,
+                None,
+            ]) as &dyn Array
+        );
+        Ok(())
+    }
+}
@@ -47,16 +47,19 @@ pub mod except;
 pub mod expr_ext;
 pub mod extract;
 pub mod flatten;
+pub mod jaccard;
 pub mod length;
 pub mod make_array;
 pub mod map;
 pub mod map_extract;
+pub mod map_from_arrays;
 pub mod map_keys;
 pub mod map_values;
 pub mod max;
 pub mod planner;
 pub mod position;
 pub mod range;
+pub mod reduce;
 pub mod remove;
 pub mod repeat;
 pub mod replace;
@@ -65,7 +68,10 @@ pub mod reverse;
 pub mod set_ops;
 pub mod sort;
 pub mod string;
+pub mod take_while;
+pub mod trim_array;
 pub mod utils;
+pub mod zip;
 
 use datafusion_common::Result;
 use datafusion_execution::FunctionRegistry;
@@ -82,26 +88,33 @@ pub mod expr_fn {
     pub use super::concat::array_append;
     pub use super::concat::array_concat;
     pub use super::concat::array_prepend;
+    pub use super::concat::concat_list;
     pub use super::dimension::array_dims;
     pub use super::dimension::array_ndims;
     pub use super::distance::array_distance;
     pub use super::empty::array_empty;
+    pub use super::empty::nullif_list_empty;
     pub use super::except::array_except;
     pub use super::extract::array_any_value;
     pub use super::extract::array_element;
     pub use super::extract::array_pop_back;
     pub use super::extract::array_pop_front;
     pub use super::extract::array_slice;
+    pub use super::flatten::array_flatten_n;
     pub use super::flatten::flatten;
+    pub use super::jaccard::jaccard_similarity;
     pub use super::length::array_length;
     pub use super::make_array::make_array;
     pub use super::map_extract::map_extract;
+    pub use super::map_from_arrays::map_from_arrays;
     pub use super::map_keys::map_keys;
     pub use super::map_values::map_values;
     pub use super::position::array_position;
     pub use super::position::array_positions;
+    pub use super::position::list_position;
     pub use super::range::gen_series;
     pub use super::range::range;
+    pub use super::reduce::list_reduce;
     pub use super::remove::array_remove;
     pub use super::remove::array_remove_all;
     pub use super::remove::array_remove_n;
@@ -114,9 +127,14 @@ pub mod expr_fn {
     pub use super::set_ops::array_distinct;
     pub use super::set_ops::array_intersect;
     pub use super::set_ops::array_union;
+    pub use super::set_ops::list_distinct;
     pub use super::sort::array_sort;
+    pub use super::sort::array_sort_by;
     pub use super::string::array_to_string;
     pub use super::string::string_to_array;
+    pub use super::take_while::list_take_while;
+    pub use super::trim_array::trim_array;
+    pub use super::zip::array_zip;
 }
 
 /// Return all default nested type functions
@@ -132,6 +150,7 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         concat::array_append_udf(),
         concat::array_prepend_udf(),
         concat::array_concat_udf(),
+        concat::concat_list_udf(),
         except::array_except_udf(),
         extract::array_element_udf(),
         extract::array_pop_back_udf(),
@@ -143,19 +162,27 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         array_has::array_has_all_udf(),
         array_has::array_has_any_udf(),
         empty::array_empty_udf(),
+        empty::nullif_list_empty_udf(),
         length::array_length_udf(),
         distance::array_distance_udf(),
+        jaccard::jaccard_similarity_udf(),
         flatten::flatten_udf(),
+        flatten::array_flatten_n_udf(),
         max::array_max_udf(),
         sort::array_sort_udf(),
+        sort::array_sort_by_udf(),
         repeat::array_repeat_udf(),
         resize::array_resize_udf(),
         reverse::array_reverse_udf(),
+        zip::array_zip_udf(),
+        trim_array::trim_array_udf(),
         set_ops::array_distinct_udf(),
         set_ops::array_intersect_udf(),
         set_ops::array_union_udf(),
+        set_ops::list_distinct_udf(),
         position::array_position_udf(),
         position::array_positions_udf(),
+        position::list_position_udf(),
         remove::array_remove_udf(),
         remove::array_remove_all_udf(),
         remove::array_remove_n_udf(),
@@ -164,6 +191,7 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         replace::array_replace_udf(),
         map::map_udf(),
         map_extract::map_extract_udf(),
+        map_from_arrays::map_from_arrays_udf(),
         map_keys::map_keys_udf(),
         map_values::map_values_udf(),
     ]
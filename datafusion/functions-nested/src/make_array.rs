@@ -34,7 +34,6 @@ use datafusion_common::{plan_err, Result};
 use datafusion_expr::binary::{
     try_type_union_resolution_with_struct, type_union_resolution,
 };
-use datafusion_expr::TypeSignature;
 use datafusion_expr::{
     ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
 };
@@ -79,10 +78,10 @@ impl Default for MakeArray {
 impl MakeArray {
     pub fn new() -> Self {
         Self {
-            signature: Signature::one_of(
-                vec![TypeSignature::Nullary, TypeSignature::UserDefined],
-                Volatility::Immutable,
-            ),
+            // a single user defined signature, rather than one of `Nullary`
+            // and `UserDefined`, reports why the elements can't be coerced
+            // to a common type instead of the failures of both signatures
+            signature: Signature::user_defined(Volatility::Immutable),
             aliases: vec![String::from("make_list")],
         }
     }
@@ -124,6 +123,10 @@ impl ScalarUDFImpl for MakeArray {
     }
 
     fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.is_empty() {
+            return Ok(vec![]);
+        }
+
         if let Ok(unified) = try_type_union_resolution_with_struct(arg_types) {
             return Ok(unified);
         }
@@ -132,8 +135,13 @@ impl ScalarUDFImpl for MakeArray {
             Ok(vec![unified; arg_types.len()])
         } else {
             plan_err!(
-                "Failed to unify argument types of {}: {arg_types:?}",
-                self.name()
+                "Failed to unify argument types of {}: no common type for {}",
+                self.name(),
+                arg_types
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
             )
         }
     }
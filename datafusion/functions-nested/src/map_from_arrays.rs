@@ -0,0 +1,302 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_from_arrays function.
+
+use crate::utils::{get_map_entry_field, make_scalar_function};
+use arrow::array::{
+    Array, ArrayRef, MapArray, NullBufferBuilder, StructArray, UInt64Array,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::take;
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::cast::as_list_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, internal_err, plan_err, HashMap, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapFromArrays,
+    map_from_arrays,
+    keys values,
+    "Returns a map built from a list of keys and a list of values. If a key appears more than once, its last value is kept.",
+    map_from_arrays_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = r#"Returns a map built from a list of keys and a list of values of the same length.
+    If a key appears more than once, the map keeps the key at the position where it first appears, with the value from its last occurrence. Keys must not be NULL. The result is NULL if either list is NULL."#,
+    syntax_example = "map_from_arrays(keys, values)",
+    sql_example = r#"```sql
+SELECT map_from_arrays(['a', 'b', 'c'], [1, 2, 3]);
+----
+{a: 1, b: 2, c: 3}
+
+SELECT map_from_arrays(['a', 'b', 'a'], [1, 2, 3]);
+----
+{a: 3, b: 2}
+```"#,
+    argument(
+        name = "keys",
+        description = "List of keys for the map. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "values",
+        description = "List of values for the map, with the same length as `keys`. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug)]
+pub struct MapFromArrays {
+    signature: Signature,
+}
+
+impl Default for MapFromArrays {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapFromArrays {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapFromArrays {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_from_arrays"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [keys, values] = take_function_args(self.name(), arg_types)?;
+        [keys, values]
+            .into_iter()
+            .map(|arg_type| match arg_type {
+                DataType::List(field)
+                | DataType::LargeList(field)
+                | DataType::FixedSizeList(field, _) => {
+                    Ok(DataType::new_list(field.data_type().clone(), true))
+                }
+                _ => plan_err!(
+                    "map_from_arrays expects two list arguments, got {keys} and {values}"
+                ),
+            })
+            .collect()
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [keys, values] = take_function_args(self.name(), arg_types)?;
+        let (DataType::List(key_field), DataType::List(value_field)) = (keys, values)
+        else {
+            return plan_err!(
+                "map_from_arrays expects two list arguments, got {keys} and {values}"
+            );
+        };
+        let fields = Fields::from(vec![
+            Field::new("key", key_field.data_type().clone(), false),
+            Field::new("value", value_field.data_type().clone(), true),
+        ]);
+        Ok(DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(fields), false)),
+            false,
+        ))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(map_from_arrays_inner)(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn map_from_arrays_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [keys, values] = take_function_args("map_from_arrays", args)?;
+    let keys = as_list_array(keys)?;
+    let values = as_list_array(values)?;
+
+    let mut key_indices = Vec::with_capacity(keys.values().len());
+    let mut value_indices = Vec::with_capacity(values.values().len());
+    let mut offsets = Vec::with_capacity(keys.len() + 1);
+    offsets.push(0_i32);
+    let mut nulls = NullBufferBuilder::new(keys.len());
+
+    for row in 0..keys.len() {
+        if keys.is_null(row) || values.is_null(row) {
+            nulls.append_null();
+            offsets.push(key_indices.len() as i32);
+            continue;
+        }
+        nulls.append_non_null();
+
+        let key_offsets = &keys.value_offsets()[row..row + 2];
+        let value_offsets = &values.value_offsets()[row..row + 2];
+        let key_count = key_offsets[1] - key_offsets[0];
+        let value_count = value_offsets[1] - value_offsets[0];
+        if key_count != value_count {
+            return exec_err!(
+                "map_from_arrays requires the same number of keys and values, got {key_count} keys and {value_count} values"
+            );
+        }
+
+        // Duplicate keys keep the position of their first occurrence and the
+        // value of their last
+        let mut positions = HashMap::with_capacity(key_count as usize);
+        for (key_index, value_index) in
+            (key_offsets[0]..key_offsets[1]).zip(value_offsets[0]..value_offsets[1])
+        {
+            let key = ScalarValue::try_from_array(keys.values(), key_index as usize)?;
+            if key.is_null() {
+                return exec_err!("map_from_arrays keys cannot be null");
+            }
+            match positions.get(&key) {
+                Some(&position) => value_indices[position] = value_index as u64,
+                None => {
+                    positions.insert(key, key_indices.len());
+                    key_indices.push(key_index as u64);
+                    value_indices.push(value_index as u64);
+                }
+            }
+        }
+        offsets.push(key_indices.len() as i32);
+    }
+
+    let map_type = MapFromArrays::new()
+        .return_type(&[keys.data_type().clone(), values.data_type().clone()])?;
+    let DataType::Map(entries_field, _) = &map_type else {
+        return internal_err!("map_from_arrays should return a map");
+    };
+    let entries = StructArray::try_new(
+        get_map_entry_field(&map_type)?.clone(),
+        vec![
+            take(keys.values(), &UInt64Array::from(key_indices), None)?,
+            take(values.values(), &UInt64Array::from(value_indices), None)?,
+        ],
+        None,
+    )?;
+
+    Ok(Arc::new(MapArray::try_new(
+        Arc::clone(entries_field),
+        OffsetBuffer::new(offsets.into()),
+        entries,
+        nulls.finish(),
+        false,
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Int32Array, ListArray, StringArray};
+    use arrow::datatypes::Int32Type;
+    use datafusion_common::cast::{as_int32_array, as_map_array, as_string_array};
+
+    fn string_lists(rows: Vec<Option<Vec<&str>>>) -> ArrayRef {
+        let offsets = OffsetBuffer::from_lengths(
+            rows.iter().map(|row| row.as_ref().map_or(0, Vec::len)),
+        );
+        let nulls = rows.iter().map(Option::is_some).collect::<Vec<_>>();
+        let values = rows.into_iter().flatten().flatten().collect::<Vec<_>>();
+        Arc::new(ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, true)),
+            offsets,
+            Arc::new(StringArray::from(values)),
+            Some(nulls.into()),
+        ))
+    }
+
+    fn int_lists(rows: Vec<Option<Vec<Option<i32>>>>) -> ArrayRef {
+        Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(rows))
+    }
+
+    #[test]
+    fn test_map_from_arrays() -> Result<()> {
+        let keys = string_lists(vec![Some(vec!["a", "b"]), None, Some(vec![])]);
+        let values = int_lists(vec![
+            Some(vec![Some(1), None]),
+            Some(vec![Some(3)]),
+            Some(vec![]),
+        ]);
+
+        let result = map_from_arrays_inner(&[keys, values])?;
+        let map = as_map_array(&result)?;
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 2]);
+        assert!(map.is_valid(0));
+        assert!(map.is_null(1));
+        assert!(map.is_valid(2));
+        assert_eq!(
+            as_string_array(map.keys())?,
+            &StringArray::from(vec!["a", "b"])
+        );
+        assert_eq!(
+            as_int32_array(map.values())?,
+            &Int32Array::from(vec![Some(1), None])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_from_arrays_duplicate_keys() -> Result<()> {
+        let keys = string_lists(vec![Some(vec!["a", "b", "a", "c", "b"])]);
+        let values =
+            int_lists(vec![Some(vec![Some(1), Some(2), Some(3), Some(4), None])]);
+
+        let result = map_from_arrays_inner(&[keys, values])?;
+        let map = as_map_array(&result)?;
+        assert_eq!(
+            as_string_array(map.keys())?,
+            &StringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(
+            as_int32_array(map.values())?,
+            &Int32Array::from(vec![Some(3), None, Some(4)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_from_arrays_errors() {
+        let keys = string_lists(vec![Some(vec!["a", "b"])]);
+        let values = int_lists(vec![Some(vec![Some(1)])]);
+        let err = map_from_arrays_inner(&[keys, values]).unwrap_err();
+        assert!(err.to_string().contains("got 2 keys and 1 values"));
+
+        let keys = int_lists(vec![Some(vec![Some(1), None])]);
+        let values = int_lists(vec![Some(vec![Some(1), Some(2)])]);
+        let err = map_from_arrays_inner(&[keys, values]).unwrap_err();
+        assert!(err.to_string().contains("keys cannot be null"));
+    }
+}
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for array_position and array_positions functions.
+//! [`ScalarUDFImpl`] definitions for array_position, list_position and array_positions functions.
 
 use arrow::datatypes::DataType;
 use arrow::datatypes::{
@@ -31,9 +31,10 @@ use std::any::Any;
 use std::sync::Arc;
 
 use arrow::array::{
-    types::UInt64Type, Array, ArrayRef, GenericListArray, ListArray, OffsetSizeTrait,
-    UInt64Array,
+    new_null_array, types::UInt64Type, Array, ArrayRef, GenericListArray, ListArray,
+    OffsetSizeTrait, UInt64Array,
 };
+use arrow::compute::{is_null, nullif};
 use datafusion_common::cast::{
     as_generic_list_array, as_int64_array, as_large_list_array, as_list_array,
 };
@@ -95,11 +96,7 @@ impl ArrayPosition {
             signature: Signature::array_and_element_and_optional_index(
                 Volatility::Immutable,
             ),
-            aliases: vec![
-                String::from("list_position"),
-                String::from("array_indexof"),
-                String::from("list_indexof"),
-            ],
+            aliases: vec![String::from("array_indexof"), String::from("list_indexof")],
         }
     }
 }
@@ -213,6 +210,102 @@ fn generic_position<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(UInt64Array::from(data)))
 }
 
+make_udf_expr_and_func!(
+    ListPosition,
+    list_position,
+    array element,
+    "searches for an element in the array, returns first occurrence, or NULL if the element is NULL.",
+    list_position_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns the 1-based position of the first occurrence of the specified element in the array, or NULL if the element is not found. Unlike `array_position`, a NULL element is never found, so the result is NULL, and there is no start index argument. Before DataFusion 48.0.0 `list_position` was an alias of `array_position`.",
+    syntax_example = "list_position(array, element)",
+    sql_example = r#"```sql
+> select list_position(['one', 'three'], 'three');
++--------------------------------------------------------------------+
+| list_position(make_array(Utf8("one"),Utf8("three")),Utf8("three")) |
++--------------------------------------------------------------------+
+| 2                                                                  |
++--------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "element",
+        description = "Element to search for position in the array."
+    )
+)]
+#[derive(Debug)]
+pub(super) struct ListPosition {
+    signature: Signature,
+}
+
+impl ListPosition {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::array_and_element(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ListPosition {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "list_position"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(UInt64)
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(list_position_inner)(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// List_position SQL function
+pub fn list_position_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array, element] = take_function_args("list_position", args)?;
+
+    let positions = match &array.data_type() {
+        List(_) => {
+            let arr = as_list_array(&array)?;
+            crate::utils::check_datatypes("list_position", &[arr.values(), element])?;
+            generic_position::<i32>(arr, element, vec![0; arr.len()])?
+        }
+        LargeList(_) => {
+            let arr = as_large_list_array(&array)?;
+            crate::utils::check_datatypes("list_position", &[arr.values(), element])?;
+            generic_position::<i64>(arr, element, vec![0; arr.len()])?
+        }
+        DataType::Null => return Ok(new_null_array(&UInt64, array.len())),
+        array_type => {
+            return exec_err!("list_position does not support type '{array_type:?}'.")
+        }
+    };
+
+    // `generic_position` matches a NULL element against NULL entries in the
+    // list, but searching for NULL should not find anything
+    Ok(nullif(&positions, &is_null(element)?)?)
+}
+
 make_udf_expr_and_func!(
     ArrayPositions,
     array_positions,
@@ -0,0 +1,329 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for list_reduce function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{Array, ArrayRef, RecordBatch, UInt64Array};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{is_not_null, is_null, nullif, take};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use datafusion_common::cast::as_list_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, DFSchema, Result};
+use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_expr::{
+    ColumnarValue, Expr, ExprSchemable, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_physical_expr::create_physical_expr;
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use std::any::Any;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+/// Name of the column holding the accumulated value in a `list_reduce` lambda
+pub const LIST_REDUCE_ACC: &str = "__acc";
+
+/// Name of the column holding the current element in a `list_reduce` lambda
+pub const LIST_REDUCE_ELEM: &str = "__elem";
+
+/// Returns the result of folding the elements of `list` into `init` with
+/// `lambda`.
+///
+/// `lambda` computes the next accumulated value from the columns
+/// [`LIST_REDUCE_ACC`] and [`LIST_REDUCE_ELEM`], which hold the accumulated
+/// value so far and the current element of the list. The accumulated value
+/// starts as `init`, and `lambda` must return the same type as `init`.
+///
+/// * An empty list returns `init`
+/// * NULL elements are skipped, so a list of only NULL elements returns `init`
+/// * A NULL list returns NULL
+///
+/// `lambda` is planned as is, without type coercion, so the types of its
+/// operands must match. For example, use `lit(0_i64)` rather than `lit(0)` to
+/// sum a list of `Int64` values.
+///
+/// As SQL has no lambdas, `list_reduce` is only available through the
+/// expression API.
+///
+/// ```
+/// # use datafusion_expr::{binary_expr, col, lit, Operator};
+/// # use datafusion_functions_nested::expr_fn::list_reduce;
+/// # use datafusion_functions_nested::reduce::{LIST_REDUCE_ACC, LIST_REDUCE_ELEM};
+/// // concatenates the strings in column "list", for example
+/// // ['two', 'three', 'four'] returns 'twothreefour'
+/// let lambda = binary_expr(
+///     col(LIST_REDUCE_ACC),
+///     Operator::StringConcat,
+///     col(LIST_REDUCE_ELEM),
+/// );
+/// let expr = list_reduce(col("list"), lit(""), lambda);
+/// ```
+pub fn list_reduce(list: Expr, init: Expr, lambda: Expr) -> Expr {
+    ScalarUDF::from(ListReduce::new(lambda)).call(vec![list, init])
+}
+
+/// Implementation of [`list_reduce`], folding lists with a lambda
+#[derive(Debug)]
+pub struct ListReduce {
+    signature: Signature,
+    lambda: Expr,
+}
+
+impl ListReduce {
+    pub fn new(lambda: Expr) -> Self {
+        let volatility = if lambda.is_volatile() {
+            Volatility::Volatile
+        } else {
+            Volatility::Immutable
+        };
+        Self {
+            signature: Signature::user_defined(volatility),
+            lambda,
+        }
+    }
+
+    /// The expression computing the next accumulated value
+    pub fn lambda(&self) -> &Expr {
+        &self.lambda
+    }
+}
+
+impl ScalarUDFImpl for ListReduce {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "list_reduce"
+    }
+
+    fn display_name(&self, args: &[Expr]) -> Result<String> {
+        let names: Vec<String> = args.iter().map(ToString::to_string).collect();
+        Ok(format!(
+            "{}({},{})",
+            self.name(),
+            names.join(","),
+            self.lambda
+        ))
+    }
+
+    fn schema_name(&self, args: &[Expr]) -> Result<String> {
+        let names: Vec<String> = args
+            .iter()
+            .map(|arg| arg.schema_name().to_string())
+            .collect();
+        Ok(format!(
+            "{}({},{})",
+            self.name(),
+            names.join(","),
+            self.lambda.schema_name()
+        ))
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [list, init] = take_function_args(self.name(), arg_types)?;
+        match list {
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _) => Ok(vec![
+                DataType::new_list(field.data_type().clone(), true),
+                init.clone(),
+            ]),
+            DataType::Null => {
+                Ok(vec![DataType::new_list(DataType::Null, true), init.clone()])
+            }
+            _ => plan_err!("list_reduce expects an array argument, got {list}"),
+        }
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [list, init] = take_function_args(self.name(), arg_types)?;
+        let DataType::List(field) = list else {
+            return plan_err!("list_reduce expects an array argument, got {list}");
+        };
+
+        let schema = DFSchema::try_from(lambda_schema(init, field.data_type()))?;
+        let lambda_type = self.lambda.get_type(&schema)?;
+        if &lambda_type != init {
+            return plan_err!(
+                "list_reduce lambda must return the type of the initial value {init}, got {lambda_type}"
+            );
+        }
+        Ok(init.clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [list, init] = take_function_args(self.name(), &args.args)?;
+        let DataType::List(field) = list.data_type() else {
+            return plan_err!(
+                "list_reduce expects an array argument, got {}",
+                list.data_type()
+            );
+        };
+
+        let schema = lambda_schema(&init.data_type(), field.data_type());
+        let lambda = create_physical_expr(
+            &self.lambda,
+            &DFSchema::try_from(Arc::clone(&schema))?,
+            &ExecutionProps::new(),
+        )?;
+        make_scalar_function(move |args| list_reduce_inner(args, &schema, &lambda))(
+            &args.args,
+        )
+    }
+
+    fn equals(&self, other: &dyn ScalarUDFImpl) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.lambda == other.lambda)
+    }
+
+    fn hash_value(&self) -> u64 {
+        let hasher = &mut DefaultHasher::new();
+        self.name().hash(hasher);
+        self.lambda.hash(hasher);
+        hasher.finish()
+    }
+}
+
+/// The schema of the batches `lambda` is evaluated on
+fn lambda_schema(acc_type: &DataType, elem_type: &DataType) -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new(LIST_REDUCE_ACC, acc_type.clone(), true),
+        Field::new(LIST_REDUCE_ELEM, elem_type.clone(), true),
+    ]))
+}
+
+/// Folds all the lists at once, evaluating `lambda` on the i-th element of
+/// every list at step i
+fn list_reduce_inner(
+    args: &[ArrayRef],
+    schema: &SchemaRef,
+    lambda: &Arc<dyn PhysicalExpr>,
+) -> Result<ArrayRef> {
+    let [list, init] = take_function_args("list_reduce", args)?;
+    let list = as_list_array(list)?;
+
+    let max_len = (0..list.len())
+        .filter(|&row| list.is_valid(row))
+        .map(|row| list.value_length(row))
+        .max()
+        .unwrap_or(0);
+
+    let mut acc = Arc::clone(init);
+    for i in 0..max_len {
+        // The i-th element of each list, or NULL if the list is NULL or shorter
+        let indices = (0..list.len())
+            .map(|row| {
+                (list.is_valid(row) && i < list.value_length(row))
+                    .then(|| (list.value_offsets()[row] + i) as u64)
+            })
+            .collect::<UInt64Array>();
+        let elem = take(list.values(), &indices, None)?;
+
+        let batch =
+            RecordBatch::try_new(Arc::clone(schema), vec![Arc::clone(&acc), elem])?;
+        let next = lambda.evaluate(&batch)?.into_array(batch.num_rows())?;
+        // Rows without an element at this step keep their accumulated value
+        acc = zip(&is_not_null(batch.column(1))?, &next, &acc)?;
+    }
+
+    Ok(nullif(&acc, &is_null(list)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Int64Array, ListArray, StringArray};
+    use arrow::datatypes::Int64Type;
+    use datafusion_common::cast::as_int64_array;
+    use datafusion_expr::{binary_expr, col, Operator};
+
+    fn plan_lambda(lambda: Expr, schema: &SchemaRef) -> Arc<dyn PhysicalExpr> {
+        let df_schema = DFSchema::try_from(Arc::clone(schema)).unwrap();
+        create_physical_expr(&lambda, &df_schema, &ExecutionProps::new()).unwrap()
+    }
+
+    #[test]
+    fn test_list_reduce() -> Result<()> {
+        let list: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(3)]),
+                Some(vec![Some(4), None, Some(5)]),
+                Some(vec![]),
+                Some(vec![None]),
+                None,
+            ]));
+        let init: ArrayRef = Arc::new(Int64Array::from(vec![10; 5]));
+
+        let schema = lambda_schema(&DataType::Int64, &DataType::Int64);
+        let lambda = plan_lambda(col(LIST_REDUCE_ACC) + col(LIST_REDUCE_ELEM), &schema);
+        let result = list_reduce_inner(&[list, init], &schema, &lambda)?;
+        assert_eq!(
+            as_int64_array(&result)?,
+            &Int64Array::from(vec![Some(16), Some(19), Some(10), Some(10), None])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_reduce_order() -> Result<()> {
+        let list: ArrayRef = Arc::new(ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, true)),
+            arrow::buffer::OffsetBuffer::from_lengths([3]),
+            Arc::new(StringArray::from(vec!["two", "three", "four"])),
+            None,
+        ));
+        let init: ArrayRef = Arc::new(StringArray::from(vec!["one"]));
+
+        let schema = lambda_schema(&DataType::Utf8, &DataType::Utf8);
+        let lambda = plan_lambda(
+            binary_expr(
+                col(LIST_REDUCE_ACC),
+                Operator::StringConcat,
+                col(LIST_REDUCE_ELEM),
+            ),
+            &schema,
+        );
+        let result = list_reduce_inner(&[list, init], &schema, &lambda)?;
+        assert_eq!(
+            result.as_ref(),
+            &StringArray::from(vec!["onetwothreefour"]) as &dyn Array
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_reduce_lambda_type() {
+        let udf = ListReduce::new(col(LIST_REDUCE_ELEM));
+        let err = udf
+            .return_type(&[DataType::new_list(DataType::Utf8, true), DataType::Int64])
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: list_reduce lambda must return the type of the initial value Int64, got Utf8"
+        );
+    }
+}
@@ -15,12 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for array_union, array_intersect and array_distinct functions.
+//! [`ScalarUDFImpl`] definitions for array_union, array_intersect, array_distinct and list_distinct functions.
 
 use crate::utils::make_scalar_function;
 use arrow::array::{
     new_null_array, Array, ArrayRef, GenericListArray, LargeListArray, ListArray,
-    OffsetSizeTrait,
+    OffsetSizeTrait, UInt64Array,
 };
 use arrow::buffer::OffsetBuffer;
 use arrow::compute;
@@ -67,9 +67,17 @@ make_udf_expr_and_func!(
     array_distinct_udf
 );
 
+make_udf_expr_and_func!(
+    ListDistinct,
+    list_distinct,
+    array,
+    "returns distinct values from the array after removing duplicates, in the order they first appear.",
+    list_distinct_udf
+);
+
 #[user_doc(
     doc_section(label = "Array Functions"),
-    description = "Returns an array of elements that are present in both arrays (all elements from both arrays) with out duplicates.",
+    description = "Returns an array of the elements that appear in either array, without duplicates. Elements are kept in the order in which they first appear, starting with `array1`. NULL elements are considered equal to each other.",
     syntax_example = "array_union(array1, array2)",
     sql_example = r#"```sql
 > select array_union([1, 2, 3, 4], [5, 6, 3, 4]);
@@ -160,7 +168,7 @@ impl ScalarUDFImpl for ArrayUnion {
 
 #[user_doc(
     doc_section(label = "Array Functions"),
-    description = "Returns an array of elements in the intersection of array1 and array2.",
+    description = "Returns an array of the elements that appear in both `array1` and `array2`, without duplicates, in the order in which they first appear in `array1`. NULL elements are considered equal to each other.",
     syntax_example = "array_intersect(array1, array2)",
     sql_example = r#"```sql
 > select array_intersect([1, 2, 3, 4], [5, 6, 3, 4]);
@@ -263,14 +271,12 @@ impl ScalarUDFImpl for ArrayIntersect {
 #[derive(Debug)]
 pub(super) struct ArrayDistinct {
     signature: Signature,
-    aliases: Vec<String>,
 }
 
 impl ArrayDistinct {
     pub fn new() -> Self {
         Self {
             signature: Signature::array(Volatility::Immutable),
-            aliases: vec!["list_distinct".to_string()],
         }
     }
 }
@@ -305,10 +311,6 @@ impl ScalarUDFImpl for ArrayDistinct {
         make_scalar_function(array_distinct_inner)(&args.args)
     }
 
-    fn aliases(&self) -> &[String] {
-        &self.aliases
-    }
-
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -332,6 +334,89 @@ fn array_distinct_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
     }
 }
 
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns distinct values from the array after removing duplicates, keeping each value at the position where it first appears. NULL elements are considered equal to each other, so at most one NULL is kept; a list of only NULLs becomes a list with a single NULL.",
+    syntax_example = "list_distinct(array)",
+    sql_example = r#"```sql
+> select list_distinct([1, 3, 2, 3, 1, 2, 4]);
++--------------------------------+
+| list_distinct(List([1,3,2,4])) |
++--------------------------------+
+| [1, 3, 2, 4]                   |
++--------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug)]
+pub(super) struct ListDistinct {
+    signature: Signature,
+}
+
+impl ListDistinct {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::array(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ListDistinct {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "list_distinct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            List(field) => Ok(DataType::new_list(field.data_type().clone(), true)),
+            LargeList(field) => {
+                Ok(DataType::new_large_list(field.data_type().clone(), true))
+            }
+            arg_type => plan_err!("{} does not support type {arg_type}", self.name()),
+        }
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(list_distinct_inner)(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// list_distinct SQL function
+/// example: from list [1, 3, 2, 3, 1, 2, 4] to [1, 3, 2, 4]
+fn list_distinct_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array] = take_function_args("list_distinct", args)?;
+    match array.data_type() {
+        Null => Ok(Arc::clone(array)),
+        List(field) => {
+            let array = as_list_array(&array)?;
+            general_list_distinct(array, field)
+        }
+        LargeList(field) => {
+            let array = as_large_list_array(&array)?;
+            general_list_distinct(array, field)
+        }
+        arg_type => exec_err!("list_distinct does not support type {arg_type}"),
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum SetOp {
     Union,
@@ -347,6 +432,9 @@ impl Display for SetOp {
     }
 }
 
+/// Applies `set_op` to each pair of lists in `l` and `r`. The result keeps
+/// the elements in the order they are first seen, starting with `l`, and
+/// contains no duplicates
 fn generic_set_lists<OffsetSize: OffsetSizeTrait>(
     l: &GenericListArray<OffsetSize>,
     r: &GenericListArray<OffsetSize>,
@@ -355,76 +443,81 @@ fn generic_set_lists<OffsetSize: OffsetSizeTrait>(
 ) -> Result<ArrayRef> {
     if l.is_empty() || l.value_type().is_null() {
         let field = Arc::new(Field::new_list_field(r.value_type(), true));
-        return general_array_distinct::<OffsetSize>(r, &field);
+        return general_list_distinct::<OffsetSize>(r, &field);
     } else if r.is_empty() || r.value_type().is_null() {
         let field = Arc::new(Field::new_list_field(l.value_type(), true));
-        return general_array_distinct::<OffsetSize>(l, &field);
+        return general_list_distinct::<OffsetSize>(l, &field);
     }
 
     if l.value_type() != r.value_type() {
         return internal_err!("{set_op:?} is not implemented for '{l:?}' and '{r:?}'");
     }
 
-    let mut offsets = vec![OffsetSize::usize_as(0)];
-    let mut new_arrays = vec![];
     let converter = RowConverter::new(vec![SortField::new(l.value_type())])?;
-    for (first_arr, second_arr) in l.iter().zip(r.iter()) {
-        let l_values = if let Some(first_arr) = first_arr {
-            converter.convert_columns(&[first_arr])?
-        } else {
-            converter.convert_columns(&[])?
-        };
+    // nulls are encoded as equal rows, so NULL elements are equal to each other
+    let l_rows = converter.convert_columns(&[Arc::clone(l.values())])?;
+    let r_rows = converter.convert_columns(&[Arc::clone(r.values())])?;
 
-        let r_values = if let Some(second_arr) = second_arr {
-            converter.convert_columns(&[second_arr])?
+    let mut offsets = Vec::with_capacity(l.len() + 1);
+    offsets.push(OffsetSize::usize_as(0));
+    // (0, index) refers to a value of `l` and (1, index) to a value of `r`
+    let mut indices = Vec::with_capacity(l.values().len());
+    let mut seen = HashSet::new();
+    let mut r_set = HashSet::new();
+    for (i, (l_w, r_w)) in l
+        .offsets()
+        .windows(2)
+        .zip(r.offsets().windows(2))
+        .enumerate()
+    {
+        // a NULL list is treated as an empty list
+        let l_slice = if l.is_valid(i) {
+            l_w[0].as_usize()..l_w[1].as_usize()
         } else {
-            converter.convert_columns(&[])?
+            0..0
         };
-
-        let l_iter = l_values.iter().sorted().dedup();
-        let values_set: HashSet<_> = l_iter.clone().collect();
-        let mut rows = if set_op == SetOp::Union {
-            l_iter.collect()
+        let r_slice = if r.is_valid(i) {
+            r_w[0].as_usize()..r_w[1].as_usize()
         } else {
-            vec![]
+            0..0
         };
 
-        for r_val in r_values.iter().sorted().dedup() {
-            match set_op {
-                SetOp::Union => {
-                    if !values_set.contains(&r_val) {
-                        rows.push(r_val);
+        seen.clear();
+        match set_op {
+            SetOp::Union => {
+                for index in l_slice {
+                    if seen.insert(l_rows.row(index)) {
+                        indices.push((0, index));
                     }
                 }
-                SetOp::Intersect => {
-                    if values_set.contains(&r_val) {
-                        rows.push(r_val);
+                for index in r_slice {
+                    if seen.insert(r_rows.row(index)) {
+                        indices.push((1, index));
                     }
                 }
             }
-        }
-
-        let last_offset = match offsets.last() {
-            Some(offset) => *offset,
-            None => return internal_err!("offsets should not be empty"),
-        };
-
-        offsets.push(last_offset + OffsetSize::usize_as(rows.len()));
-        let arrays = converter.convert_rows(rows)?;
-        let array = match arrays.first() {
-            Some(array) => Arc::clone(array),
-            None => {
-                return internal_err!("{set_op}: failed to get array from rows");
+            SetOp::Intersect => {
+                r_set.clear();
+                r_set.extend(r_slice.map(|index| r_rows.row(index)));
+                for index in l_slice {
+                    let row = l_rows.row(index);
+                    if r_set.contains(&row) && seen.insert(row) {
+                        indices.push((0, index));
+                    }
+                }
             }
-        };
-
-        new_arrays.push(array);
+        }
+        offsets.push(OffsetSize::usize_as(indices.len()));
     }
 
-    let offsets = OffsetBuffer::new(offsets.into());
-    let new_arrays_ref: Vec<_> = new_arrays.iter().map(|v| v.as_ref()).collect();
-    let values = compute::concat(&new_arrays_ref)?;
-    let arr = GenericListArray::<OffsetSize>::try_new(field, offsets, values, None)?;
+    let values =
+        compute::interleave(&[l.values().as_ref(), r.values().as_ref()], &indices)?;
+    let arr = GenericListArray::<OffsetSize>::try_new(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        values,
+        None,
+    )?;
     Ok(Arc::new(arr))
 }
 
@@ -463,28 +556,28 @@ fn general_set_op(
                 return empty_array(field.data_type(), array1.len(), false);
             }
             let array = as_list_array(&array2)?;
-            general_array_distinct::<i32>(array, field)
+            general_list_distinct::<i32>(array, field)
         }
         (List(field), Null) => {
             if set_op == SetOp::Intersect {
                 return empty_array(field.data_type(), array1.len(), false);
             }
             let array = as_list_array(&array1)?;
-            general_array_distinct::<i32>(array, field)
+            general_list_distinct::<i32>(array, field)
         }
         (Null, LargeList(field)) => {
             if set_op == SetOp::Intersect {
                 return empty_array(field.data_type(), array1.len(), true);
             }
             let array = as_large_list_array(&array2)?;
-            general_array_distinct::<i64>(array, field)
+            general_list_distinct::<i64>(array, field)
         }
         (LargeList(field), Null) => {
             if set_op == SetOp::Intersect {
                 return empty_array(field.data_type(), array1.len(), true);
             }
             let array = as_large_list_array(&array1)?;
-            general_array_distinct::<i64>(array, field)
+            general_list_distinct::<i64>(array, field)
         }
         (List(field), List(_)) => {
             let array1 = as_list_array(&array1)?;
@@ -563,3 +656,39 @@ fn general_array_distinct<OffsetSize: OffsetSizeTrait>(
         array.nulls().cloned(),
     )?))
 }
+
+/// Removes duplicates from each list in `array`, keeping the first occurrence
+/// of each value in its original position
+fn general_list_distinct<OffsetSize: OffsetSizeTrait>(
+    array: &GenericListArray<OffsetSize>,
+    field: &FieldRef,
+) -> Result<ArrayRef> {
+    let converter = RowConverter::new(vec![SortField::new(array.value_type())])?;
+    // nulls are encoded as equal rows, so they are deduplicated as well
+    let rows = converter.convert_columns(&[Arc::clone(array.values())])?;
+
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    offsets.push(OffsetSize::usize_as(0));
+    let mut indices = Vec::with_capacity(array.values().len());
+    let mut seen = HashSet::new();
+    for (i, window) in array.offsets().windows(2).enumerate() {
+        if array.is_valid(i) {
+            seen.clear();
+            for index in window[0].as_usize()..window[1].as_usize() {
+                if seen.insert(rows.row(index)) {
+                    indices.push(index as u64);
+                }
+            }
+        }
+        offsets.push(OffsetSize::usize_as(indices.len()));
+    }
+
+    let values = compute::take(array.values(), &UInt64Array::from(indices), None)?;
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        Arc::clone(field),
+        OffsetBuffer::new(offsets.into()),
+        values,
+        // Keep the list nulls
+        array.nulls().cloned(),
+    )?))
+}
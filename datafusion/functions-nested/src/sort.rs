@@ -15,16 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for array_sort function.
+//! [`ScalarUDFImpl`] definitions for array_sort and array_sort_by functions.
 
 use crate::utils::make_scalar_function;
-use arrow::array::{new_null_array, Array, ArrayRef, ListArray, NullBufferBuilder};
+use arrow::array::{
+    make_comparator, new_null_array, Array, ArrayRef, ListArray, NullBufferBuilder,
+    UInt32Array,
+};
 use arrow::buffer::OffsetBuffer;
 use arrow::compute::SortColumn;
 use arrow::datatypes::{DataType, Field};
 use arrow::{compute, compute::SortOptions};
 use datafusion_common::cast::{as_list_array, as_string_array};
-use datafusion_common::utils::ListCoercion;
+use datafusion_common::utils::{take_function_args, ListCoercion};
 use datafusion_common::{exec_err, plan_err, Result};
 use datafusion_expr::{
     ArrayFunctionArgument, ArrayFunctionSignature, ColumnarValue, Documentation,
@@ -42,6 +45,14 @@ make_udf_expr_and_func!(
     array_sort_udf
 );
 
+make_udf_expr_and_func!(
+    ArraySortBy,
+    array_sort_by,
+    array keys desc null_first,
+    "returns the array sorted by the corresponding elements of keys.",
+    array_sort_by_udf
+);
+
 /// Implementation of `array_sort` function
 ///
 /// `array_sort` sorts the elements of an array
@@ -184,7 +195,7 @@ pub fn array_sort_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
         2 => {
             let sort = as_string_array(&args[1])?.value(0);
             Some(SortOptions {
-                descending: order_desc(sort)?,
+                descending: order_desc("array_sort", "second", sort)?,
                 nulls_first: true,
             })
         }
@@ -192,8 +203,8 @@ pub fn array_sort_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
             let sort = as_string_array(&args[1])?.value(0);
             let nulls_first = as_string_array(&args[2])?.value(0);
             Some(SortOptions {
-                descending: order_desc(sort)?,
-                nulls_first: order_nulls_first(nulls_first)?,
+                descending: order_desc("array_sort", "second", sort)?,
+                nulls_first: order_nulls_first("array_sort", "third", nulls_first)?,
             })
         }
         _ => return exec_err!("array_sort expects 1 to 3 arguments"),
@@ -254,20 +265,231 @@ pub fn array_sort_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
     Ok(Arc::new(list_arr))
 }
 
-fn order_desc(modifier: &str) -> Result<bool> {
+/// Implementation of `array_sort_by` function
+///
+/// `array_sort_by` sorts the elements of an array by the elements at the
+/// same positions of a second array
+///
+/// # Example
+///
+/// `array_sort_by(['a', 'b', 'c'], [3, 1, 2])` returns `['b', 'c', 'a']`
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Sort array by the elements at the same positions of a second array of keys. Elements with equal keys keep their relative order. \
+Returns an error if the array and keys have different lengths.",
+    syntax_example = "array_sort_by(array, keys, desc, nulls_first)",
+    sql_example = r#"```sql
+> select array_sort_by(['a', 'b', 'c'], [3, 1, 2]);
++-------------------------------------------------------------------------------------------------+
+| array_sort_by(make_array(Utf8("a"),Utf8("b"),Utf8("c")),make_array(Int64(3),Int64(1),Int64(2))) |
++-------------------------------------------------------------------------------------------------+
+| [b, c, a]                                                                                       |
++-------------------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "keys",
+        description = "Array expression of the keys to sort by, with the same length as `array`."
+    ),
+    argument(
+        name = "desc",
+        description = "Whether to sort in descending order(`ASC` or `DESC`). Defaults to `ASC`."
+    ),
+    argument(
+        name = "nulls_first",
+        description = "Whether to sort null keys first(`NULLS FIRST` or `NULLS LAST`). Defaults to `NULLS LAST`."
+    ),
+    related_udf(name = "array_sort")
+)]
+#[derive(Debug)]
+pub struct ArraySortBy {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArraySortBy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArraySortBy {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec!["list_sort_by".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArraySortBy {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_sort_by"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=4).contains(&arg_types.len()) {
+            return plan_err!(
+                "{} expects two to four arguments, got {}",
+                self.name(),
+                arg_types.len()
+            );
+        }
+
+        let (arrays, modifiers) = arg_types.split_at(2);
+        let arrays = arrays.iter().map(|arg_type| match arg_type {
+            DataType::Null => Ok(DataType::Null),
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _) => {
+                Ok(DataType::new_list(field.data_type().clone(), true))
+            }
+            arg_type => plan_err!("{} does not support type {arg_type}", self.name()),
+        });
+        let modifiers = modifiers.iter().map(|arg_type| match arg_type {
+            DataType::Null
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View => Ok(DataType::Utf8),
+            arg_type => plan_err!("{} does not support type {arg_type}", self.name()),
+        });
+        arrays.chain(modifiers).collect()
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            DataType::Null => Ok(DataType::Null),
+            DataType::List(field) => {
+                Ok(DataType::new_list(field.data_type().clone(), true))
+            }
+            arg_type => {
+                plan_err!("{} does not support type {arg_type}", self.name())
+            }
+        }
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(array_sort_by_inner)(&args.args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Array_sort_by SQL function
+pub fn array_sort_by_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() < 2 || args.len() > 4 {
+        return exec_err!("array_sort_by expects two to four arguments");
+    }
+
+    if args[0].data_type().is_null() {
+        return Ok(Arc::clone(&args[0]));
+    }
+    if args[1].data_type().is_null() || args[2..].iter().any(|array| array.is_null(0)) {
+        return Ok(new_null_array(args[0].data_type(), args[0].len()));
+    }
+
+    let [array, keys] = take_function_args("array_sort_by", &args[..2])?;
+    let array = as_list_array(array)?;
+    let keys = as_list_array(keys)?;
+
+    let sort_options = SortOptions {
+        descending: match args.get(2) {
+            Some(desc) => {
+                order_desc("array_sort_by", "third", as_string_array(desc)?.value(0))?
+            }
+            None => false,
+        },
+        nulls_first: match args.get(3) {
+            Some(nulls_first) => order_nulls_first(
+                "array_sort_by",
+                "fourth",
+                as_string_array(nulls_first)?.value(0),
+            )?,
+            None => false,
+        },
+    };
+    let compare = make_comparator(keys.values(), keys.values(), sort_options)?;
+
+    // The indices of the sorted elements in the values of `array`
+    let mut indices = vec![];
+    let mut array_lengths = vec![];
+    let mut valid = NullBufferBuilder::new(array.len());
+    for row in 0..array.len() {
+        if array.is_null(row) || keys.is_null(row) {
+            array_lengths.push(0);
+            valid.append_null();
+            continue;
+        }
+
+        let len = array.value_length(row) as usize;
+        let keys_len = keys.value_length(row) as usize;
+        if len != keys_len {
+            return exec_err!(
+                "array_sort_by expects the array and keys to have the same length, got {len} and {keys_len}"
+            );
+        }
+
+        let array_start = array.value_offsets()[row] as usize;
+        let keys_start = keys.value_offsets()[row] as usize;
+        let mut positions = (0..len).collect::<Vec<_>>();
+        // sort_by is stable, so elements with equal keys keep their order
+        positions.sort_by(|&a, &b| compare(keys_start + a, keys_start + b));
+        indices.extend(
+            positions
+                .into_iter()
+                .map(|position| (array_start + position) as u32),
+        );
+        array_lengths.push(len);
+        valid.append_non_null();
+    }
+
+    let values = compute::take(array.values(), &UInt32Array::from(indices), None)?;
+    Ok(Arc::new(ListArray::try_new(
+        Arc::new(Field::new_list_field(array.value_type(), true)),
+        OffsetBuffer::from_lengths(array_lengths),
+        values,
+        valid.finish(),
+    )?))
+}
+
+fn order_desc(function_name: &str, position: &str, modifier: &str) -> Result<bool> {
     match modifier.to_uppercase().as_str() {
         "DESC" => Ok(true),
         "ASC" => Ok(false),
-        _ => exec_err!("the second parameter of array_sort expects DESC or ASC"),
+        _ => exec_err!("the {position} parameter of {function_name} expects DESC or ASC"),
     }
 }
 
-fn order_nulls_first(modifier: &str) -> Result<bool> {
+fn order_nulls_first(
+    function_name: &str,
+    position: &str,
+    modifier: &str,
+) -> Result<bool> {
     match modifier.to_uppercase().as_str() {
         "NULLS FIRST" => Ok(true),
         "NULLS LAST" => Ok(false),
         _ => exec_err!(
-            "the third parameter of array_sort expects NULLS FIRST or NULLS LAST"
+            "the {position} parameter of {function_name} expects NULLS FIRST or NULLS LAST"
         ),
     }
 }
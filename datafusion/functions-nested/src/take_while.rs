@@ -0,0 +1,331 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for list_take_while function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{Array, ArrayRef, BooleanBufferBuilder, ListArray, RecordBatch};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::filter;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use datafusion_common::cast::{as_boolean_array, as_list_array};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, DFSchema, Result};
+use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_expr::{
+    ColumnarValue, Expr, ExprSchemable, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_physical_expr::create_physical_expr;
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use std::any::Any;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+/// Name of the column holding the current element in a `list_take_while`
+/// lambda
+pub const LIST_TAKE_WHILE_ELEM: &str = "__elem";
+
+/// Returns the longest prefix of each list in `list` whose elements all
+/// satisfy `lambda`.
+///
+/// `lambda` is a boolean expression over the column [`LIST_TAKE_WHILE_ELEM`],
+/// which holds the current element of the list. The prefix ends before the
+/// first element for which `lambda` is false or NULL.
+///
+/// * An empty list returns an empty list
+/// * A list whose elements all satisfy `lambda` is returned as is
+/// * A NULL list returns NULL
+///
+/// `lambda` is planned as is, without type coercion, so the types of its
+/// operands must match. For example, use `lit(3_i32)` rather than `lit(3_i64)`
+/// to compare with the `Int32` result of `length`.
+///
+/// As SQL has no lambdas, `list_take_while` is only available through the
+/// expression API.
+///
+/// ```
+/// # use datafusion_expr::{col, lit};
+/// # use datafusion_functions_nested::expr_fn::list_take_while;
+/// # use datafusion_functions_nested::take_while::LIST_TAKE_WHILE_ELEM;
+/// // the strings in column "list" before the first 'four', for example
+/// // ['two', 'three', 'four'] returns ['two', 'three']
+/// let lambda = col(LIST_TAKE_WHILE_ELEM).not_eq(lit("four"));
+/// let expr = list_take_while(col("list"), lambda);
+/// ```
+pub fn list_take_while(list: Expr, lambda: Expr) -> Expr {
+    ScalarUDF::from(ListTakeWhile::new(lambda)).call(vec![list])
+}
+
+/// Implementation of [`list_take_while`], taking the elements of lists while
+/// a lambda is true
+#[derive(Debug)]
+pub struct ListTakeWhile {
+    signature: Signature,
+    lambda: Expr,
+}
+
+impl ListTakeWhile {
+    pub fn new(lambda: Expr) -> Self {
+        let volatility = if lambda.is_volatile() {
+            Volatility::Volatile
+        } else {
+            Volatility::Immutable
+        };
+        Self {
+            signature: Signature::user_defined(volatility),
+            lambda,
+        }
+    }
+
+    /// The expression deciding whether to take the current element
+    pub fn lambda(&self) -> &Expr {
+        &self.lambda
+    }
+}
+
+impl ScalarUDFImpl for ListTakeWhile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "list_take_while"
+    }
+
+    fn display_name(&self, args: &[Expr]) -> Result<String> {
+        let names: Vec<String> = args.iter().map(ToString::to_string).collect();
+        Ok(format!(
+            "{}({},{})",
+            self.name(),
+            names.join(","),
+            self.lambda
+        ))
+    }
+
+    fn schema_name(&self, args: &[Expr]) -> Result<String> {
+        let names: Vec<String> = args
+            .iter()
+            .map(|arg| arg.schema_name().to_string())
+            .collect();
+        Ok(format!(
+            "{}({},{})",
+            self.name(),
+            names.join(","),
+            self.lambda.schema_name()
+        ))
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [list] = take_function_args(self.name(), arg_types)?;
+        match list {
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _) => {
+                Ok(vec![DataType::new_list(field.data_type().clone(), true)])
+            }
+            DataType::Null => Ok(vec![DataType::new_list(DataType::Null, true)]),
+            _ => plan_err!("list_take_while expects an array argument, got {list}"),
+        }
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [list] = take_function_args(self.name(), arg_types)?;
+        let DataType::List(field) = list else {
+            return plan_err!("list_take_while expects an array argument, got {list}");
+        };
+
+        let schema = DFSchema::try_from(lambda_schema(field.data_type()))?;
+        let lambda_type = self.lambda.get_type(&schema)?;
+        if lambda_type != DataType::Boolean {
+            return plan_err!(
+                "list_take_while lambda must return Boolean, got {lambda_type}"
+            );
+        }
+        Ok(list.clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [list] = take_function_args(self.name(), &args.args)?;
+        let DataType::List(field) = list.data_type() else {
+            return plan_err!(
+                "list_take_while expects an array argument, got {}",
+                list.data_type()
+            );
+        };
+
+        let schema = lambda_schema(field.data_type());
+        let lambda = create_physical_expr(
+            &self.lambda,
+            &DFSchema::try_from(Arc::clone(&schema))?,
+            &ExecutionProps::new(),
+        )?;
+        make_scalar_function(move |args| list_take_while_inner(args, &schema, &lambda))(
+            &args.args,
+        )
+    }
+
+    fn equals(&self, other: &dyn ScalarUDFImpl) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.lambda == other.lambda)
+    }
+
+    fn hash_value(&self) -> u64 {
+        let hasher = &mut DefaultHasher::new();
+        self.name().hash(hasher);
+        self.lambda.hash(hasher);
+        hasher.finish()
+    }
+}
+
+/// The schema of the batches `lambda` is evaluated on
+fn lambda_schema(elem_type: &DataType) -> SchemaRef {
+    Arc::new(Schema::new(vec![Field::new(
+        LIST_TAKE_WHILE_ELEM,
+        elem_type.clone(),
+        true,
+    )]))
+}
+
+/// Evaluates `lambda` on the elements of all the lists at once, and then
+/// keeps the elements of each list before the first one it is not true for
+fn list_take_while_inner(
+    args: &[ArrayRef],
+    schema: &SchemaRef,
+    lambda: &Arc<dyn PhysicalExpr>,
+) -> Result<ArrayRef> {
+    let [list] = take_function_args("list_take_while", args)?;
+    let list = as_list_array(list)?;
+    let values = list.values();
+
+    let batch = RecordBatch::try_new(Arc::clone(schema), vec![Arc::clone(values)])?;
+    let taken = lambda.evaluate(&batch)?.into_array(batch.num_rows())?;
+    let taken = as_boolean_array(&taken)?;
+
+    let mut mask = BooleanBufferBuilder::new(values.len());
+    mask.append_n(values.len(), false);
+    let mut lengths = Vec::with_capacity(list.len());
+    for (row, offsets) in list.value_offsets().windows(2).enumerate() {
+        let (start, end) = (offsets[0] as usize, offsets[1] as usize);
+        // NULL lists are empty in the result, and keep their NULL
+        let length = if list.is_valid(row) {
+            (start..end)
+                .take_while(|&idx| taken.is_valid(idx) && taken.value(idx))
+                .count()
+        } else {
+            0
+        };
+        (start..start + length).for_each(|idx| mask.set_bit(idx, true));
+        lengths.push(length);
+    }
+
+    let DataType::List(field) = list.data_type() else {
+        return plan_err!("list_take_while expects a List, got {}", list.data_type());
+    };
+    Ok(Arc::new(ListArray::try_new(
+        Arc::clone(field),
+        OffsetBuffer::from_lengths(lengths),
+        filter(values, &mask.finish().into())?,
+        list.nulls().cloned(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::StringArray;
+    use arrow::datatypes::Int64Type;
+    use datafusion_expr::{col, lit};
+
+    fn plan_lambda(lambda: Expr, schema: &SchemaRef) -> Arc<dyn PhysicalExpr> {
+        let df_schema = DFSchema::try_from(Arc::clone(schema)).unwrap();
+        create_physical_expr(&lambda, &df_schema, &ExecutionProps::new()).unwrap()
+    }
+
+    #[test]
+    fn test_list_take_while() -> Result<()> {
+        let list: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(5), Some(1)]),
+                Some(vec![Some(1), None, Some(2)]),
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(7)]),
+                Some(vec![]),
+                None,
+            ]));
+
+        let schema = lambda_schema(&DataType::Int64);
+        let lambda = plan_lambda(col(LIST_TAKE_WHILE_ELEM).lt(lit(3i64)), &schema);
+        let result = list_take_while_inner(&[list], &schema, &lambda)?;
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            Some(vec![]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_take_while_sliced() -> Result<()> {
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, true)),
+            OffsetBuffer::from_lengths([1, 3, 1]),
+            Arc::new(StringArray::from(vec![
+                "one", "two", "three", "four", "five",
+            ])),
+            None,
+        );
+        let list: ArrayRef = Arc::new(list.slice(1, 2));
+
+        let schema = lambda_schema(&DataType::Utf8);
+        let lambda = plan_lambda(col(LIST_TAKE_WHILE_ELEM).not_eq(lit("four")), &schema);
+        let result = list_take_while_inner(&[list], &schema, &lambda)?;
+        let result = as_list_array(&result)?;
+        assert_eq!(
+            result.value(0).as_ref(),
+            &StringArray::from(vec!["two", "three"]) as &dyn Array
+        );
+        assert_eq!(
+            result.value(1).as_ref(),
+            &StringArray::from(vec!["five"]) as &dyn Array
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_take_while_lambda_type() {
+        let udf = ListTakeWhile::new(col(LIST_TAKE_WHILE_ELEM));
+        let err = udf
+            .return_type(&[DataType::new_list(DataType::Int64, true)])
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: list_take_while lambda must return Boolean, got Int64"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for trim_array function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{
+    Array, ArrayRef, BooleanBufferBuilder, GenericListArray, Int64Array, OffsetSizeTrait,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::filter;
+use arrow::datatypes::{
+    DataType,
+    DataType::{LargeList, List, Null},
+    Field,
+};
+use datafusion_common::cast::{as_int64_array, as_large_list_array, as_list_array};
+use datafusion_common::utils::{take_function_args, ListCoercion};
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ArrayFunctionArgument, ArrayFunctionSignature, ColumnarValue, Documentation,
+    ReturnFieldArgs, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    TrimArray,
+    trim_array,
+    array n,
+    "removes the last n elements of each array.",
+    trim_array_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns the array without its last `n` elements, or an empty array if it has at most `n` elements. A negative `n` is an error.",
+    syntax_example = "trim_array(array, n)",
+    sql_example = r#"```sql
+> select trim_array([1, 2, 3, 4], 2);
++--------------------------------------+
+| trim_array(List([1,2,3,4]),Int64(2)) |
++--------------------------------------+
+| [1, 2]                               |
++--------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "n",
+        description = "Number of elements to remove from the end of the array."
+    )
+)]
+#[derive(Debug)]
+pub struct TrimArray {
+    signature: Signature,
+}
+
+impl Default for TrimArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrimArray {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature {
+                type_signature: TypeSignature::ArraySignature(
+                    ArrayFunctionSignature::Array {
+                        arguments: vec![
+                            ArrayFunctionArgument::Array,
+                            ArrayFunctionArgument::Index,
+                        ],
+                        array_coercion: Some(ListCoercion::FixedSizedListToList),
+                    },
+                ),
+                volatility: Volatility::Immutable,
+            },
+        }
+    }
+}
+
+impl ScalarUDFImpl for TrimArray {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "trim_array"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let [array, n] = take_function_args(self.name(), args.arg_fields)?;
+        // a constant count is checked when planning rather than for each row
+        if let Some(ScalarValue::Int64(Some(n))) = args.scalar_arguments[1] {
+            if *n < 0 {
+                return plan_err!(
+                    "{} expects a non-negative number of elements, got {n}",
+                    self.name()
+                );
+            }
+        }
+        Ok(Field::new(
+            self.name(),
+            array.data_type().clone(),
+            array.is_nullable() || n.is_nullable(),
+        ))
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(trim_array_inner)(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// trim_array SQL function
+pub fn trim_array_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array, n] = take_function_args("trim_array", args)?;
+    let n = as_int64_array(n)?;
+    match array.data_type() {
+        List(_) => general_trim_array(as_list_array(array)?, n),
+        LargeList(_) => general_trim_array(as_large_list_array(array)?, n),
+        Null => Ok(Arc::clone(array)),
+        array_type => exec_err!("trim_array does not support type '{array_type}'"),
+    }
+}
+
+fn general_trim_array<O: OffsetSizeTrait>(
+    list: &GenericListArray<O>,
+    n: &Int64Array,
+) -> Result<ArrayRef> {
+    let values = list.values();
+    let mut mask = BooleanBufferBuilder::new(values.len());
+    mask.append_n(values.len(), false);
+    let mut lengths = Vec::with_capacity(list.len());
+    for (row, offsets) in list.value_offsets().windows(2).enumerate() {
+        let (start, end) = (offsets[0].as_usize(), offsets[1].as_usize());
+        // NULL lists and counts are empty in the result, and NULL
+        let length = if list.is_valid(row) && n.is_valid(row) {
+            let n = n.value(row);
+            if n < 0 {
+                return exec_err!(
+                    "trim_array expects a non-negative number of elements, got {n}"
+                );
+            }
+            (end - start).saturating_sub(n.try_into().unwrap_or(usize::MAX))
+        } else {
+            0
+        };
+        (start..start + length).for_each(|idx| mask.set_bit(idx, true));
+        lengths.push(length);
+    }
+
+    let (List(field) | LargeList(field)) = list.data_type() else {
+        return exec_err!("trim_array does not support type '{}'", list.data_type());
+    };
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::clone(field),
+        OffsetBuffer::from_lengths(lengths),
+        filter(values, &mask.finish().into())?,
+        NullBuffer::union(list.nulls(), n.nulls()),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::ListArray;
+    use arrow::datatypes::Int64Type;
+
+    #[test]
+    fn test_trim_array() -> Result<()> {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![Some(1), None]),
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(1)]),
+        ]);
+        let n = Int64Array::from(vec![Some(1), Some(0), Some(5), Some(1), Some(1), None]);
+        let result = trim_array_inner(&[Arc::new(list), Arc::new(n)])?;
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), None]),
+            Some(vec![]),
+            Some(vec![]),
+            None,
+            None,
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_array_sliced() -> Result<()> {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3), Some(4), Some(5)]),
+            Some(vec![Some(6), Some(7)]),
+        ]);
+        let list: ArrayRef = Arc::new(list.slice(1, 2));
+        let n = Int64Array::from(vec![1, 1]);
+        let result = trim_array_inner(&[list, Arc::new(n)])?;
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3), Some(4)]),
+            Some(vec![Some(6)]),
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_array_negative() {
+        let list =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![Some(1)])]);
+        let n = Int64Array::from(vec![-1]);
+        let err = trim_array_inner(&[Arc::new(list), Arc::new(n)]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: trim_array expects a non-negative number of elements, got -1"
+        );
+    }
+}
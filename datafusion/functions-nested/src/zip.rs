@@ -0,0 +1,261 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for array_zip function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{
+    Array, ArrayBuilder, ArrayRef, ListArray, NullBufferBuilder, StructArray,
+    UInt64Builder,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::take;
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::cast::as_list_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    ArrayZip,
+    array_zip,
+    array1 array2,
+    "returns a list of structs pairing the elements of two arrays at the same position.",
+    array_zip_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = r#"Returns an array of structs, where the struct at position i contains the elements at position i of both arrays, in fields named `0` and `1`.
+    If the arrays have different lengths, the shorter array is padded with NULLs. The result is NULL if either array is NULL."#,
+    syntax_example = "array_zip(array1, array2)",
+    sql_example = r#"```sql
+> select array_zip([1, 2, 3], ['a', 'b']);
++-----------------------------------------------------------------------------------+
+| array_zip(make_array(Int64(1),Int64(2),Int64(3)),make_array(Utf8("a"),Utf8("b"))) |
++-----------------------------------------------------------------------------------+
+| [{0: 1, 1: a}, {0: 2, 1: b}, {0: 3, 1: NULL}]                                     |
++-----------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "array1",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "array2",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug)]
+pub struct ArrayZip {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayZip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayZip {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec![String::from("arrays_zip"), String::from("list_zip")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayZip {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_zip"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [array1, array2] = take_function_args(self.name(), arg_types)?;
+        [array1, array2]
+            .into_iter()
+            .map(|arg_type| match arg_type {
+                DataType::List(field)
+                | DataType::LargeList(field)
+                | DataType::FixedSizeList(field, _) => {
+                    Ok(DataType::new_list(field.data_type().clone(), true))
+                }
+                DataType::Null => Ok(DataType::new_list(DataType::Null, true)),
+                _ => plan_err!(
+                    "array_zip expects two array arguments, got {array1} and {array2}"
+                ),
+            })
+            .collect()
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [array1, array2] = take_function_args(self.name(), arg_types)?;
+        let (DataType::List(field1), DataType::List(field2)) = (array1, array2) else {
+            return plan_err!(
+                "array_zip expects two array arguments, got {array1} and {array2}"
+            );
+        };
+        Ok(DataType::new_list(
+            DataType::Struct(zip_fields(field1.data_type(), field2.data_type())),
+            true,
+        ))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(array_zip_inner)(&args.args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// The fields of the structs returned by `array_zip`, which are named after
+/// the position of the argument they come from
+fn zip_fields(data_type1: &DataType, data_type2: &DataType) -> Fields {
+    Fields::from(vec![
+        Field::new("0", data_type1.clone(), true),
+        Field::new("1", data_type2.clone(), true),
+    ])
+}
+
+/// array_zip SQL function
+fn array_zip_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array1, array2] = take_function_args("array_zip", args)?;
+    let array1 = as_list_array(array1)?;
+    let array2 = as_list_array(array2)?;
+
+    // The elements of both arrays at each position of the result, with a null
+    // index where the shorter array is padded
+    let capacity = array1.values().len().max(array2.values().len());
+    let mut indices1 = UInt64Builder::with_capacity(capacity);
+    let mut indices2 = UInt64Builder::with_capacity(capacity);
+    let mut offsets = Vec::with_capacity(array1.len() + 1);
+    offsets.push(0_i32);
+    let mut nulls = NullBufferBuilder::new(array1.len());
+
+    for row in 0..array1.len() {
+        if array1.is_null(row) || array2.is_null(row) {
+            nulls.append_null();
+            offsets.push(indices1.len() as i32);
+            continue;
+        }
+        nulls.append_non_null();
+
+        let range1 = array1.value_offsets()[row]..array1.value_offsets()[row + 1];
+        let range2 = array2.value_offsets()[row]..array2.value_offsets()[row + 1];
+        for i in 0..range1.len().max(range2.len()) as i32 {
+            let index = |range: &std::ops::Range<i32>| {
+                range
+                    .contains(&(range.start + i))
+                    .then(|| (range.start + i) as u64)
+            };
+            indices1.append_option(index(&range1));
+            indices2.append_option(index(&range2));
+        }
+        offsets.push(indices1.len() as i32);
+    }
+
+    let fields = zip_fields(array1.values().data_type(), array2.values().data_type());
+    let values = StructArray::try_new(
+        fields.clone(),
+        vec![
+            take(array1.values(), &indices1.finish(), None)?,
+            take(array2.values(), &indices2.finish(), None)?,
+        ],
+        None,
+    )?;
+
+    Ok(Arc::new(ListArray::try_new(
+        Arc::new(Field::new_list_field(DataType::Struct(fields), true)),
+        OffsetBuffer::new(offsets.into()),
+        Arc::new(values),
+        nulls.finish(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::Int32Array;
+    use arrow::datatypes::Int32Type;
+    use datafusion_common::cast::{as_int32_array, as_struct_array};
+
+    fn int_lists(rows: Vec<Option<Vec<Option<i32>>>>) -> ArrayRef {
+        Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(rows))
+    }
+
+    #[test]
+    fn test_array_zip() -> Result<()> {
+        let array1 = int_lists(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![Some(4)]),
+            None,
+            Some(vec![]),
+        ]);
+        let array2 = int_lists(vec![
+            Some(vec![Some(10), None]),
+            Some(vec![Some(40), Some(50)]),
+            Some(vec![Some(60)]),
+            Some(vec![]),
+        ]);
+
+        let result = array_zip_inner(&[array1, array2])?;
+        let result = as_list_array(&result)?;
+        assert_eq!(result.value_offsets(), &[0, 3, 5, 5, 5]);
+        assert!(result.is_valid(0));
+        assert!(result.is_valid(1));
+        assert!(result.is_null(2));
+        assert!(result.is_valid(3));
+
+        let values = as_struct_array(result.values())?;
+        assert_eq!(
+            values.fields(),
+            &zip_fields(&DataType::Int32, &DataType::Int32)
+        );
+        assert_eq!(
+            as_int32_array(values.column(0))?,
+            &Int32Array::from(vec![Some(1), Some(2), Some(3), Some(4), None])
+        );
+        assert_eq!(
+            as_int32_array(values.column(1))?,
+            &Int32Array::from(vec![Some(10), None, None, Some(40), Some(50)])
+        );
+        Ok(())
+    }
+}
@@ -65,9 +65,7 @@ pub fn lag(
     shift_offset: Option<i64>,
     default_value: Option<ScalarValue>,
 ) -> datafusion_expr::Expr {
-    let shift_offset_lit = shift_offset
-        .map(|v| v.lit())
-        .unwrap_or(ScalarValue::Null.lit());
+    let shift_offset_lit = shift_offset.unwrap_or(1).lit();
     let default_lit = default_value.unwrap_or(ScalarValue::Null).lit();
 
     lag_udwf().call(vec![arg, shift_offset_lit, default_lit])
@@ -84,9 +82,7 @@ pub fn lead(
     shift_offset: Option<i64>,
     default_value: Option<ScalarValue>,
 ) -> datafusion_expr::Expr {
-    let shift_offset_lit = shift_offset
-        .map(|v| v.lit())
-        .unwrap_or(ScalarValue::Null.lit());
+    let shift_offset_lit = shift_offset.unwrap_or(1).lit();
     let default_lit = default_value.unwrap_or(ScalarValue::Null).lit();
 
     lead_udwf().call(vec![arg, shift_offset_lit, default_lit])
@@ -787,4 +783,35 @@ mod tests {
             .collect::<Int32Array>(),
         )
     }
+
+    #[test]
+    fn test_lag_with_null_offset() -> Result<()> {
+        // like `lag(c3, NULL)` in SQL, a NULL offset is an offset of 0
+        let expr = Arc::new(Column::new("c3", 0)) as Arc<dyn PhysicalExpr>;
+        let shift_offset =
+            Arc::new(Literal::new(ScalarValue::Null)) as Arc<dyn PhysicalExpr>;
+
+        let input_exprs = &[expr, shift_offset];
+        let input_fields = [DataType::Int32, DataType::Null]
+            .into_iter()
+            .map(|d| Field::new("f", d, true))
+            .collect::<Vec<_>>();
+
+        test_i32_result(
+            WindowShift::lag(),
+            PartitionEvaluatorArgs::new(input_exprs, &input_fields, false, false),
+            [
+                Some(1),
+                Some(-2),
+                Some(3),
+                Some(-4),
+                Some(5),
+                Some(-6),
+                Some(7),
+                Some(8),
+            ]
+            .iter()
+            .collect::<Int32Array>(),
+        )
+    }
 }
@@ -40,6 +40,7 @@ impl ExprPlanner for WindowFunctionPlanner {
             partition_by,
             order_by,
             window_frame,
+            filter,
             null_treatment,
         } = raw_expr;
 
@@ -50,6 +51,7 @@ impl ExprPlanner for WindowFunctionPlanner {
                 partition_by,
                 order_by,
                 window_frame,
+                filter,
                 null_treatment,
             },
         });
@@ -64,6 +66,7 @@ impl ExprPlanner for WindowFunctionPlanner {
                     partition_by,
                     order_by,
                     window_frame,
+                    filter,
                     null_treatment,
                 },
         }) = origin_expr
@@ -76,6 +79,7 @@ impl ExprPlanner for WindowFunctionPlanner {
             partition_by,
             order_by,
             window_frame,
+            filter,
             null_treatment,
         };
 
@@ -92,18 +96,22 @@ impl ExprPlanner for WindowFunctionPlanner {
                 partition_by,
                 order_by,
                 window_frame,
+                filter,
                 null_treatment,
             } = raw_expr;
 
-            let new_expr = Expr::WindowFunction(WindowFunction::new(
+            let mut builder = Expr::WindowFunction(WindowFunction::new(
                 func_def,
                 vec![Expr::Literal(COUNT_STAR_EXPANSION)],
             ))
             .partition_by(partition_by)
             .order_by(order_by)
             .window_frame(window_frame)
-            .null_treatment(null_treatment)
-            .build()?;
+            .null_treatment(null_treatment);
+            if let Some(filter) = filter {
+                builder = builder.filter(*filter);
+            }
+            let new_expr = builder.build()?;
 
             let new_expr = saved_name.restore(new_expr);
 
@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::array::Array;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, internal_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Returns its argument, which is marked as not nullable in the schema. \
+Returns an error, including the index of the row within its batch, if the argument is NULL.",
+    syntax_example = "assert_not_null(expression)",
+    sql_example = r#"```sql
+> select assert_not_null(column1) from (values (1), (2));
++--------------------------+
+| assert_not_null(column1) |
++--------------------------+
+| 1                        |
+| 2                        |
++--------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression that must not be NULL. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug)]
+pub struct AssertNotNullFunc {
+    signature: Signature,
+}
+
+impl Default for AssertNotNullFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssertNotNullFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for AssertNotNullFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "assert_not_null"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let [arg] = take_function_args(self.name(), args.arg_fields)?;
+        Ok(Field::new(self.name(), arg.data_type().clone(), false))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+        match &arg {
+            ColumnarValue::Array(array) => {
+                // only look for the offending row if there is one
+                if array.logical_null_count() > 0 {
+                    let nulls = array.logical_nulls().unwrap();
+                    let row = nulls.iter().position(|valid| !valid).unwrap();
+                    return exec_err!("{} found a NULL value at row {row}", self.name());
+                }
+            }
+            ColumnarValue::Scalar(scalar) => {
+                if scalar.is_null() && args.number_rows > 0 {
+                    return exec_err!("{} found a NULL value at row 0", self.name());
+                }
+            }
+        }
+        Ok(arg)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use arrow::array::Int32Array;
+    use datafusion_common::ScalarValue;
+
+    fn invoke(arg: ColumnarValue, number_rows: usize) -> Result<ColumnarValue> {
+        let arg_field = Field::new("a", DataType::Int32, true);
+        AssertNotNullFunc::new().invoke_with_args(ScalarFunctionArgs {
+            args: vec![arg],
+            arg_fields: vec![&arg_field],
+            number_rows,
+            return_field: &Field::new("f", DataType::Int32, false),
+        })
+    }
+
+    #[test]
+    fn test_assert_not_null() -> Result<()> {
+        let array = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let ColumnarValue::Array(result) = invoke(ColumnarValue::Array(array), 3)? else {
+            panic!("expected an array");
+        };
+        assert_eq!(result.len(), 3);
+
+        let array = Arc::new(Int32Array::from(vec![Some(1), Some(2), None, None]));
+        let err = invoke(ColumnarValue::Array(array), 4).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: assert_not_null found a NULL value at row 2"
+        );
+
+        // a NULL scalar is the value of every row, so is an error unless
+        // there are no rows
+        let null = ColumnarValue::Scalar(ScalarValue::Int32(None));
+        assert!(invoke(null.clone(), 1).is_err());
+        assert!(invoke(null, 0).is_ok());
+        Ok(())
+    }
+}
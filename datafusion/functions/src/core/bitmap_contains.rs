@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, BooleanArray};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::{DataType, Int64Type};
+
+use crate::utils::make_scalar_function;
+use datafusion_common::types::{logical_binary, logical_int64, NativeType};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignatureClass, Volatility,
+};
+use datafusion_functions_aggregate_common::bitmap::Bitmap;
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Returns true if a bitmap created by `bitmap_agg` contains an integer value. Returns NULL if either argument is NULL.",
+    syntax_example = "bitmap_contains(bitmap, value)",
+    sql_example = r#"```sql
+> SELECT bitmap_contains(bitmap_agg(column1), 7) FROM (VALUES (5), (NULL), (10));
++-----------------------------------------------+
+| bitmap_contains(bitmap_agg(column1),Int64(7)) |
++-----------------------------------------------+
+| false                                         |
++-----------------------------------------------+
+```"#,
+    argument(name = "bitmap", description = "Bitmap created by `bitmap_agg`."),
+    argument(
+        name = "value",
+        description = "Integer expression to check for. Can be a constant, column, or function, and any combination of operators."
+    ),
+    related_udf(name = "bitmap_agg")
+)]
+#[derive(Debug)]
+pub struct BitmapContainsFunc {
+    signature: Signature,
+}
+
+impl Default for BitmapContainsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitmapContainsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::coercible(
+                vec![
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_binary())),
+                    // Accept all integer types but cast them to i64
+                    Coercion::new_implicit(
+                        TypeSignatureClass::Native(logical_int64()),
+                        vec![TypeSignatureClass::Integer],
+                        NativeType::Int64,
+                    ),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for BitmapContainsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "bitmap_contains"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(bitmap_contains, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns whether each bitmap contains its value, looking the value up in
+/// the serialized bitmap without deserializing it
+fn bitmap_contains(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [bitmap, value] = take_function_args("bitmap_contains", args)?;
+    let bitmap = cast(bitmap, &DataType::Binary)?;
+
+    let result = bitmap
+        .as_binary::<i32>()
+        .iter()
+        .zip(value.as_primitive::<Int64Type>().iter())
+        .map(|(bitmap, value)| match (bitmap, value) {
+            (Some(bitmap), Some(value)) => {
+                Bitmap::serialized_contains(bitmap, value as u64).map(Some)
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<BooleanArray>>()?;
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{BinaryArray, Int64Array};
+
+    #[test]
+    fn test_bitmap_contains() -> Result<()> {
+        let mut bitmap = Bitmap::new();
+        bitmap.insert(5);
+        bitmap.insert(10);
+        let bytes = bitmap.serialize();
+
+        let bitmaps: ArrayRef = Arc::new(BinaryArray::from(vec![
+            Some(bytes.as_slice()),
+            Some(bytes.as_slice()),
+            None,
+            Some(bytes.as_slice()),
+        ]));
+        let values: ArrayRef =
+            Arc::new(Int64Array::from(vec![Some(5), Some(7), Some(5), None]));
+
+        let result = bitmap_contains(&[bitmaps, values])?;
+        assert_eq!(
+            result.as_boolean(),
+            &BooleanArray::from(vec![Some(true), Some(false), None, None])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitmap_contains_invalid() {
+        let bitmaps: ArrayRef = Arc::new(BinaryArray::from(vec![Some(&[1u8][..])]));
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![Some(5)]));
+        let err = bitmap_contains(&[bitmaps, values]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: Invalid bitmap: missing number of containers"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{new_null_array, Array, ArrayRef, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{is_not_null, is_null, nullif, or};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::{
+    internal_err, plan_err, utils::take_function_args, Result, ScalarValue,
+};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = r#"Merges two structs field by field. The result has the fields of the first struct followed by the fields that only exist in the second struct.
+    Fields that exist in both structs take the value from the first struct unless it is NULL, in which case the value from the second struct is used. A NULL struct is treated as if all of its fields were NULL, and the result is NULL only if both structs are NULL.
+    Fields with the same name must have the same type, unless one of them is always NULL."#,
+    syntax_example = "coalesce_struct(expression1, expression2)",
+    sql_example = r#"```sql
+> select coalesce_struct(named_struct('a', NULL, 'b', 2), named_struct('a', 1, 'c', 3));
++----------------------------------------------------------------------------------------------------------------------+
+| coalesce_struct(named_struct(Utf8("a"),NULL,Utf8("b"),Int64(2)),named_struct(Utf8("a"),Int64(1),Utf8("c"),Int64(3))) |
++----------------------------------------------------------------------------------------------------------------------+
+| {a: 1, b: 2, c: 3}                                                                                                   |
++----------------------------------------------------------------------------------------------------------------------+
+```
+"#,
+    argument(
+        name = "expression1",
+        description = "The struct whose non-NULL field values take precedence."
+    ),
+    argument(
+        name = "expression2",
+        description = "The struct to take field values from when they are missing or NULL in `expression1`."
+    )
+)]
+#[derive(Debug)]
+pub struct CoalesceStructFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceStructFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoalesceStructFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for CoalesceStructFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_struct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let [left, right] = take_function_args(self.name(), args.arg_fields)?;
+        let (DataType::Struct(left_fields), DataType::Struct(right_fields)) =
+            (left.data_type(), right.data_type())
+        else {
+            return plan_err!(
+                "coalesce_struct expects two struct arguments, got {} and {}",
+                left.data_type(),
+                right.data_type()
+            );
+        };
+
+        // A field of a NULL struct is NULL, so the nullability of each struct
+        // carries over to its fields
+        let mut fields = Vec::with_capacity(left_fields.len() + right_fields.len());
+        for left_field in left_fields {
+            let left_nullable = left_field.is_nullable() || left.is_nullable();
+            let field = match right_fields.find(left_field.name()) {
+                // A field that is always NULL takes the type of the other field
+                Some((_, right_field)) if left_field.data_type().is_null() => right_field
+                    .as_ref()
+                    .clone()
+                    .with_nullable(right_field.is_nullable() || right.is_nullable()),
+                Some((_, right_field)) if right_field.data_type().is_null() => {
+                    left_field.as_ref().clone().with_nullable(left_nullable)
+                }
+                Some((_, right_field)) => {
+                    if left_field.data_type() != right_field.data_type() {
+                        return plan_err!(
+                            "coalesce_struct field {} has type {} in the first struct and {} in the second struct",
+                            left_field.name(),
+                            left_field.data_type(),
+                            right_field.data_type()
+                        );
+                    }
+                    let right_nullable = right_field.is_nullable() || right.is_nullable();
+                    left_field
+                        .as_ref()
+                        .clone()
+                        .with_nullable(left_nullable && right_nullable)
+                }
+                None => left_field.as_ref().clone().with_nullable(left_nullable),
+            };
+            fields.push(field);
+        }
+        for right_field in right_fields {
+            if left_fields.find(right_field.name()).is_none() {
+                let nullable = right_field.is_nullable() || right.is_nullable();
+                fields.push(right_field.as_ref().clone().with_nullable(nullable));
+            }
+        }
+
+        Ok(Field::new(
+            self.name(),
+            DataType::Struct(Fields::from(fields)),
+            left.is_nullable() && right.is_nullable(),
+        ))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("coalesce_struct should return a struct");
+        };
+        let all_scalars = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let [left, right] = take_function_args(self.name(), &arrays)?;
+
+        let result = coalesce_struct_inner(fields, left, right)?;
+        if all_scalars {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Merges `left` and `right` into a struct with the given `fields`, taking
+/// each field from `left` unless it is NULL
+fn coalesce_struct_inner(
+    fields: &Fields,
+    left: &ArrayRef,
+    right: &ArrayRef,
+) -> Result<ArrayRef> {
+    let left = as_struct_array(left)?;
+    let right = as_struct_array(right)?;
+
+    let columns = fields
+        .iter()
+        .map(|field| {
+            let left_column = column_or_null(left, field)?;
+            let right_column = column_or_null(right, field)?;
+            match (left_column, right_column) {
+                (Some(l), Some(r)) => Ok(zip(&is_not_null(&l)?, &l, &r)?),
+                (Some(column), None) | (None, Some(column)) => Ok(column),
+                (None, None) => {
+                    internal_err!("coalesce_struct field {} not found", field.name())
+                }
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let nulls = match (left.nulls(), right.nulls()) {
+        (Some(_), Some(_)) => Some(NullBuffer::from(
+            or(&is_not_null(left)?, &is_not_null(right)?)?
+                .values()
+                .clone(),
+        )),
+        _ => None,
+    };
+
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        columns,
+        nulls,
+    )?))
+}
+
+/// Returns the column for `field`, with the rows where the struct is NULL set
+/// to NULL
+fn column_or_null(array: &StructArray, field: &Field) -> Result<Option<ArrayRef>> {
+    let Some(column) = array.column_by_name(field.name()) else {
+        return Ok(None);
+    };
+    if column.data_type().is_null() {
+        return Ok(Some(new_null_array(field.data_type(), array.len())));
+    }
+    if array.null_count() == 0 {
+        return Ok(Some(Arc::clone(column)));
+    }
+    Ok(Some(nullif(column, &is_null(array)?)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::Int32Array;
+    use datafusion_common::cast::as_int32_array;
+
+    fn struct_array(
+        names: &[&str],
+        values: Vec<Vec<Option<i32>>>,
+        nulls: Option<Vec<bool>>,
+    ) -> ArrayRef {
+        let fields = names
+            .iter()
+            .map(|name| Field::new(*name, DataType::Int32, true))
+            .collect::<Fields>();
+        let columns = values
+            .into_iter()
+            .map(|v| Arc::new(Int32Array::from(v)) as ArrayRef)
+            .collect();
+        Arc::new(StructArray::new(
+            fields,
+            columns,
+            nulls.map(NullBuffer::from),
+        ))
+    }
+
+    fn return_type(left: &ArrayRef, right: &ArrayRef) -> Result<Field> {
+        CoalesceStructFunc::new().return_field_from_args(ReturnFieldArgs {
+            arg_fields: &[
+                Field::new("left", left.data_type().clone(), true),
+                Field::new("right", right.data_type().clone(), true),
+            ],
+            scalar_arguments: &[None, None],
+        })
+    }
+
+    #[test]
+    fn test_coalesce_struct() -> Result<()> {
+        let left = struct_array(
+            &["a", "b"],
+            vec![vec![None, Some(2), Some(3)], vec![Some(4), None, Some(6)]],
+            Some(vec![true, true, false]),
+        );
+        let right = struct_array(
+            &["c", "a"],
+            vec![
+                vec![Some(7), Some(8), Some(9)],
+                vec![Some(10), Some(11), None],
+            ],
+            None,
+        );
+        let field = return_type(&left, &right)?;
+        let DataType::Struct(fields) = field.data_type() else {
+            return internal_err!("expected struct");
+        };
+        assert_eq!(
+            fields.iter().map(|f| f.name().as_str()).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        let result = coalesce_struct_inner(fields, &left, &right)?;
+        let result = as_struct_array(&result)?;
+        assert_eq!(result.null_count(), 0);
+        let column = |name| as_int32_array(result.column_by_name(name).unwrap()).cloned();
+        assert_eq!(
+            column("a")?,
+            Int32Array::from(vec![Some(10), Some(2), None])
+        );
+        assert_eq!(column("b")?, Int32Array::from(vec![Some(4), None, None]));
+        assert_eq!(
+            column("c")?,
+            Int32Array::from(vec![Some(7), Some(8), Some(9)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_struct_both_null() -> Result<()> {
+        let left = struct_array(
+            &["a"],
+            vec![vec![Some(1), Some(2)]],
+            Some(vec![false, true]),
+        );
+        let right = struct_array(
+            &["a"],
+            vec![vec![Some(3), Some(4)]],
+            Some(vec![false, false]),
+        );
+        let field = return_type(&left, &right)?;
+        let DataType::Struct(fields) = field.data_type() else {
+            return internal_err!("expected struct");
+        };
+
+        let result = coalesce_struct_inner(fields, &left, &right)?;
+        assert!(result.is_null(0));
+        assert!(result.is_valid(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_struct_type_mismatch() {
+        let left = struct_array(&["a"], vec![vec![Some(1)]], None);
+        let right: ArrayRef = Arc::new(StructArray::new(
+            Fields::from(vec![Field::new("a", DataType::Int64, true)]),
+            vec![Arc::new(arrow::array::Int64Array::from(vec![1]))],
+            None,
+        ));
+        let err = return_type(&left, &right).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("field a has type Int32 in the first struct and Int64"));
+    }
+}
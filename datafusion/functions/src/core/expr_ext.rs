@@ -19,7 +19,7 @@
 
 use datafusion_expr::{Expr, Literal};
 
-use super::expr_fn::get_field;
+use super::expr_fn::{assert_not_null, get_field, try_get_field};
 
 /// Return access to the named field. Example `expr["name"]`
 ///
@@ -43,14 +43,76 @@ use super::expr_fn::get_field;
 ///    .field("my_field");
 /// assert_eq!(expr.schema_name().to_string(), "c1[my_field]");
 /// ```
+///
+/// ## Access a field by position
+///
+/// [`FieldAccessor::field_at`] accesses a field by its zero-based position in
+/// the struct, which is useful when field names are unreliable. The position
+/// is resolved to the field name during planning, and it is an error if the
+/// struct has no field at that position
+///
+/// ```
+/// # use datafusion_expr::{col};
+/// # use datafusion_functions::core::expr_ext::FieldAccessor;
+/// let expr = col("c1")
+///    .field_at(0);
+/// assert_eq!(expr.schema_name().to_string(), "c1[0]");
+/// ```
+///
+/// ## Access a field that may not exist
+///
+/// [`FieldAccessor::try_field`] returns `NULL` rather than an error when the
+/// struct does not have a field with the given name
+///
+/// ```
+/// # use datafusion_expr::{col};
+/// # use datafusion_functions::core::expr_ext::FieldAccessor;
+/// let expr = col("c1")
+///    .try_field("maybe_missing");
+/// ```
 pub trait FieldAccessor {
     fn field(self, name: impl Literal) -> Expr;
+
+    fn try_field(self, name: impl Literal) -> Expr;
+
+    fn field_at(self, index: usize) -> Expr;
 }
 
 impl FieldAccessor for Expr {
     fn field(self, name: impl Literal) -> Expr {
         get_field(self, name)
     }
+
+    fn try_field(self, name: impl Literal) -> Expr {
+        try_get_field(self, name)
+    }
+
+    fn field_at(self, index: usize) -> Expr {
+        get_field(self, index as i64)
+    }
+}
+
+/// Assert that an expression is not NULL, see [`assert_not_null`]
+///
+/// The resulting expression is not nullable in the schema, which is useful
+/// when the input is known to never be NULL but its nullability can not be
+/// inferred, for example to write to a `NOT NULL` column. Evaluating it
+/// returns an error if the input is NULL.
+///
+/// ```
+/// # use datafusion_expr::col;
+/// # use datafusion_functions::core::expr_ext::NotNullAssertion;
+/// let expr = (col("a") + col("b")).assert_not_null();
+/// assert_eq!(expr.schema_name().to_string(), "assert_not_null(a + b)");
+/// ```
+pub trait NotNullAssertion {
+    fn assert_not_null(self) -> Expr;
+}
+
+impl NotNullAssertion for Expr {
+    fn assert_not_null(self) -> Expr {
+        assert_not_null(self)
+    }
 }
 
 #[cfg(test)]
@@ -65,4 +127,18 @@ mod tests {
         let expr2 = get_field(col("a"), "b");
         assert_eq!(expr1, expr2);
     }
+
+    #[test]
+    fn test_try_field() {
+        let expr1 = col("a").try_field("b");
+        let expr2 = try_get_field(col("a"), "b");
+        assert_eq!(expr1, expr2);
+    }
+
+    #[test]
+    fn test_field_at() {
+        let expr1 = col("a").field_at(1);
+        let expr2 = get_field(col("a"), 1i64);
+        assert_eq!(expr1, expr2);
+    }
 }
@@ -20,15 +20,17 @@ use arrow::array::{
     Scalar,
 };
 use arrow::compute::SortOptions;
-use arrow::datatypes::{DataType, Field};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
 use arrow_buffer::NullBuffer;
 use datafusion_common::cast::{as_map_array, as_struct_array};
 use datafusion_common::{
     exec_err, internal_err, plan_datafusion_err, utils::take_function_args, Result,
     ScalarValue,
 };
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion_expr::{
-    ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
+    lit, ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
 };
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
@@ -72,7 +74,7 @@ use std::sync::Arc;
     ),
     argument(
         name = "expression2",
-        description = "The field name in the map or struct to retrieve data for. Must evaluate to a string."
+        description = "The field name in the map or struct to retrieve data for. Must evaluate to a string, or for a struct, may also be the zero-based position of the field."
     )
 )]
 #[derive(Debug)]
@@ -94,6 +96,30 @@ impl GetFieldFunc {
     }
 }
 
+/// Returns the zero-based field position if `field_name` is a non-null integer
+fn field_index(field_name: &ScalarValue) -> Option<i64> {
+    if !field_name.data_type().is_integer() {
+        return None;
+    }
+    match field_name.cast_to(&DataType::Int64) {
+        Ok(ScalarValue::Int64(Some(index))) => Some(index),
+        _ => None,
+    }
+}
+
+/// Returns the field of `fields` at position `index`
+fn field_at(fields: &Fields, index: i64) -> Result<&FieldRef> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| fields.get(i))
+        .ok_or_else(|| {
+            plan_datafusion_err!(
+                "Field index {index} out of range for struct with {} fields",
+                fields.len()
+            )
+        })
+}
+
 // get_field(struct_array, field_name)
 impl ScalarUDFImpl for GetFieldFunc {
     fn as_any(&self) -> &dyn Any {
@@ -152,6 +178,14 @@ impl ScalarUDFImpl for GetFieldFunc {
                     _ => exec_err!("Map fields must contain a Struct with exactly 2 fields"),
                 }
             }
+            (DataType::Struct(fields), Some(sv)) if field_index(sv).is_some() => {
+                let index = field_index(sv).unwrap();
+                let mut child_field = field_at(fields, index)?.as_ref().clone();
+                if args.arg_fields[0].is_nullable() {
+                    child_field = child_field.with_nullable(true);
+                }
+                Ok(child_field)
+            }
             (DataType::Struct(fields),sv) => {
                 sv.and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()))
                 .map_or_else(
@@ -268,6 +302,14 @@ impl ScalarUDFImpl for GetFieldFunc {
                     Some(col) => Ok(ColumnarValue::Array(Arc::clone(col))),
                 }
             }
+            (DataType::Struct(fields), name) if field_index(&name).is_some() => {
+                let index = field_index(&name).unwrap();
+                field_at(fields, index)?;
+                let as_struct_array = as_struct_array(&array)?;
+                Ok(ColumnarValue::Array(Arc::clone(
+                    as_struct_array.column(index as usize),
+                )))
+            }
             (DataType::Struct(_), name) => exec_err!(
                 "get_field is only possible on struct with utf8 indexes. \
                              Received with {name:?} index"
@@ -280,6 +322,48 @@ impl ScalarUDFImpl for GetFieldFunc {
         }
     }
 
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        // A field of a NULL literal is always NULL, so replace the call with a
+        // NULL literal of the type `get_field` would have returned
+        if let (Expr::Literal(base), Expr::Literal(name)) = (&args[0], &args[1]) {
+            if base.is_null() {
+                let arg_fields = [
+                    Field::new("base", base.data_type(), true),
+                    Field::new("name", name.data_type(), true),
+                ];
+                let return_field = self.return_field_from_args(ReturnFieldArgs {
+                    arg_fields: &arg_fields,
+                    scalar_arguments: &[Some(base), Some(name)],
+                })?;
+                let null = ScalarValue::try_from(return_field.data_type())?;
+                return Ok(ExprSimplifyResult::Simplified(lit(null)));
+            }
+        }
+
+        // Rewrite access by position to access by name, so both forms are
+        // planned identically
+        if let (base, Expr::Literal(name)) = (&args[0], &args[1]) {
+            if let (Some(index), Ok(DataType::Struct(fields))) =
+                (field_index(name), info.get_data_type(base))
+            {
+                if let Ok(field) = field_at(&fields, index) {
+                    let name = lit(field.name().as_str());
+                    return Ok(ExprSimplifyResult::Simplified(Expr::ScalarFunction(
+                        ScalarFunction::new_udf(
+                            super::get_field(),
+                            vec![base.clone(), name],
+                        ),
+                    )));
+                }
+            }
+        }
+        Ok(ExprSimplifyResult::Original(args))
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -16,11 +16,15 @@
 // under the License.
 
 use arrow::array::{Array, ArrayRef, BooleanArray};
+use arrow::compute::kernels::boolean::{is_null, or};
+use arrow::compute::kernels::nullif::nullif;
 use arrow::compute::kernels::zip::zip;
 use arrow::datatypes::DataType;
 use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
 use datafusion_expr_common::columnar_value::ColumnarValue;
-use datafusion_expr_common::type_coercion::binary::type_union_resolution;
+use datafusion_expr_common::type_coercion::binary::{
+    comparison_coercion, type_union_resolution,
+};
 use std::sync::Arc;
 
 pub(super) trait GreatestLeastOperator {
@@ -47,8 +51,54 @@ fn keep_array<Op: GreatestLeastOperator>(
     Ok(result)
 }
 
+/// Returns the value `Op` keeps out of `args` for every row.
+///
+/// NULL arguments are ignored unless `propagate_nulls` is true, in which case
+/// the result is NULL in the rows in which any argument is NULL.
 pub(super) fn execute_conditional<Op: GreatestLeastOperator>(
     args: &[ColumnarValue],
+    propagate_nulls: bool,
+) -> Result<ColumnarValue> {
+    let result = execute_ignoring_nulls::<Op>(args)?;
+    if propagate_nulls {
+        propagate_nulls_of(args, result)
+    } else {
+        Ok(result)
+    }
+}
+
+/// Makes `result` NULL in the rows in which any of `args` is NULL
+fn propagate_nulls_of(
+    args: &[ColumnarValue],
+    result: ColumnarValue,
+) -> Result<ColumnarValue> {
+    let mut any_null: Option<BooleanArray> = None;
+    for arg in args {
+        match arg {
+            ColumnarValue::Scalar(scalar) if scalar.is_null() => {
+                return Ok(ColumnarValue::Scalar(scalar.clone()));
+            }
+            ColumnarValue::Scalar(_) => {}
+            ColumnarValue::Array(array) => {
+                let is_null = is_null(array)?;
+                any_null = Some(match any_null {
+                    Some(any_null) => or(&any_null, &is_null)?,
+                    None => is_null,
+                });
+            }
+        }
+    }
+
+    match (result, any_null) {
+        (ColumnarValue::Array(result), Some(any_null)) => {
+            Ok(ColumnarValue::Array(nullif(&result, &any_null)?))
+        }
+        (result, _) => Ok(result),
+    }
+}
+
+fn execute_ignoring_nulls<Op: GreatestLeastOperator>(
+    args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
     if args.is_empty() {
         return internal_err!(
@@ -117,6 +167,12 @@ pub(super) fn execute_conditional<Op: GreatestLeastOperator>(
     Ok(ColumnarValue::Array(result))
 }
 
+/// Returns the type all the arguments are coerced to.
+///
+/// The arguments are coerced pairwise the same way as the operands of a
+/// binary comparison such as `a = b`, so for example a mix of numbers and
+/// strings is compared as strings. Types that comparisons can not coerce are
+/// coerced like the branches of a `UNION`.
 pub(super) fn find_coerced_type<Op: GreatestLeastOperator>(
     data_types: &[DataType],
 ) -> Result<DataType> {
@@ -125,9 +181,65 @@ pub(super) fn find_coerced_type<Op: GreatestLeastOperator>(
             "{} was called without any arguments. It requires at least 1.",
             Op::NAME
         )
-    } else if let Some(coerced_type) = type_union_resolution(data_types) {
+    } else if let Some(coerced_type) = comparison_coercion_of_all(data_types)
+        .or_else(|| type_union_resolution(data_types))
+    {
         Ok(coerced_type)
     } else {
         plan_err!("Cannot find a common type for arguments")
     }
 }
+
+fn comparison_coercion_of_all(data_types: &[DataType]) -> Option<DataType> {
+    data_types[1..]
+        .iter()
+        .try_fold(data_types[0].clone(), |coerced_type, data_type| {
+            comparison_coercion(&coerced_type, data_type)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::greatest::GreatestFunc;
+
+    fn coerced_type(data_types: &[DataType]) -> Result<DataType> {
+        find_coerced_type::<GreatestFunc>(data_types)
+    }
+
+    #[test]
+    fn test_find_coerced_type() -> Result<()> {
+        // numbers are widened
+        assert_eq!(
+            coerced_type(&[DataType::Int32, DataType::Int64, DataType::Int8])?,
+            DataType::Int64
+        );
+        assert_eq!(
+            coerced_type(&[DataType::Int64, DataType::Float64])?,
+            DataType::Float64
+        );
+
+        // numbers and strings are compared as strings, like in `a = b`
+        assert_eq!(
+            coerced_type(&[DataType::Int32, DataType::Utf8, DataType::Int64])?,
+            DataType::Utf8
+        );
+        assert_eq!(
+            coerced_type(&[
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                DataType::Int64
+            ])?,
+            DataType::Utf8
+        );
+
+        // strings are cast to dates, like in `a = b`
+        assert_eq!(
+            coerced_type(&[DataType::Date32, DataType::Utf8])?,
+            DataType::Date32
+        );
+
+        assert!(coerced_type(&[DataType::Boolean, DataType::Int32]).is_err());
+        assert!(coerced_type(&[]).is_err());
+        Ok(())
+    }
+}
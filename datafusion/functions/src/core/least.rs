@@ -27,6 +27,7 @@ use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
 use std::any::Any;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 const SORT_OPTIONS: SortOptions = SortOptions {
     // Having the smallest result first
@@ -56,6 +57,7 @@ const SORT_OPTIONS: SortOptions = SortOptions {
 #[derive(Debug)]
 pub struct LeastFunc {
     signature: Signature,
+    propagate_nulls: bool,
 }
 
 impl Default for LeastFunc {
@@ -68,8 +70,21 @@ impl LeastFunc {
     pub fn new() -> Self {
         Self {
             signature: Signature::user_defined(Volatility::Immutable),
+            propagate_nulls: false,
         }
     }
+
+    /// Whether to return NULL if any argument is NULL, instead of the
+    /// smallest of the non-NULL arguments. Defaults to `false`.
+    pub fn with_propagate_nulls(mut self, propagate_nulls: bool) -> Self {
+        self.propagate_nulls = propagate_nulls;
+        self
+    }
+
+    /// Whether NULL is returned if any argument is NULL
+    pub fn propagate_nulls(&self) -> bool {
+        self.propagate_nulls
+    }
 }
 
 impl GreatestLeastOperator for LeastFunc {
@@ -157,7 +172,10 @@ impl ScalarUDFImpl for LeastFunc {
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        super::greatest_least_utils::execute_conditional::<Self>(&args.args)
+        super::greatest_least_utils::execute_conditional::<Self>(
+            &args.args,
+            self.propagate_nulls,
+        )
     }
 
     fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
@@ -167,6 +185,20 @@ impl ScalarUDFImpl for LeastFunc {
         Ok(vec![coerced_type; arg_types.len()])
     }
 
+    fn equals(&self, other: &dyn ScalarUDFImpl) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self.propagate_nulls == other.propagate_nulls)
+    }
+
+    fn hash_value(&self) -> u64 {
+        let hasher = &mut DefaultHasher::new();
+        self.name().hash(hasher);
+        self.propagate_nulls.hash(hasher);
+        hasher.finish()
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -22,7 +22,10 @@ use std::sync::Arc;
 
 pub mod arrow_cast;
 pub mod arrowtypeof;
+pub mod assert_not_null;
+pub mod bitmap_contains;
 pub mod coalesce;
+pub mod coalesce_struct;
 pub mod expr_ext;
 pub mod getfield;
 pub mod greatest;
@@ -35,6 +38,9 @@ pub mod nvl2;
 pub mod overlay;
 pub mod planner;
 pub mod r#struct;
+pub mod struct_keys;
+pub mod struct_rename_fields;
+pub mod try_getfield;
 pub mod union_extract;
 pub mod union_tag;
 pub mod version;
@@ -46,10 +52,19 @@ make_udf_function!(nvl::NVLFunc, nvl);
 make_udf_function!(nvl2::NVL2Func, nvl2);
 make_udf_function!(overlay::OverlayFunc, overlay);
 make_udf_function!(arrowtypeof::ArrowTypeOfFunc, arrow_typeof);
+make_udf_function!(assert_not_null::AssertNotNullFunc, assert_not_null);
+make_udf_function!(bitmap_contains::BitmapContainsFunc, bitmap_contains);
 make_udf_function!(r#struct::StructFunc, r#struct);
 make_udf_function!(named_struct::NamedStructFunc, named_struct);
+make_udf_function!(struct_keys::StructKeysFunc, struct_keys);
+make_udf_function!(
+    struct_rename_fields::StructRenameFieldsFunc,
+    struct_rename_fields
+);
 make_udf_function!(getfield::GetFieldFunc, get_field);
+make_udf_function!(try_getfield::TryGetFieldFunc, try_get_field);
 make_udf_function!(coalesce::CoalesceFunc, coalesce);
+make_udf_function!(coalesce_struct::CoalesceStructFunc, coalesce_struct);
 make_udf_function!(greatest::GreatestFunc, greatest);
 make_udf_function!(least::LeastFunc, least);
 make_udf_function!(union_extract::UnionExtractFun, union_extract);
@@ -57,7 +72,7 @@ make_udf_function!(union_tag::UnionTagFunc, union_tag);
 make_udf_function!(version::VersionFunc, version);
 
 pub mod expr_fn {
-    use datafusion_expr::{Expr, Literal};
+    use datafusion_expr::{Expr, Literal, ScalarUDF};
 
     export_functions!((
         nullif,
@@ -83,6 +98,14 @@ pub mod expr_fn {
         arrow_typeof,
         "Returns the Arrow type of the input expression.",
         arg1
+    ),(
+        assert_not_null,
+        "Returns the input expression as not nullable, returning an error if it is NULL",
+        arg1
+    ),(
+        bitmap_contains,
+        "Returns true if the bitmap created by bitmap_agg contains the value",
+        bitmap value
     ),(
         r#struct,
         "Returns a struct with the given arguments",
@@ -91,10 +114,18 @@ pub mod expr_fn {
         named_struct,
         "Returns a struct with the given names and arguments pairs",
         args,
+    ),(
+        struct_keys,
+        "Returns a list of the field names of a struct",
+        arg1
     ),(
         coalesce,
         "Returns `coalesce(args...)`, which evaluates to the value of the first expr which is not NULL",
         args,
+    ),(
+        coalesce_struct,
+        "Returns a struct with the fields of both structs, taking each field from the first struct unless it is NULL",
+        arg1 arg2
     ),(
         greatest,
         "Returns `greatest(args...)`, which evaluates to the greatest value in the list of expressions or NULL if all the expressions are NULL",
@@ -114,6 +145,37 @@ pub mod expr_fn {
         super::get_field().call(vec![arg1, arg2.lit()])
     }
 
+    #[doc = "Returns the value of the field with the given name from the struct, or NULL if the struct has no such field"]
+    pub fn try_get_field(arg1: Expr, arg2: impl Literal) -> Expr {
+        super::try_get_field().call(vec![arg1, arg2.lit()])
+    }
+
+    #[doc = "Returns the struct with the fields named by the first element of each pair renamed to the second element"]
+    pub fn struct_rename_fields(arg1: Expr, renames: Vec<(&str, &str)>) -> Expr {
+        let mut args = vec![arg1];
+        for (old_name, new_name) in renames {
+            args.push(old_name.lit());
+            args.push(new_name.lit());
+        }
+        super::struct_rename_fields().call(args)
+    }
+
+    #[doc = "Returns `greatest(args...)`, which evaluates to the greatest value in the list of expressions or NULL if any of the expressions is NULL"]
+    pub fn greatest_propagating_nulls(args: Vec<Expr>) -> Expr {
+        ScalarUDF::new_from_impl(
+            super::greatest::GreatestFunc::new().with_propagate_nulls(true),
+        )
+        .call(args)
+    }
+
+    #[doc = "Returns `least(args...)`, which evaluates to the smallest value in the list of expressions or NULL if any of the expressions is NULL"]
+    pub fn least_propagating_nulls(args: Vec<Expr>) -> Expr {
+        ScalarUDF::new_from_impl(
+            super::least::LeastFunc::new().with_propagate_nulls(true),
+        )
+        .call(args)
+    }
+
     #[doc = "Returns the value of the field with the given name from the union when it's selected, or NULL otherwise"]
     pub fn union_extract(arg1: Expr, arg2: impl Literal) -> Expr {
         super::union_extract().call(vec![arg1, arg2.lit()])
@@ -129,7 +191,11 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         nvl2(),
         overlay(),
         arrow_typeof(),
+        assert_not_null(),
+        bitmap_contains(),
         named_struct(),
+        struct_keys(),
+        struct_rename_fields(),
         // Note: most users invoke `get_field` indirectly via field access
         // syntax like `my_struct_col['field_name']`, which results in a call to
         // `get_field(my_struct_col, "field_name")`.
@@ -138,7 +204,9 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         // serializing / deserializing plans with the field access desugared to
         // calls to [`get_field`]
         get_field(),
+        try_get_field(),
         coalesce(),
+        coalesce_struct(),
         greatest(),
         least(),
         union_extract(),
@@ -0,0 +1,177 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{new_null_array, Array, ArrayRef, ListBuilder, StringBuilder};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{
+    internal_err, plan_err, utils::take_function_args, Result, ScalarValue,
+};
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns a list of the field names of a struct. Returns NULL if the struct is NULL.",
+    syntax_example = "struct_keys(expression)",
+    sql_example = r#"```sql
+> select struct_keys(named_struct('a', 1, 'b', 'x'));
++-------------------------------------------------------------------+
+| struct_keys(named_struct(Utf8("a"),Int64(1),Utf8("b"),Utf8("x"))) |
++-------------------------------------------------------------------+
+| [a, b]                                                            |
++-------------------------------------------------------------------+
+```
+"#,
+    argument(
+        name = "expression",
+        description = "The struct to return the field names of."
+    )
+)]
+#[derive(Debug)]
+pub struct StructKeysFunc {
+    signature: Signature,
+}
+
+impl Default for StructKeysFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructKeysFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructKeysFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_keys"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [arg_type] = take_function_args(self.name(), arg_types)?;
+        match arg_type {
+            DataType::Struct(_) | DataType::Null => Ok(DataType::List(Arc::new(
+                Field::new_list_field(DataType::Utf8, true),
+            ))),
+            other => plan_err!("struct_keys expects a struct argument, got {other}"),
+        }
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+        match arg {
+            ColumnarValue::Array(array) => {
+                Ok(ColumnarValue::Array(struct_keys_inner(&array)?))
+            }
+            ColumnarValue::Scalar(scalar) => {
+                let keys = struct_keys_inner(&scalar.to_array()?)?;
+                Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                    &keys, 0,
+                )?))
+            }
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns a list of the field names for each non-null row of `array`
+fn struct_keys_inner(array: &ArrayRef) -> Result<ArrayRef> {
+    let fields = match array.data_type() {
+        DataType::Struct(fields) => fields,
+        DataType::Null => {
+            return Ok(new_null_array(
+                &DataType::List(Arc::new(Field::new_list_field(DataType::Utf8, true))),
+                array.len(),
+            ))
+        }
+        other => {
+            return internal_err!("struct_keys expects a struct argument, got {other}")
+        }
+    };
+
+    let mut builder = ListBuilder::with_capacity(StringBuilder::new(), array.len());
+    for row in 0..array.len() {
+        if array.is_null(row) {
+            builder.append_null();
+        } else {
+            for field in fields {
+                builder.values().append_value(field.name());
+            }
+            builder.append(true);
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Int32Array, StructArray};
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::Fields;
+    use datafusion_common::cast::{as_list_array, as_string_array};
+
+    #[test]
+    fn test_struct_keys() -> Result<()> {
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+        ]);
+        let array: ArrayRef = Arc::new(StructArray::new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(Int32Array::from(vec![3, 4])),
+            ],
+            Some(NullBuffer::from(vec![true, false])),
+        ));
+
+        let result = struct_keys_inner(&array)?;
+        let result = as_list_array(&result)?;
+        assert_eq!(result.len(), 2);
+        assert!(result.is_null(1));
+        let keys = result.value(0);
+        let keys = as_string_array(&keys)?;
+        assert_eq!(keys.iter().collect::<Vec<_>>(), vec![Some("a"), Some("b")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_keys_not_struct() {
+        let result = StructKeysFunc::new().return_type(&[DataType::Int32]);
+        assert!(result.is_err());
+    }
+}
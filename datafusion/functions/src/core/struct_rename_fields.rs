@@ -0,0 +1,256 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, ArrayRef, AsArray, StructArray};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::{internal_err, plan_err, HashSet, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns a struct with the same values as the input struct, and the given fields renamed. Fields that are not renamed keep their names.",
+    syntax_example = "struct_rename_fields(expression, old_name1, new_name1[, ..., old_name_n, new_name_n])",
+    sql_example = r#"```sql
+> select struct_rename_fields(named_struct('a', 1, 'b', 'x'), 'a', 'c');
++------------------------------------------------------------------------------------------------+
+| struct_rename_fields(named_struct(Utf8("a"),Int64(1),Utf8("b"),Utf8("x")),Utf8("a"),Utf8("c")) |
++------------------------------------------------------------------------------------------------+
+| {c: 1, b: x}                                                                                   |
++------------------------------------------------------------------------------------------------+
+```
+"#,
+    argument(
+        name = "expression",
+        description = "The struct to rename the fields of."
+    ),
+    argument(
+        name = "old_name_n",
+        description = "Name of a field of the struct to rename. Must be a constant string."
+    ),
+    argument(
+        name = "new_name_n",
+        description = "New name of the field. Must be a constant string that is not the name of another field of the result."
+    )
+)]
+#[derive(Debug)]
+pub struct StructRenameFieldsFunc {
+    signature: Signature,
+}
+
+impl Default for StructRenameFieldsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructRenameFieldsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructRenameFieldsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_rename_fields"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!(
+            "struct_rename_fields: return_type called instead of return_field_from_args"
+        )
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let Some((input, names)) = args.arg_fields.split_first() else {
+            return plan_err!("struct_rename_fields requires a struct argument");
+        };
+        let DataType::Struct(fields) = input.data_type() else {
+            return plan_err!(
+                "struct_rename_fields expects a struct argument, got {}",
+                input.data_type()
+            );
+        };
+        if names.len() % 2 != 0 {
+            return plan_err!(
+                "struct_rename_fields requires pairs of old and new field names, got {} names",
+                names.len()
+            );
+        }
+
+        let names = args.scalar_arguments[1..]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                match name.and_then(|name| name.try_as_str().flatten()) {
+                    Some(name) if !name.is_empty() => Ok(name),
+                    _ => plan_err!(
+                        "struct_rename_fields requires the {}-th (0-indexed) argument to be a non-empty constant string",
+                        i + 1
+                    ),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let fields = rename_fields(fields, &names)?;
+        Ok(Field::new(
+            self.name(),
+            DataType::Struct(fields),
+            input.is_nullable(),
+        ))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_type() else {
+            return internal_err!("incorrect struct_rename_fields return type");
+        };
+        let fields = fields.clone();
+
+        match &args.args[0] {
+            ColumnarValue::Array(array) => Ok(ColumnarValue::Array(
+                struct_rename_fields_inner(array, fields)?,
+            )),
+            ColumnarValue::Scalar(scalar) => {
+                let array = struct_rename_fields_inner(&scalar.to_array()?, fields)?;
+                Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                    &array, 0,
+                )?))
+            }
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns `fields` with each field named `names[2 * i]` renamed to
+/// `names[2 * i + 1]`
+fn rename_fields(fields: &Fields, names: &[&str]) -> Result<Fields> {
+    let mut renamed = fields
+        .iter()
+        .map(|f| f.as_ref().clone())
+        .collect::<Vec<_>>();
+    let mut seen = HashSet::new();
+    for pair in names.chunks_exact(2) {
+        let (old_name, new_name) = (pair[0], pair[1]);
+        if !seen.insert(old_name) {
+            return plan_err!("struct_rename_fields field {old_name} is renamed twice");
+        }
+        let Some((idx, _)) = fields.find(old_name) else {
+            return plan_err!(
+                "struct_rename_fields struct has no field named {old_name}"
+            );
+        };
+        renamed[idx] = renamed[idx].clone().with_name(new_name);
+    }
+
+    // `find` returns the first field with a name, so a later field with the
+    // same name is a duplicate
+    let renamed = Fields::from(renamed);
+    if let Some(duplicate) = renamed.iter().enumerate().find_map(|(idx, field)| {
+        (renamed.find(field.name()).map(|(first, _)| first) != Some(idx)).then_some(field)
+    }) {
+        return plan_err!(
+            "struct_rename_fields result has more than one field named {}",
+            duplicate.name()
+        );
+    }
+    Ok(renamed)
+}
+
+/// Returns the struct `array` with its fields replaced by `fields`, which
+/// must have the same types
+fn struct_rename_fields_inner(array: &ArrayRef, fields: Fields) -> Result<ArrayRef> {
+    let array = array.as_struct();
+    Ok(Arc::new(StructArray::try_new(
+        fields,
+        array.columns().to_vec(),
+        array.nulls().cloned(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_fields() -> Fields {
+        Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ])
+    }
+
+    #[test]
+    fn test_rename_fields() -> Result<()> {
+        let fields = rename_fields(&test_fields(), &["a", "c"])?;
+        assert_eq!(
+            fields,
+            Fields::from(vec![
+                Field::new("c", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, false),
+            ])
+        );
+
+        // swapping names does not collide
+        let fields = rename_fields(&test_fields(), &["a", "b", "b", "a"])?;
+        assert_eq!(
+            fields,
+            Fields::from(vec![
+                Field::new("b", DataType::Int32, true),
+                Field::new("a", DataType::Utf8, false),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_fields_errors() {
+        let err = rename_fields(&test_fields(), &["a", "b"]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: struct_rename_fields result has more than one field named b"
+        );
+
+        let err = rename_fields(&test_fields(), &["x", "y"]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: struct_rename_fields struct has no field named x"
+        );
+
+        let err = rename_fields(&test_fields(), &["a", "x", "a", "y"]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: struct_rename_fields field a is renamed twice"
+        );
+    }
+}
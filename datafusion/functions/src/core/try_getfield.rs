@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::NullArray;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::{
+    exec_err, internal_err, plan_err, utils::take_function_args, Result, ScalarValue,
+};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{
+    lit, ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+use super::getfield::GetFieldFunc;
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = r#"Returns a field within a map or a struct with the given key, or NULL if a struct does not have a field with the given name.
+    Unlike `get_field`, a missing struct field is not an error. The result is always nullable."#,
+    syntax_example = "try_get_field(expression1, expression2)",
+    sql_example = r#"```sql
+> create table t (idx varchar, v varchar) as values ('data','fusion'), ('apache', 'arrow');
+> select try_get_field((select struct(idx, v) from t), 'c0');
++-----------------------------------------------+
+| try_get_field(struct(t.idx,t.v),Utf8("c0"))   |
++-----------------------------------------------+
+| data                                          |
+| apache                                        |
++-----------------------------------------------+
+> select try_get_field((select struct(idx, v) from t), 'missing');
++--------------------------------------------------+
+| try_get_field(struct(t.idx,t.v),Utf8("missing")) |
++--------------------------------------------------+
+| NULL                                             |
+| NULL                                             |
++--------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1",
+        description = "The map or struct to retrieve a field for."
+    ),
+    argument(
+        name = "expression2",
+        description = "The field name in the map or struct to retrieve data for. Must evaluate to a string."
+    )
+)]
+#[derive(Debug)]
+pub struct TryGetFieldFunc {
+    signature: Signature,
+    get_field: GetFieldFunc,
+}
+
+impl Default for TryGetFieldFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryGetFieldFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            get_field: GetFieldFunc::new(),
+        }
+    }
+}
+
+/// Returns the field name if `field_name` is a non-empty string literal
+fn literal_field_name(field_name: Option<&ScalarValue>) -> Option<&str> {
+    field_name.and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()))
+}
+
+// try_get_field(struct_array, field_name)
+impl ScalarUDFImpl for TryGetFieldFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "try_get_field"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        // Length check handled in the signature
+        debug_assert_eq!(args.scalar_arguments.len(), 2);
+
+        match args.arg_fields[0].data_type() {
+            DataType::Struct(fields) => {
+                let Some(field_name) = literal_field_name(args.scalar_arguments[1])
+                else {
+                    return exec_err!("Field name must be a non-empty string");
+                };
+                // A field that is missing from the struct is always NULL, while
+                // a field that exists may be NULL when it is absent from another
+                // struct that shares the same column, so both are nullable
+                match fields.iter().find(|f| f.name() == field_name) {
+                    Some(f) => Ok(f.as_ref().clone().with_nullable(true)),
+                    None => Ok(Field::new(self.name(), DataType::Null, true)),
+                }
+            }
+            DataType::Map(_, _) | DataType::Null => {
+                self.get_field.return_field_from_args(args)
+            }
+            other => plan_err!(
+                "The expression to get an indexed field is only valid for `Struct`, `Map` or `Null` types, got {other}"
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [base, field_name] = take_function_args(self.name(), &args.args)?;
+
+        if let (DataType::Struct(_), ColumnarValue::Scalar(ScalarValue::Utf8(Some(k)))) =
+            (base.data_type(), field_name)
+        {
+            match base {
+                ColumnarValue::Array(array)
+                    if as_struct_array(array)?.column_by_name(k).is_none() =>
+                {
+                    return Ok(ColumnarValue::Array(Arc::new(NullArray::new(
+                        array.len(),
+                    ))));
+                }
+                ColumnarValue::Scalar(ScalarValue::Struct(array))
+                    if array.column_by_name(k).is_none() =>
+                {
+                    return Ok(ColumnarValue::Scalar(ScalarValue::Null));
+                }
+                _ => {}
+            }
+        }
+
+        self.get_field.invoke_with_args(args)
+    }
+
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        // Rewrite to a NULL literal when the field is known to be missing
+        let missing = match (&args[0], &args[1]) {
+            (base, Expr::Literal(name)) => match info.get_data_type(base) {
+                Ok(DataType::Struct(fields)) => literal_field_name(Some(name))
+                    .is_some_and(|name| fields.iter().all(|f| f.name() != name)),
+                _ => false,
+            },
+            _ => false,
+        };
+
+        if missing {
+            Ok(ExprSimplifyResult::Simplified(lit(ScalarValue::Null)))
+        } else {
+            Ok(ExprSimplifyResult::Original(args))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Array, ArrayRef, Int32Array, StructArray};
+
+    fn invoke(base: ColumnarValue, name: &str) -> Result<ColumnarValue> {
+        let base_field = Field::new("base", base.data_type(), true);
+        let name_field = Field::new("name", DataType::Utf8, false);
+        let name = ScalarValue::Utf8(Some(name.to_string()));
+        let return_field =
+            TryGetFieldFunc::new().return_field_from_args(ReturnFieldArgs {
+                arg_fields: &[base_field.clone(), name_field.clone()],
+                scalar_arguments: &[None, Some(&name)],
+            })?;
+        TryGetFieldFunc::new().invoke_with_args(ScalarFunctionArgs {
+            args: vec![base, ColumnarValue::Scalar(name)],
+            arg_fields: vec![&base_field, &name_field],
+            number_rows: 2,
+            return_field: &return_field,
+        })
+    }
+
+    fn struct_array() -> ArrayRef {
+        Arc::new(StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+        )]))
+    }
+
+    #[test]
+    fn test_try_get_field_existing() -> Result<()> {
+        let ColumnarValue::Array(result) =
+            invoke(ColumnarValue::Array(struct_array()), "a")?
+        else {
+            return internal_err!("expected array");
+        };
+        assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 2]) as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_get_field_missing() -> Result<()> {
+        let ColumnarValue::Array(result) =
+            invoke(ColumnarValue::Array(struct_array()), "missing")?
+        else {
+            return internal_err!("expected array");
+        };
+        assert_eq!(result.data_type(), &DataType::Null);
+        assert_eq!(result.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_get_field_not_struct() {
+        let result = invoke(ColumnarValue::Scalar(ScalarValue::Int32(Some(1))), "a");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,476 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, BinaryArray, UInt64Array};
+use arrow::buffer::{Buffer, NullBuffer};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{
+    exec_err, internal_err, plan_err, DataFusionError, Result, ScalarValue,
+};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use md5::Md5;
+use sha2::{Digest, Sha256};
+use twox_hash::XxHash64;
+
+#[user_doc(
+    doc_section(label = "Hashing Functions"),
+    description = r#"Computes the hash of one or more expressions using the specified algorithm.
+`xxhash64` and `murmur3` return a `UInt64`, and `md5` and `sha256` return the binary digest.
+The values of multiple expressions are hashed in order, each seeded with the hash of the previous values, so `hash(a, 'md5')` is the same as `digest(a, 'md5')`.
+Returns NULL if any of the values is NULL."#,
+    syntax_example = "hash(expression[, ..., expression_n], algorithm)",
+    sql_example = r#"```sql
+> select hash('foo', 'xxhash64');
++------------------------------------+
+| hash(Utf8("foo"),Utf8("xxhash64")) |
++------------------------------------+
+| 3728699739546630719                |
++------------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to hash. Can be a string, binary, boolean, numeric or temporal expression."
+    ),
+    argument(
+        name = "algorithm",
+        description = "Constant string specifying the algorithm to use. Must be one of:
+    - xxhash64
+    - murmur3 (the lower 64 bits of the x64 128-bit variant)
+    - md5
+    - sha256"
+    )
+)]
+#[derive(Debug)]
+pub struct HashFunc {
+    signature: Signature,
+}
+
+impl Default for HashFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HashFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for HashFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "hash"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let Some((algorithm_type, value_types)) = arg_types.split_last() else {
+            return plan_err!("{} expects at least 2 arguments, got 0", self.name());
+        };
+        if value_types.is_empty() {
+            return plan_err!(
+                "{} expects at least 2 arguments, got {}",
+                self.name(),
+                arg_types.len()
+            );
+        }
+
+        let mut coerced = value_types
+            .iter()
+            .map(|value_type| match value_type {
+                // hash the values rather than the keys of dictionaries
+                DataType::Dictionary(_, value_type) if is_hashable(value_type) => {
+                    Ok(value_type.as_ref().clone())
+                }
+                value_type if is_hashable(value_type) => Ok(value_type.clone()),
+                value_type => {
+                    plan_err!("{} does not support type {value_type}", self.name())
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        match algorithm_type {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                coerced.push(algorithm_type.clone())
+            }
+            DataType::Null => coerced.push(DataType::Utf8),
+            _ => {
+                return plan_err!(
+                    "{} expects its last argument to be a string algorithm name, got {algorithm_type}",
+                    self.name()
+                )
+            }
+        }
+        Ok(coerced)
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let nullable = args.arg_fields.iter().any(|f| f.is_nullable());
+        let algorithm = args
+            .scalar_arguments
+            .last()
+            .copied()
+            .flatten()
+            .and_then(|algorithm| algorithm.try_as_str().flatten());
+        let Some(algorithm) = algorithm else {
+            return plan_err!(
+                "{} requires its last argument to be a constant string algorithm name",
+                self.name()
+            );
+        };
+        let algorithm = algorithm.parse::<HashAlgorithm>()?;
+        Ok(Field::new(self.name(), algorithm.return_type(), nullable))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let Some((algorithm, values)) = args.args.split_last() else {
+            return internal_err!("{} expects at least 2 arguments", self.name());
+        };
+        let algorithm = match algorithm {
+            ColumnarValue::Scalar(algorithm) => algorithm.try_as_str().flatten(),
+            ColumnarValue::Array(_) => None,
+        };
+        let Some(algorithm) = algorithm else {
+            return exec_err!(
+                "{} requires its last argument to be a constant string algorithm name",
+                self.name()
+            );
+        };
+        let algorithm = algorithm.parse::<HashAlgorithm>()?;
+
+        let is_scalar = values
+            .iter()
+            .all(|value| matches!(value, ColumnarValue::Scalar(_)));
+        let arrays = ColumnarValue::values_to_arrays(values)?;
+        let result = hash(&arrays, algorithm)?;
+        if is_scalar {
+            ScalarValue::try_from_array(&result, 0).map(ColumnarValue::Scalar)
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns true if the values of `data_type` can be hashed by their bytes
+fn is_hashable(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView
+            | DataType::FixedSizeBinary(_)
+    ) || data_type.primitive_width().is_some()
+}
+
+/// An algorithm supported by the `hash` function
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    XxHash64,
+    Murmur3,
+    Md5,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    fn return_type(self) -> DataType {
+        match self {
+            Self::XxHash64 | Self::Murmur3 => DataType::UInt64,
+            Self::Md5 | Self::Sha256 => DataType::Binary,
+        }
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = DataFusionError;
+    fn from_str(name: &str) -> Result<HashAlgorithm> {
+        Ok(match name {
+            "xxhash64" => Self::XxHash64,
+            "murmur3" => Self::Murmur3,
+            "md5" => Self::Md5,
+            "sha256" => Self::Sha256,
+            _ => {
+                let options = [Self::XxHash64, Self::Murmur3, Self::Md5, Self::Sha256]
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return plan_err!(
+                    "There is no built-in hash algorithm named '{name}', currently supported algorithms are: {options}"
+                );
+            }
+        })
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{self:?}").to_lowercase())
+    }
+}
+
+/// The bytes of each value of an array that are hashed
+struct HashInput {
+    array: ArrayRef,
+    nulls: Option<NullBuffer>,
+    /// The values buffer, offset and byte width of fixed width types
+    fixed_width: Option<(Buffer, usize, usize)>,
+}
+
+impl HashInput {
+    fn try_new(array: &ArrayRef) -> Result<Self> {
+        // strings are hashed by their UTF-8 bytes
+        let array = match array.data_type() {
+            DataType::Utf8 => cast(array, &DataType::Binary)?,
+            DataType::LargeUtf8 => cast(array, &DataType::LargeBinary)?,
+            DataType::Utf8View => cast(array, &DataType::BinaryView)?,
+            _ => Arc::clone(array),
+        };
+        let fixed_width = array.data_type().primitive_width().map(|width| {
+            let data = array.to_data();
+            (data.buffers()[0].clone(), data.offset(), width)
+        });
+        Ok(Self {
+            nulls: array.logical_nulls(),
+            array,
+            fixed_width,
+        })
+    }
+
+    /// Returns the bytes of the value at `index`, or `None` if it is null
+    fn value(&self, index: usize) -> Option<&[u8]> {
+        if self
+            .nulls
+            .as_ref()
+            .is_some_and(|nulls| nulls.is_null(index))
+        {
+            return None;
+        }
+        if let Some((buffer, offset, width)) = &self.fixed_width {
+            let start = (offset + index) * width;
+            return Some(&buffer.as_slice()[start..start + width]);
+        }
+        Some(match self.array.data_type() {
+            DataType::Boolean => {
+                if self.array.as_boolean().value(index) {
+                    &[1]
+                } else {
+                    &[0]
+                }
+            }
+            DataType::Binary => self.array.as_binary::<i32>().value(index),
+            DataType::LargeBinary => self.array.as_binary::<i64>().value(index),
+            DataType::BinaryView => self.array.as_binary_view().value(index),
+            DataType::FixedSizeBinary(_) => {
+                self.array.as_fixed_size_binary().value(index)
+            }
+            // only null arrays are left, whose values are all null
+            _ => &[],
+        })
+    }
+}
+
+/// Hashes the values of each row of `arrays` with `algorithm`
+fn hash(arrays: &[ArrayRef], algorithm: HashAlgorithm) -> Result<ArrayRef> {
+    let inputs = arrays
+        .iter()
+        .map(HashInput::try_new)
+        .collect::<Result<Vec<_>>>()?;
+    let num_rows = arrays.first().map_or(0, |array| array.len());
+    let row_values = |row| {
+        inputs
+            .iter()
+            .map(|input| input.value(row))
+            .collect::<Option<Vec<_>>>()
+    };
+
+    Ok(match algorithm {
+        HashAlgorithm::XxHash64 | HashAlgorithm::Murmur3 => {
+            let hash_value = match algorithm {
+                HashAlgorithm::XxHash64 => XxHash64::oneshot,
+                _ => murmur3_x64_64,
+            };
+            let hashes = (0..num_rows)
+                .map(|row| {
+                    row_values(row).map(|values| {
+                        values.iter().fold(0, |seed, value| hash_value(seed, value))
+                    })
+                })
+                .collect::<UInt64Array>();
+            Arc::new(hashes)
+        }
+        HashAlgorithm::Md5 | HashAlgorithm::Sha256 => {
+            let digest = match algorithm {
+                HashAlgorithm::Md5 => chained_digest::<Md5>,
+                _ => chained_digest::<Sha256>,
+            };
+            let digests = (0..num_rows)
+                .map(|row| row_values(row).map(|values| digest(&values)))
+                .collect::<BinaryArray>();
+            Arc::new(digests)
+        }
+    })
+}
+
+/// Returns the digest of the first value, and then of the digest of the
+/// previous values followed by each other value
+fn chained_digest<D: Digest>(values: &[&[u8]]) -> Vec<u8> {
+    values.iter().fold(vec![], |previous, value| {
+        let mut hasher = D::new();
+        hasher.update(&previous);
+        hasher.update(value);
+        hasher.finalize().to_vec()
+    })
+}
+
+/// Returns the lower 64 bits of the 128-bit MurmurHash3 x64 hash of `data`
+fn murmur3_x64_64(seed: u64, data: &[u8]) -> u64 {
+    const C1: u64 = 0x87c3_7b91_1142_53d5;
+    const C2: u64 = 0x4cf5_ad43_2745_937f;
+    let mix_k1 = |k1: u64| k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+    let mix_k2 = |k2: u64| k2.wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+
+    let (mut h1, mut h2) = (seed, seed);
+    let mut blocks = data.chunks_exact(16);
+    for block in &mut blocks {
+        let (k1, k2) = block.split_at(8);
+        h1 ^= mix_k1(u64::from_le_bytes(k1.try_into().unwrap()));
+        h1 = h1
+            .rotate_left(27)
+            .wrapping_add(h2)
+            .wrapping_mul(5)
+            .wrapping_add(0x52dc_e729);
+        h2 ^= mix_k2(u64::from_le_bytes(k2.try_into().unwrap()));
+        h2 = h2
+            .rotate_left(31)
+            .wrapping_add(h1)
+            .wrapping_mul(5)
+            .wrapping_add(0x3849_5ab5);
+    }
+
+    let tail = blocks.remainder();
+    let (mut k1, mut k2) = (0u64, 0u64);
+    for (i, &byte) in tail.iter().enumerate() {
+        if i < 8 {
+            k1 |= (byte as u64) << (8 * i);
+        } else {
+            k2 |= (byte as u64) << (8 * (i - 8));
+        }
+    }
+    if tail.len() > 8 {
+        h2 ^= mix_k2(k2);
+    }
+    if !tail.is_empty() {
+        h1 ^= mix_k1(k1);
+    }
+
+    h1 ^= data.len() as u64;
+    h2 ^= data.len() as u64;
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    fmix64(h1).wrapping_add(fmix64(h2))
+}
+
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    k ^ (k >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::UInt64Type;
+
+    #[test]
+    fn test_murmur3() {
+        // the same as the reference implementation, for example
+        // `mmh3.hash64("foo")` in Python is (-2129773440516405919, ...)
+        assert_eq!(murmur3_x64_64(0, b"foo"), -2129773440516405919_i64 as u64);
+        assert_eq!(murmur3_x64_64(0, b""), 0);
+        // more than one block, with a tail in both halves
+        assert_ne!(
+            murmur3_x64_64(0, b"The quick brown fox jumps over the lazy dog"),
+            murmur3_x64_64(0, b"The quick brown fox jumps over the lazy cog")
+        );
+    }
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        let strings: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("foo"), Some("foo"), None]));
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(2), Some(3)]));
+
+        let result = hash(&[Arc::clone(&strings)], HashAlgorithm::XxHash64)?;
+        let result = result.as_primitive::<UInt64Type>();
+        assert_eq!(result.value(0), XxHash64::oneshot(0, b"foo"));
+        assert!(result.is_null(2));
+
+        // the second column is seeded with the hash of the first
+        let result = hash(&[strings, ints], HashAlgorithm::Murmur3)?;
+        let result = result.as_primitive::<UInt64Type>();
+        let foo = murmur3_x64_64(0, b"foo");
+        assert_eq!(result.value(0), murmur3_x64_64(foo, &1_i32.to_le_bytes()));
+        assert_eq!(result.value(1), murmur3_x64_64(foo, &2_i32.to_le_bytes()));
+        assert!(result.is_null(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_algorithm_from_str() {
+        assert_eq!("md5".parse::<HashAlgorithm>().unwrap(), HashAlgorithm::Md5);
+        let err = "crc32".parse::<HashAlgorithm>().unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: There is no built-in hash algorithm named 'crc32', currently supported algorithms are: xxhash64, murmur3, md5, sha256"
+        );
+    }
+}
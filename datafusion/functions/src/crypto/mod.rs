@@ -22,12 +22,14 @@ use std::sync::Arc;
 
 pub mod basic;
 pub mod digest;
+pub mod hash;
 pub mod md5;
 pub mod sha224;
 pub mod sha256;
 pub mod sha384;
 pub mod sha512;
 make_udf_function!(digest::DigestFunc, digest);
+make_udf_function!(hash::HashFunc, hash);
 make_udf_function!(md5::Md5Func, md5);
 make_udf_function!(sha224::SHA224Func, sha224);
 make_udf_function!(sha256::SHA256Func, sha256);
@@ -39,6 +41,10 @@ pub mod expr_fn {
         digest,
         "Computes the binary hash of an expression using the specified algorithm.",
         input_arg1 input_arg2
+    ),(
+        hash,
+        "Computes the hash of one or more expressions using the specified algorithm.",
+        args,
     ),(
         md5,
         "Computes an MD5 128-bit checksum for a string expression.",
@@ -64,5 +70,13 @@ pub mod expr_fn {
 
 /// Returns all DataFusion functions defined in this package
 pub fn functions() -> Vec<Arc<ScalarUDF>> {
-    vec![digest(), md5(), sha224(), sha256(), sha384(), sha512()]
+    vec![
+        digest(),
+        hash(),
+        md5(),
+        sha224(),
+        sha256(),
+        sha384(),
+        sha512(),
+    ]
 }
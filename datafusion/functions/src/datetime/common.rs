@@ -23,13 +23,14 @@ use arrow::array::{
 };
 use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
 use arrow::datatypes::DataType;
-use chrono::format::{parse, Parsed, StrftimeItems};
+use chrono::format::{parse, Item, Parsed, StrftimeItems};
 use chrono::LocalResult::Single;
 use chrono::{DateTime, TimeZone, Utc};
 
 use datafusion_common::cast::as_generic_string_array;
 use datafusion_common::{
-    exec_err, unwrap_or_internal_err, DataFusionError, Result, ScalarType, ScalarValue,
+    exec_err, plan_err, unwrap_or_internal_err, DataFusionError, Result, ScalarType,
+    ScalarValue,
 };
 use datafusion_expr::ColumnarValue;
 
@@ -65,6 +66,18 @@ pub(crate) fn validate_data_types(args: &[ColumnarValue], name: &str) -> Result<
     Ok(())
 }
 
+/// Returns an error if the constant `format` argument of the function `name`
+/// contains a [`chrono::format::strftime`] specifier that is not valid, so
+/// that invalid formats are reported when planning rather than when executing
+///
+/// [`chrono::format::strftime`]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+pub(crate) fn validate_chrono_format(format: &str, name: &str) -> Result<()> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return plan_err!("{name} format '{format}' is not a valid chrono format");
+    }
+    Ok(())
+}
+
 /// Accepts a string and parses it using the [`chrono::format::strftime`] specifiers
 /// relative to the provided `timezone`
 ///
@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use arrow::datatypes::DataType;
+
+use crate::datetime::date_bin::DateBinFunc;
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Time and Date Functions"),
+    description = r#"
+Bins timestamps like `date_bin` and fills in the bins that have no data. `date_bin_gapfill` can only be used as a `GROUP BY` expression of an aggregate: the result has a row for every bin between the first and the last bin with data, for every combination of the other group keys. The aggregates of a bin with no data are `NULL`; use `coalesce` to fill them with another value.
+"#,
+    syntax_example = "date_bin_gapfill(interval, expression, origin-timestamp)",
+    sql_example = r#"```sql
+> SELECT date_bin_gapfill(interval '1 day', time) as bin, count(*) as count
+FROM VALUES ('2023-01-01T18:18:18Z'), ('2023-01-03T19:00:03Z')  t(time)
+GROUP BY bin;
++---------------------+-------+
+| bin                 | count |
++---------------------+-------+
+| 2023-01-01T00:00:00 | 1     |
+| 2023-01-02T00:00:00 |       |
+| 2023-01-03T00:00:00 | 1     |
++---------------------+-------+
+3 row(s) fetched.
+```"#,
+    argument(name = "interval", description = "Bin interval."),
+    argument(
+        name = "expression",
+        description = "Time expression to operate on. Can be a constant, column, or function."
+    ),
+    argument(
+        name = "origin-timestamp",
+        description = "Optional. Starting point used to determine bin boundaries. If not specified defaults 1970-01-01T00:00:00Z (the UNIX epoch in UTC)."
+    )
+)]
+#[derive(Debug, Default)]
+pub struct DateBinGapfillFunc {
+    date_bin: DateBinFunc,
+}
+
+impl DateBinGapfillFunc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ScalarUDFImpl for DateBinGapfillFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "date_bin_gapfill"
+    }
+
+    fn signature(&self) -> &Signature {
+        self.date_bin.signature()
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        self.date_bin.return_type(arg_types)
+    }
+
+    fn invoke_with_args(
+        &self,
+        _args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        // Aggregates grouped by date_bin_gapfill are rewritten to use
+        // date_bin by the analyzer, so it is never evaluated directly
+        exec_err!(
+            "date_bin_gapfill can only be used as a GROUP BY expression of an aggregate"
+        )
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::builder::Int32Builder;
+use arrow::array::cast::AsArray;
+use arrow::array::temporal_conversions::{
+    as_datetime, as_datetime_with_timezone, date32_to_datetime, date64_to_datetime,
+};
+use arrow::array::timezone::Tz;
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{
+    ArrowTimestampType, DataType, Date32Type, Date64Type, Field, Fields, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
+};
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+use datafusion_common::types::{logical_date, logical_string, NativeType};
+use datafusion_common::{exec_err, utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+use datafusion_expr_common::signature::{Coercion, TypeSignatureClass};
+use datafusion_macros::user_doc;
+
+/// The names of the fields of the returned struct
+const PARTS: [&str; 6] = ["year", "month", "day", "hour", "minute", "second"];
+
+#[user_doc(
+    doc_section(label = "Time and Date Functions"),
+    description = "Returns a struct with the `year`, `month`, `day`, `hour`, `minute` and `second` of the date, which are computed together rather than with a `date_part` call each. Timestamps with a time zone are converted to the local time of the time zone first.",
+    syntax_example = "extract_date_parts(expression)",
+    sql_example = r#"```sql
+> select extract_date_parts(timestamp '2024-05-06T07:08:09') as parts;
++---------------------------------------------------------------+
+| parts                                                         |
++---------------------------------------------------------------+
+| {year: 2024, month: 5, day: 6, hour: 7, minute: 8, second: 9} |
++---------------------------------------------------------------+
+> select extract_date_parts(date '2024-05-06')['month'] as month;
++-------+
+| month |
++-------+
+| 5     |
++-------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Date or timestamp expression to operate on. Can be a constant, column, or function."
+    )
+)]
+#[derive(Debug)]
+pub struct ExtractDatePartsFunc {
+    signature: Signature,
+}
+
+impl Default for ExtractDatePartsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExtractDatePartsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::Coercible(vec![Coercion::new_implicit(
+                        TypeSignatureClass::Timestamp,
+                        vec![TypeSignatureClass::Native(logical_string())],
+                        NativeType::Timestamp(TimeUnit::Nanosecond, None),
+                    )]),
+                    TypeSignature::Coercible(vec![Coercion::new_exact(
+                        TypeSignatureClass::Native(logical_date()),
+                    )]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+fn part_fields() -> Fields {
+    PARTS
+        .iter()
+        .map(|name| Field::new(*name, DataType::Int32, true))
+        .collect()
+}
+
+impl ScalarUDFImpl for ExtractDatePartsFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "extract_date_parts"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Struct(part_fields()))
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        let [array] = take_function_args(self.name(), args.args)?;
+
+        let is_scalar = matches!(array, ColumnarValue::Scalar(_));
+        let array = match array {
+            ColumnarValue::Array(array) => array,
+            ColumnarValue::Scalar(scalar) => scalar.to_array()?,
+        };
+
+        let mut builder = DatePartsBuilder::with_capacity(array.len());
+        match array.data_type() {
+            DataType::Date32 => array
+                .as_primitive::<Date32Type>()
+                .iter()
+                .for_each(|v| builder.append(v.and_then(date32_to_datetime))),
+            DataType::Date64 => array
+                .as_primitive::<Date64Type>()
+                .iter()
+                .for_each(|v| builder.append(v.and_then(date64_to_datetime))),
+            DataType::Timestamp(unit, tz) => {
+                let tz = tz.as_deref();
+                match unit {
+                    TimeUnit::Second => {
+                        append_timestamps::<TimestampSecondType>(&mut builder, &array, tz)
+                    }
+                    TimeUnit::Millisecond => {
+                        append_timestamps::<TimestampMillisecondType>(
+                            &mut builder,
+                            &array,
+                            tz,
+                        )
+                    }
+                    TimeUnit::Microsecond => {
+                        append_timestamps::<TimestampMicrosecondType>(
+                            &mut builder,
+                            &array,
+                            tz,
+                        )
+                    }
+                    TimeUnit::Nanosecond => append_timestamps::<TimestampNanosecondType>(
+                        &mut builder,
+                        &array,
+                        tz,
+                    ),
+                }?
+            }
+            other => {
+                return exec_err!(
+                    "Unsupported data type {other} for function {}",
+                    self.name()
+                )
+            }
+        }
+        let parts: ArrayRef = Arc::new(builder.finish(array.nulls().cloned()));
+
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &parts, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(parts))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Appends the parts of the timestamps in `array`, in the local time of the
+/// time zone `tz` if there is one
+fn append_timestamps<T: ArrowTimestampType>(
+    builder: &mut DatePartsBuilder,
+    array: &ArrayRef,
+    tz: Option<&str>,
+) -> Result<()> {
+    let array = array.as_primitive::<T>();
+    match tz {
+        None => array
+            .iter()
+            .for_each(|v| builder.append(v.and_then(as_datetime::<T>))),
+        Some(tz) => {
+            let tz: Tz = tz.parse()?;
+            array.iter().for_each(|v| {
+                let local = v
+                    .and_then(|v| as_datetime_with_timezone::<T>(v, tz))
+                    .map(|datetime| datetime.naive_local());
+                builder.append(local)
+            })
+        }
+    }
+    Ok(())
+}
+
+/// Builds the columns of the struct of date parts in a single pass over the
+/// input
+struct DatePartsBuilder {
+    parts: [Int32Builder; 6],
+}
+
+impl DatePartsBuilder {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            parts: std::array::from_fn(|_| Int32Builder::with_capacity(capacity)),
+        }
+    }
+
+    fn append(&mut self, datetime: Option<NaiveDateTime>) {
+        let Some(datetime) = datetime else {
+            self.parts.iter_mut().for_each(|part| part.append_null());
+            return;
+        };
+        let values = [
+            datetime.year(),
+            datetime.month() as i32,
+            datetime.day() as i32,
+            datetime.hour() as i32,
+            datetime.minute() as i32,
+            datetime.second() as i32,
+        ];
+        for (part, value) in self.parts.iter_mut().zip(values) {
+            part.append_value(value);
+        }
+    }
+
+    fn finish(mut self, nulls: Option<NullBuffer>) -> StructArray {
+        let columns = self
+            .parts
+            .iter_mut()
+            .map(|part| Arc::new(part.finish()) as ArrayRef)
+            .collect();
+        StructArray::new(part_fields(), columns, nulls)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Date32Array, Int32Array, TimestampSecondArray};
+    use datafusion_expr::ScalarFunctionArgs;
+
+    fn invoke(array: ArrayRef) -> Result<StructArray> {
+        let arg_field = Field::new("a", array.data_type().clone(), true);
+        let args = ScalarFunctionArgs {
+            number_rows: array.len(),
+            arg_fields: vec![&arg_field],
+            return_field: &Field::new("f", DataType::Struct(part_fields()), true),
+            args: vec![ColumnarValue::Array(array)],
+        };
+        let ColumnarValue::Array(parts) =
+            ExtractDatePartsFunc::new().invoke_with_args(args)?
+        else {
+            return exec_err!("expected an array");
+        };
+        Ok(parts.as_struct().clone())
+    }
+
+    fn part(parts: &StructArray, name: &str) -> Int32Array {
+        parts.column_by_name(name).unwrap().as_primitive().clone()
+    }
+
+    #[test]
+    fn extract_date_parts_date() -> Result<()> {
+        // 2024-05-06
+        let parts = invoke(Arc::new(Date32Array::from(vec![Some(19849), None])))?;
+        assert_eq!(parts.logical_nulls().unwrap().null_count(), 1);
+        assert!(parts.is_null(1));
+        assert_eq!(
+            part(&parts, "year"),
+            Int32Array::from(vec![Some(2024), None])
+        );
+        assert_eq!(part(&parts, "month"), Int32Array::from(vec![Some(5), None]));
+        assert_eq!(part(&parts, "day"), Int32Array::from(vec![Some(6), None]));
+        assert_eq!(part(&parts, "hour"), Int32Array::from(vec![Some(0), None]));
+        Ok(())
+    }
+
+    #[test]
+    fn extract_date_parts_timestamp_with_timezone() -> Result<()> {
+        // 2024-05-06T23:08:09Z is 2024-05-07T01:08:09 in Europe/Berlin
+        let timestamps =
+            TimestampSecondArray::from(vec![1715036889]).with_timezone("Europe/Berlin");
+        let parts = invoke(Arc::new(timestamps))?;
+        let values = PARTS.map(|name| part(&parts, name).value(0));
+        assert_eq!(values, [2024, 5, 7, 1, 8, 9]);
+
+        let timestamps = TimestampSecondArray::from(vec![1715036889]);
+        let parts = invoke(Arc::new(timestamps))?;
+        let values = PARTS.map(|name| part(&parts, name).value(0));
+        assert_eq!(values, [2024, 5, 6, 23, 8, 9]);
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, TimestampNanosecondArray};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::DataType::{Timestamp, Utf8};
+use arrow::datatypes::TimeUnit::Nanosecond;
+use arrow::datatypes::{DataType, Field};
+
+use crate::datetime::common::{
+    string_to_timestamp_nanos_formatted, validate_chrono_format,
+};
+use crate::utils::make_scalar_function;
+use datafusion_common::types::logical_string;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+    ScalarUDFImpl, Signature, TypeSignatureClass, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Time and Date Functions"),
+    description = "Parses a string into a timestamp using a [Chrono format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html). This is the inverse of `to_char`. Returns an error if the string does not match the format.",
+    syntax_example = "from_char(expression, format)",
+    sql_example = r#"```sql
+> select from_char('01-03-2023 10:20', '%d-%m-%Y %H:%M');
++------------------------------------------------------------+
+| from_char(Utf8("01-03-2023 10:20"),Utf8("%d-%m-%Y %H:%M")) |
++------------------------------------------------------------+
+| 2023-03-01T10:20:00                                        |
++------------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "String expression to parse. Can be a constant, column, or function."
+    ),
+    argument(
+        name = "format",
+        description = "A [Chrono format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) string to use to parse the expression."
+    ),
+    related_udf(name = "to_char"),
+    related_udf(name = "to_timestamp")
+)]
+#[derive(Debug)]
+pub struct FromCharFunc {
+    signature: Signature,
+}
+
+impl Default for FromCharFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromCharFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::coercible(
+                vec![
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for FromCharFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "from_char"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Timestamp(Nanosecond, None))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        let format = args
+            .scalar_arguments
+            .get(1)
+            .copied()
+            .flatten()
+            .and_then(|format| format.try_as_str().flatten());
+        if let Some(format) = format {
+            validate_chrono_format(format, self.name())?;
+        }
+        Ok(Field::new(self.name(), Timestamp(Nanosecond, None), true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(from_char, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Parses each string with its format, returning NULL if either is NULL
+fn from_char(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [string, format] = take_function_args("from_char", args)?;
+    let (string, format) = (cast(string, &Utf8)?, cast(format, &Utf8)?);
+
+    let result = string
+        .as_string::<i32>()
+        .iter()
+        .zip(format.as_string::<i32>().iter())
+        .map(|(string, format)| match (string, format) {
+            (Some(string), Some(format)) => {
+                string_to_timestamp_nanos_formatted(string, format).map(Some)
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<TimestampNanosecondArray>>()?;
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Array, StringArray};
+    use arrow::datatypes::TimestampNanosecondType;
+
+    #[test]
+    fn test_from_char() -> Result<()> {
+        let string: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("01-03-2023"),
+            Some("2023/03/01 10:20"),
+            None,
+            Some("01-03-2023"),
+        ]));
+        let format: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("%d-%m-%Y"),
+            Some("%Y/%m/%d %H:%M"),
+            Some("%d-%m-%Y"),
+            None,
+        ]));
+
+        let result = from_char(&[string, format])?;
+        let result = result.as_primitive::<TimestampNanosecondType>();
+        assert_eq!(result.value(0), 1_677_628_800_000_000_000);
+        assert_eq!(result.value(1), 1_677_666_000_000_000_000);
+        assert!(result.is_null(2));
+        assert!(result.is_null(3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_char_invalid_format() {
+        let err = validate_chrono_format("%Y-%Q", "from_char").unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: from_char format '%Y-%Q' is not a valid chrono format"
+        );
+    }
+}
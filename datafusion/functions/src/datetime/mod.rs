@@ -25,8 +25,11 @@ pub mod common;
 pub mod current_date;
 pub mod current_time;
 pub mod date_bin;
+pub mod date_bin_gapfill;
 pub mod date_part;
 pub mod date_trunc;
+pub mod extract_date_parts;
+pub mod from_char;
 pub mod from_unixtime;
 pub mod make_date;
 pub mod now;
@@ -40,8 +43,11 @@ pub mod to_unixtime;
 make_udf_function!(current_date::CurrentDateFunc, current_date);
 make_udf_function!(current_time::CurrentTimeFunc, current_time);
 make_udf_function!(date_bin::DateBinFunc, date_bin);
+make_udf_function!(date_bin_gapfill::DateBinGapfillFunc, date_bin_gapfill);
 make_udf_function!(date_part::DatePartFunc, date_part);
 make_udf_function!(date_trunc::DateTruncFunc, date_trunc);
+make_udf_function!(extract_date_parts::ExtractDatePartsFunc, extract_date_parts);
+make_udf_function!(from_char::FromCharFunc, from_char);
 make_udf_function!(make_date::MakeDateFunc, make_date);
 make_udf_function!(from_unixtime::FromUnixtimeFunc, from_unixtime);
 make_udf_function!(now::NowFunc, now);
@@ -67,6 +73,10 @@ pub mod expr_fn {
     ),(
         current_time,
         "returns current UTC time as a Time64 value",
+    ),(
+        from_char,
+        "parses a string with a chrono format to a `Timestamp(Nanoseconds, None)`",
+        expression format
     ),(
         from_unixtime,
         "converts an integer to RFC3339 timestamp format string",
@@ -75,6 +85,10 @@ pub mod expr_fn {
         date_bin,
         "coerces an arbitrary timestamp to the start of the nearest specified interval",
         stride source origin
+    ),(
+        date_bin_gapfill,
+        "like date_bin, but also produces the empty bins when used as a GROUP BY expression of an aggregate",
+        stride source origin
     ),(
         date_part,
         "extracts a subfield from the date",
@@ -83,6 +97,10 @@ pub mod expr_fn {
         date_trunc,
         "truncates the date to a specified level of precision",
         part date
+    ),(
+        extract_date_parts,
+        "returns a struct with the year, month, day, hour, minute and second of the date",
+        date
     ),(
         make_date,
         "make a date from year, month and day component parts",
@@ -258,8 +276,11 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         current_date(),
         current_time(),
         date_bin(),
+        date_bin_gapfill(),
         date_part(),
         date_trunc(),
+        extract_date_parts(),
+        from_char(),
         from_unixtime(),
         make_date(),
         now(),
@@ -20,21 +20,24 @@ use std::sync::Arc;
 
 use arrow::array::cast::AsArray;
 use arrow::array::{new_null_array, Array, ArrayRef, StringArray};
-use arrow::datatypes::DataType;
 use arrow::datatypes::DataType::{
     Date32, Date64, Duration, Time32, Time64, Timestamp, Utf8,
 };
 use arrow::datatypes::TimeUnit::{Microsecond, Millisecond, Nanosecond, Second};
+use arrow::datatypes::{DataType, Field};
 use arrow::error::ArrowError;
 use arrow::util::display::{ArrayFormatter, DurationFormat, FormatOptions};
 
 use datafusion_common::{exec_err, utils::take_function_args, Result, ScalarValue};
 use datafusion_expr::TypeSignature::Exact;
 use datafusion_expr::{
-    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility, TIMEZONE_WILDCARD,
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarUDFImpl, Signature, Volatility,
+    TIMEZONE_WILDCARD,
 };
 use datafusion_macros::user_doc;
 
+use crate::datetime::common::validate_chrono_format;
+
 #[user_doc(
     doc_section(label = "Time and Date Functions"),
     description = "Returns a string representation of a date, time, timestamp or duration based on a [Chrono format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html). Unlike the PostgreSQL equivalent of this function numerical formatting is not supported.",
@@ -135,6 +138,24 @@ impl ScalarUDFImpl for ToCharFunc {
         Ok(Utf8)
     }
 
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        // durations are not formatted with chrono formats
+        let is_duration = matches!(
+            args.arg_fields.first().map(|f| f.data_type()),
+            Some(Duration(_))
+        );
+        let format = args
+            .scalar_arguments
+            .get(1)
+            .copied()
+            .flatten()
+            .and_then(|format| format.try_as_str().flatten());
+        if let Some(format) = format.filter(|_| !is_duration) {
+            validate_chrono_format(format, self.name())?;
+        }
+        Ok(Field::new(self.name(), Utf8, true))
+    }
+
     fn invoke_with_args(
         &self,
         args: datafusion_expr::ScalarFunctionArgs,
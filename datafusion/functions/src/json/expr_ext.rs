@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extension methods for Expr.
+
+use datafusion_expr::{Expr, Literal};
+
+use super::expr_fn::{json_get, json_get_array};
+
+/// Return access to values in a JSON string. Example `expr.json_get("a.b")`
+///
+/// ## Access a nested value of column "payload"
+///
+/// For example if column "payload" holds JSON strings like this
+///
+/// ```json
+/// {
+///   "user": {"name": "Alice", "tags": ["admin", "dev"]},
+///   "events": [{"id": 1}, {"id": 2}]
+/// }
+/// ```
+///
+/// You can access the name of the user, and the id of the second event with
+///
+/// ```
+/// # use datafusion_expr::col;
+/// # use datafusion_functions::json::expr_ext::JsonAccessor;
+/// let name = col("payload").json_get("user.name");
+/// let id = col("payload").json_get("events[1].id");
+/// ```
+///
+/// ## Access an array as a list
+///
+/// [`JsonAccessor::json_get_array`] returns the elements of a JSON array as a
+/// list of strings
+///
+/// ```
+/// # use datafusion_expr::col;
+/// # use datafusion_functions::json::expr_ext::JsonAccessor;
+/// let tags = col("payload").json_get_array("user.tags");
+/// ```
+pub trait JsonAccessor {
+    fn json_get(self, path: impl Literal) -> Expr;
+
+    fn json_get_array(self, path: impl Literal) -> Expr;
+}
+
+impl JsonAccessor for Expr {
+    fn json_get(self, path: impl Literal) -> Expr {
+        json_get(self, path.lit())
+    }
+
+    fn json_get_array(self, path: impl Literal) -> Expr {
+        json_get_array(self, path.lit())
+    }
+}
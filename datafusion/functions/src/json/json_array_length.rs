@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, Int64Array};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::DataType;
+use serde_json::Value;
+
+use crate::utils::make_scalar_function;
+use datafusion_common::types::logical_string;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignatureClass, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "JSON Functions"),
+    description = "Returns the number of elements of the JSON array in a string. Returns NULL if the string is not valid JSON or does not contain an array.",
+    syntax_example = "json_array_length(json)",
+    sql_example = r#"```sql
+> select json_array_length('[1, "two", {"three": 3}]');
++-----------------------------------------------------+
+| json_array_length(Utf8("[1, "two", {"three": 3}]")) |
++-----------------------------------------------------+
+| 3                                                   |
++-----------------------------------------------------+
+```"#,
+    argument(
+        name = "json",
+        description = "String expression containing a JSON document."
+    ),
+    related_udf(name = "json_object_keys")
+)]
+#[derive(Debug)]
+pub struct JsonArrayLengthFunc {
+    signature: Signature,
+}
+
+impl Default for JsonArrayLengthFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonArrayLengthFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::coercible(
+                vec![Coercion::new_exact(TypeSignatureClass::Native(
+                    logical_string(),
+                ))],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonArrayLengthFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "json_array_length"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(json_array_length, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the number of elements of each JSON array.
+/// JSON_ARRAY_LENGTH('[1, [2, 3]]') = 2
+fn json_array_length(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [json] = take_function_args("json_array_length", args)?;
+    let json = cast(json, &DataType::Utf8)?;
+
+    let result = json
+        .as_string::<i32>()
+        .iter()
+        .map(|json| match serde_json::from_str::<Value>(json?) {
+            Ok(Value::Array(values)) => Some(values.len() as i64),
+            _ => None,
+        })
+        .collect::<Int64Array>();
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::StringArray;
+
+    use datafusion_common::cast::as_int64_array;
+
+    use super::*;
+
+    #[test]
+    fn test_json_array_length() -> Result<()> {
+        let json = Arc::new(StringArray::from(vec![
+            Some(r#"[1, "two", [3, 4], null]"#),
+            Some("[]"),
+            Some(r#"{"a": [1]}"#),
+            Some("1"),
+            Some("[1, 2"),
+            None,
+        ])) as ArrayRef;
+
+        let result = json_array_length(&[json])?;
+        let expected = Int64Array::from(vec![Some(4), Some(0), None, None, None, None]);
+        assert_eq!(as_int64_array(&result)?, &expected);
+        Ok(())
+    }
+}
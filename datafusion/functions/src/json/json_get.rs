@@ -0,0 +1,425 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, GenericStringArray, ListBuilder, StringBuilder};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::{DataType, Field};
+use serde_json::Value;
+
+use crate::utils::make_scalar_function;
+use datafusion_common::types::logical_string;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignatureClass, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "JSON Functions"),
+    description = "Extracts the value at a path from a string containing a JSON document. String values are returned without quotes, and other values as JSON text. Returns NULL if the string is not valid JSON, if there is no value at the path, or if the value is JSON `null`.",
+    syntax_example = "json_get(json, path)",
+    sql_example = r#"```sql
+> select json_get('{"a": {"b": [1, "two"]}}', 'a.b[1]');
++-----------------------------------------------------------+
+| json_get(Utf8("{"a": {"b": [1, "two"]}}"),Utf8("a.b[1]")) |
++-----------------------------------------------------------+
+| two                                                       |
++-----------------------------------------------------------+
+```"#,
+    argument(
+        name = "json",
+        description = "String expression containing a JSON document."
+    ),
+    argument(
+        name = "path",
+        description = r#"Path of the value to extract: object keys separated by `.`, and array indexes or keys that contain `.` in brackets, such as `a.b[0]` or `a["b.c"]`. An empty path refers to the whole document."#
+    ),
+    related_udf(name = "json_get_array")
+)]
+#[derive(Debug)]
+pub struct JsonGetFunc {
+    signature: Signature,
+}
+
+impl Default for JsonGetFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonGetFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: json_signature(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "json_get"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(json_get, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "JSON Functions"),
+    description = "Extracts the array at a path from a string containing a JSON document, and returns its elements as a list of strings, in the same format as `json_get`. Returns NULL if the string is not valid JSON or if the value at the path is not an array.",
+    syntax_example = "json_get_array(json, path)",
+    sql_example = r#"```sql
+> select json_get_array('{"a": [1, "two", {"three": 3}]}', 'a');
++-------------------------------------------------------------------+
+| json_get_array(Utf8("{"a": [1, "two", {"three": 3}]}"),Utf8("a")) |
++-------------------------------------------------------------------+
+| [1, two, {"three":3}]                                             |
++-------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "json",
+        description = "String expression containing a JSON document."
+    ),
+    argument(
+        name = "path",
+        description = "Path of the array to extract, in the same format as for `json_get`."
+    ),
+    related_udf(name = "json_get")
+)]
+#[derive(Debug)]
+pub struct JsonGetArrayFunc {
+    signature: Signature,
+}
+
+impl Default for JsonGetArrayFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonGetArrayFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: json_signature(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonGetArrayFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "json_get_array"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(json_get_array, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn json_signature() -> Signature {
+    Signature::coercible(
+        vec![
+            Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+            Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+        ],
+        Volatility::Immutable,
+    )
+}
+
+/// Returns the string value at the given path of each JSON document.
+/// JSON_GET('{"a": {"b": 1}}', 'a.b') = '1'
+fn json_get(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [json, path] = take_function_args("json_get", args)?;
+    let (json, path) = (cast(json, &DataType::Utf8)?, cast(path, &DataType::Utf8)?);
+
+    let result = json
+        .as_string::<i32>()
+        .iter()
+        .zip(path.as_string::<i32>().iter())
+        .map(|(json, path)| match (json, path) {
+            (Some(json), Some(path)) => {
+                Ok(get_path(json, path)?.and_then(json_to_string))
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<GenericStringArray<i32>>>()?;
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the elements of the array at the given path of each JSON document.
+/// JSON_GET_ARRAY('{"a": [1, "b"]}', 'a') = ['1', 'b']
+fn json_get_array(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [json, path] = take_function_args("json_get_array", args)?;
+    let (json, path) = (cast(json, &DataType::Utf8)?, cast(path, &DataType::Utf8)?);
+
+    let mut builder = ListBuilder::new(StringBuilder::new())
+        .with_field(Arc::new(Field::new_list_field(DataType::Utf8, true)));
+    for (json, path) in json
+        .as_string::<i32>()
+        .iter()
+        .zip(path.as_string::<i32>().iter())
+    {
+        let value = match (json, path) {
+            (Some(json), Some(path)) => get_path(json, path)?,
+            _ => None,
+        };
+        match value {
+            Some(Value::Array(values)) => {
+                builder.append_value(values.into_iter().map(json_to_string))
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+/// Returns the value at `path` of the JSON document in `json`, or `None` if
+/// `json` is not valid JSON or has no value at `path`
+fn get_path(json: &str, path: &str) -> Result<Option<Value>> {
+    let segments = parse_path(path)?;
+    let Ok(mut value) = serde_json::from_str::<Value>(json) else {
+        return Ok(None);
+    };
+    for segment in segments {
+        let next = match (value, segment) {
+            (Value::Object(mut object), PathSegment::Key(key)) => object.remove(&key),
+            (Value::Array(mut array), PathSegment::Index(index))
+                if index < array.len() =>
+            {
+                Some(array.swap_remove(index))
+            }
+            _ => None,
+        };
+        let Some(next) = next else {
+            return Ok(None);
+        };
+        value = next;
+    }
+    Ok(Some(value))
+}
+
+/// Returns JSON strings without quotes and other values as JSON text, and
+/// `None` for JSON `null`
+fn json_to_string(value: Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s),
+        other => Some(other.to_string()),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum PathSegment {
+    /// The value of an object key
+    Key(String),
+    /// The element at an array index
+    Index(usize),
+}
+
+/// Parses a path like `a.b[0]["c.d"]` into its segments. An empty path refers
+/// to the whole document.
+fn parse_path(path: &str) -> Result<Vec<PathSegment>> {
+    let mut segments = vec![];
+    let mut rest = path;
+    // A key is expected at the start of the path, unless it starts with a
+    // bracket, and after every `.`
+    let mut expect_key = !path.is_empty() && !path.starts_with('[');
+    while expect_key || !rest.is_empty() {
+        if expect_key {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            if end == 0 {
+                return exec_err!("Invalid JSON path '{path}': expected a key");
+            }
+            segments.push(PathSegment::Key(rest[..end].to_string()));
+            rest = &rest[end..];
+            expect_key = false;
+        } else if let Some(after_dot) = rest.strip_prefix('.') {
+            rest = after_dot;
+            expect_key = true;
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let (segment, after_segment) = parse_bracket(path, after_bracket)?;
+            segments.push(segment);
+            rest = after_segment;
+        } else {
+            return exec_err!(
+                "Invalid JSON path '{path}': expected '.' or '[' before '{rest}'"
+            );
+        }
+    }
+    Ok(segments)
+}
+
+/// Parses the segment in brackets at the start of `rest`, which follows a
+/// `[`, returning the segment and the rest of the path after the `]`
+fn parse_bracket<'a>(path: &str, rest: &'a str) -> Result<(PathSegment, &'a str)> {
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'');
+    let (segment, after_segment) = match quote {
+        Some(quote) => {
+            let Some(end) = rest[1..].find(quote) else {
+                return exec_err!("Invalid JSON path '{path}': unterminated quoted key");
+            };
+            (
+                PathSegment::Key(rest[1..end + 1].to_string()),
+                &rest[end + 2..],
+            )
+        }
+        None => {
+            let end = rest.find(']').unwrap_or(rest.len());
+            let Ok(index) = rest[..end].parse::<usize>() else {
+                return exec_err!(
+                    "Invalid JSON path '{path}': expected an array index or a quoted key in brackets"
+                );
+            };
+            (PathSegment::Index(index), &rest[end..])
+        }
+    };
+    match after_segment.strip_prefix(']') {
+        Some(after_bracket) => Ok((segment, after_bracket)),
+        None => exec_err!("Invalid JSON path '{path}': expected ']'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, StringArray};
+
+    use datafusion_common::cast::{as_list_array, as_string_array};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_path() -> Result<()> {
+        use PathSegment::*;
+        let key = |k: &str| Key(k.to_string());
+        let tests = [
+            ("", vec![]),
+            ("a", vec![key("a")]),
+            ("a.b", vec![key("a"), key("b")]),
+            ("a[0].b", vec![key("a"), Index(0), key("b")]),
+            ("[1][2]", vec![Index(1), Index(2)]),
+            (r#"a["b.c"]['d]']"#, vec![key("a"), key("b.c"), key("d]")]),
+        ];
+        for (path, expected) in tests {
+            assert_eq!(parse_path(path)?, expected, "path {path:?}");
+        }
+
+        for path in [
+            "a.", ".a", "a..b", "a[", "a[x]", "a[-1]", "a[0]b", r#"a["b]"#,
+        ] {
+            let err = parse_path(path).unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid JSON path"),
+                "path {path:?}: {err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_get() -> Result<()> {
+        let json = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": {"b": "x", "c": [1, {"d": true}]}}"#),
+            Some(r#"{"a": {"b": "x", "c": [1, {"d": true}]}}"#),
+            Some(r#"{"a": {"b": "x", "c": [1, {"d": true}]}}"#),
+            Some(r#"{"a": {"b": "x", "c": [1, {"d": true}]}}"#),
+            Some(r#"{"a": null}"#),
+            Some("not json"),
+            None,
+        ])) as ArrayRef;
+        let path = Arc::new(StringArray::from(vec![
+            Some("a.b"),
+            Some("a.c[1]"),
+            Some("a.c[1].d"),
+            Some("a.c[2]"),
+            Some("a"),
+            Some("a"),
+            Some("a"),
+        ])) as ArrayRef;
+
+        let result = json_get(&[json, path])?;
+        let expected = StringArray::from(vec![
+            Some("x"),
+            Some(r#"{"d":true}"#),
+            Some("true"),
+            None,
+            None,
+            None,
+            None,
+        ]);
+        assert_eq!(as_string_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_get_array() -> Result<()> {
+        let json = Arc::new(StringArray::from(vec![
+            Some(r#"{"a": [1, "b", null, [2]]}"#),
+            Some(r#"{"a": []}"#),
+            Some(r#"{"a": "b"}"#),
+            Some("[1, 2"),
+        ])) as ArrayRef;
+        let path = Arc::new(StringArray::from(vec!["a"; 4])) as ArrayRef;
+
+        let result = json_get_array(&[json, path])?;
+        let result = as_list_array(&result)?;
+        assert_eq!(result.value_offsets(), &[0, 4, 4, 4, 4]);
+        assert!(result.is_valid(0) && result.is_valid(1));
+        assert!(result.is_null(2) && result.is_null(3));
+        assert_eq!(
+            as_string_array(result.values())?,
+            &StringArray::from(vec![Some("1"), Some("b"), None, Some("[2]")])
+        );
+        Ok(())
+    }
+}
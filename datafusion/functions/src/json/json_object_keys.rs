@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, ListBuilder, StringBuilder};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::{DataType, Field};
+use serde_json::Value;
+
+use crate::utils::make_scalar_function;
+use datafusion_common::types::logical_string;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignatureClass, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "JSON Functions"),
+    description = "Returns the keys of the JSON object in a string as a list of strings, in ascending order. Returns NULL if the string is not valid JSON or does not contain an object.",
+    syntax_example = "json_object_keys(json)",
+    sql_example = r#"```sql
+> select json_object_keys('{"b": 1, "a": {"c": 2}}');
++---------------------------------------------------+
+| json_object_keys(Utf8("{"b": 1, "a": {"c": 2}}")) |
++---------------------------------------------------+
+| [a, b]                                            |
++---------------------------------------------------+
+```"#,
+    argument(
+        name = "json",
+        description = "String expression containing a JSON document."
+    ),
+    related_udf(name = "json_array_length")
+)]
+#[derive(Debug)]
+pub struct JsonObjectKeysFunc {
+    signature: Signature,
+}
+
+impl Default for JsonObjectKeysFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsonObjectKeysFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::coercible(
+                vec![Coercion::new_exact(TypeSignatureClass::Native(
+                    logical_string(),
+                ))],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for JsonObjectKeysFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "json_object_keys"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(json_object_keys, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the sorted keys of each JSON object.
+/// JSON_OBJECT_KEYS('{"b": 1, "a": 2}') = ['a', 'b']
+fn json_object_keys(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [json] = take_function_args("json_object_keys", args)?;
+    let json = cast(json, &DataType::Utf8)?;
+
+    let mut builder = ListBuilder::new(StringBuilder::new())
+        .with_field(Arc::new(Field::new_list_field(DataType::Utf8, true)));
+    for json in json.as_string::<i32>().iter() {
+        match json.map(serde_json::from_str::<Value>) {
+            Some(Ok(Value::Object(object))) => {
+                // the order of the keys of a parsed object depends on the
+                // features of serde_json, so they are sorted
+                let mut keys = object.into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+                keys.sort_unstable();
+                builder.append_value(keys.into_iter().map(Some))
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, StringArray};
+
+    use datafusion_common::cast::{as_list_array, as_string_array};
+
+    use super::*;
+
+    #[test]
+    fn test_json_object_keys() -> Result<()> {
+        let json = Arc::new(StringArray::from(vec![
+            Some(r#"{"b": 1, "a": {"c": 2}}"#),
+            Some("{}"),
+            Some(r#"[{"a": 1}]"#),
+            Some(r#"{"a": 1"#),
+            None,
+        ])) as ArrayRef;
+
+        let result = json_object_keys(&[json])?;
+        let result = as_list_array(&result)?;
+        assert_eq!(result.value_offsets(), &[0, 2, 2, 2, 2, 2]);
+        assert!(result.is_valid(0) && result.is_valid(1));
+        assert!(result.is_null(2) && result.is_null(3) && result.is_null(4));
+        assert_eq!(
+            as_string_array(result.values())?,
+            &StringArray::from(vec!["a", "b"])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! "json" DataFusion functions, which operate on strings containing JSON
+
+use datafusion_expr::ScalarUDF;
+use std::sync::Arc;
+
+pub mod expr_ext;
+pub mod json_array_length;
+pub mod json_get;
+pub mod json_object_keys;
+
+// create UDFs
+make_udf_function!(json_get::JsonGetFunc, json_get);
+make_udf_function!(json_get::JsonGetArrayFunc, json_get_array);
+make_udf_function!(json_array_length::JsonArrayLengthFunc, json_array_length);
+make_udf_function!(json_object_keys::JsonObjectKeysFunc, json_object_keys);
+
+pub mod expr_fn {
+    export_functions!((
+        json_get,
+        "Extracts the value at `path` from the JSON document in `json` as a string",
+        json path
+    ),(
+        json_get_array,
+        "Extracts the array at `path` from the JSON document in `json` as a list of strings",
+        json path
+    ),(
+        json_array_length,
+        "Returns the number of elements of the JSON array in `json`",
+        json
+    ),(
+        json_object_keys,
+        "Returns the keys of the JSON object in `json` as a sorted list of strings",
+        json
+    ));
+}
+
+/// Returns all DataFusion functions defined in this package
+pub fn functions() -> Vec<Arc<ScalarUDF>> {
+    vec![
+        json_get(),
+        json_get_array(),
+        json_array_length(),
+        json_object_keys(),
+    ]
+}
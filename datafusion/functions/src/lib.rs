@@ -117,6 +117,13 @@ make_stub_package!(datetime, "datetime_expressions");
 pub mod encoding;
 make_stub_package!(encoding, "encoding_expressions");
 
+/// JSON expressions.
+/// Contains functions that extract values from strings containing JSON.
+/// Enabled via feature flag `json_expressions`
+#[cfg(feature = "json_expressions")]
+pub mod json;
+make_stub_package!(json, "json_expressions");
+
 /// Mathematical functions.
 /// Enabled via feature flag `math_expressions`
 #[cfg(feature = "math_expressions")]
@@ -153,6 +160,8 @@ pub mod expr_fn {
     pub use super::datetime::expr_fn::*;
     #[cfg(feature = "encoding_expressions")]
     pub use super::encoding::expr_fn::*;
+    #[cfg(feature = "json_expressions")]
+    pub use super::json::expr_fn::*;
     #[cfg(feature = "math_expressions")]
     pub use super::math::expr_fn::*;
     #[cfg(feature = "regex_expressions")]
@@ -169,6 +178,7 @@ pub fn all_default_functions() -> Vec<Arc<ScalarUDF>> {
         .into_iter()
         .chain(datetime::functions())
         .chain(encoding::functions())
+        .chain(json::functions())
         .chain(math::functions())
         .chain(regex::functions())
         .chain(crypto::functions())
@@ -38,6 +38,7 @@ pub mod random;
 pub mod round;
 pub mod signum;
 pub mod trunc;
+pub mod width_bucket;
 
 // Create UDFs
 make_udf_function!(abs::AbsFunc, abs);
@@ -238,6 +239,7 @@ make_math_unary_udf!(
     super::get_tanh_doc
 );
 make_udf_function!(trunc::TruncFunc, trunc);
+make_udf_function!(width_bucket::WidthBucketFunc, width_bucket);
 
 pub mod expr_fn {
     export_functions!(
@@ -278,7 +280,8 @@ pub mod expr_fn {
         (tan, "returns the tangent of a number", num),
         (tanh, "returns the hyperbolic tangent of a number", num),
         (round, "round to nearest integer", args,),
-        (trunc, "truncate toward zero, with optional precision", args,)
+        (trunc, "truncate toward zero, with optional precision", args,),
+        (width_bucket, "returns the bucket number of a value in a histogram of equal width buckets", num low high count)
     );
 }
 
@@ -323,6 +326,7 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         tanh(),
         round(),
         trunc(),
+        width_bucket(),
     ]
 }
 
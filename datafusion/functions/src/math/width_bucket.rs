@@ -0,0 +1,229 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::utils::make_scalar_function;
+
+use arrow::array::{ArrayRef, AsArray, Int32Array};
+use arrow::datatypes::DataType::{Float64, Int32, Int64};
+use arrow::datatypes::{DataType, Float64Type, Int64Type};
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Math Functions"),
+    description = r#"Returns the bucket number of a value in a histogram of `count` equal width buckets from `low` to `high`.
+Values below the range are in bucket 0, and values at or above `high` are in bucket `count + 1`.
+If `low` is greater than `high`, the buckets are numbered in decreasing order of values."#,
+    syntax_example = "width_bucket(expression, low, high, count)",
+    sql_example = r#"```sql
+> select width_bucket(42, 0, 100, 10);
++-------------------------------------------------------+
+| width_bucket(Int64(42),Int64(0),Int64(100),Int64(10)) |
++-------------------------------------------------------+
+| 5                                                     |
++-------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Numeric expression to find the bucket of. Can be a constant, column, or function, and any combination of arithmetic operators."
+    ),
+    argument(name = "low", description = "Lower bound of the first bucket."),
+    argument(name = "high", description = "Upper bound of the last bucket."),
+    argument(
+        name = "count",
+        description = "Number of buckets, which must be greater than zero."
+    )
+)]
+#[derive(Debug)]
+pub struct WidthBucketFunc {
+    signature: Signature,
+}
+
+impl Default for WidthBucketFunc {
+    fn default() -> Self {
+        WidthBucketFunc::new()
+    }
+}
+
+impl WidthBucketFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![Float64, Float64, Float64, Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for WidthBucketFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "width_bucket"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(Int32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(width_bucket, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Width_bucket SQL function
+fn width_bucket(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let value = args[0].as_primitive::<Float64Type>();
+    let low = args[1].as_primitive::<Float64Type>();
+    let high = args[2].as_primitive::<Float64Type>();
+    let count = args[3].as_primitive::<Int64Type>();
+
+    let result = value
+        .iter()
+        .zip(low.iter())
+        .zip(high.iter())
+        .zip(count.iter())
+        .map(
+            |(((value, low), high), count)| match (value, low, high, count) {
+                (Some(value), Some(low), Some(high), Some(count)) => {
+                    compute_width_bucket(value, low, high, count).map(Some)
+                }
+                _ => Ok(None),
+            },
+        )
+        .collect::<Result<Int32Array>>()?;
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+fn compute_width_bucket(value: f64, low: f64, high: f64, count: i64) -> Result<i32> {
+    // count + 1 is the bucket of values above the range, so must fit in an i32
+    if count <= 0 || count >= i32::MAX as i64 {
+        return exec_err!(
+            "width_bucket count must be between 1 and {}, got {count}",
+            i32::MAX - 1
+        );
+    }
+    if value.is_nan() || low.is_nan() || high.is_nan() {
+        return exec_err!("width_bucket operand, low and high can not be NaN");
+    }
+    if low.is_infinite() || high.is_infinite() {
+        return exec_err!("width_bucket low and high must be finite");
+    }
+    if low == high {
+        return exec_err!("width_bucket low and high can not be equal");
+    }
+
+    let count = count as i32;
+    let (below, above) = if low < high {
+        (value < low, value >= high)
+    } else {
+        (value > low, value <= high)
+    };
+    if below {
+        return Ok(0);
+    }
+    if above {
+        return Ok(count + 1);
+    }
+
+    let bucket = ((value - low) * count as f64 / (high - low)).floor() as i32 + 1;
+    // Rounding may put values just below the end of the range past the last bucket
+    Ok(bucket.min(count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use arrow::array::{Float64Array, Int64Array};
+    use arrow::datatypes::Int32Type;
+
+    #[test]
+    fn test_width_bucket() {
+        #[rustfmt::skip]
+        let cases = [
+            // value, low, high, count, bucket
+            (-1.0, 0.0, 100.0, 10, 0),
+            (0.0, 0.0, 100.0, 10, 1),
+            (42.0, 0.0, 100.0, 10, 5),
+            (99.9, 0.0, 100.0, 10, 10),
+            (100.0, 0.0, 100.0, 10, 11),
+            (0.75, 0.5, 1.0, 2, 2),
+            // decreasing buckets
+            (101.0, 100.0, 0.0, 10, 0),
+            (100.0, 100.0, 0.0, 10, 1),
+            (42.0, 100.0, 0.0, 10, 6),
+            (0.0, 100.0, 0.0, 10, 11),
+        ];
+        for (value, low, high, count, expected) in cases {
+            assert_eq!(
+                compute_width_bucket(value, low, high, count).unwrap(),
+                expected,
+                "width_bucket({value}, {low}, {high}, {count})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_width_bucket_nulls() {
+        let args: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![Some(5.0), None, Some(5.0)])),
+            Arc::new(Float64Array::from(vec![Some(0.0), Some(0.0), Some(0.0)])),
+            Arc::new(Float64Array::from(vec![Some(10.0), Some(10.0), Some(10.0)])),
+            Arc::new(Int64Array::from(vec![Some(2), Some(2), None])),
+        ];
+        let result = width_bucket(&args).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(2), None, None])
+        );
+    }
+
+    #[test]
+    fn test_width_bucket_invalid() {
+        let err = compute_width_bucket(1.0, 0.0, 10.0, 0).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: width_bucket count must be between 1 and 2147483646, got 0"
+        );
+        let err = compute_width_bucket(1.0, 5.0, 5.0, 1).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: width_bucket low and high can not be equal"
+        );
+        assert!(compute_width_bucket(f64::NAN, 0.0, 10.0, 1).is_err());
+        assert!(compute_width_bucket(1.0, 0.0, f64::INFINITY, 1).is_err());
+    }
+}
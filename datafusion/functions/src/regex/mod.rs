@@ -20,15 +20,19 @@
 use std::sync::Arc;
 
 pub mod regexpcount;
+pub mod regexpextract;
 pub mod regexplike;
 pub mod regexpmatch;
 pub mod regexpreplace;
+pub mod regexpreplaceall;
 
 // create UDFs
 make_udf_function!(regexpcount::RegexpCountFunc, regexp_count);
+make_udf_function!(regexpextract::RegexpExtractFunc, regexp_extract);
 make_udf_function!(regexpmatch::RegexpMatchFunc, regexp_match);
 make_udf_function!(regexplike::RegexpLikeFunc, regexp_like);
 make_udf_function!(regexpreplace::RegexpReplaceFunc, regexp_replace);
+make_udf_function!(regexpreplaceall::RegexpReplaceAllFunc, regexp_replace_all);
 
 pub mod expr_fn {
     use datafusion_expr::Expr;
@@ -51,6 +55,11 @@ pub mod expr_fn {
         super::regexp_count().call(args)
     }
 
+    /// Returns the capture group `group` of the first regular expression match in a string.
+    pub fn regexp_extract(values: Expr, regex: Expr, group: Expr) -> Expr {
+        super::regexp_extract().call(vec![values, regex, group])
+    }
+
     /// Returns a list of regular expression matches in a string.
     pub fn regexp_match(values: Expr, regex: Expr, flags: Option<Expr>) -> Expr {
         let mut args = vec![values, regex];
@@ -82,14 +91,30 @@ pub mod expr_fn {
         };
         super::regexp_replace().call(args)
     }
+
+    /// Replaces all the substrings in a string that match, with `$` references to capture groups.
+    pub fn regexp_replace_all(
+        string: Expr,
+        pattern: Expr,
+        replacement: Expr,
+        flags: Option<Expr>,
+    ) -> Expr {
+        let mut args = vec![string, pattern, replacement];
+        if let Some(flags) = flags {
+            args.push(flags);
+        };
+        super::regexp_replace_all().call(args)
+    }
 }
 
 /// Returns all DataFusion functions defined in this package
 pub fn functions() -> Vec<Arc<datafusion_expr::ScalarUDF>> {
     vec![
         regexp_count(),
+        regexp_extract(),
         regexp_match(),
         regexp_like(),
         regexp_replace(),
+        regexp_replace_all(),
     ]
 }
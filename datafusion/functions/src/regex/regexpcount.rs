@@ -15,7 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::{Array, ArrayRef, AsArray, Datum, Int64Array, StringArrayType};
+use arrow::array::{
+    new_null_array, Array, ArrayRef, AsArray, Datum, Int64Array, StringArrayType,
+};
 use arrow::datatypes::{DataType, Int64Type};
 use arrow::datatypes::{
     DataType::Int64, DataType::LargeUtf8, DataType::Utf8, DataType::Utf8View,
@@ -35,7 +37,7 @@ use std::sync::Arc;
 
 #[user_doc(
     doc_section(label = "Regular Expression Functions"),
-    description = "Returns the number of matches that a [regular expression](https://docs.rs/regex/latest/regex/#syntax) has in a string.",
+    description = "Returns the number of matches that a [regular expression](https://docs.rs/regex/latest/regex/#syntax) has in a string. Matches are found from left to right and do not overlap, so `regexp_count('aaaa', 'aa')` is 2. Returns NULL if the string or the regular expression is NULL.",
     syntax_example = "regexp_count(str, regexp[, start, flags])",
     sql_example = r#"```sql
 > select regexp_count('abcAbAbc', 'abc', 2, 'i');
@@ -273,7 +275,7 @@ where
     S: StringArrayType<'a>,
 {
     let (regex_scalar, is_regex_scalar) = if is_regex_scalar || regex_array.len() == 1 {
-        (Some(regex_array.value(0)), true)
+        (regex_array.is_valid(0).then(|| regex_array.value(0)), true)
     } else {
         (None, false)
     };
@@ -305,9 +307,8 @@ where
     match (is_regex_scalar, is_start_scalar, is_flags_scalar) {
         (true, true, true) => {
             let regex = match regex_scalar {
-                None | Some("") => {
-                    return Ok(Arc::new(Int64Array::from(vec![0; values.len()])))
-                }
+                None => return Ok(new_null_array(&Int64, values.len())),
+                Some("") => return Ok(count_empty_regex(values)),
                 Some(regex) => regex,
             };
 
@@ -322,9 +323,8 @@ where
         }
         (true, true, false) => {
             let regex = match regex_scalar {
-                None | Some("") => {
-                    return Ok(Arc::new(Int64Array::from(vec![0; values.len()])))
-                }
+                None => return Ok(new_null_array(&Int64, values.len())),
+                Some("") => return Ok(count_empty_regex(values)),
                 Some(regex) => regex,
             };
 
@@ -351,9 +351,8 @@ where
         }
         (true, false, true) => {
             let regex = match regex_scalar {
-                None | Some("") => {
-                    return Ok(Arc::new(Int64Array::from(vec![0; values.len()])))
-                }
+                None => return Ok(new_null_array(&Int64, values.len())),
+                Some("") => return Ok(count_empty_regex(values)),
                 Some(regex) => regex,
             };
 
@@ -371,9 +370,8 @@ where
         }
         (true, false, false) => {
             let regex = match regex_scalar {
-                None | Some("") => {
-                    return Ok(Arc::new(Int64Array::from(vec![0; values.len()])))
-                }
+                None => return Ok(new_null_array(&Int64, values.len())),
+                Some("") => return Ok(count_empty_regex(values)),
                 Some(regex) => regex,
             };
 
@@ -416,7 +414,8 @@ where
                     .zip(regex_array.iter())
                     .map(|(value, regex)| {
                         let regex = match regex {
-                            None | Some("") => return Ok(0),
+                            None => return Ok(None),
+                            Some("") => return Ok(value.map(|_| 0)),
                             Some(regex) => regex,
                         };
 
@@ -452,7 +451,8 @@ where
                 izip!(values.iter(), regex_array.iter(), flags_array.iter())
                     .map(|(value, regex, flags)| {
                         let regex = match regex {
-                            None | Some("") => return Ok(0),
+                            None => return Ok(None),
+                            Some("") => return Ok(value.map(|_| 0)),
                             Some(regex) => regex,
                         };
 
@@ -486,7 +486,8 @@ where
                 izip!(values.iter(), regex_array.iter(), start_array.iter())
                     .map(|(value, regex, start)| {
                         let regex = match regex {
-                            None | Some("") => return Ok(0),
+                            None => return Ok(None),
+                            Some("") => return Ok(value.map(|_| 0)),
                             Some(regex) => regex,
                         };
 
@@ -536,7 +537,8 @@ where
                 )
                 .map(|(value, regex, start, flags)| {
                     let regex = match regex {
-                        None | Some("") => return Ok(0),
+                        None => return Ok(None),
+                        Some("") => return Ok(value.map(|_| 0)),
                         Some(regex) => regex,
                     };
 
@@ -586,13 +588,30 @@ fn compile_regex(regex: &str, flags: Option<&str>) -> Result<Regex, ArrowError>
     })
 }
 
+/// Returns 0 for each non null value, as an empty regular expression is
+/// treated as never matching
+fn count_empty_regex<'a, S>(values: S) -> ArrayRef
+where
+    S: StringArrayType<'a>,
+{
+    Arc::new(
+        values
+            .iter()
+            .map(|value| value.map(|_| 0))
+            .collect::<Int64Array>(),
+    )
+}
+
+/// Counts the non-overlapping matches of `pattern` in `value`, starting at
+/// the 1 based character position `start`
 fn count_matches(
     value: Option<&str>,
     pattern: &Regex,
     start: Option<i64>,
-) -> Result<i64, ArrowError> {
+) -> Result<Option<i64>, ArrowError> {
     let value = match value {
-        None | Some("") => return Ok(0),
+        None => return Ok(None),
+        Some("") => return Ok(Some(0)),
         Some(value) => value,
     };
 
@@ -605,10 +624,10 @@ fn count_matches(
 
         let find_slice = value.chars().skip(start as usize - 1).collect::<String>();
         let count = pattern.find_iter(find_slice.as_str()).count();
-        Ok(count as i64)
+        Ok(Some(count as i64))
     } else {
         let count = pattern.find_iter(value).count();
-        Ok(count as i64)
+        Ok(Some(count as i64))
     }
 }
 
@@ -643,6 +662,12 @@ mod tests {
         test_case_sensitive_regexp_count_array_complex::<StringViewArray>();
 
         test_case_regexp_count_cache_check::<GenericStringArray<i32>>();
+
+        test_regexp_count_non_overlapping();
+
+        test_regexp_count_nulls::<GenericStringArray<i32>>();
+        test_regexp_count_nulls::<GenericStringArray<i64>>();
+        test_regexp_count_nulls::<StringViewArray>();
     }
 
     fn regexp_count_with_scalar_values(args: &[ScalarValue]) -> Result<ColumnarValue> {
@@ -818,6 +843,65 @@ mod tests {
         });
     }
 
+    fn test_regexp_count_non_overlapping() {
+        // matches are counted left to right, and a match can not start inside
+        // the previous match
+        let values = ["aaaa", "aaa", "ababab", "ababab"];
+        let regex = ["aa", "aa", "aba", "(?:ab)+"];
+        let expected = [2, 1, 1, 1];
+
+        for ((value, regex), expected) in values.iter().zip(regex).zip(expected) {
+            let re = regexp_count_with_scalar_values(&[
+                ScalarValue::from(*value),
+                ScalarValue::from(regex),
+            ]);
+            match re {
+                Ok(ColumnarValue::Scalar(ScalarValue::Int64(v))) => {
+                    assert_eq!(v, Some(expected), "regexp_count({value}, {regex})");
+                }
+                _ => panic!("Unexpected result"),
+            }
+        }
+    }
+
+    fn test_regexp_count_nulls<A>()
+    where
+        A: From<Vec<Option<&'static str>>> + Array + 'static,
+    {
+        let values = A::from(vec![None, Some("abc"), Some("abc"), Some("")]);
+        let regex = A::from(vec![Some("a"), None, Some(""), None]);
+        let flags = A::from(vec![Some("i"), Some("i"), None, Some("i")]);
+
+        let expected = Int64Array::from(vec![None, None, Some(0), None]);
+
+        let re = regexp_count_func(&[Arc::new(values), Arc::new(regex)]).unwrap();
+        assert_eq!(re.as_ref(), &expected);
+
+        let values = A::from(vec![None, Some("abc"), Some("abc"), Some("")]);
+        let regex = A::from(vec![Some("a"), None, Some(""), None]);
+        let start = Int64Array::from(vec![1, 1, 1, 1]);
+        let re = regexp_count_func(&[
+            Arc::new(values),
+            Arc::new(regex),
+            Arc::new(start),
+            Arc::new(flags),
+        ])
+        .unwrap();
+        assert_eq!(re.as_ref(), &expected);
+
+        // null scalar regex
+        let values = A::from(vec![None, Some("abc")]);
+        let regex = A::from(vec![None]);
+        let re = regexp_count_func(&[Arc::new(values), Arc::new(regex)]).unwrap();
+        assert_eq!(re.as_ref(), &Int64Array::from(vec![None, None]));
+
+        // empty scalar regex
+        let values = A::from(vec![None, Some("abc")]);
+        let regex = A::from(vec![Some("")]);
+        let re = regexp_count_func(&[Arc::new(values), Arc::new(regex)]).unwrap();
+        assert_eq!(re.as_ref(), &Int64Array::from(vec![None, Some(0)]));
+    }
+
     fn test_case_sensitive_regexp_count_array<A>()
     where
         A: From<Vec<&'static str>> + Array + 'static,
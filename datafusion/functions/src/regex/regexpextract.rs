@@ -0,0 +1,307 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, Int64Array, LargeStringArray, StringArray, StringArrayType,
+    StringViewArray,
+};
+use arrow::datatypes::DataType::{Int64, LargeUtf8, Utf8, Utf8View};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::cast::as_int64_array;
+use datafusion_common::{
+    exec_err, internal_err, plan_err, utils::take_function_args, Result, ScalarValue,
+};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, TypeSignature::Exact, Volatility,
+};
+use datafusion_macros::user_doc;
+use regex::Regex;
+use std::any::Any;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Regular Expression Functions"),
+    description = "Returns the capture group of the first [regular expression](https://docs.rs/regex/latest/regex/#syntax) match in a string, or NULL if the string does not match.",
+    syntax_example = "regexp_extract(str, regexp, group)",
+    sql_example = r#"```sql
+> select regexp_extract('order-1234', '([a-z]+)-(\d+)', 2);
++--------------------------------------------------------------------+
+| regexp_extract(Utf8("order-1234"),Utf8("([a-z]+)-(\d+)"),Int64(2)) |
++--------------------------------------------------------------------+
+| 1234                                                               |
++--------------------------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    standard_argument(name = "regexp", prefix = "Regular"),
+    argument(
+        name = "group",
+        description = "Index of the capture group to return. `0` returns the whole match. Must be a valid group index for `regexp`."
+    )
+)]
+#[derive(Debug)]
+pub struct RegexpExtractFunc {
+    signature: Signature,
+}
+
+impl Default for RegexpExtractFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpExtractFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8View, Utf8View, Int64]),
+                    Exact(vec![Utf8, Utf8, Int64]),
+                    Exact(vec![LargeUtf8, LargeUtf8, Int64]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpExtractFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        // Check the group index while planning when both it and the pattern
+        // are constants
+        if let [_, Some(regex), Some(group)] = args.scalar_arguments {
+            if let (Some(regex), ScalarValue::Int64(Some(group))) =
+                (regex.try_as_str().flatten(), group.cast_to(&Int64)?)
+            {
+                let pattern = match Regex::new(regex) {
+                    Ok(pattern) => pattern,
+                    Err(_) => {
+                        return plan_err!("Regular expression did not compile: {regex}")
+                    }
+                };
+                if let Some(msg) = group_out_of_range(&pattern, group) {
+                    return plan_err!("{msg}");
+                }
+            }
+        }
+
+        let data_type = match args.arg_fields[0].data_type() {
+            LargeUtf8 => LargeUtf8,
+            Utf8View => Utf8View,
+            _ => Utf8,
+        };
+        Ok(Field::new(self.name(), data_type, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let args = &args.args;
+
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let args = args
+            .iter()
+            .map(|arg| arg.to_array(inferred_length))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = regexp_extract(&args);
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the selected capture group of the first match of each pattern in
+/// the corresponding value
+pub fn regexp_extract(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [values, regex_array, group_array] = take_function_args("regexp_extract", args)?;
+    let group_array = as_int64_array(group_array)?;
+
+    match (values.data_type(), regex_array.data_type()) {
+        (Utf8, Utf8) => Ok(Arc::new(StringArray::from(regexp_extract_inner(
+            values.as_string::<i32>(),
+            regex_array.as_string::<i32>(),
+            group_array,
+        )?))),
+        (LargeUtf8, LargeUtf8) => Ok(Arc::new(LargeStringArray::from(
+            regexp_extract_inner(
+                values.as_string::<i64>(),
+                regex_array.as_string::<i64>(),
+                group_array,
+            )?,
+        ))),
+        (Utf8View, Utf8View) => Ok(Arc::new(StringViewArray::from(
+            regexp_extract_inner(
+                values.as_string_view(),
+                regex_array.as_string_view(),
+                group_array,
+            )?,
+        ))),
+        (values_type, regex_type) => exec_err!(
+            "regexp_extract was called with unexpected data types {values_type} and {regex_type}"
+        ),
+    }
+}
+
+fn regexp_extract_inner<'a, S>(
+    values: S,
+    regex_array: S,
+    group_array: &Int64Array,
+) -> Result<Vec<Option<&'a str>>>
+where
+    S: StringArrayType<'a>,
+{
+    let mut regex_cache = HashMap::new();
+    values
+        .iter()
+        .zip(regex_array.iter())
+        .zip(group_array.iter())
+        .map(|((value, regex), group)| {
+            let (Some(value), Some(regex), Some(group)) = (value, regex, group) else {
+                return Ok(None);
+            };
+            let pattern = match regex_cache.entry(regex) {
+                Entry::Occupied(occupied_entry) => occupied_entry.into_mut(),
+                Entry::Vacant(vacant_entry) => match Regex::new(regex) {
+                    Ok(pattern) => vacant_entry.insert(pattern),
+                    Err(_) => {
+                        return exec_err!("Regular expression did not compile: {regex}")
+                    }
+                },
+            };
+            if let Some(msg) = group_out_of_range(pattern, group) {
+                return exec_err!("{msg}");
+            }
+            Ok(pattern
+                .captures(value)
+                .and_then(|captures| captures.get(group as usize))
+                .map(|m| m.as_str()))
+        })
+        .collect()
+}
+
+/// Returns an error message if `group` is not a capture group of `pattern`
+fn group_out_of_range(pattern: &Regex, group: i64) -> Option<String> {
+    let groups = pattern.captures_len() - 1;
+    (group < 0 || group as usize > groups).then(|| {
+        format!("regexp_extract group index {group} is out of range for pattern {pattern} with {groups} capture groups")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(values: Vec<Option<&str>>, regex: &str, group: i64) -> Result<ArrayRef> {
+        let len = values.len();
+        regexp_extract(&[
+            Arc::new(StringArray::from(values)),
+            Arc::new(StringArray::from(vec![regex; len])),
+            Arc::new(Int64Array::from(vec![group; len])),
+        ])
+    }
+
+    #[test]
+    fn test_regexp_extract() -> Result<()> {
+        let values = vec![Some("a-1"), Some("bc-23"), Some("none"), None];
+
+        let result = extract(values.clone(), r"([a-z]+)-(\d+)", 2)?;
+        let expected = StringArray::from(vec![Some("1"), Some("23"), None, None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = extract(values, r"([a-z]+)-(\d+)", 0)?;
+        let expected = StringArray::from(vec![Some("a-1"), Some("bc-23"), None, None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_extract_unmatched_group() -> Result<()> {
+        // the second group is optional, so it may not take part in the match
+        let result = extract(vec![Some("a"), Some("ab")], "(a)(b)?", 2)?;
+        let expected = StringArray::from(vec![None, Some("b")]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_extract_group_out_of_range() {
+        let err = extract(vec![Some("a")], "(a)", 2).unwrap_err();
+        assert!(err.to_string().contains(
+            "group index 2 is out of range for pattern (a) with 1 capture groups"
+        ));
+
+        let err = extract(vec![Some("a")], "(a)", -1).unwrap_err();
+        assert!(err.to_string().contains("group index -1 is out of range"));
+    }
+
+    #[test]
+    fn test_regexp_extract_return_field() {
+        let fields = [
+            Field::new("str", Utf8, true),
+            Field::new("regexp", Utf8, true),
+            Field::new("group", Int64, true),
+        ];
+        let regex = ScalarValue::from("(a)");
+        let return_field = |group: ScalarValue| {
+            RegexpExtractFunc::new().return_field_from_args(ReturnFieldArgs {
+                arg_fields: &fields,
+                scalar_arguments: &[None, Some(&regex), Some(&group)],
+            })
+        };
+
+        assert_eq!(
+            return_field(ScalarValue::Int32(Some(1)))
+                .unwrap()
+                .data_type(),
+            &Utf8
+        );
+        assert!(return_field(ScalarValue::Int32(Some(2))).is_err());
+    }
+}
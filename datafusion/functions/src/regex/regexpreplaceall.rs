@@ -0,0 +1,453 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, LargeStringArray, StringArray, StringArrayType,
+    StringViewArray,
+};
+use arrow::datatypes::DataType::{LargeUtf8, Utf8, Utf8View};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, TypeSignature::Exact, Volatility,
+};
+use datafusion_macros::user_doc;
+use regex::Regex;
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Regular Expression Functions"),
+    description = r#"Replaces every match of a [regular expression](https://docs.rs/regex/latest/regex/#syntax) in a string.
+
+Unlike `regexp_replace`, all the matches are always replaced, and the replacement refers to capture groups with `$` rather than `\`:
+- `$1` or `${1}` is replaced with the text of the capture group with that index, and `$0` with the whole match
+- `$name` or `${name}` is replaced with the text of the named capture group `(?<name>...)`
+- `$$` is replaced with a literal `$`
+
+A group name extends as far as possible, so use the braced form to follow a group with a letter, digit or underscore: `${1}a` rather than `$1a`. It is an error to refer to a group that is not in the regular expression. A group that does not take part in a match is replaced with the empty string."#,
+    syntax_example = "regexp_replace_all(str, regexp, replacement[, flags])",
+    sql_example = r#"```sql
+> select regexp_replace_all('12 34', '(\d)(\d)', '$2$1');
++-----------------------------------------------------------------+
+| regexp_replace_all(Utf8("12 34"),Utf8("(\d)(\d)"),Utf8("$2$1")) |
++-----------------------------------------------------------------+
+| 21 43                                                           |
++-----------------------------------------------------------------+
+> select regexp_replace_all('John Smith', '(?<first>\w+) (?<last>\w+)', '${last}, ${first}');
++-----------------------------------------------------------------------------------------------------+
+| regexp_replace_all(Utf8("John Smith"),Utf8("(?<first>\w+) (?<last>\w+)"),Utf8("${last}, ${first}")) |
++-----------------------------------------------------------------------------------------------------+
+| Smith, John                                                                                         |
++-----------------------------------------------------------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    standard_argument(name = "regexp", prefix = "Regular"),
+    argument(
+        name = "replacement",
+        description = "Replacement string expression, which can refer to the capture groups of `regexp`. Can be a constant, column, or function."
+    ),
+    argument(
+        name = "flags",
+        description = r#"Optional regular expression flags that control the behavior of the regular expression. The following flags are supported:
+- **i**: case-insensitive: letters match both upper and lower case
+- **m**: multi-line mode: ^ and $ match begin/end of line
+- **s**: allow . to match \n
+- **R**: enables CRLF mode: when multi-line mode is enabled, \r\n is used
+- **U**: swap the meaning of x* and x*?
+- **x**: ignore whitespace and allow line comments (starting with `#`)
+- **g**: accepted for compatibility with `regexp_replace`, all the matches are always replaced"#
+    )
+)]
+#[derive(Debug)]
+pub struct RegexpReplaceAllFunc {
+    signature: Signature,
+}
+
+impl Default for RegexpReplaceAllFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpReplaceAllFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8View, Utf8View, Utf8View]),
+                    Exact(vec![Utf8, Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, LargeUtf8, LargeUtf8]),
+                    Exact(vec![Utf8View, Utf8View, Utf8View, Utf8View]),
+                    Exact(vec![Utf8, Utf8, Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, LargeUtf8, LargeUtf8, LargeUtf8]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpReplaceAllFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_replace_all"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Field> {
+        // Check the pattern, flags and replacement while planning when they
+        // are constants
+        fn constant_str<'a>(arg: Option<&Option<&'a ScalarValue>>) -> Option<&'a str> {
+            arg.copied()
+                .flatten()
+                .and_then(|arg| arg.try_as_str().flatten())
+        }
+        let scalar_arguments = args.scalar_arguments;
+        let regex = constant_str(scalar_arguments.get(1));
+        let replacement = constant_str(scalar_arguments.get(2));
+        let flags = match scalar_arguments.get(3) {
+            None => Some(""),
+            flags => constant_str(flags),
+        };
+        if let (Some(regex), Some(flags)) = (regex, flags) {
+            let pattern = match compile_regex(regex, flags) {
+                Ok(pattern) => pattern,
+                Err(msg) => return plan_err!("{msg}"),
+            };
+            if let Some(replacement) = replacement {
+                if let Some(msg) = invalid_group_reference(&pattern, replacement) {
+                    return plan_err!("{msg}");
+                }
+            }
+        }
+
+        let data_type = match args.arg_fields[0].data_type() {
+            LargeUtf8 => LargeUtf8,
+            Utf8View => Utf8View,
+            _ => Utf8,
+        };
+        Ok(Field::new(self.name(), data_type, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let args = &args.args;
+
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let args = args
+            .iter()
+            .map(|arg| arg.to_array(inferred_length))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = regexp_replace_all(&args);
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Replaces all the matches of each pattern in the corresponding value,
+/// using the optional fourth array as the flags of the patterns
+pub fn regexp_replace_all(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let (values, patterns, replacements, flags) = match args {
+        [values, patterns, replacements] => (values, patterns, replacements, None),
+        [values, patterns, replacements, flags] => {
+            (values, patterns, replacements, Some(flags))
+        }
+        _ => {
+            return exec_err!(
+                "regexp_replace_all was called with {} arguments. It requires 3 or 4.",
+                args.len()
+            )
+        }
+    };
+
+    match values.data_type() {
+        Utf8 => {
+            let result = regexp_replace_all_inner(
+                values.as_string::<i32>(),
+                patterns.as_string::<i32>(),
+                replacements.as_string::<i32>(),
+                flags.map(|flags| flags.as_string::<i32>()),
+            )?;
+            Ok(Arc::new(result.into_iter().collect::<StringArray>()))
+        }
+        LargeUtf8 => {
+            let result = regexp_replace_all_inner(
+                values.as_string::<i64>(),
+                patterns.as_string::<i64>(),
+                replacements.as_string::<i64>(),
+                flags.map(|flags| flags.as_string::<i64>()),
+            )?;
+            Ok(Arc::new(result.into_iter().collect::<LargeStringArray>()))
+        }
+        Utf8View => {
+            let result = regexp_replace_all_inner(
+                values.as_string_view(),
+                patterns.as_string_view(),
+                replacements.as_string_view(),
+                flags.map(|flags| flags.as_string_view()),
+            )?;
+            Ok(Arc::new(result.into_iter().collect::<StringViewArray>()))
+        }
+        other => {
+            exec_err!("regexp_replace_all was called with unexpected data type {other}")
+        }
+    }
+}
+
+fn regexp_replace_all_inner<'a, S>(
+    values: S,
+    patterns: S,
+    replacements: S,
+    flags: Option<S>,
+) -> Result<Vec<Option<Cow<'a, str>>>>
+where
+    S: StringArrayType<'a>,
+{
+    let mut regex_cache = HashMap::new();
+    (0..values.len())
+        .map(|i| {
+            let flags = match flags.as_ref() {
+                Some(flags) if flags.is_null(i) => return Ok(None),
+                Some(flags) => flags.value(i),
+                None => "",
+            };
+            if values.is_null(i) || patterns.is_null(i) || replacements.is_null(i) {
+                return Ok(None);
+            }
+            let (value, regex, replacement) =
+                (values.value(i), patterns.value(i), replacements.value(i));
+
+            let pattern = match regex_cache.entry((regex, flags)) {
+                Entry::Occupied(occupied_entry) => occupied_entry.into_mut(),
+                Entry::Vacant(vacant_entry) => match compile_regex(regex, flags) {
+                    Ok(pattern) => vacant_entry.insert(pattern),
+                    Err(msg) => return exec_err!("{msg}"),
+                },
+            };
+            if let Some(msg) = invalid_group_reference(pattern, replacement) {
+                return exec_err!("{msg}");
+            }
+            Ok(Some(pattern.replace_all(value, replacement)))
+        })
+        .collect()
+}
+
+/// Compiles `regex` with the regular expression `flags`, or returns an error
+/// message
+fn compile_regex(regex: &str, flags: &str) -> std::result::Result<Regex, String> {
+    if let Some(flag) = flags.chars().find(|flag| !"imsRUxg".contains(*flag)) {
+        return Err(format!(
+            "regexp_replace_all does not support the regular expression flag '{flag}'"
+        ));
+    }
+    let flags = flags.replace('g', "");
+    let pattern = if flags.is_empty() {
+        Regex::new(regex)
+    } else {
+        Regex::new(&format!("(?{flags}){regex}"))
+    };
+    pattern.map_err(|_| format!("Regular expression did not compile: {regex}"))
+}
+
+/// Returns an error message if `replacement` refers to a capture group that
+/// is not in `pattern`.
+///
+/// This follows the syntax of [`Regex::replace_all`]: `$name` takes the
+/// longest sequence of letters, digits and underscores as the group name,
+/// `${name}` delimits it explicitly, and `$$` is a literal `$`. A `$` that
+/// does not start a group reference is kept as is.
+fn invalid_group_reference(pattern: &Regex, replacement: &str) -> Option<String> {
+    let mut rest = replacement;
+    while let Some(idx) = rest.find('$') {
+        rest = &rest[idx + 1..];
+        let (reference, name) = if let Some(after) = rest.strip_prefix('$') {
+            rest = after;
+            continue;
+        } else if let Some(after) = rest.strip_prefix('{') {
+            let Some(end) = after.find('}') else {
+                continue;
+            };
+            let reference = &rest[..end + 2];
+            rest = &after[end + 1..];
+            (reference, &after[..end])
+        } else {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let name = &rest[..end];
+            rest = &rest[end..];
+            (name, name)
+        };
+        if name.is_empty() {
+            continue;
+        }
+
+        let is_group = match name.parse::<usize>() {
+            Ok(index) => index < pattern.captures_len(),
+            Err(_) => pattern.capture_names().flatten().any(|group| group == name),
+        };
+        if !is_group {
+            return Some(format!(
+                "regexp_replace_all replacement refers to capture group ${reference}, which is not in pattern {pattern}"
+            ));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replace_all(
+        values: Vec<Option<&str>>,
+        regex: &str,
+        replacement: &str,
+        flags: Option<&str>,
+    ) -> Result<ArrayRef> {
+        let len = values.len();
+        let mut args: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(values)),
+            Arc::new(StringArray::from(vec![regex; len])),
+            Arc::new(StringArray::from(vec![replacement; len])),
+        ];
+        if let Some(flags) = flags {
+            args.push(Arc::new(StringArray::from(vec![flags; len])));
+        }
+        regexp_replace_all(&args)
+    }
+
+    #[test]
+    fn test_regexp_replace_all() -> Result<()> {
+        let values = vec![Some("12 34"), Some("5"), None];
+
+        let result = replace_all(values.clone(), r"(\d)(\d)", "$2$1", None)?;
+        let expected = StringArray::from(vec![Some("21 43"), Some("5"), None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = replace_all(values, r"(?<a>\d)(?<b>\d)", "${b}_${a}", None)?;
+        let expected = StringArray::from(vec![Some("2_1 4_3"), Some("5"), None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        // `$$` is a literal `$`, and `$0` is the whole match
+        let result = replace_all(vec![Some("ab")], "b", "[$$$0]", None)?;
+        assert_eq!(result.as_string::<i32>(), &StringArray::from(vec!["a[$b]"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_replace_all_flags() -> Result<()> {
+        let values = vec![Some("aBcAbC")];
+
+        let result = replace_all(values.clone(), "b", "x", Some("i"))?;
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec!["axcAxC"])
+        );
+
+        // `g` is accepted, but does not change anything
+        let result = replace_all(values.clone(), "b", "x", Some("gi"))?;
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec!["axcAxC"])
+        );
+
+        let err = replace_all(values, "b", "x", Some("z")).unwrap_err();
+        assert!(err.to_string().contains(
+            "regexp_replace_all does not support the regular expression flag 'z'"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_group_reference() {
+        let pattern = Regex::new(r"(\d)(?<name>\d)").unwrap();
+        for valid in [
+            "", "$0", "$1$2", "${2}", "$name", "${name}x", "$$3", "$", "${1",
+        ] {
+            assert_eq!(invalid_group_reference(&pattern, valid), None, "{valid}");
+        }
+        for (invalid, reference) in [
+            ("$3", "$3"),
+            ("${3}", "${3}"),
+            ("$1a", "$1a"),
+            ("x$other", "$other"),
+            ("${other}", "${other}"),
+        ] {
+            let msg = invalid_group_reference(&pattern, invalid).unwrap();
+            assert!(
+                msg.contains(&format!("refers to capture group {reference},")),
+                "{msg}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_regexp_replace_all_return_field() {
+        let fields = [
+            Field::new("str", Utf8, true),
+            Field::new("regexp", Utf8, true),
+            Field::new("replacement", Utf8, true),
+        ];
+        let regex = ScalarValue::from(r"(\d)");
+        let return_field = |replacement: &str| {
+            RegexpReplaceAllFunc::new().return_field_from_args(ReturnFieldArgs {
+                arg_fields: &fields,
+                scalar_arguments: &[
+                    None,
+                    Some(&regex),
+                    Some(&ScalarValue::from(replacement)),
+                ],
+            })
+        };
+
+        assert_eq!(return_field("$1").unwrap().data_type(), &Utf8);
+        assert!(return_field("$2").is_err());
+    }
+}
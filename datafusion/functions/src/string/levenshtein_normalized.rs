@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, Float64Array, StringArrayType};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::DataType;
+
+use crate::utils::make_scalar_function;
+use datafusion_common::types::logical_string;
+use datafusion_common::utils::datafusion_strsim;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::type_coercion::binary::{
+    binary_to_string_coercion, string_coercion,
+};
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignatureClass, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "String Functions"),
+    description = "Returns the similarity of two strings as a number between 0 and 1, computed as `1 - levenshtein(str1, str2) / max(length(str1), length(str2))`. Identical strings, including two empty strings, have a similarity of 1.",
+    syntax_example = "levenshtein_normalized(str1, str2)",
+    sql_example = r#"```sql
+> select levenshtein_normalized('kitten', 'sitting');
++--------------------------------------------------------+
+| levenshtein_normalized(Utf8("kitten"),Utf8("sitting")) |
++--------------------------------------------------------+
+| 0.5714285714285714                                     |
++--------------------------------------------------------+
+```"#,
+    argument(
+        name = "str1",
+        description = "String expression to compute the normalized Levenshtein distance with str2."
+    ),
+    argument(
+        name = "str2",
+        description = "String expression to compute the normalized Levenshtein distance with str1."
+    ),
+    related_udf(name = "levenshtein")
+)]
+#[derive(Debug)]
+pub struct LevenshteinNormalizedFunc {
+    signature: Signature,
+}
+
+impl Default for LevenshteinNormalizedFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LevenshteinNormalizedFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::coercible(
+                vec![
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for LevenshteinNormalizedFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "levenshtein_normalized"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(levenshtein_normalized, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the normalized Levenshtein similarity of the two given strings.
+/// LEVENSHTEIN_NORMALIZED('kitten', 'sitten') = 0.8333333333333334
+fn levenshtein_normalized(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [str1, str2] = take_function_args("levenshtein_normalized", args)?;
+
+    let Some(coercion_data_type) = string_coercion(str1.data_type(), str2.data_type())
+        .or_else(|| binary_to_string_coercion(str1.data_type(), str2.data_type()))
+    else {
+        return exec_err!("Unsupported data types for levenshtein_normalized. Expected Utf8, LargeUtf8 or Utf8View");
+    };
+    let str1 = cast(str1, &coercion_data_type)?;
+    let str2 = cast(str2, &coercion_data_type)?;
+
+    let result = match coercion_data_type {
+        DataType::Utf8View => {
+            levenshtein_normalized_inner(str1.as_string_view(), str2.as_string_view())
+        }
+        DataType::Utf8 => levenshtein_normalized_inner(
+            str1.as_string::<i32>(),
+            str2.as_string::<i32>(),
+        ),
+        DataType::LargeUtf8 => levenshtein_normalized_inner(
+            str1.as_string::<i64>(),
+            str2.as_string::<i64>(),
+        ),
+        other => {
+            return exec_err!(
+                "levenshtein_normalized was called with {other} datatype arguments. It requires Utf8View, Utf8 or LargeUtf8."
+            )
+        }
+    };
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+fn levenshtein_normalized_inner<'a, S>(str1: S, str2: S) -> Float64Array
+where
+    S: StringArrayType<'a>,
+{
+    str1.iter()
+        .zip(str2.iter())
+        .map(|(string1, string2)| {
+            Some(datafusion_strsim::normalized_levenshtein(
+                string1?, string2?,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::StringArray;
+
+    use datafusion_common::cast::as_float64_array;
+
+    use super::*;
+
+    #[test]
+    fn to_levenshtein_normalized() -> Result<()> {
+        let string1_array = Arc::new(StringArray::from(vec![
+            Some("kitten"),
+            Some(""),
+            Some(""),
+            Some("abc"),
+            Some("abc"),
+            None,
+        ]));
+        let string2_array = Arc::new(StringArray::from(vec![
+            Some("sitten"),
+            Some(""),
+            Some("abc"),
+            Some("abc"),
+            Some("xyz"),
+            Some("abc"),
+        ]));
+        let res = levenshtein_normalized(&[string1_array, string2_array])?;
+        let result = as_float64_array(&res)?;
+        let expected = Float64Array::from(vec![
+            Some(1.0 - 1.0 / 6.0),
+            Some(1.0),
+            Some(0.0),
+            Some(1.0),
+            Some(0.0),
+            None,
+        ]);
+        assert_eq!(&expected, result);
+
+        Ok(())
+    }
+}
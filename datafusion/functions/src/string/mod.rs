@@ -31,6 +31,7 @@ pub mod concat_ws;
 pub mod contains;
 pub mod ends_with;
 pub mod levenshtein;
+pub mod levenshtein_normalized;
 pub mod lower;
 pub mod ltrim;
 pub mod octet_length;
@@ -39,6 +40,7 @@ pub mod repeat;
 pub mod replace;
 pub mod rtrim;
 pub mod split_part;
+pub mod split_part_all;
 pub mod starts_with;
 pub mod to_hex;
 pub mod upper;
@@ -52,6 +54,10 @@ make_udf_function!(concat::ConcatFunc, concat);
 make_udf_function!(concat_ws::ConcatWsFunc, concat_ws);
 make_udf_function!(ends_with::EndsWithFunc, ends_with);
 make_udf_function!(levenshtein::LevenshteinFunc, levenshtein);
+make_udf_function!(
+    levenshtein_normalized::LevenshteinNormalizedFunc,
+    levenshtein_normalized
+);
 make_udf_function!(ltrim::LtrimFunc, ltrim);
 make_udf_function!(lower::LowerFunc, lower);
 make_udf_function!(octet_length::OctetLengthFunc, octet_length);
@@ -60,6 +66,7 @@ make_udf_function!(replace::ReplaceFunc, replace);
 make_udf_function!(rtrim::RtrimFunc, rtrim);
 make_udf_function!(starts_with::StartsWithFunc, starts_with);
 make_udf_function!(split_part::SplitPartFunc, split_part);
+make_udf_function!(split_part_all::SplitPartAllFunc, split_part_all);
 make_udf_function!(to_hex::ToHexFunc, to_hex);
 make_udf_function!(upper::UpperFunc, upper);
 make_udf_function!(uuid::UuidFunc, uuid);
@@ -95,6 +102,10 @@ pub mod expr_fn {
         levenshtein,
         "Returns the Levenshtein distance between the two given strings",
         arg1 arg2
+    ),(
+        levenshtein_normalized,
+        "Returns the similarity of the two given strings between 0 and 1, based on their Levenshtein distance",
+        arg1 arg2
     ),(
         lower,
         "Converts a string to lowercase.",
@@ -123,6 +134,10 @@ pub mod expr_fn {
         split_part,
         "Splits a string based on a delimiter and picks out the desired field based on the index.",
         string delimiter index
+    ),(
+        split_part_all,
+        "Splits a string based on a delimiter and returns all of the fields as a list.",
+        string delimiter
     ),(
         starts_with,
         "Returns true if string starts with prefix.",
@@ -168,6 +183,7 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         concat_ws(),
         ends_with(),
         levenshtein(),
+        levenshtein_normalized(),
         lower(),
         ltrim(),
         octet_length(),
@@ -175,6 +191,7 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         replace(),
         rtrim(),
         split_part(),
+        split_part_all(),
         starts_with(),
         to_hex(),
         upper(),
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow::compute::cast;
 use arrow::compute::kernels::length::length;
 use arrow::datatypes::DataType;
 use std::any::Any;
@@ -25,13 +26,13 @@ use datafusion_common::utils::take_function_args;
 use datafusion_common::{Result, ScalarValue};
 use datafusion_expr::{
     Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
-    TypeSignatureClass, Volatility,
+    TypeSignature, TypeSignatureClass, Volatility,
 };
 use datafusion_macros::user_doc;
 
 #[user_doc(
     doc_section(label = "String Functions"),
-    description = "Returns the length of a string in bytes.",
+    description = "Returns the length of a string or binary value in bytes.",
     syntax_example = "octet_length(str)",
     sql_example = r#"```sql
 > select octet_length('Ångström');
@@ -59,10 +60,15 @@ impl Default for OctetLengthFunc {
 impl OctetLengthFunc {
     pub fn new() -> Self {
         Self {
-            signature: Signature::coercible(
-                vec![Coercion::new_exact(TypeSignatureClass::Native(
-                    logical_string(),
-                ))],
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::Coercible(vec![Coercion::new_exact(
+                        TypeSignatureClass::Native(logical_string()),
+                    )]),
+                    TypeSignature::Exact(vec![DataType::Binary]),
+                    TypeSignature::Exact(vec![DataType::LargeBinary]),
+                    TypeSignature::Exact(vec![DataType::BinaryView]),
+                ],
                 Volatility::Immutable,
             ),
         }
@@ -83,26 +89,32 @@ impl ScalarUDFImpl for OctetLengthFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_int_type(&arg_types[0], "octet_length")
+        // validate the argument, but always return Int64 rather than the
+        // offset size of the input
+        utf8_to_int_type(&arg_types[0], "octet_length")?;
+        Ok(DataType::Int64)
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
         let [array] = take_function_args(self.name(), &args.args)?;
 
         match array {
-            ColumnarValue::Array(v) => Ok(ColumnarValue::Array(length(v.as_ref())?)),
-            ColumnarValue::Scalar(v) => match v {
-                ScalarValue::Utf8(v) => Ok(ColumnarValue::Scalar(ScalarValue::Int32(
-                    v.as_ref().map(|x| x.len() as i32),
-                ))),
-                ScalarValue::LargeUtf8(v) => Ok(ColumnarValue::Scalar(
-                    ScalarValue::Int64(v.as_ref().map(|x| x.len() as i64)),
-                )),
-                ScalarValue::Utf8View(v) => Ok(ColumnarValue::Scalar(
-                    ScalarValue::Int32(v.as_ref().map(|x| x.len() as i32)),
-                )),
-                _ => unreachable!("OctetLengthFunc"),
-            },
+            ColumnarValue::Array(v) => Ok(ColumnarValue::Array(cast(
+                &length(v.as_ref())?,
+                &DataType::Int64,
+            )?)),
+            ColumnarValue::Scalar(v) => {
+                let len = match v {
+                    ScalarValue::Utf8(v)
+                    | ScalarValue::LargeUtf8(v)
+                    | ScalarValue::Utf8View(v) => v.as_ref().map(|x| x.len() as i64),
+                    ScalarValue::Binary(v)
+                    | ScalarValue::LargeBinary(v)
+                    | ScalarValue::BinaryView(v) => v.as_ref().map(|x| x.len() as i64),
+                    _ => unreachable!("OctetLengthFunc"),
+                };
+                Ok(ColumnarValue::Scalar(ScalarValue::Int64(len)))
+            }
         }
     }
 
@@ -115,8 +127,10 @@ impl ScalarUDFImpl for OctetLengthFunc {
 mod tests {
     use std::sync::Arc;
 
-    use arrow::array::{Array, Int32Array, StringArray};
-    use arrow::datatypes::DataType::Int32;
+    use arrow::array::{
+        Array, BinaryViewArray, Int32Array, Int64Array, LargeStringArray, StringArray,
+    };
+    use arrow::datatypes::DataType::{Int32, Int64};
 
     use datafusion_common::ScalarValue;
     use datafusion_common::{exec_err, Result};
@@ -144,9 +158,9 @@ mod tests {
                 String::from("chars2"),
             ])))],
             Ok(Some(5)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -155,9 +169,9 @@ mod tests {
                 ColumnarValue::Scalar(ScalarValue::Utf8(Some(String::from("chars"))))
             ],
             exec_err!("octet_length function requires 1 argument, got 2"),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -165,9 +179,9 @@ mod tests {
                 String::from("chars")
             )))],
             Ok(Some(5)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -175,9 +189,9 @@ mod tests {
                 String::from("josé")
             )))],
             Ok(Some(5)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -185,17 +199,17 @@ mod tests {
                 String::from("")
             )))],
             Ok(Some(0)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
             vec![ColumnarValue::Scalar(ScalarValue::Utf8(None))],
             Ok(None),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -203,9 +217,9 @@ mod tests {
                 String::from("joséjoséjoséjosé")
             )))],
             Ok(Some(20)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -213,9 +227,9 @@ mod tests {
                 String::from("josé")
             )))],
             Ok(Some(5)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
         );
         test_function!(
             OctetLengthFunc::new(),
@@ -223,9 +237,78 @@ mod tests {
                 String::from("")
             )))],
             Ok(Some(0)),
-            i32,
-            Int32,
-            Int32Array
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Scalar(ScalarValue::LargeUtf8(Some(
+                String::from("josé")
+            )))],
+            Ok(Some(5)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Array(Arc::new(LargeStringArray::from(
+                vec![String::from("josé"),]
+            )))],
+            Ok(Some(5)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Scalar(ScalarValue::Binary(Some(vec![
+                1, 2, 3
+            ])))],
+            Ok(Some(3)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Scalar(ScalarValue::LargeBinary(Some(vec![
+                1, 2, 3
+            ])))],
+            Ok(Some(3)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Scalar(ScalarValue::BinaryView(Some(vec![
+                1, 2, 3
+            ])))],
+            Ok(Some(3)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Array(Arc::new(BinaryViewArray::from(vec![
+                &b"abc"[..],
+                &b"abcdefghijklmnopqrstuvwxyz"[..],
+            ])))],
+            Ok(Some(3)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        test_function!(
+            OctetLengthFunc::new(),
+            vec![ColumnarValue::Scalar(ScalarValue::BinaryView(None))],
+            Ok(None),
+            i64,
+            Int64,
+            Int64Array
         );
 
         Ok(())
@@ -0,0 +1,169 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray, ListBuilder, StringBuilder};
+use arrow::compute::kernels::cast::cast;
+use arrow::datatypes::DataType;
+
+use crate::utils::make_scalar_function;
+use datafusion_common::types::logical_string;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    Coercion, ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignatureClass, Volatility,
+};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "String Functions"),
+    description = "Splits a string based on a specified delimiter and returns all of the substrings as an array. An empty string returns an array with a single empty string, and an empty delimiter returns an array with the whole string.",
+    syntax_example = "split_part_all(str, delimiter)",
+    sql_example = r#"```sql
+> select split_part_all('2021-02-01', '-');
++----------------------------------------------+
+| split_part_all(Utf8("2021-02-01"),Utf8("-")) |
++----------------------------------------------+
+| [2021, 02, 01]                               |
++----------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    argument(name = "delimiter", description = "String or character to split on."),
+    related_udf(name = "split_part")
+)]
+#[derive(Debug)]
+pub struct SplitPartAllFunc {
+    signature: Signature,
+}
+
+impl Default for SplitPartAllFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SplitPartAllFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::coercible(
+                vec![
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+                    Coercion::new_exact(TypeSignatureClass::Native(logical_string())),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SplitPartAllFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "split_part_all"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(split_part_all, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns all the parts of each string split on the delimiter.
+/// SPLIT_PART_ALL('a-b-c', '-') = ['a', 'b', 'c']
+fn split_part_all(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [string, delimiter] = take_function_args("split_part_all", args)?;
+    let string = cast(string, &DataType::Utf8)?;
+    let delimiter = cast(delimiter, &DataType::Utf8)?;
+
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    string
+        .as_string::<i32>()
+        .iter()
+        .zip(delimiter.as_string::<i32>().iter())
+        .for_each(|(string, delimiter)| match (string, delimiter) {
+            (Some(string), Some("")) => builder.append_value([Some(string)]),
+            (Some(string), Some(delimiter)) => {
+                builder.append_value(string.split(delimiter).map(Some))
+            }
+            _ => builder.append_null(),
+        });
+
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Array, StringArray};
+    use datafusion_common::cast::as_list_array;
+
+    #[test]
+    fn test_split_part_all() -> Result<()> {
+        let string: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("2021-02-01"),
+            Some(""),
+            Some("abc"),
+            Some("abc"),
+            None,
+            Some("abc"),
+        ]));
+        let delimiter: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("-"),
+            Some("-"),
+            Some("-"),
+            Some(""),
+            Some("-"),
+            None,
+        ]));
+
+        let result = split_part_all(&[string, delimiter])?;
+        let result = as_list_array(&result)?;
+        let parts = |i: usize| {
+            let value = result.value(i);
+            value
+                .as_string::<i32>()
+                .iter()
+                .map(|part| part.unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(parts(0), ["2021", "02", "01"]);
+        assert_eq!(parts(1), [""]);
+        assert_eq!(parts(2), ["abc"]);
+        assert_eq!(parts(3), ["abc"]);
+        assert!(result.is_null(4));
+        assert!(result.is_null(5));
+        Ok(())
+    }
+}
@@ -46,7 +46,9 @@ use datafusion_expr::type_coercion::functions::{
 use datafusion_expr::type_coercion::other::{
     get_coerce_type_for_case_expression, get_coerce_type_for_list,
 };
-use datafusion_expr::type_coercion::{is_datetime, is_utf8_or_utf8view_or_large_utf8};
+use datafusion_expr::type_coercion::{
+    is_datetime, is_utf8_or_utf8view_or_large_utf8, CoercionRules, DefaultCoercionRules,
+};
 use datafusion_expr::utils::merge_schema;
 use datafusion_expr::{
     is_false, is_not_false, is_not_true, is_not_unknown, is_true, is_unknown, not,
@@ -152,13 +154,29 @@ fn analyze_internal(
 /// Rewrite expressions to apply type coercion.
 pub struct TypeCoercionRewriter<'a> {
     pub(crate) schema: &'a DFSchema,
+    rules: &'a dyn CoercionRules,
 }
 
 impl<'a> TypeCoercionRewriter<'a> {
     /// Create a new [`TypeCoercionRewriter`] with a provided schema
     /// representing both the inputs and output of the [`LogicalPlan`] node.
     pub fn new(schema: &'a DFSchema) -> Self {
-        Self { schema }
+        Self {
+            schema,
+            rules: &DefaultCoercionRules,
+        }
+    }
+
+    /// Use `rules` to coerce the arguments of operators and functions to the
+    /// types they require, instead of [`DefaultCoercionRules`]
+    pub fn with_coercion_rules(mut self, rules: &'a dyn CoercionRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Coerces `expr` to `data_type` with the [`CoercionRules`]
+    fn coerce_to(&self, expr: Expr, data_type: &DataType) -> Result<Expr> {
+        coerce_to(expr, data_type, self.schema, self.rules)
     }
 
     /// Coerce the [`LogicalPlan`].
@@ -277,7 +295,7 @@ impl<'a> TypeCoercionRewriter<'a> {
         let expr_type = expr.get_type(self.schema)?;
         match expr_type {
             DataType::Boolean => Ok(expr),
-            DataType::Null => expr.cast_to(&DataType::Boolean, self.schema),
+            DataType::Null => self.coerce_to(expr, &DataType::Boolean),
             other => plan_err!("Join condition must be boolean type, but got {other:?}"),
         }
     }
@@ -298,8 +316,8 @@ impl<'a> TypeCoercionRewriter<'a> {
         .get_input_types()?;
 
         Ok((
-            left.cast_to(&left_type, left_schema)?,
-            right.cast_to(&right_type, right_schema)?,
+            coerce_to(left, &left_type, left_schema, self.rules)?,
+            coerce_to(right, &right_type, right_schema, self.rules)?,
         ))
     }
 }
@@ -362,7 +380,7 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                     spans: subquery.spans,
                 };
                 Ok(Transformed::yes(Expr::InSubquery(InSubquery::new(
-                    Box::new(expr.cast_to(&common_type, self.schema)?),
+                    Box::new(self.coerce_to(*expr, &common_type)?),
                     cast_subquery(new_subquery, &common_type)?,
                     negated,
                 ))))
@@ -370,24 +388,25 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
             Expr::Not(expr) => Ok(Transformed::yes(not(get_casted_expr_for_bool_op(
                 *expr,
                 self.schema,
+                self.rules,
             )?))),
             Expr::IsTrue(expr) => Ok(Transformed::yes(is_true(
-                get_casted_expr_for_bool_op(*expr, self.schema)?,
+                get_casted_expr_for_bool_op(*expr, self.schema, self.rules)?,
             ))),
             Expr::IsNotTrue(expr) => Ok(Transformed::yes(is_not_true(
-                get_casted_expr_for_bool_op(*expr, self.schema)?,
+                get_casted_expr_for_bool_op(*expr, self.schema, self.rules)?,
             ))),
             Expr::IsFalse(expr) => Ok(Transformed::yes(is_false(
-                get_casted_expr_for_bool_op(*expr, self.schema)?,
+                get_casted_expr_for_bool_op(*expr, self.schema, self.rules)?,
             ))),
             Expr::IsNotFalse(expr) => Ok(Transformed::yes(is_not_false(
-                get_casted_expr_for_bool_op(*expr, self.schema)?,
+                get_casted_expr_for_bool_op(*expr, self.schema, self.rules)?,
             ))),
             Expr::IsUnknown(expr) => Ok(Transformed::yes(is_unknown(
-                get_casted_expr_for_bool_op(*expr, self.schema)?,
+                get_casted_expr_for_bool_op(*expr, self.schema, self.rules)?,
             ))),
             Expr::IsNotUnknown(expr) => Ok(Transformed::yes(is_not_unknown(
-                get_casted_expr_for_bool_op(*expr, self.schema)?,
+                get_casted_expr_for_bool_op(*expr, self.schema, self.rules)?,
             ))),
             Expr::Like(Like {
                 negated,
@@ -410,9 +429,9 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                 })?;
                 let expr = match left_type {
                     DataType::Dictionary(_, inner) if *inner == DataType::Utf8 => expr,
-                    _ => Box::new(expr.cast_to(&coerced_type, self.schema)?),
+                    _ => Box::new(self.coerce_to(*expr, &coerced_type)?),
                 };
-                let pattern = Box::new(pattern.cast_to(&coerced_type, self.schema)?);
+                let pattern = Box::new(self.coerce_to(*pattern, &coerced_type)?);
                 Ok(Transformed::yes(Expr::Like(Like::new(
                     negated,
                     expr,
@@ -459,10 +478,10 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                             ))
                         })?;
                 Ok(Transformed::yes(Expr::Between(Between::new(
-                    Box::new(expr.cast_to(&coercion_type, self.schema)?),
+                    Box::new(self.coerce_to(*expr, &coercion_type)?),
                     negated,
-                    Box::new(low.cast_to(&coercion_type, self.schema)?),
-                    Box::new(high.cast_to(&coercion_type, self.schema)?),
+                    Box::new(self.coerce_to(*low, &coercion_type)?),
+                    Box::new(self.coerce_to(*high, &coercion_type)?),
                 ))))
             }
             Expr::InList(InList {
@@ -483,11 +502,11 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                     ),
                     Some(coerced_type) => {
                         // find the coerced type
-                        let cast_expr = expr.cast_to(&coerced_type, self.schema)?;
+                        let cast_expr = self.coerce_to(*expr, &coerced_type)?;
                         let cast_list_expr = list
                             .into_iter()
                             .map(|list_expr| {
-                                list_expr.cast_to(&coerced_type, self.schema)
+                                self.coerce_to(list_expr, &coerced_type)
                             })
                             .collect::<Result<Vec<_>>>()?;
                         Ok(Transformed::yes(Expr::InList(InList ::new(
@@ -499,7 +518,7 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                 }
             }
             Expr::Case(case) => {
-                let case = coerce_case_expression(case, self.schema)?;
+                let case = coerce_case_expression(case, self.schema, self.rules)?;
                 Ok(Transformed::yes(Expr::Case(case)))
             }
             Expr::ScalarFunction(ScalarFunction { func, args }) => {
@@ -507,6 +526,7 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                     args,
                     self.schema,
                     &func,
+                    self.rules,
                 )?;
                 Ok(Transformed::yes(Expr::ScalarFunction(
                     ScalarFunction::new_udf(func, new_expr),
@@ -527,6 +547,7 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                     args,
                     self.schema,
                     &func,
+                    self.rules,
                 )?;
                 Ok(Transformed::yes(Expr::AggregateFunction(
                     expr::AggregateFunction::new_udf(
@@ -547,6 +568,7 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                         partition_by,
                         order_by,
                         window_frame,
+                        filter,
                         null_treatment,
                     },
             }) => {
@@ -559,19 +581,21 @@ impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
                             args,
                             self.schema,
                             udf,
+                            self.rules,
                         )?
                     }
                     _ => args,
                 };
 
-                Ok(Transformed::yes(
-                    Expr::WindowFunction(WindowFunction::new(fun, args))
-                        .partition_by(partition_by)
-                        .order_by(order_by)
-                        .window_frame(window_frame)
-                        .null_treatment(null_treatment)
-                        .build()?,
-                ))
+                let mut builder = Expr::WindowFunction(WindowFunction::new(fun, args))
+                    .partition_by(partition_by)
+                    .order_by(order_by)
+                    .window_frame(window_frame)
+                    .null_treatment(null_treatment);
+                if let Some(filter) = filter {
+                    builder = builder.filter(*filter);
+                }
+                Ok(Transformed::yes(builder.build()?))
             }
             // TODO: remove the next line after `Expr::Wildcard` is removed
             #[expect(deprecated)]
@@ -764,11 +788,30 @@ fn coerce_window_frame(
 
 // Support the `IsTrue` `IsNotTrue` `IsFalse` `IsNotFalse` type coercion.
 // The above op will be rewrite to the binary op when creating the physical op.
-fn get_casted_expr_for_bool_op(expr: Expr, schema: &DFSchema) -> Result<Expr> {
+fn get_casted_expr_for_bool_op(
+    expr: Expr,
+    schema: &DFSchema,
+    rules: &dyn CoercionRules,
+) -> Result<Expr> {
     let left_type = expr.get_type(schema)?;
     BinaryTypeCoercer::new(&left_type, &Operator::IsDistinctFrom, &DataType::Boolean)
         .get_input_types()?;
-    expr.cast_to(&DataType::Boolean, schema)
+    coerce_to(expr, &DataType::Boolean, schema, rules)
+}
+
+/// Coerces `expr` to `data_type` with `rules`, unless it already has that type
+fn coerce_to(
+    expr: Expr,
+    data_type: &DataType,
+    schema: &DFSchema,
+    rules: &dyn CoercionRules,
+) -> Result<Expr> {
+    let expr_type = expr.get_type(schema)?;
+    if expr_type == *data_type {
+        Ok(expr)
+    } else {
+        rules.coerce(expr, &expr_type, data_type, schema)
+    }
 }
 
 /// Returns `expressions` coerced to types compatible with
@@ -779,6 +822,7 @@ fn coerce_arguments_for_signature_with_scalar_udf(
     expressions: Vec<Expr>,
     schema: &DFSchema,
     func: &ScalarUDF,
+    rules: &dyn CoercionRules,
 ) -> Result<Vec<Expr>> {
     if expressions.is_empty() {
         return Ok(expressions);
@@ -794,7 +838,7 @@ fn coerce_arguments_for_signature_with_scalar_udf(
     expressions
         .into_iter()
         .enumerate()
-        .map(|(i, expr)| expr.cast_to(&new_types[i], schema))
+        .map(|(i, expr)| coerce_to(expr, &new_types[i], schema, rules))
         .collect()
 }
 
@@ -806,6 +850,7 @@ fn coerce_arguments_for_signature_with_aggregate_udf(
     expressions: Vec<Expr>,
     schema: &DFSchema,
     func: &AggregateUDF,
+    rules: &dyn CoercionRules,
 ) -> Result<Vec<Expr>> {
     if expressions.is_empty() {
         return Ok(expressions);
@@ -824,11 +869,15 @@ fn coerce_arguments_for_signature_with_aggregate_udf(
     expressions
         .into_iter()
         .enumerate()
-        .map(|(i, expr)| expr.cast_to(&new_types[i], schema))
+        .map(|(i, expr)| coerce_to(expr, &new_types[i], schema, rules))
         .collect()
 }
 
-fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
+fn coerce_case_expression(
+    case: Case,
+    schema: &DFSchema,
+    rules: &dyn CoercionRules,
+) -> Result<Case> {
     // Given expressions like:
     //
     // CASE a1
@@ -909,7 +958,9 @@ fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
     let case_expr = case
         .expr
         .zip(case_when_coerce_type.as_ref())
-        .map(|(case_expr, coercible_type)| case_expr.cast_to(coercible_type, schema))
+        .map(|(case_expr, coercible_type)| {
+            coerce_to(*case_expr, coercible_type, schema, rules)
+        })
         .transpose()?
         .map(Box::new);
     let when_then = case
@@ -917,7 +968,7 @@ fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
         .into_iter()
         .map(|(when, then)| {
             let when_type = case_when_coerce_type.as_ref().unwrap_or(&DataType::Boolean);
-            let when = when.cast_to(when_type, schema).map_err(|e| {
+            let when = coerce_to(*when, when_type, schema, rules).map_err(|e| {
                 DataFusionError::Context(
                     format!(
                         "WHEN expressions in CASE couldn't be \
@@ -926,13 +977,13 @@ fn coerce_case_expression(case: Case, schema: &DFSchema) -> Result<Case> {
                     Box::new(e),
                 )
             })?;
-            let then = then.cast_to(&then_else_coerce_type, schema)?;
+            let then = coerce_to(*then, &then_else_coerce_type, schema, rules)?;
             Ok((Box::new(when), Box::new(then)))
         })
         .collect::<Result<Vec<_>>>()?;
     let else_expr = case
         .else_expr
-        .map(|expr| expr.cast_to(&then_else_coerce_type, schema))
+        .map(|expr| coerce_to(*expr, &then_else_coerce_type, schema, rules))
         .transpose()?
         .map(Box::new);
 
@@ -1074,6 +1125,7 @@ mod test {
     use datafusion_expr::expr::{self, InSubquery, Like, ScalarFunction};
     use datafusion_expr::logical_plan::{EmptyRelation, Projection, Sort};
     use datafusion_expr::test::function_stub::avg_udaf;
+    use datafusion_expr::type_coercion::DefaultCoercionRules;
     use datafusion_expr::{
         cast, col, create_udaf, is_true, lit, AccumulatorFactoryFunction, AggregateUDF,
         BinaryExpr, Case, ColumnarValue, Expr, ExprSchemable, Filter, LogicalPlan,
@@ -2017,7 +2069,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema: &schema };
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
         let expr = is_true(lit(12i32).gt(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).gt(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -2028,7 +2080,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema: &schema };
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
         let expr = is_true(lit(12i32).eq(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).eq(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -2039,7 +2091,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema: &schema };
+        let mut rewriter = TypeCoercionRewriter::new(&schema);
         let expr = is_true(lit(12i32).lt(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).lt(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -2152,7 +2204,7 @@ mod test {
             &then_else_common_type,
             &schema,
         );
-        let actual = coerce_case_expression(case, &schema)?;
+        let actual = coerce_case_expression(case, &schema, &DefaultCoercionRules)?;
         assert_eq!(expected, actual);
 
         let case = Case {
@@ -2172,7 +2224,7 @@ mod test {
             &then_else_common_type,
             &schema,
         );
-        let actual = coerce_case_expression(case, &schema)?;
+        let actual = coerce_case_expression(case, &schema, &DefaultCoercionRules)?;
         assert_eq!(expected, actual);
 
         let case = Case {
@@ -2184,7 +2236,8 @@ mod test {
             ],
             else_expr: Some(Box::new(col("string"))),
         };
-        let err = coerce_case_expression(case, &schema).unwrap_err();
+        let err =
+            coerce_case_expression(case, &schema, &DefaultCoercionRules).unwrap_err();
         assert_snapshot!(
             err.strip_backtrace(),
             @"Error during planning: Failed to coerce case (Interval(MonthDayNano)) and when ([Float32, Binary, Utf8]) to common types in CASE WHEN expression"
@@ -2199,7 +2252,8 @@ mod test {
             ],
             else_expr: Some(Box::new(col("timestamp"))),
         };
-        let err = coerce_case_expression(case, &schema).unwrap_err();
+        let err =
+            coerce_case_expression(case, &schema, &DefaultCoercionRules).unwrap_err();
         assert_snapshot!(
             err.strip_backtrace(),
             @"Error during planning: Failed to coerce then ([Date32, Float32, Binary]) and else (Some(Timestamp(Nanosecond, None))) to common types in CASE WHEN expression"
@@ -2219,7 +2273,7 @@ mod test {
             let expected =
                 cast_helper(case.clone(), &$case_when_type, &$then_else_type, &$schema);
 
-            let actual = coerce_case_expression(case, &$schema)?;
+            let actual = coerce_case_expression(case, &$schema, &DefaultCoercionRules)?;
             assert_eq!(expected, actual);
         };
     }
@@ -386,9 +386,7 @@ fn build_join(
                     )))),
                 })
             };
-            let mut expr_rewrite = TypeCoercionRewriter {
-                schema: new_plan.schema(),
-            };
+            let mut expr_rewrite = TypeCoercionRewriter::new(new_plan.schema());
             computation_project_expr
                 .insert(name, computer_expr.rewrite(&mut expr_rewrite).data()?);
         }
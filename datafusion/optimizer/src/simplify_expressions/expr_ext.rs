@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extension methods for simplifying [`Expr`]s.
+
+use std::sync::Arc;
+
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::{plan_err, DFSchema, Result, ScalarValue};
+use datafusion_expr::execution_props::ExecutionProps;
+use datafusion_expr::simplify::SimplifyContext;
+use datafusion_expr::{BinaryExpr, Expr, Operator};
+
+use super::utils::{is_false, is_true};
+use super::ExprSimplifier;
+
+/// Adds methods to [`Expr`] that run the [`ExprSimplifier`] without having to
+/// set up a [`SimplifyContext`] first.
+///
+/// # Example
+/// ```
+/// # use datafusion_common::ScalarValue;
+/// # use datafusion_expr::lit;
+/// # use datafusion_optimizer::simplify_expressions::ExprSimplifyExt;
+/// // 1 + 2
+/// let expr = lit(1i32) + lit(2i32);
+/// assert_eq!(expr.evaluate_scalar().unwrap(), ScalarValue::Int32(Some(3)));
+/// ```
+pub trait ExprSimplifyExt {
+    /// Evaluates an expression that has no column references and no volatile
+    /// functions to a single constant [`ScalarValue`].
+    ///
+    /// Type coercion is applied before evaluating, so `lit(1i32) + lit(2i64)`
+    /// evaluates to `Int64(3)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression references a column, contains a
+    /// volatile function such as `random()`, or can not otherwise be folded
+    /// to a constant (for example, aggregate functions or placeholders).
+    fn evaluate_scalar(&self) -> Result<ScalarValue>;
+
+    /// Removes the boolean constants `true` and `false` from `AND` and `OR`,
+    /// and double negations, without needing a schema.
+    ///
+    /// The rules respect SQL's three-valued logic, where the operands may be
+    /// NULL:
+    ///
+    /// * `A AND true` and `A OR false` are `A`, including when `A` is NULL
+    /// * `A AND false` is `false` and `A OR true` is `true`, even if `A` is
+    ///   NULL
+    /// * `NOT (NOT A)` is `A`
+    ///
+    /// [`ExprSimplifier::simplify`] applies these rules as well, among many
+    /// others that need the types of the columns.
+    fn simplify_boolean_constants(self) -> Expr;
+}
+
+impl ExprSimplifyExt for Expr {
+    fn evaluate_scalar(&self) -> Result<ScalarValue> {
+        if self.any_column_refs() {
+            return plan_err!(
+                "Can not evaluate expression with column references to a scalar: {self}"
+            );
+        }
+        if self.is_volatile() {
+            return plan_err!(
+                "Can not evaluate expression with volatile functions to a scalar: {self}"
+            );
+        }
+
+        let schema = Arc::new(DFSchema::empty());
+        let props = ExecutionProps::new();
+        let info = SimplifyContext::new(&props).with_schema(Arc::clone(&schema));
+        let simplifier = ExprSimplifier::new(info);
+
+        let expr = simplifier.coerce(self.clone(), &schema)?;
+        match simplifier.simplify(expr)?.unalias() {
+            Expr::Literal(value) => Ok(value),
+            other => {
+                plan_err!("Expression could not be evaluated to a scalar: {other}")
+            }
+        }
+    }
+
+    fn simplify_boolean_constants(self) -> Expr {
+        self.transform_up(|expr| {
+            Ok(match expr {
+                // A AND true --> A, false AND A --> false
+                Expr::BinaryExpr(BinaryExpr {
+                    left,
+                    op: Operator::And,
+                    right,
+                }) if is_false(&left) || is_true(&right) => Transformed::yes(*left),
+                // true AND A --> A, A AND false --> false
+                Expr::BinaryExpr(BinaryExpr {
+                    left,
+                    op: Operator::And,
+                    right,
+                }) if is_true(&left) || is_false(&right) => Transformed::yes(*right),
+                // A OR false --> A, true OR A --> true
+                Expr::BinaryExpr(BinaryExpr {
+                    left,
+                    op: Operator::Or,
+                    right,
+                }) if is_true(&left) || is_false(&right) => Transformed::yes(*left),
+                // false OR A --> A, A OR true --> true
+                Expr::BinaryExpr(BinaryExpr {
+                    left,
+                    op: Operator::Or,
+                    right,
+                }) if is_false(&left) || is_true(&right) => Transformed::yes(*right),
+                // NOT (NOT A) --> A
+                Expr::Not(inner) => match *inner {
+                    Expr::Not(inner) => Transformed::yes(*inner),
+                    inner => Transformed::no(Expr::Not(Box::new(inner))),
+                },
+                _ => Transformed::no(expr),
+            })
+        })
+        .data()
+        .expect("transform closure is infallible")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use datafusion_common::assert_contains;
+    use datafusion_expr::{col, lit, placeholder};
+
+    #[test]
+    fn evaluate_scalar() {
+        let expr = lit(1i32) + lit(2i32);
+        assert_eq!(expr.evaluate_scalar().unwrap(), ScalarValue::Int32(Some(3)));
+
+        // types are coerced before evaluating
+        let expr = lit(1i32) + lit(2i64);
+        assert_eq!(expr.evaluate_scalar().unwrap(), ScalarValue::Int64(Some(3)));
+
+        // aliases are ignored
+        let expr = (lit(1i32) + lit(2i32)).alias("three");
+        assert_eq!(expr.evaluate_scalar().unwrap(), ScalarValue::Int32(Some(3)));
+    }
+
+    #[test]
+    fn evaluate_scalar_column() {
+        let err = (col("a") + lit(1i32)).evaluate_scalar().unwrap_err();
+        assert_contains!(err.to_string(), "column references");
+    }
+
+    #[test]
+    fn simplify_boolean_constants() {
+        let a = || col("a");
+        let cases = [
+            (a().and(lit(true)), a()),
+            (lit(true).and(a()), a()),
+            (a().or(lit(false)), a()),
+            (lit(false).or(a()), a()),
+            (a().and(lit(false)), lit(false)),
+            (lit(false).and(a()), lit(false)),
+            (a().or(lit(true)), lit(true)),
+            (lit(true).or(a()), lit(true)),
+            (!!a(), a()),
+            (!!!a(), !a()),
+            // nested constants are removed bottom up
+            ((a().and(lit(true))).or(col("b").and(lit(false))), a()),
+            (!(!a().or(lit(false))), a()),
+        ];
+        for (expr, expected) in cases {
+            assert_eq!(
+                expr.clone().simplify_boolean_constants(),
+                expected,
+                "{expr}"
+            );
+        }
+
+        // a NULL constant is not `true` nor `false`
+        let expr = a().and(lit(ScalarValue::Boolean(None)));
+        assert_eq!(expr.clone().simplify_boolean_constants(), expr);
+    }
+
+    #[test]
+    fn evaluate_scalar_not_constant() {
+        let err = placeholder("$1").evaluate_scalar().unwrap_err();
+        assert_contains!(err.to_string(), "could not be evaluated to a scalar");
+    }
+}
@@ -31,20 +31,30 @@ use datafusion_common::{
     cast::{as_large_list_array, as_list_array},
     tree_node::{Transformed, TransformedResult, TreeNode, TreeNodeRewriter},
 };
-use datafusion_common::{internal_err, DFSchema, DataFusionError, Result, ScalarValue};
+use datafusion_common::{
+    internal_err, stats::Precision, Column, ColumnStatistics, DFSchema, DataFusionError,
+    Result, ScalarValue,
+};
 use datafusion_expr::{
     and, binary::BinaryTypeCoercer, lit, or, BinaryExpr, Case, ColumnarValue, Expr, Like,
     Operator, Volatility, WindowFunctionDefinition,
 };
-use datafusion_expr::{expr::ScalarFunction, interval_arithmetic::NullableInterval};
+use datafusion_expr::{
+    expr::ScalarFunction,
+    interval_arithmetic::{Interval, NullableInterval},
+};
 use datafusion_expr::{
     expr::{InList, InSubquery, WindowFunction},
     utils::{iter_conjunction, iter_conjunction_owned},
 };
-use datafusion_expr::{simplify::ExprSimplifyResult, Cast, TryCast};
+use datafusion_expr::{
+    simplify::ExprSimplifyResult, type_coercion::CoercionRules, Cast, TryCast,
+};
 use datafusion_physical_expr::{create_physical_expr, execution_props::ExecutionProps};
 
 use super::inlist_simplifier::ShortenInListSimplifier;
+use super::normal_form::{BooleanForm, NormalFormRewriter};
+use super::prefix_range::PrefixRangeRewriter;
 use super::utils::*;
 use crate::simplify_expressions::guarantees::GuaranteeRewriter;
 use crate::simplify_expressions::regex::simplify_regex_expr;
@@ -106,6 +116,12 @@ pub struct ExprSimplifier<S> {
     canonicalize: bool,
     /// Maximum number of simplifier cycles
     max_simplifier_cycles: u32,
+    /// Should `starts_with` predicates be rewritten to range predicates?
+    /// Defaults to false
+    prefix_range_rewrite: bool,
+    /// Normal form to rewrite boolean expressions to, if any. Defaults to
+    /// None
+    normal_form: Option<BooleanForm>,
 }
 
 pub const THRESHOLD_INLINE_INLIST: usize = 3;
@@ -123,6 +139,8 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
             guarantees: vec![],
             canonicalize: true,
             max_simplifier_cycles: DEFAULT_MAX_SIMPLIFIER_CYCLES,
+            prefix_range_rewrite: false,
+            normal_form: None,
         }
     }
 
@@ -223,8 +241,56 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
     /// See [Self::simplify] for details and usage examples.
     ///
     pub fn simplify_with_cycle_count_transformed(
+        &self,
+        expr: Expr,
+    ) -> Result<(Transformed<Expr>, u32)> {
+        self.simplify_with_max_cycles(expr, self.max_simplifier_cycles)
+    }
+
+    /// Applies a single simplification cycle to this [`Expr`], returning the
+    /// result and whether anything changed.
+    ///
+    /// This uses the same rules as [Self::simplify], but doesn't repeat them
+    /// until the expression stops changing, which makes it possible to observe
+    /// the intermediate states of a simplification. Note the setting of
+    /// [Self::with_max_cycles] is ignored.
+    ///
+    /// ```rust
+    /// use arrow::datatypes::{DataType, Field, Schema};
+    /// use datafusion_expr::{col, lit};
+    /// use datafusion_common::ToDFSchema;
+    /// use datafusion_expr::execution_props::ExecutionProps;
+    /// use datafusion_expr::simplify::SimplifyContext;
+    /// use datafusion_optimizer::simplify_expressions::ExprSimplifier;
+    ///
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)])
+    ///   .to_dfschema_ref().unwrap();
+    /// let props = ExecutionProps::new();
+    /// let simplifier = ExprSimplifier::new(SimplifyContext::new(&props).with_schema(schema));
+    ///
+    /// // Expression: a IS NOT NULL
+    /// let expr = col("a").is_not_null();
+    ///
+    /// // The first cycle simplifies the expression
+    /// let (expr, changed) = simplifier.simplify_once(expr).unwrap();
+    /// assert_eq!(expr, lit(true));
+    /// assert!(changed);
+    ///
+    /// // The second cycle has nothing left to do
+    /// let (expr, changed) = simplifier.simplify_once(expr).unwrap();
+    /// assert_eq!(expr, lit(true));
+    /// assert!(!changed);
+    /// ```
+    pub fn simplify_once(&self, expr: Expr) -> Result<(Expr, bool)> {
+        let (expr, _) = self.simplify_with_max_cycles(expr, 1)?;
+        Ok((expr.data, expr.transformed))
+    }
+
+    /// Simplifies `expr`, running at most `max_simplifier_cycles` cycles
+    fn simplify_with_max_cycles(
         &self,
         mut expr: Expr,
+        max_simplifier_cycles: u32,
     ) -> Result<(Transformed<Expr>, u32)> {
         let mut simplifier = Simplifier::new(&self.info);
         let mut const_evaluator = ConstEvaluator::try_new(self.info.execution_props())?;
@@ -235,11 +301,21 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
             expr = expr.rewrite(&mut Canonicalizer::new()).data()?
         }
 
+        // `starts_with` is otherwise simplified to `LIKE`, so it is rewritten
+        // before the simplification loop
+        let mut has_transformed = false;
+        if self.prefix_range_rewrite {
+            let Transformed {
+                data, transformed, ..
+            } = expr.rewrite(&mut PrefixRangeRewriter::new(&self.info))?;
+            expr = data;
+            has_transformed = transformed;
+        }
+
         // Evaluating constants can enable new simplifications and
         // simplifications can enable new constant evaluation
         // see `Self::with_max_cycles`
         let mut num_cycles = 0;
-        let mut has_transformed = false;
         loop {
             let Transformed {
                 data, transformed, ..
@@ -251,10 +327,19 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
             num_cycles += 1;
             // Track if any transformation occurred
             has_transformed = has_transformed || transformed;
-            if !transformed || num_cycles >= self.max_simplifier_cycles {
+            if !transformed || num_cycles >= max_simplifier_cycles {
                 break;
             }
         }
+        // normalizing is done after the simplification loop, which would
+        // otherwise factor out the expressions that normalizing duplicates
+        if let Some(normal_form) = self.normal_form {
+            let Transformed {
+                data, transformed, ..
+            } = expr.rewrite(&mut NormalFormRewriter::new(normal_form))?;
+            expr = data;
+            has_transformed = has_transformed || transformed;
+        }
         // shorten inlist should be started after other inlist rules are applied
         expr = expr.rewrite(&mut shorten_in_list_simplifier).data()?;
         Ok((
@@ -269,7 +354,27 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
     /// See the [type coercion module](datafusion_expr::type_coercion)
     /// documentation for more details on type coercion
     pub fn coerce(&self, expr: Expr, schema: &DFSchema) -> Result<Expr> {
-        let mut expr_rewrite = TypeCoercionRewriter { schema };
+        let mut expr_rewrite = TypeCoercionRewriter::new(schema);
+        expr.rewrite(&mut expr_rewrite).data()
+    }
+
+    /// Apply type coercion to an [`Expr`] like [`Self::coerce`], but using
+    /// the provided [`CoercionRules`] for the casts that coercion adds.
+    ///
+    /// For example, rules that return an error when coercing an integer to a
+    /// string make coercing `a = 1` an error if `a` is a string column, rather
+    /// than casting `1` to a string.
+    ///
+    /// [`DefaultCoercionRules`](datafusion_expr::type_coercion::DefaultCoercionRules)
+    /// give the same result as [`Self::coerce`].
+    pub fn coerce_with_rules(
+        &self,
+        expr: Expr,
+        schema: &DFSchema,
+        rules: &dyn CoercionRules,
+    ) -> Result<Expr> {
+        let mut expr_rewrite =
+            TypeCoercionRewriter::new(schema).with_coercion_rules(rules);
         expr.rewrite(&mut expr_rewrite).data()
     }
 
@@ -332,6 +437,61 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
         self
     }
 
+    /// Input statistics about the values of columns.
+    ///
+    /// Only [`Precision::Exact`] minimum, maximum and null count values are
+    /// used, and are converted to guarantees as described in
+    /// [`with_guarantees`](Self::with_guarantees). Statistics that are
+    /// inexact or absent are ignored, so they never change the result of
+    /// an expression. Guarantees derived from columns that may contain nulls
+    /// only simplify expressions whose result does not depend on the nulls.
+    ///
+    /// As with guarantees, the expression should be [coerced](Self::coerce)
+    /// before it is simplified.
+    ///
+    /// ```rust
+    /// use arrow::datatypes::{DataType, Field, Schema};
+    /// use datafusion_expr::{col, lit, Expr};
+    /// use datafusion_common::{Column, ColumnStatistics, ScalarValue, ToDFSchema};
+    /// use datafusion_common::stats::Precision;
+    /// use datafusion_expr::execution_props::ExecutionProps;
+    /// use datafusion_expr::simplify::SimplifyContext;
+    /// use datafusion_optimizer::simplify_expressions::ExprSimplifier;
+    ///
+    /// let schema = Schema::new(vec![Field::new("x", DataType::Int64, true)])
+    ///   .to_dfschema_ref().unwrap();
+    ///
+    /// let props = ExecutionProps::new();
+    /// let context = SimplifyContext::new(&props)
+    ///    .with_schema(schema);
+    ///
+    /// // x ∈ [0, 100] and contains no nulls
+    /// let statistics = ColumnStatistics::new_unknown()
+    ///    .with_min_value(Precision::Exact(ScalarValue::Int64(Some(0))))
+    ///    .with_max_value(Precision::Exact(ScalarValue::Int64(Some(100))))
+    ///    .with_null_count(Precision::Exact(0));
+    /// let simplifier = ExprSimplifier::new(context)
+    ///    .with_statistics(vec![(Column::from_name("x"), statistics)]);
+    ///
+    /// // Expression: x > 1000 is always false
+    /// let expr = col("x").gt(lit(1000_i64));
+    /// let output = simplifier.simplify(expr).unwrap();
+    /// assert_eq!(output, lit(false));
+    /// ```
+    pub fn with_statistics(
+        mut self,
+        statistics: Vec<(Column, ColumnStatistics)>,
+    ) -> Self {
+        let guarantees = statistics.into_iter().filter_map(|(column, stats)| {
+            let expr = Expr::Column(column);
+            let data_type = self.info.get_data_type(&expr).ok()?;
+            let interval = statistics_to_interval(&stats, &data_type)?;
+            Some((expr, interval))
+        });
+        self.guarantees.extend(guarantees);
+        self
+    }
+
     /// Should `Canonicalizer` be applied before simplification?
     ///
     /// If true (the default), the expression will be rewritten to canonical
@@ -442,6 +602,95 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
         self.max_simplifier_cycles = max_simplifier_cycles;
         self
     }
+
+    /// Should `starts_with(col, 'prefix')` be rewritten to a range predicate?
+    ///
+    /// If true, `starts_with(col, 'abc')` is rewritten to
+    /// `col >= 'abc' AND col < 'abd'`, which can be used to prune data that
+    /// is sorted or has min/max statistics on `col`, such as Parquet row
+    /// groups. The rewrite only applies to `Utf8` and `LargeUtf8` columns and
+    /// non empty literal prefixes of the same type. If the prefix consists of
+    /// only [`char::MAX`] there is no upper bound and only `col >= 'prefix'`
+    /// is kept. Other string predicates such as `ends_with` and `contains`
+    /// can't be expressed as ranges and are not changed.
+    ///
+    /// Defaults to false, in which case `starts_with` is simplified to
+    /// `col LIKE 'prefix%'`.
+    pub fn with_prefix_range_rewrite(mut self, prefix_range_rewrite: bool) -> Self {
+        self.prefix_range_rewrite = prefix_range_rewrite;
+        self
+    }
+
+    /// Rewrite boolean expressions to the given [`BooleanForm`] after
+    /// simplifying them.
+    ///
+    /// In conjunctive normal form (CNF), an expression is an `AND` of `OR`s
+    /// that do not contain `AND`, so for example `a AND (b OR c AND d)` is
+    /// rewritten to `a AND (b OR c) AND (b OR d)`. Each conjunct can then be
+    /// used separately, such as to push parts of a predicate down to different
+    /// scans. Disjunctive normal form (DNF) is the opposite, an `OR` of `AND`s.
+    ///
+    /// As normalizing can make an expression exponentially larger,
+    /// expressions that would have more than [`MAX_NORMAL_FORM_CLAUSES`]
+    /// clauses are kept in their original form.
+    ///
+    /// [`MAX_NORMAL_FORM_CLAUSES`]: crate::simplify_expressions::MAX_NORMAL_FORM_CLAUSES
+    ///
+    /// ```rust
+    /// use arrow::datatypes::{DataType, Field, Schema};
+    /// use datafusion_expr::{col, lit};
+    /// use datafusion_common::ToDFSchema;
+    /// use datafusion_expr::execution_props::ExecutionProps;
+    /// use datafusion_expr::simplify::SimplifyContext;
+    /// use datafusion_optimizer::simplify_expressions::{BooleanForm, ExprSimplifier};
+    ///
+    /// let schema = Schema::new(vec![
+    ///   Field::new("a", DataType::Boolean, true),
+    ///   Field::new("b", DataType::Boolean, true),
+    ///   Field::new("c", DataType::Boolean, true),
+    ///   ])
+    ///   .to_dfschema_ref().unwrap();
+    /// let props = ExecutionProps::new();
+    /// let simplifier = ExprSimplifier::new(SimplifyContext::new(&props).with_schema(schema))
+    ///   .with_normal_form(BooleanForm::Dnf);
+    ///
+    /// // a AND (b OR c) --> a AND b OR a AND c
+    /// let expr = col("a").and(col("b").or(col("c")));
+    /// let expected = col("a").and(col("b")).or(col("a").and(col("c")));
+    /// assert_eq!(simplifier.simplify(expr).unwrap(), expected);
+    /// ```
+    pub fn with_normal_form(mut self, normal_form: BooleanForm) -> Self {
+        self.normal_form = Some(normal_form);
+        self
+    }
+}
+
+/// Converts the exact bounds in `stats` to a [`NullableInterval`] of
+/// `data_type` values, returning `None` if there are no exact bounds
+fn statistics_to_interval(
+    stats: &ColumnStatistics,
+    data_type: &DataType,
+) -> Option<NullableInterval> {
+    // An unknown bound is represented as a null value of the column type.
+    // Bounds of another type are not used, as casting them may be lossy.
+    let bound = |value: &Precision<ScalarValue>| match value {
+        Precision::Exact(value) if value.data_type() == *data_type => Some(value.clone()),
+        Precision::Exact(_) => None,
+        _ => ScalarValue::try_from(data_type).ok(),
+    };
+    if !matches!(stats.min_value, Precision::Exact(_))
+        && !matches!(stats.max_value, Precision::Exact(_))
+    {
+        return None;
+    }
+
+    let values =
+        Interval::try_new(bound(&stats.min_value)?, bound(&stats.max_value)?).ok()?;
+    if stats.null_count == Precision::Exact(0) {
+        Some(NullableInterval::NotNull { values })
+    } else {
+        Some(NullableInterval::MaybeNull { values })
+    }
 }
 
 /// Canonicalize any BinaryExprs that are not in canonical form
@@ -1897,6 +2146,15 @@ impl<S: SimplifyInfo> TreeNodeRewriter for Simplifier<'_, S> {
                 }))
             }
 
+            // cast/try_cast(expr as data_type) --> expr
+            // if expr is already of type data_type
+            Expr::Cast(Cast { expr, data_type })
+            | Expr::TryCast(TryCast { expr, data_type })
+                if info.get_data_type(&expr).is_ok_and(|t| t == data_type) =>
+            {
+                Transformed::yes(*expr)
+            }
+
             // no additional rewrites possible
             expr => Transformed::no(expr),
         })
@@ -3385,6 +3643,19 @@ mod tests {
         simplifier.simplify(expr).unwrap()
     }
 
+    fn simplify_with_statistics(
+        expr: Expr,
+        statistics: Vec<(Column, ColumnStatistics)>,
+    ) -> Expr {
+        let schema = expr_test_schema();
+        let execution_props = ExecutionProps::new();
+        let simplifier = ExprSimplifier::new(
+            SimplifyContext::new(&execution_props).with_schema(schema),
+        )
+        .with_statistics(statistics);
+        simplifier.simplify(expr).unwrap()
+    }
+
     fn expr_test_schema() -> DFSchemaRef {
         Arc::new(
             DFSchema::from_unqualified_fields(
@@ -3435,6 +3706,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn simplify_expr_cast_to_same_type() {
+        // c3 is already Int64, so the casts are removed
+        assert_eq!(simplify(cast(col("c3"), DataType::Int64)), col("c3"));
+        assert_eq!(simplify(try_cast(col("c3"), DataType::Int64)), col("c3"));
+        assert_eq!(
+            simplify(cast(cast(col("c3"), DataType::Int64), DataType::Int64)),
+            col("c3")
+        );
+
+        // casts that change the type are kept
+        let expr = cast(col("c3"), DataType::Int32);
+        assert_eq!(simplify(expr.clone()), expr);
+        let expr = cast(col("c4"), DataType::Int64);
+        assert_eq!(simplify(expr.clone()), expr);
+
+        // casts of expressions whose type is unknown are kept
+        let expr = cast(col("c3"), DataType::Int64);
+        let props = ExecutionProps::new();
+        let simplifier = ExprSimplifier::new(SimplifyContext::new(&props));
+        assert_eq!(simplifier.simplify(expr.clone()).unwrap(), expr);
+        let expr = try_cast(col("missing"), DataType::Int64);
+        assert_eq!(simplify(expr.clone()), expr);
+    }
+
     #[test]
     fn simplify_expr_is_not_null() {
         assert_eq!(
@@ -4197,6 +4493,71 @@ mod tests {
         assert_eq!(&output, &expr_x);
     }
 
+    #[test]
+    fn test_simplify_with_statistics() {
+        let c3_stats = |min: Precision<ScalarValue>,
+                        max: Precision<ScalarValue>,
+                        null_count: Precision<usize>| {
+            vec![(
+                Column::from_name("c3"),
+                ColumnStatistics::new_unknown()
+                    .with_min_value(min)
+                    .with_max_value(max)
+                    .with_null_count(null_count),
+            )]
+        };
+        let c3_gt_1000 = col("c3").gt(lit(1000_i64));
+
+        // c3 ∈ [0, 100] without nulls
+        let statistics = c3_stats(
+            Precision::Exact(ScalarValue::Int64(Some(0))),
+            Precision::Exact(ScalarValue::Int64(Some(100))),
+            Precision::Exact(0),
+        );
+        let output = simplify_with_statistics(c3_gt_1000.clone(), statistics.clone());
+        assert_eq!(output, lit(false));
+        let output = simplify_with_statistics(col("c3").is_null(), statistics.clone());
+        assert_eq!(output, lit(false));
+        let output = simplify_with_statistics(col("c3").lt_eq(lit(100_i64)), statistics);
+        assert_eq!(output, lit(true));
+
+        // only the maximum is known
+        let statistics = c3_stats(
+            Precision::Absent,
+            Precision::Exact(ScalarValue::Int64(Some(100))),
+            Precision::Exact(0),
+        );
+        let output = simplify_with_statistics(c3_gt_1000.clone(), statistics);
+        assert_eq!(output, lit(false));
+
+        // c3 may contain nulls, so c3 > 1000 is false or null -> no change
+        let statistics = c3_stats(
+            Precision::Exact(ScalarValue::Int64(Some(0))),
+            Precision::Exact(ScalarValue::Int64(Some(100))),
+            Precision::Absent,
+        );
+        let output = simplify_with_statistics(c3_gt_1000.clone(), statistics);
+        assert_eq!(output, c3_gt_1000);
+
+        // inexact statistics are ignored
+        let statistics = c3_stats(
+            Precision::Inexact(ScalarValue::Int64(Some(0))),
+            Precision::Inexact(ScalarValue::Int64(Some(100))),
+            Precision::Exact(0),
+        );
+        let output = simplify_with_statistics(c3_gt_1000.clone(), statistics);
+        assert_eq!(output, c3_gt_1000);
+
+        // statistics of a different type are ignored
+        let statistics = c3_stats(
+            Precision::Exact(ScalarValue::Float64(Some(0.0))),
+            Precision::Exact(ScalarValue::Float64(Some(100.0))),
+            Precision::Exact(0),
+        );
+        let output = simplify_with_statistics(c3_gt_1000.clone(), statistics);
+        assert_eq!(output, c3_gt_1000);
+    }
+
     #[test]
     fn test_expression_partial_simplify_1() {
         // (1 + 2) + (4 / 0) -> 3 + (4 / 0)
@@ -4251,6 +4612,106 @@ mod tests {
         assert_eq!(num_iter, 2);
     }
 
+    fn simplify_to_normal_form(expr: Expr, normal_form: BooleanForm) -> Expr {
+        let schema = expr_test_schema();
+        let execution_props = ExecutionProps::new();
+        let simplifier = ExprSimplifier::new(
+            SimplifyContext::new(&execution_props).with_schema(schema),
+        )
+        .with_normal_form(normal_form);
+        simplifier.simplify(expr).unwrap()
+    }
+
+    #[test]
+    fn test_simplify_normal_form() {
+        let a = || col("c3").gt(lit(1i64));
+        let b = || col("c2");
+        let c = || col("c1").eq(lit("x"));
+
+        // a AND (b OR c)
+        let expr = a().and(b().or(c()));
+        assert_eq!(
+            simplify_to_normal_form(expr.clone(), BooleanForm::Cnf),
+            expr
+        );
+        assert_eq!(
+            simplify_to_normal_form(expr, BooleanForm::Dnf),
+            a().and(b()).or(a().and(c()))
+        );
+
+        // a OR (b AND NOT (c OR d))
+        let d = || col("c4").eq(lit(5u32));
+        let expr = a().or(b().and(c().or(d()).not()));
+        let not_c = || col("c1").not_eq(lit("x"));
+        let not_d = || col("c4").not_eq(lit(5u32));
+        // --> (a OR b) AND (a OR NOT c) AND (a OR NOT d)
+        assert_eq!(
+            simplify_to_normal_form(expr.clone(), BooleanForm::Cnf),
+            a().or(b()).and(a().or(not_c())).and(a().or(not_d()))
+        );
+        // --> a OR b AND NOT c AND NOT d
+        assert_eq!(
+            simplify_to_normal_form(expr, BooleanForm::Dnf),
+            a().or(b().and(not_c()).and(not_d()))
+        );
+
+        // NOT (a AND b) is already normalized by the simplifier
+        let expr = a().and(b()).not();
+        let expected = col("c3").lt_eq(lit(1i64)).or(b().not());
+        assert_eq!(
+            simplify_to_normal_form(expr.clone(), BooleanForm::Cnf),
+            expected
+        );
+        assert_eq!(simplify_to_normal_form(expr, BooleanForm::Dnf), expected);
+    }
+
+    #[test]
+    fn test_simplify_normal_form_too_large() {
+        // (c3 = 0 AND c4 = 0) OR ... OR (c3 = 7 AND c4 = 7) has 2^8 clauses in
+        // CNF, so it is not normalized
+        let expr = utils::disjunction(
+            (0..8).map(|i| col("c3").eq(lit(i as i64)).and(col("c4").eq(lit(i as u32)))),
+        )
+        .unwrap();
+        assert_eq!(
+            simplify_to_normal_form(expr.clone(), BooleanForm::Cnf),
+            expr
+        );
+    }
+
+    #[test]
+    fn test_simplify_once() {
+        let schema = expr_test_schema();
+        let execution_props = ExecutionProps::new();
+        let simplifier = ExprSimplifier::new(
+            SimplifyContext::new(&execution_props).with_schema(schema),
+        )
+        .with_max_cycles(10);
+
+        // ((c4<1 or c3<2) and c3_non_null<3) and false
+        let expr = col("c4")
+            .lt(lit(1))
+            .or(col("c3").lt(lit(2)))
+            .and(col("c3_non_null").lt(lit(3)))
+            .and(lit(false));
+
+        // applying single cycles until nothing changes reaches the same result
+        // as the full simplifier
+        let expected = simplifier.simplify(expr.clone()).unwrap();
+        let (expr, transformed) = simplifier.simplify_once(expr).unwrap();
+        assert!(transformed);
+        assert_eq!(expr, expected);
+        let (expr, transformed) = simplifier.simplify_once(expr).unwrap();
+        assert!(!transformed);
+        assert_eq!(expr, expected);
+
+        // (true != NULL) OR (5 > 10) needs two cycles
+        let expr = lit(true).not_eq(lit_bool_null()).or(lit(5).gt(lit(10)));
+        let (expr, transformed) = simplifier.simplify_once(expr).unwrap();
+        assert!(transformed);
+        assert_eq!(expr, lit_bool_null());
+    }
+
     fn boolean_test_schema() -> DFSchemaRef {
         Schema::new(vec![
             Field::new("A", DataType::Boolean, false),
@@ -18,9 +18,12 @@
 //! [`SimplifyExpressions`] simplifies expressions in the logical plan,
 //! [`ExprSimplifier`] simplifies individual `Expr`s.
 
+pub mod expr_ext;
 pub mod expr_simplifier;
 mod guarantees;
 mod inlist_simplifier;
+mod normal_form;
+mod prefix_range;
 mod regex;
 pub mod simplify_exprs;
 mod unwrap_cast;
@@ -29,7 +32,9 @@ mod utils;
 // backwards compatibility
 pub use datafusion_expr::simplify::{SimplifyContext, SimplifyInfo};
 
+pub use expr_ext::ExprSimplifyExt;
 pub use expr_simplifier::*;
+pub use normal_form::{BooleanForm, MAX_NORMAL_FORM_CLAUSES};
 pub use simplify_exprs::*;
 
 // Export for test in datafusion/core/tests/optimizer_integration.rs
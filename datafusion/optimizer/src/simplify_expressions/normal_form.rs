@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Simplifier implementation for [`ExprSimplifier::with_normal_form()`]
+//!
+//! [`ExprSimplifier::with_normal_form()`]: crate::simplify_expressions::expr_simplifier::ExprSimplifier::with_normal_form
+
+use datafusion_common::tree_node::{Transformed, TreeNodeRecursion, TreeNodeRewriter};
+use datafusion_common::Result;
+use datafusion_expr::utils::{conjunction, disjunction};
+use datafusion_expr::{binary_expr, BinaryExpr, Expr, Operator};
+
+/// The maximum number of clauses an expression is expanded to by
+/// [`ExprSimplifier::with_normal_form()`]. Expressions that would have more
+/// clauses are left in their original form.
+///
+/// [`ExprSimplifier::with_normal_form()`]: crate::simplify_expressions::expr_simplifier::ExprSimplifier::with_normal_form
+pub const MAX_NORMAL_FORM_CLAUSES: usize = 64;
+
+/// A normal form for boolean expressions, see
+/// [`ExprSimplifier::with_normal_form()`]
+///
+/// [`ExprSimplifier::with_normal_form()`]: crate::simplify_expressions::expr_simplifier::ExprSimplifier::with_normal_form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanForm {
+    /// Conjunctive normal form, an `AND` of `OR`s such as
+    /// `(a OR b) AND (a OR c)`
+    Cnf,
+    /// Disjunctive normal form, an `OR` of `AND`s such as
+    /// `(a AND b) OR (a AND c)`
+    Dnf,
+}
+
+impl BooleanForm {
+    /// The operator joining the clauses, and the operator joining the
+    /// expressions within a clause
+    fn operators(self) -> (Operator, Operator) {
+        match self {
+            BooleanForm::Cnf => (Operator::And, Operator::Or),
+            BooleanForm::Dnf => (Operator::Or, Operator::And),
+        }
+    }
+}
+
+/// Rewrites the outermost `AND`, `OR` and `NOT` expressions to a
+/// [`BooleanForm`].
+///
+/// `NOT` is first pushed down to the operands of `AND` and `OR` with De
+/// Morgan's laws, and then `AND` and `OR` are distributed over each other.
+/// Both hold for SQL's three valued logic, so the result is equivalent even
+/// when operands are NULL.
+pub(super) struct NormalFormRewriter {
+    form: BooleanForm,
+}
+
+impl NormalFormRewriter {
+    pub(super) fn new(form: BooleanForm) -> Self {
+        Self { form }
+    }
+}
+
+impl TreeNodeRewriter for NormalFormRewriter {
+    type Node = Expr;
+
+    fn f_down(&mut self, expr: Expr) -> Result<Transformed<Expr>> {
+        if !is_boolean_operator(&expr) {
+            return Ok(Transformed::no(expr));
+        }
+
+        // The operands of the normalized expression are not rewritten again
+        let normalized = to_normal_form(expr.clone(), self.form);
+        Ok(match normalized {
+            Some(normalized) if normalized != expr => {
+                Transformed::new(normalized, true, TreeNodeRecursion::Jump)
+            }
+            _ => Transformed::new(expr, false, TreeNodeRecursion::Jump),
+        })
+    }
+}
+
+fn is_boolean_operator(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Not(_)
+            | Expr::BinaryExpr(BinaryExpr {
+                op: Operator::And | Operator::Or,
+                ..
+            })
+    )
+}
+
+/// Returns `expr` in `form`, or `None` if it would have more than
+/// [`MAX_NORMAL_FORM_CLAUSES`] clauses
+fn to_normal_form(expr: Expr, form: BooleanForm) -> Option<Expr> {
+    let (outer, inner) = form.operators();
+    let join = |exprs: Vec<Expr>, op| match op {
+        Operator::And => conjunction(exprs),
+        _ => disjunction(exprs),
+    };
+
+    let clauses = clauses(push_down_not(expr, false), form)?
+        .into_iter()
+        .map(|clause| join(clause, inner))
+        .collect::<Option<Vec<_>>>()?;
+    join(clauses, outer)
+}
+
+/// Returns `expr`, negated if `negated` is true, with all `NOT`s pushed down
+/// to the operands of `AND` and `OR`
+fn push_down_not(expr: Expr, negated: bool) -> Expr {
+    match expr {
+        Expr::Not(expr) => push_down_not(*expr, !negated),
+        Expr::BinaryExpr(BinaryExpr {
+            left,
+            op: op @ (Operator::And | Operator::Or),
+            right,
+        }) => {
+            // NOT (a AND b) --> NOT a OR NOT b
+            // NOT (a OR b) --> NOT a AND NOT b
+            let op = match (op, negated) {
+                (Operator::And, true) => Operator::Or,
+                (Operator::Or, true) => Operator::And,
+                (op, _) => op,
+            };
+            binary_expr(
+                push_down_not(*left, negated),
+                op,
+                push_down_not(*right, negated),
+            )
+        }
+        expr if negated => Expr::Not(Box::new(expr)),
+        expr => expr,
+    }
+}
+
+/// Returns the clauses of `expr`, which must not have `NOT`s above `AND` or
+/// `OR`, each of which is a list of the expressions it joins with the inner
+/// operator of `form`. Returns `None` if there are more than
+/// [`MAX_NORMAL_FORM_CLAUSES`] clauses.
+fn clauses(expr: Expr, form: BooleanForm) -> Option<Vec<Vec<Expr>>> {
+    let (outer, inner) = form.operators();
+    match expr {
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) if op == outer => {
+            let mut left = clauses(*left, form)?;
+            left.extend(clauses(*right, form)?);
+            (left.len() <= MAX_NORMAL_FORM_CLAUSES).then_some(left)
+        }
+        // a OR (b AND c) --> (a OR b) AND (a OR c) in CNF, and
+        // a AND (b OR c) --> (a AND b) OR (a AND c) in DNF
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) if op == inner => {
+            let left = clauses(*left, form)?;
+            let right = clauses(*right, form)?;
+            if left.len() * right.len() > MAX_NORMAL_FORM_CLAUSES {
+                return None;
+            }
+            Some(
+                left.iter()
+                    .flat_map(|left| {
+                        right
+                            .iter()
+                            .map(move |right| [left.as_slice(), right].concat())
+                    })
+                    .collect(),
+            )
+        }
+        expr => Some(vec![vec![expr]]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use datafusion_expr::col;
+    use std::ops::Not;
+
+    #[test]
+    fn test_push_down_not() {
+        let (a, b, c) = (col("a"), col("b"), col("c"));
+
+        // NOT (a AND NOT (b OR c)) --> NOT a OR b OR c
+        let expr = a.clone().and(b.clone().or(c.clone()).not()).not();
+        assert_eq!(
+            push_down_not(expr, false),
+            a.clone().not().or(b.clone().or(c.clone()))
+        );
+
+        // NOT NOT a --> a
+        assert_eq!(push_down_not(a.clone().not().not(), false), a);
+    }
+
+    #[test]
+    fn test_clauses_cap() {
+        // (a1 AND b1) OR ... OR (a7 AND b7) has 2^7 CNF clauses
+        let expr =
+            disjunction((0..7).map(|i| col(format!("a{i}")).and(col(format!("b{i}")))))
+                .unwrap();
+        assert_eq!(to_normal_form(expr.clone(), BooleanForm::Cnf), None);
+        // but is already in DNF
+        assert_eq!(to_normal_form(expr.clone(), BooleanForm::Dnf), Some(expr));
+
+        // 2^6 clauses are allowed
+        let expr =
+            disjunction((0..6).map(|i| col(format!("a{i}")).and(col(format!("b{i}")))))
+                .unwrap();
+        let cnf = to_normal_form(expr, BooleanForm::Cnf).unwrap();
+        assert_eq!(
+            clauses(cnf, BooleanForm::Cnf).unwrap().len(),
+            MAX_NORMAL_FORM_CLAUSES
+        );
+    }
+}
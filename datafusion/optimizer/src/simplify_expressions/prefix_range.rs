@@ -0,0 +1,157 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Simplifier implementation for [`ExprSimplifier::with_prefix_range_rewrite()`]
+//!
+//! [`ExprSimplifier::with_prefix_range_rewrite()`]: crate::simplify_expressions::expr_simplifier::ExprSimplifier::with_prefix_range_rewrite
+
+use arrow::datatypes::DataType;
+use datafusion_common::tree_node::{Transformed, TreeNodeRewriter};
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::simplify::SimplifyInfo;
+use datafusion_expr::Expr;
+
+/// Rewrites `starts_with(col, 'prefix')` to a range predicate on `col`.
+///
+/// For example `starts_with(col, 'abc')` is rewritten to
+/// `col >= 'abc' AND col < 'abd'`, which can be used to prune files and row
+/// groups with min/max statistics. Only `Utf8` and `LargeUtf8` columns
+/// compared against a non empty literal prefix of the same type are
+/// rewritten.
+///
+/// See [`ExprSimplifier::with_prefix_range_rewrite()`].
+///
+/// [`ExprSimplifier::with_prefix_range_rewrite()`]: crate::simplify_expressions::expr_simplifier::ExprSimplifier::with_prefix_range_rewrite
+pub(super) struct PrefixRangeRewriter<'a, S> {
+    info: &'a S,
+}
+
+impl<'a, S: SimplifyInfo> PrefixRangeRewriter<'a, S> {
+    pub(super) fn new(info: &'a S) -> Self {
+        Self { info }
+    }
+}
+
+impl<S: SimplifyInfo> TreeNodeRewriter for PrefixRangeRewriter<'_, S> {
+    type Node = Expr;
+
+    fn f_up(&mut self, expr: Expr) -> Result<Transformed<Expr>> {
+        let Expr::ScalarFunction(ScalarFunction { func, args }) = &expr else {
+            return Ok(Transformed::no(expr));
+        };
+        if func.name() != "starts_with" {
+            return Ok(Transformed::no(expr));
+        }
+        let [column @ Expr::Column(_), Expr::Literal(prefix)] = args.as_slice() else {
+            return Ok(Transformed::no(expr));
+        };
+
+        let (prefix, data_type, make_scalar): (_, _, fn(Option<String>) -> ScalarValue) =
+            match prefix {
+                ScalarValue::Utf8(Some(prefix)) => {
+                    (prefix, DataType::Utf8, ScalarValue::Utf8)
+                }
+                ScalarValue::LargeUtf8(Some(prefix)) => {
+                    (prefix, DataType::LargeUtf8, ScalarValue::LargeUtf8)
+                }
+                _ => return Ok(Transformed::no(expr)),
+            };
+        // An empty prefix matches every non null value, so there is no range
+        // to prune with
+        if prefix.is_empty() || self.info.get_data_type(column)? != data_type {
+            return Ok(Transformed::no(expr));
+        }
+
+        let lower = column
+            .clone()
+            .gt_eq(Expr::Literal(make_scalar(Some(prefix.clone()))));
+        let range = match prefix_upper_bound(prefix) {
+            Some(upper) => {
+                lower.and(column.clone().lt(Expr::Literal(make_scalar(Some(upper)))))
+            }
+            // A prefix of only `char::MAX` has no upper bound
+            None => lower,
+        };
+        Ok(Transformed::yes(range))
+    }
+}
+
+/// Returns the smallest string that is larger than every string starting with
+/// `prefix`, or `None` if there is no such string.
+///
+/// Strings are compared by their UTF-8 bytes, which orders them by code
+/// point. The bound is found by incrementing the last code point of `prefix`
+/// that is not [`char::MAX`], skipping the surrogate range, and dropping the
+/// code points after it.
+fn prefix_upper_bound(prefix: &str) -> Option<String> {
+    let mut chars = prefix.chars().collect::<Vec<_>>();
+    while let Some(last) = chars.pop() {
+        let next = match last {
+            // The code point after the surrogate range
+            '\u{D7FF}' => Some('\u{E000}'),
+            _ => char::from_u32(last as u32 + 1),
+        };
+        if let Some(next) = next {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_upper_bound() {
+        let tests = [
+            ("abc", Some("abd")),
+            ("a", Some("b")),
+            ("az", Some("a{")),
+            ("ab\u{7F}", Some("ab\u{80}")),
+            ("\u{FF}", Some("\u{100}")),
+            ("a\u{D7FF}", Some("a\u{E000}")),
+            ("a\u{10FFFF}", Some("b")),
+            ("ab\u{10FFFF}\u{10FFFF}", Some("ac")),
+            ("\u{10FFFF}", None),
+            ("\u{10FFFF}\u{10FFFF}", None),
+            ("", None),
+        ];
+        for (prefix, expected) in tests {
+            assert_eq!(
+                prefix_upper_bound(prefix).as_deref(),
+                expected,
+                "upper bound of {prefix:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_prefix_upper_bound_orders_after_matches() {
+        for prefix in ["abc", "a\u{10FFFF}", "\u{D7FF}", "x\u{FFFF}"] {
+            let upper = prefix_upper_bound(prefix).unwrap();
+            for suffix in ["", "a", "\u{10FFFF}", "\u{10FFFF}\u{10FFFF}"] {
+                let value = format!("{prefix}{suffix}");
+                assert!(value.as_str() >= prefix);
+                assert!(value < upper, "{value:?} should be less than {upper:?}");
+            }
+            assert!(!upper.starts_with(prefix));
+        }
+    }
+}
@@ -130,6 +130,45 @@ impl SimplifyExpressions {
             ))
         };
 
+        if let LogicalPlan::Join(mut join) = plan {
+            // Columns with the same qualified name on both sides of a join can
+            // not be told apart in the merged schema, so simplify each join key
+            // against the schema of its own input to resolve the right types
+            let key_simplifier = |schema: &DFSchemaRef| {
+                ExprSimplifier::new(
+                    SimplifyContext::new(execution_props).with_schema(Arc::clone(schema)),
+                )
+                .with_canonicalize(false)
+            };
+            let left_simplifier = key_simplifier(join.left.schema());
+            let right_simplifier = key_simplifier(join.right.schema());
+
+            let mut transformed = false;
+            join.on = join
+                .on
+                .into_iter()
+                .map(|(left, right)| {
+                    let left = left_simplifier
+                        .simplify_with_cycle_count_transformed(left)?
+                        .0;
+                    let right = right_simplifier
+                        .simplify_with_cycle_count_transformed(right)?
+                        .0;
+                    transformed |= left.transformed || right.transformed;
+                    Ok((left.data, right.data))
+                })
+                .collect::<Result<_>>()?;
+            if let Some(filter) = join.filter.take() {
+                let filter = rewrite_expr(filter)?;
+                transformed |= filter.transformed;
+                join.filter = Some(filter.data);
+            }
+            return Ok(Transformed::new_transformed(
+                LogicalPlan::Join(join),
+                transformed,
+            ));
+        }
+
         plan.map_expressions(|expr| {
             // Preserve the aliasing of grouping sets.
             if let Expr::GroupingSet(_) = &expr {
@@ -806,6 +845,82 @@ mod tests {
         )
     }
 
+    #[test]
+    fn simplify_join_keys_against_their_own_input() -> Result<()> {
+        let t1 = test_table_scan_with_name("t1")?;
+        let t2 = test_table_scan_with_name("t2")?;
+
+        // the casts to UInt32 are redundant and removed, the casts to Int64
+        // are kept, and the filter is simplified like any other expression
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_with_expr_keys(
+                t2,
+                JoinType::Inner,
+                (
+                    vec![
+                        cast(col("t1.a"), DataType::UInt32),
+                        cast(col("t1.b"), DataType::Int64),
+                    ],
+                    vec![
+                        cast(col("t2.a"), DataType::UInt32),
+                        cast(col("t2.b"), DataType::Int64),
+                    ],
+                ),
+                Some(col("t1.c").gt(col("t2.c")).and(lit(true))),
+            )?
+            .build()?;
+
+        assert_optimized_plan_equal!(
+            plan,
+            @ r"
+        Inner Join: t1.a = t2.a, CAST(t1.b AS Int64) = CAST(t2.b AS Int64) Filter: t1.c > t2.c
+          TableScan: t1
+          TableScan: t2
+        "
+        )
+    }
+
+    #[test]
+    fn simplify_equijoin_keys_with_same_qualifier() -> Result<()> {
+        // both inputs have a column `t.a`, of different types
+        let left = table_scan(
+            Some("t"),
+            &Schema::new(vec![Field::new("a", DataType::Int64, false)]),
+            None,
+        )?
+        .build()?;
+        let right = table_scan(
+            Some("t"),
+            &Schema::new(vec![Field::new("a", DataType::UInt32, false)]),
+            None,
+        )?
+        .build()?;
+
+        // the cast on the right key is needed, and the one on the left is not.
+        // An anti join only outputs the left columns, so the qualified names
+        // do not clash in the output schema
+        let plan = LogicalPlanBuilder::from(left)
+            .join_with_expr_keys(
+                right,
+                JoinType::LeftAnti,
+                (
+                    vec![cast(col("a"), DataType::Int64)],
+                    vec![cast(col("a"), DataType::Int64)],
+                ),
+                None,
+            )?
+            .build()?;
+
+        assert_optimized_plan_equal!(
+            plan,
+            @ r"
+        LeftAnti Join: t.a = CAST(t.a AS Int64)
+          TableScan: t
+          TableScan: t
+        "
+        )
+    }
+
     #[test]
     fn simplify_is_not_null() -> Result<()> {
         let table_scan = test_table_scan();
@@ -71,10 +71,18 @@ pub trait PhysicalExpr: Send + Sync + Display + Debug + DynEq + DynHash {
     /// downcast to a specific implementation.
     fn as_any(&self) -> &dyn Any;
     /// Get the data type of this expression, given the schema of the input
+    ///
+    /// The type is computed without evaluating the expression, so it can be
+    /// used to build output schemas or size buffers up front. It always
+    /// matches the [`DataType`] of the [`ColumnarValue`] returned by
+    /// [`Self::evaluate`] for a batch with the same schema.
     fn data_type(&self, input_schema: &Schema) -> Result<DataType> {
         Ok(self.return_field(input_schema)?.data_type().to_owned())
     }
     /// Determine whether this expression is nullable, given the schema of the input
+    ///
+    /// If this returns `false`, the result of [`Self::evaluate`] never
+    /// contains nulls.
     fn nullable(&self, input_schema: &Schema) -> Result<bool> {
         Ok(self.return_field(input_schema)?.is_nullable())
     }
@@ -201,11 +201,14 @@ pub fn create_physical_expr(
             case_insensitive,
         }) => {
             // `\` is the implicit escape, see https://github.com/apache/datafusion/issues/13291
-            if escape_char.unwrap_or('\\') != '\\' {
-                return exec_err!(
-                    "LIKE does not support escape_char other than the backslash (\\)"
-                );
-            }
+            let escaped_pattern;
+            let pattern = match escape_char {
+                Some(escape_char) if *escape_char != '\\' => {
+                    escaped_pattern = with_backslash_escapes(pattern, *escape_char)?;
+                    &escaped_pattern
+                }
+                _ => pattern.as_ref(),
+            };
             let physical_expr =
                 create_physical_expr(expr, input_dfschema, execution_props)?;
             let physical_pattern =
@@ -368,6 +371,58 @@ pub fn create_physical_expr(
     }
 }
 
+/// Rewrites the literal LIKE `pattern` with escape character `escape_char` to
+/// the equivalent pattern escaped with backslashes, which is the only escape
+/// the LIKE kernels support
+fn with_backslash_escapes(pattern: &Expr, escape_char: char) -> Result<Expr> {
+    let escape = |pattern: &Option<String>| {
+        pattern
+            .as_deref()
+            .map(|pattern| replace_escape_char(pattern, escape_char))
+            .transpose()
+    };
+    let pattern = match pattern {
+        Expr::Literal(ScalarValue::Utf8(pattern)) => ScalarValue::Utf8(escape(pattern)?),
+        Expr::Literal(ScalarValue::LargeUtf8(pattern)) => {
+            ScalarValue::LargeUtf8(escape(pattern)?)
+        }
+        Expr::Literal(ScalarValue::Utf8View(pattern)) => {
+            ScalarValue::Utf8View(escape(pattern)?)
+        }
+        _ => {
+            return exec_err!(
+                "LIKE only supports escape_char other than the backslash (\\) for literal patterns"
+            )
+        }
+    };
+    Ok(Expr::Literal(pattern))
+}
+
+fn replace_escape_char(pattern: &str, escape_char: char) -> Result<String> {
+    let mut escaped = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(char) = chars.next() {
+        if char == escape_char {
+            match chars.next() {
+                Some(char @ ('%' | '_' | '\\')) => {
+                    escaped.push('\\');
+                    escaped.push(char);
+                }
+                Some(char) => escaped.push(char),
+                None => {
+                    return exec_err!("LIKE pattern must not end with escape character")
+                }
+            }
+        } else if char == '\\' {
+            // a backslash that is not the escape character matches itself
+            escaped.push_str("\\\\");
+        } else {
+            escaped.push(char);
+        }
+    }
+    Ok(escaped)
+}
+
 /// Create vector of Physical Expression from a vector of logical expression
 pub fn create_physical_exprs<'a, I>(
     exprs: I,
@@ -423,4 +478,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_replace_escape_char() -> Result<()> {
+        #[rustfmt::skip]
+        let cases = [
+            // pattern, escape_char, escaped pattern
+            ("100$%", '$', "100\\%"),
+            ("a$_b%", '$', "a\\_b%"),
+            ("$$", '$', "$"),
+            ("$a", '$', "a"),
+            ("a\\b", '$', "a\\\\b"),
+            ("a#\\b", '#', "a\\\\b"),
+        ];
+        for (pattern, escape_char, expected) in cases {
+            assert_eq!(replace_escape_char(pattern, escape_char)?, expected);
+        }
+
+        let err = replace_escape_char("100$", '$').unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: LIKE pattern must not end with escape character"
+        );
+        Ok(())
+    }
 }
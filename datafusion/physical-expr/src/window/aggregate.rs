@@ -23,13 +23,13 @@ use std::sync::Arc;
 
 use crate::aggregate::AggregateFunctionExpr;
 use crate::window::standard::add_new_ordering_expr_with_partition_by;
-use crate::window::window_expr::AggregateWindowExpr;
+use crate::window::window_expr::{filtered_slice, AggregateWindowExpr};
 use crate::window::{
     PartitionBatches, PartitionWindowAggStates, SlidingAggregateWindowExpr, WindowExpr,
 };
 use crate::{reverse_order_bys, EquivalenceProperties, PhysicalExpr};
 
-use arrow::array::Array;
+use arrow::array::BooleanArray;
 use arrow::record_batch::RecordBatch;
 use arrow::{array::ArrayRef, datatypes::Field};
 use datafusion_common::{DataFusionError, Result, ScalarValue};
@@ -45,6 +45,7 @@ pub struct PlainAggregateWindowExpr {
     partition_by: Vec<Arc<dyn PhysicalExpr>>,
     order_by: LexOrdering,
     window_frame: Arc<WindowFrame>,
+    filter: Option<Arc<dyn PhysicalExpr>>,
 }
 
 impl PlainAggregateWindowExpr {
@@ -60,14 +61,27 @@ impl PlainAggregateWindowExpr {
             partition_by: partition_by.to_vec(),
             order_by: order_by.clone(),
             window_frame,
+            filter: None,
         }
     }
 
+    /// Only accumulate the rows for which `filter` is true, as for a
+    /// `FILTER (WHERE ...)` clause
+    pub fn with_filter(mut self, filter: Option<Arc<dyn PhysicalExpr>>) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Get aggregate expr of AggregateWindowExpr
     pub fn get_aggregate_expr(&self) -> &AggregateFunctionExpr {
         &self.aggregate
     }
 
+    /// Get the filter of the `FILTER (WHERE ...)` clause, if any
+    pub fn get_filter(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        self.filter.as_ref()
+    }
+
     pub fn add_equal_orderings(
         &self,
         eq_properties: &mut EquivalenceProperties,
@@ -103,8 +117,23 @@ impl WindowExpr for PlainAggregateWindowExpr {
         self.aggregate.name()
     }
 
+    /// Returns the arguments of the aggregate followed by the filter, if any
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
-        self.aggregate.expressions()
+        let mut exprs = self.aggregate.expressions();
+        exprs.extend(self.filter.iter().cloned());
+        exprs
+    }
+
+    fn evaluate_args(&self, batch: &RecordBatch) -> Result<Vec<ArrayRef>> {
+        // the filter is evaluated separately, see `filter_expr`
+        self.aggregate
+            .expressions()
+            .iter()
+            .map(|e| {
+                e.evaluate(batch)
+                    .and_then(|v| v.into_array(batch.num_rows()))
+            })
+            .collect()
     }
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ArrayRef> {
@@ -153,19 +182,25 @@ impl WindowExpr for PlainAggregateWindowExpr {
         self.aggregate.reverse_expr().map(|reverse_expr| {
             let reverse_window_frame = self.window_frame.reverse();
             if reverse_window_frame.is_ever_expanding() {
-                Arc::new(PlainAggregateWindowExpr::new(
-                    Arc::new(reverse_expr),
-                    &self.partition_by.clone(),
-                    reverse_order_bys(self.order_by.as_ref()).as_ref(),
-                    Arc::new(self.window_frame.reverse()),
-                )) as _
+                Arc::new(
+                    PlainAggregateWindowExpr::new(
+                        Arc::new(reverse_expr),
+                        &self.partition_by.clone(),
+                        reverse_order_bys(self.order_by.as_ref()).as_ref(),
+                        Arc::new(self.window_frame.reverse()),
+                    )
+                    .with_filter(self.filter.clone()),
+                ) as _
             } else {
-                Arc::new(SlidingAggregateWindowExpr::new(
-                    Arc::new(reverse_expr),
-                    &self.partition_by.clone(),
-                    reverse_order_bys(self.order_by.as_ref()).as_ref(),
-                    Arc::new(self.window_frame.reverse()),
-                )) as _
+                Arc::new(
+                    SlidingAggregateWindowExpr::new(
+                        Arc::new(reverse_expr),
+                        &self.partition_by.clone(),
+                        reverse_order_bys(self.order_by.as_ref()).as_ref(),
+                        Arc::new(self.window_frame.reverse()),
+                    )
+                    .with_filter(self.filter.clone()),
+                ) as _
             }
         })
     }
@@ -176,6 +211,10 @@ impl WindowExpr for PlainAggregateWindowExpr {
 }
 
 impl AggregateWindowExpr for PlainAggregateWindowExpr {
+    fn filter_expr(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        self.filter.as_ref()
+    }
+
     fn get_accumulator(&self) -> Result<Box<dyn Accumulator>> {
         self.aggregate.create_accumulator()
     }
@@ -190,6 +229,7 @@ impl AggregateWindowExpr for PlainAggregateWindowExpr {
         last_range: &Range<usize>,
         cur_range: &Range<usize>,
         value_slice: &[ArrayRef],
+        filter_mask: Option<&BooleanArray>,
         accumulator: &mut Box<dyn Accumulator>,
     ) -> Result<ScalarValue> {
         if cur_range.start == cur_range.end {
@@ -203,10 +243,12 @@ impl AggregateWindowExpr for PlainAggregateWindowExpr {
             // same point (i.e. the beginning of the table/frame). Hence, we
             // do not call `retract_batch`.
             if update_bound > 0 {
-                let update: Vec<ArrayRef> = value_slice
-                    .iter()
-                    .map(|v| v.slice(last_range.end, update_bound))
-                    .collect();
+                let update = filtered_slice(
+                    value_slice,
+                    filter_mask,
+                    last_range.end,
+                    update_bound,
+                )?;
                 accumulator.update_batch(&update)?
             }
             accumulator.evaluate()
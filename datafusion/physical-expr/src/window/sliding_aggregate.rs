@@ -22,13 +22,13 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use crate::aggregate::AggregateFunctionExpr;
-use crate::window::window_expr::AggregateWindowExpr;
+use crate::window::window_expr::{filtered_slice, AggregateWindowExpr};
 use crate::window::{
     PartitionBatches, PartitionWindowAggStates, PlainAggregateWindowExpr, WindowExpr,
 };
 use crate::{expressions::PhysicalSortExpr, reverse_order_bys, PhysicalExpr};
 
-use arrow::array::{Array, ArrayRef};
+use arrow::array::{ArrayRef, BooleanArray};
 use arrow::datatypes::Field;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::{Result, ScalarValue};
@@ -45,6 +45,7 @@ pub struct SlidingAggregateWindowExpr {
     partition_by: Vec<Arc<dyn PhysicalExpr>>,
     order_by: LexOrdering,
     window_frame: Arc<WindowFrame>,
+    filter: Option<Arc<dyn PhysicalExpr>>,
 }
 
 impl SlidingAggregateWindowExpr {
@@ -60,13 +61,26 @@ impl SlidingAggregateWindowExpr {
             partition_by: partition_by.to_vec(),
             order_by: order_by.clone(),
             window_frame,
+            filter: None,
         }
     }
 
+    /// Only accumulate the rows for which `filter` is true, as for a
+    /// `FILTER (WHERE ...)` clause
+    pub fn with_filter(mut self, filter: Option<Arc<dyn PhysicalExpr>>) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Get the [AggregateFunctionExpr] of this object.
     pub fn get_aggregate_expr(&self) -> &AggregateFunctionExpr {
         &self.aggregate
     }
+
+    /// Get the filter of the `FILTER (WHERE ...)` clause, if any
+    pub fn get_filter(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        self.filter.as_ref()
+    }
 }
 
 /// Incrementally update window function using the fact that batch is
@@ -88,8 +102,23 @@ impl WindowExpr for SlidingAggregateWindowExpr {
         self.aggregate.name()
     }
 
+    /// Returns the arguments of the aggregate followed by the filter, if any
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
-        self.aggregate.expressions()
+        let mut exprs = self.aggregate.expressions();
+        exprs.extend(self.filter.iter().cloned());
+        exprs
+    }
+
+    fn evaluate_args(&self, batch: &RecordBatch) -> Result<Vec<ArrayRef>> {
+        // the filter is evaluated separately, see `filter_expr`
+        self.aggregate
+            .expressions()
+            .iter()
+            .map(|e| {
+                e.evaluate(batch)
+                    .and_then(|v| v.into_array(batch.num_rows()))
+            })
+            .collect()
     }
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ArrayRef> {
@@ -120,19 +149,25 @@ impl WindowExpr for SlidingAggregateWindowExpr {
         self.aggregate.reverse_expr().map(|reverse_expr| {
             let reverse_window_frame = self.window_frame.reverse();
             if reverse_window_frame.is_ever_expanding() {
-                Arc::new(PlainAggregateWindowExpr::new(
-                    Arc::new(reverse_expr),
-                    &self.partition_by.clone(),
-                    reverse_order_bys(self.order_by.as_ref()).as_ref(),
-                    Arc::new(self.window_frame.reverse()),
-                )) as _
+                Arc::new(
+                    PlainAggregateWindowExpr::new(
+                        Arc::new(reverse_expr),
+                        &self.partition_by.clone(),
+                        reverse_order_bys(self.order_by.as_ref()).as_ref(),
+                        Arc::new(self.window_frame.reverse()),
+                    )
+                    .with_filter(self.filter.clone()),
+                ) as _
             } else {
-                Arc::new(SlidingAggregateWindowExpr::new(
-                    Arc::new(reverse_expr),
-                    &self.partition_by.clone(),
-                    reverse_order_bys(self.order_by.as_ref()).as_ref(),
-                    Arc::new(self.window_frame.reverse()),
-                )) as _
+                Arc::new(
+                    SlidingAggregateWindowExpr::new(
+                        Arc::new(reverse_expr),
+                        &self.partition_by.clone(),
+                        reverse_order_bys(self.order_by.as_ref()).as_ref(),
+                        Arc::new(self.window_frame.reverse()),
+                    )
+                    .with_filter(self.filter.clone()),
+                ) as _
             }
         })
     }
@@ -143,12 +178,18 @@ impl WindowExpr for SlidingAggregateWindowExpr {
 
     fn with_new_expressions(
         &self,
-        args: Vec<Arc<dyn PhysicalExpr>>,
+        mut args: Vec<Arc<dyn PhysicalExpr>>,
         partition_bys: Vec<Arc<dyn PhysicalExpr>>,
         order_by_exprs: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Option<Arc<dyn WindowExpr>> {
         debug_assert_eq!(self.order_by.len(), order_by_exprs.len());
 
+        // the filter, if any, follows the arguments, see `expressions`
+        let filter = match self.filter {
+            Some(_) => Some(args.pop()?),
+            None => None,
+        };
+
         let new_order_by = self
             .order_by
             .iter()
@@ -166,11 +207,16 @@ impl WindowExpr for SlidingAggregateWindowExpr {
             partition_by: partition_bys,
             order_by: new_order_by,
             window_frame: Arc::clone(&self.window_frame),
+            filter,
         }))
     }
 }
 
 impl AggregateWindowExpr for SlidingAggregateWindowExpr {
+    fn filter_expr(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        self.filter.as_ref()
+    }
+
     fn get_accumulator(&self) -> Result<Box<dyn Accumulator>> {
         self.aggregate.create_sliding_accumulator()
     }
@@ -182,6 +228,7 @@ impl AggregateWindowExpr for SlidingAggregateWindowExpr {
         last_range: &Range<usize>,
         cur_range: &Range<usize>,
         value_slice: &[ArrayRef],
+        filter_mask: Option<&BooleanArray>,
         accumulator: &mut Box<dyn Accumulator>,
     ) -> Result<ScalarValue> {
         if cur_range.start == cur_range.end {
@@ -191,20 +238,24 @@ impl AggregateWindowExpr for SlidingAggregateWindowExpr {
             // Accumulate any new rows that have entered the window:
             let update_bound = cur_range.end - last_range.end;
             if update_bound > 0 {
-                let update: Vec<ArrayRef> = value_slice
-                    .iter()
-                    .map(|v| v.slice(last_range.end, update_bound))
-                    .collect();
+                let update = filtered_slice(
+                    value_slice,
+                    filter_mask,
+                    last_range.end,
+                    update_bound,
+                )?;
                 accumulator.update_batch(&update)?
             }
 
             // Remove rows that have now left the window:
             let retract_bound = cur_range.start - last_range.start;
             if retract_bound > 0 {
-                let retract: Vec<ArrayRef> = value_slice
-                    .iter()
-                    .map(|v| v.slice(last_range.start, retract_bound))
-                    .collect();
+                let retract = filtered_slice(
+                    value_slice,
+                    filter_mask,
+                    last_range.start,
+                    retract_bound,
+                )?;
                 accumulator.retract_batch(&retract)?
             }
             accumulator.evaluate()
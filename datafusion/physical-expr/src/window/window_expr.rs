@@ -22,11 +22,13 @@ use std::sync::Arc;
 
 use crate::{LexOrdering, PhysicalExpr};
 
-use arrow::array::{new_empty_array, Array, ArrayRef};
+use arrow::array::{new_empty_array, Array, ArrayRef, BooleanArray};
+use arrow::compute::filter;
 use arrow::compute::kernels::sort::SortColumn;
 use arrow::compute::SortOptions;
 use arrow::datatypes::Field;
 use arrow::record_batch::RecordBatch;
+use datafusion_common::cast::as_boolean_array;
 use datafusion_common::utils::compare_rows;
 use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::window_state::{
@@ -176,13 +178,21 @@ pub trait AggregateWindowExpr: WindowExpr {
     /// (non-sliding) expressions will return sliding (normal) accumulators.
     fn get_accumulator(&self) -> Result<Box<dyn Accumulator>>;
 
+    /// Returns the `FILTER (WHERE ...)` expression, if any. Rows for which
+    /// it is not true are not accumulated, but still produce an output row.
+    fn filter_expr(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        None
+    }
+
     /// Given current range and the last range, calculates the accumulator
-    /// result for the range of interest.
+    /// result for the range of interest. Only rows selected by `filter_mask`,
+    /// if any, are accumulated.
     fn get_aggregate_result_inside_range(
         &self,
         last_range: &Range<usize>,
         cur_range: &Range<usize>,
         value_slice: &[ArrayRef],
+        filter_mask: Option<&BooleanArray>,
         accumulator: &mut Box<dyn Accumulator>,
     ) -> Result<ScalarValue>;
 
@@ -272,6 +282,15 @@ pub trait AggregateWindowExpr: WindowExpr {
         not_end: bool,
     ) -> Result<ArrayRef> {
         let values = self.evaluate_args(record_batch)?;
+        let filter_mask = match self.filter_expr() {
+            Some(filter) => {
+                let mask = filter
+                    .evaluate(record_batch)?
+                    .into_array(record_batch.num_rows())?;
+                Some(as_boolean_array(&mask)?.clone())
+            }
+            None => None,
+        };
         let order_bys = get_orderby_values(self.order_by_columns(record_batch)?);
 
         let most_recent_row_order_bys = most_recent_row
@@ -305,6 +324,7 @@ pub trait AggregateWindowExpr: WindowExpr {
                 last_range,
                 &cur_range,
                 &values,
+                filter_mask.as_ref(),
                 accumulator,
             )?;
             // Update last range
@@ -323,6 +343,26 @@ pub trait AggregateWindowExpr: WindowExpr {
     }
 }
 
+/// Slices `len` rows starting at `offset` out of each of `values`, keeping
+/// only the rows selected by `filter_mask`, if there is one.
+pub(crate) fn filtered_slice(
+    values: &[ArrayRef],
+    filter_mask: Option<&BooleanArray>,
+    offset: usize,
+    len: usize,
+) -> Result<Vec<ArrayRef>> {
+    let values = values.iter().map(|v| v.slice(offset, len));
+    match filter_mask {
+        Some(mask) => {
+            let mask = mask.slice(offset, len);
+            values
+                .map(|v| filter(&v, &mask).map_err(Into::into))
+                .collect()
+        }
+        None => Ok(values.collect()),
+    }
+}
+
 /// Determines whether the end bound calculation for a window frame context is
 /// safe, meaning that the end bound stays the same, regardless of future data,
 /// based on the current sort expressions and ORDER BY columns. This function
@@ -37,7 +37,7 @@ use arrow::compute::kernels::zip::zip;
 use arrow::compute::{cast, is_not_null, kernels, sum};
 use arrow::datatypes::{DataType, Int64Type, Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
-use arrow_ord::cmp::lt;
+use arrow_ord::cmp::{lt, not_distinct};
 use datafusion_common::{
     exec_datafusion_err, exec_err, internal_err, HashMap, HashSet, Result, UnnestOptions,
 };
@@ -681,21 +681,29 @@ fn build_batch(
 /// For example if we have the following two list arrays:
 ///
 /// ```ignore
-/// l1: [1, 2, 3], null, [], [3]
-/// l2: [4,5], [], null, [6, 7]
+/// l1: [1, 2, 3], null, [], [3], null
+/// l2: [4,5], null, [], [6, 7], []
 /// ```
 ///
 /// If `preserve_nulls` is false, the longest length array will be:
 ///
 /// ```ignore
-/// longest_length: [3, 0, 0, 2]
+/// longest_length: [3, 0, 0, 2, 0]
 /// ```
 ///
 /// whereas if `preserve_nulls` is true, the longest length array will be:
 ///
 ///
 /// ```ignore
-/// longest_length: [3, 1, 1, 2]
+/// longest_length: [3, 1, 0, 2, 1]
+/// ```
+///
+/// Empty lists are treated like lists with a single null if
+/// `preserve_empty_lists` is true, so if `preserve_nulls` is false the longest
+/// length array will then be:
+///
+/// ```ignore
+/// longest_length: [3, 0, 1, 2, 1]
 /// ```
 ///
 fn find_longest_length(
@@ -708,12 +716,19 @@ fn find_longest_length(
     } else {
         Scalar::new(Int64Array::from_value(0, 1))
     };
+    let one = Scalar::new(Int64Array::from_value(1, 1));
+    let zero = Scalar::new(Int64Array::from_value(0, 1));
     let list_lengths: Vec<ArrayRef> = list_arrays
         .iter()
         .map(|list_array| {
             let mut length_array = length(list_array)?;
             // Make sure length arrays have the same type. Int64 is the most general one.
             length_array = cast(&length_array, &DataType::Int64)?;
+            if options.preserve_empty_lists() {
+                // NULL lists have a NULL length, which is distinct from zero
+                let is_empty = not_distinct(&length_array, &zero)?;
+                length_array = zip(&is_empty, &one, &length_array)?;
+            }
             length_array =
                 zip(&is_not_null(&length_array)?, &length_array, &null_length)?;
             Ok(length_array)
@@ -1139,10 +1154,7 @@ mod tests {
             &out_schema,
             list_type_columns.as_ref(),
             &HashSet::default(),
-            &UnnestOptions {
-                preserve_nulls: true,
-                recursions: vec![],
-            },
+            &UnnestOptions::new(),
         )?
         .unwrap();
 
@@ -1226,10 +1238,7 @@ mod tests {
         preserve_nulls: bool,
         expected: Vec<i64>,
     ) -> Result<()> {
-        let options = UnnestOptions {
-            preserve_nulls,
-            recursions: vec![],
-        };
+        let options = UnnestOptions::new().with_preserve_nulls(preserve_nulls);
         let longest_length = find_longest_length(list_arrays, &options)?;
         let expected_array = Int64Array::from(expected);
         assert_eq!(
@@ -1274,6 +1283,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_longest_list_length_preserve_empty_lists() -> Result<()> {
+        //  [A, B, C], [], NULL, [D], NULL, [NULL, F]
+        let list_array = Arc::new(make_generic_array::<i32>()) as ArrayRef;
+        for (preserve_nulls, expected) in [
+            (false, vec![3, 1, 0, 1, 0, 2]),
+            (true, vec![3, 1, 1, 1, 1, 2]),
+        ] {
+            let options = UnnestOptions::new()
+                .with_preserve_nulls(preserve_nulls)
+                .with_preserve_empty_lists(true);
+            let longest_length =
+                find_longest_length(&[Arc::clone(&list_array)], &options)?;
+            assert_eq!(
+                longest_length.as_primitive::<Int64Type>(),
+                &Int64Array::from(expected)
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_create_take_indices() -> Result<()> {
         let length_array = Int64Array::from(vec![2, 3, 1]);
@@ -32,7 +32,7 @@ use crate::{
 
 use arrow::datatypes::{Field, Schema, SchemaRef};
 use arrow_schema::SortOptions;
-use datafusion_common::{exec_err, Result};
+use datafusion_common::{exec_err, plan_err, Result};
 use datafusion_expr::{
     PartitionEvaluator, ReversedUDWF, SetMonotonicity, WindowFrame,
     WindowFunctionDefinition, WindowUDF,
@@ -100,6 +100,7 @@ pub fn create_window_expr(
     window_frame: Arc<WindowFrame>,
     input_schema: &Schema,
     ignore_nulls: bool,
+    filter: Option<Arc<dyn PhysicalExpr>>,
 ) -> Result<Arc<dyn WindowExpr>> {
     Ok(match fun {
         WindowFunctionDefinition::AggregateUDF(fun) => {
@@ -114,8 +115,15 @@ pub fn create_window_expr(
                 order_by,
                 window_frame,
                 aggregate,
+                filter,
             )
         }
+        WindowFunctionDefinition::WindowUDF(fun) if filter.is_some() => {
+            return plan_err!(
+                "FILTER is not supported for non-aggregate window function {}",
+                fun.name()
+            );
+        }
         WindowFunctionDefinition::WindowUDF(fun) => Arc::new(StandardWindowExpr::new(
             create_udwf_window_expr(fun, args, input_schema, name, ignore_nulls)?,
             partition_by,
@@ -131,24 +139,31 @@ fn window_expr_from_aggregate_expr(
     order_by: &LexOrdering,
     window_frame: Arc<WindowFrame>,
     aggregate: Arc<AggregateFunctionExpr>,
+    filter: Option<Arc<dyn PhysicalExpr>>,
 ) -> Arc<dyn WindowExpr> {
     // Is there a potentially unlimited sized window frame?
     let unbounded_window = window_frame.is_ever_expanding();
 
     if !unbounded_window {
-        Arc::new(SlidingAggregateWindowExpr::new(
-            aggregate,
-            partition_by,
-            order_by,
-            window_frame,
-        ))
+        Arc::new(
+            SlidingAggregateWindowExpr::new(
+                aggregate,
+                partition_by,
+                order_by,
+                window_frame,
+            )
+            .with_filter(filter),
+        )
     } else {
-        Arc::new(PlainAggregateWindowExpr::new(
-            aggregate,
-            partition_by,
-            order_by,
-            window_frame,
-        ))
+        Arc::new(
+            PlainAggregateWindowExpr::new(
+                aggregate,
+                partition_by,
+                order_by,
+                window_frame,
+            )
+            .with_filter(filter),
+        )
     }
 }
 
@@ -636,9 +651,13 @@ mod tests {
     use crate::test::assert_is_pending;
     use crate::test::exec::{assert_strong_count_converges_to_zero, BlockingExec};
 
+    use arrow::array::{BooleanArray, Int32Array, Int64Array};
     use arrow::compute::SortOptions;
+    use arrow::record_batch::RecordBatch;
     use arrow_schema::DataType;
+    use datafusion_common::ScalarValue;
     use datafusion_execution::TaskContext;
+    use datafusion_expr::{WindowFrameBound, WindowFrameUnits};
 
     use datafusion_functions_aggregate::count::count_udaf;
     use futures::FutureExt;
@@ -772,6 +791,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_aggregate_window_expr_filter_expressions() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Boolean, true),
+        ]));
+        let count_a_filter_b = |window_frame| {
+            create_window_expr(
+                &WindowFunctionDefinition::AggregateUDF(count_udaf()),
+                "count".to_owned(),
+                &[col("a", &schema)?],
+                &[],
+                &LexOrdering::default(),
+                Arc::new(window_frame),
+                schema.as_ref(),
+                false,
+                Some(col("b", &schema)?),
+            )
+        };
+
+        // the filter follows the arguments
+        let plain = count_a_filter_b(WindowFrame::new(None))?;
+        assert!(plain.as_any().is::<PlainAggregateWindowExpr>());
+        assert_eq!(
+            plain.expressions(),
+            vec![col("a", &schema)?, col("b", &schema)?]
+        );
+
+        let sliding = count_a_filter_b(WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            WindowFrameBound::CurrentRow,
+        ))?;
+        assert!(sliding.as_any().is::<SlidingAggregateWindowExpr>());
+        assert_eq!(
+            sliding.expressions(),
+            vec![col("a", &schema)?, col("b", &schema)?]
+        );
+
+        // the filter is not passed to the accumulator as an argument
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+                Arc::new(BooleanArray::from(vec![true, false, true, true])),
+            ],
+        )?;
+        assert_eq!(
+            sliding.evaluate(&batch)?.as_ref(),
+            &Int64Array::from(vec![1, 1, 1, 2])
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_drop_cancel() -> Result<()> {
         let task_ctx = Arc::new(TaskContext::default());
@@ -790,6 +864,7 @@ mod tests {
                 Arc::new(WindowFrame::new(None)),
                 schema.as_ref(),
                 false,
+                None,
             )?],
             blocking_exec,
             false,
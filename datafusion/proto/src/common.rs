@@ -15,7 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
+use datafusion::functions::core::greatest::GreatestFunc;
+use datafusion::functions::core::least::LeastFunc;
+use datafusion::functions_aggregate::sum::{OverflowMode, Sum};
 use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_expr::{AggregateUDF, ScalarUDF};
+
+use crate::protobuf;
 
 pub(crate) fn str_to_byte(s: &String, description: &str) -> Result<u8> {
     if s.len() != 1 {
@@ -36,6 +44,73 @@ pub(crate) fn byte_to_string(b: u8, description: &str) -> Result<String> {
     Ok(b.to_owned())
 }
 
+/// Returns true if `func` is `greatest` or `least` configured to return NULL
+/// if any argument is NULL
+pub(crate) fn propagates_nulls(func: &ScalarUDF) -> bool {
+    let inner = func.inner().as_any();
+    if let Some(greatest) = inner.downcast_ref::<GreatestFunc>() {
+        greatest.propagate_nulls()
+    } else if let Some(least) = inner.downcast_ref::<LeastFunc>() {
+        least.propagate_nulls()
+    } else {
+        false
+    }
+}
+
+/// Returns `func`, which must be `greatest` or `least`, configured to return
+/// NULL if any argument is NULL
+pub(crate) fn with_propagate_nulls(func: Arc<ScalarUDF>) -> Result<Arc<ScalarUDF>> {
+    let inner = func.inner().as_any();
+    if inner.is::<GreatestFunc>() {
+        Ok(Arc::new(ScalarUDF::new_from_impl(
+            GreatestFunc::new().with_propagate_nulls(true),
+        )))
+    } else if inner.is::<LeastFunc>() {
+        Ok(Arc::new(ScalarUDF::new_from_impl(
+            LeastFunc::new().with_propagate_nulls(true),
+        )))
+    } else {
+        internal_err!("{} can not propagate nulls", func.name())
+    }
+}
+
+/// Returns how `func` handles a sum that overflows, which only `sum` can be
+/// configured to change
+pub(crate) fn sum_overflow(func: &AggregateUDF) -> protobuf::SumOverflowMode {
+    match func
+        .inner()
+        .as_any()
+        .downcast_ref::<Sum>()
+        .map(Sum::overflow)
+    {
+        Some(OverflowMode::Checked) => protobuf::SumOverflowMode::Checked,
+        Some(OverflowMode::Saturating) => protobuf::SumOverflowMode::Saturating,
+        Some(OverflowMode::Wrapping) | None => protobuf::SumOverflowMode::Wrapping,
+    }
+}
+
+/// Returns `func` configured to handle a sum that overflows as the
+/// [`protobuf::SumOverflowMode`] `overflow` specifies, which must be the
+/// default unless `func` is `sum`
+pub(crate) fn with_sum_overflow(
+    func: Arc<AggregateUDF>,
+    overflow: i32,
+) -> Result<Arc<AggregateUDF>> {
+    let overflow = match protobuf::SumOverflowMode::try_from(overflow) {
+        Ok(protobuf::SumOverflowMode::Wrapping) => return Ok(func),
+        Ok(protobuf::SumOverflowMode::Checked) => OverflowMode::Checked,
+        Ok(protobuf::SumOverflowMode::Saturating) => OverflowMode::Saturating,
+        Err(_) => return internal_err!("Unknown sum overflow mode {overflow}"),
+    };
+    if func.inner().as_any().is::<Sum>() {
+        Ok(Arc::new(AggregateUDF::new_from_impl(
+            Sum::new().with_overflow(overflow),
+        )))
+    } else {
+        internal_err!("{} has no overflow mode", func.name())
+    }
+}
+
 #[macro_export]
 macro_rules! convert_required {
     ($PB:expr) => {{
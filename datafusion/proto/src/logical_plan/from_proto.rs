@@ -25,6 +25,7 @@ use datafusion_common::{
 use datafusion_expr::dml::InsertOp;
 use datafusion_expr::expr::{Alias, Placeholder, Sort};
 use datafusion_expr::expr::{Unnest, WildcardOptions};
+use datafusion_expr::sqlparser::ast::NullTreatment;
 use datafusion_expr::{
     expr::{self, InList, WindowFunction},
     logical_plan::{PlanType, StringifiedPlan},
@@ -36,6 +37,7 @@ use datafusion_expr::{
 use datafusion_expr::{ExprFunctionExt, WriteOp};
 use datafusion_proto_common::{from_proto::FromOptionalField, FromProtoError as Error};
 
+use crate::common::{with_propagate_nulls, with_sum_overflow};
 use crate::protobuf::plan_type::PlanTypeEnum::{
     FinalPhysicalPlanWithSchema, InitialPhysicalPlanWithSchema,
 };
@@ -55,18 +57,19 @@ use super::LogicalExtensionCodec;
 
 impl From<&protobuf::UnnestOptions> for UnnestOptions {
     fn from(opts: &protobuf::UnnestOptions) -> Self {
-        Self {
-            preserve_nulls: opts.preserve_nulls,
-            recursions: opts
-                .recursions
-                .iter()
-                .map(|r| RecursionUnnestOption {
-                    input_column: r.input_column.as_ref().unwrap().into(),
-                    output_column: r.output_column.as_ref().unwrap().into(),
-                    depth: r.depth as usize,
-                })
-                .collect::<Vec<_>>(),
-        }
+        let mut options = Self::new()
+            .with_preserve_nulls(opts.preserve_nulls)
+            .with_preserve_empty_lists(opts.preserve_empty_lists);
+        options.recursions = opts
+            .recursions
+            .iter()
+            .map(|r| RecursionUnnestOption {
+                input_column: r.input_column.as_ref().unwrap().into(),
+                output_column: r.output_column.as_ref().unwrap().into(),
+                depth: r.depth as usize,
+            })
+            .collect::<Vec<_>>();
+        options
     }
 }
 
@@ -80,6 +83,15 @@ impl From<protobuf::WindowFrameUnits> for WindowFrameUnits {
     }
 }
 
+impl From<protobuf::NullTreatment> for NullTreatment {
+    fn from(null_treatment: protobuf::NullTreatment) -> Self {
+        match null_treatment {
+            protobuf::NullTreatment::RespectNulls => Self::RespectNulls,
+            protobuf::NullTreatment::IgnoreNulls => Self::IgnoreNulls,
+        }
+    }
+}
+
 impl TryFrom<protobuf::TableReference> for TableReference {
     type Error = Error;
 
@@ -290,8 +302,9 @@ pub fn parse_expr(
                 .ok_or_else(|| {
                     exec_datafusion_err!("missing window frame during deserialization")
                 })?;
+            let null_treatment = parse_null_treatment(expr.null_treatment)?;
+            let filter = parse_optional_expr(expr.filter.as_deref(), registry, codec)?;
 
-            // TODO: support proto for null treatment
             match window_function {
                 window_expr_node::WindowFunction::Udaf(udaf_name) => {
                     let udaf_function = match &expr.fun_definition {
@@ -302,15 +315,18 @@ pub fn parse_expr(
                     };
 
                     let args = parse_exprs(&expr.exprs, registry, codec)?;
-                    Expr::WindowFunction(WindowFunction::new(
+                    let mut builder = Expr::WindowFunction(WindowFunction::new(
                         expr::WindowFunctionDefinition::AggregateUDF(udaf_function),
                         args,
                     ))
                     .partition_by(partition_by)
                     .order_by(order_by)
                     .window_frame(window_frame)
-                    .build()
-                    .map_err(Error::DataFusionError)
+                    .null_treatment(null_treatment);
+                    if let Some(filter) = filter {
+                        builder = builder.filter(filter);
+                    }
+                    builder.build().map_err(Error::DataFusionError)
                 }
                 window_expr_node::WindowFunction::Udwf(udwf_name) => {
                     let udwf_function = match &expr.fun_definition {
@@ -321,15 +337,18 @@ pub fn parse_expr(
                     };
 
                     let args = parse_exprs(&expr.exprs, registry, codec)?;
-                    Expr::WindowFunction(WindowFunction::new(
+                    let mut builder = Expr::WindowFunction(WindowFunction::new(
                         expr::WindowFunctionDefinition::WindowUDF(udwf_function),
                         args,
                     ))
                     .partition_by(partition_by)
                     .order_by(order_by)
                     .window_frame(window_frame)
-                    .build()
-                    .map_err(Error::DataFusionError)
+                    .null_treatment(null_treatment);
+                    if let Some(filter) = filter {
+                        builder = builder.filter(filter);
+                    }
+                    builder.build().map_err(Error::DataFusionError)
                 }
             }
         }
@@ -460,7 +479,7 @@ pub fn parse_expr(
                 codec,
             )?),
             parse_escape_char(&like.escape_char)?,
-            false,
+            like.case_insensitive,
         ))),
         ExprType::Case(case) => {
             let when_then_expr = case
@@ -541,13 +560,17 @@ pub fn parse_expr(
             fun_name,
             args,
             fun_definition,
+            propagate_nulls,
         }) => {
-            let scalar_fn = match fun_definition {
+            let mut scalar_fn = match fun_definition {
                 Some(buf) => codec.try_decode_udf(fun_name, buf)?,
                 None => registry
                     .udf(fun_name.as_str())
                     .or_else(|_| codec.try_decode_udf(fun_name, &[]))?,
             };
+            if *propagate_nulls {
+                scalar_fn = with_propagate_nulls(scalar_fn)?;
+            }
             Ok(Expr::ScalarFunction(expr::ScalarFunction::new_udf(
                 scalar_fn,
                 parse_exprs(args, registry, codec)?,
@@ -560,6 +583,7 @@ pub fn parse_expr(
                     .udaf(&pb.fun_name)
                     .or_else(|_| codec.try_decode_udaf(&pb.fun_name, &[]))?,
             };
+            let agg_fn = with_sum_overflow(agg_fn, pb.sum_overflow)?;
 
             Ok(Expr::AggregateFunction(expr::AggregateFunction::new_udf(
                 agg_fn,
@@ -570,7 +594,7 @@ pub fn parse_expr(
                     0 => None,
                     _ => Some(parse_sorts(&pb.order_by, registry, codec)?),
                 },
-                None,
+                parse_null_treatment(pb.null_treatment)?,
             )))
         }
 
@@ -650,6 +674,18 @@ fn parse_escape_char(s: &str) -> Result<Option<char>> {
     }
 }
 
+fn parse_null_treatment(
+    null_treatment: Option<i32>,
+) -> Result<Option<NullTreatment>, Error> {
+    null_treatment
+        .map(|n| {
+            protobuf::NullTreatment::try_from(n)
+                .map(Into::into)
+                .map_err(|_| Error::unknown("NullTreatment", n))
+        })
+        .transpose()
+}
+
 pub fn from_proto_binary_op(op: &str) -> Result<Operator, Error> {
     match op {
         "And" => Ok(Operator::And),
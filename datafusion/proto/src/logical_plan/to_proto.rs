@@ -27,6 +27,7 @@ use datafusion_expr::expr::{
     self, AggregateFunctionParams, Alias, Between, BinaryExpr, Cast, GroupingSet, InList,
     Like, Placeholder, ScalarFunction, Unnest,
 };
+use datafusion_expr::sqlparser::ast::NullTreatment;
 use datafusion_expr::WriteOp;
 use datafusion_expr::{
     logical_plan::PlanType, logical_plan::StringifiedPlan, Expr, JoinConstraint,
@@ -34,6 +35,7 @@ use datafusion_expr::{
     WindowFunctionDefinition,
 };
 
+use crate::common::{propagates_nulls, sum_overflow};
 use crate::protobuf::RecursionUnnestOption;
 use crate::protobuf::{
     self,
@@ -55,6 +57,7 @@ impl From<&UnnestOptions> for protobuf::UnnestOptions {
     fn from(opts: &UnnestOptions) -> Self {
         Self {
             preserve_nulls: opts.preserve_nulls,
+            preserve_empty_lists: opts.preserve_empty_lists(),
             recursions: opts
                 .recursions
                 .iter()
@@ -139,6 +142,15 @@ impl From<WindowFrameUnits> for protobuf::WindowFrameUnits {
     }
 }
 
+impl From<NullTreatment> for protobuf::NullTreatment {
+    fn from(null_treatment: NullTreatment) -> Self {
+        match null_treatment {
+            NullTreatment::RespectNulls => Self::RespectNulls,
+            NullTreatment::IgnoreNulls => Self::IgnoreNulls,
+        }
+    }
+}
+
 impl TryFrom<&WindowFrameBound> for protobuf::WindowFrameBound {
     type Error = Error;
 
@@ -290,13 +302,14 @@ pub fn serialize_expr(
             expr,
             pattern,
             escape_char,
-            case_insensitive: _,
+            case_insensitive,
         }) => {
             let pb = Box::new(protobuf::SimilarToNode {
                 negated: *negated,
                 expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
                 pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
                 escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
+                case_insensitive: *case_insensitive,
             });
             protobuf::LogicalExprNode {
                 expr_type: Some(ExprType::SimilarTo(pb)),
@@ -310,12 +323,17 @@ pub fn serialize_expr(
                     ref partition_by,
                     ref order_by,
                     ref window_frame,
-                    // TODO: support null treatment in proto
-                    null_treatment: _,
+                    ref filter,
+                    ref null_treatment,
                 },
         }) => {
             let (window_function, fun_definition) = match fun {
                 WindowFunctionDefinition::AggregateUDF(aggr_udf) => {
+                    if sum_overflow(aggr_udf) != protobuf::SumOverflowMode::Wrapping {
+                        return Err(Error::General(
+                            "Proto serialization error: sum with an overflow mode is not supported in window expressions".to_string(),
+                        ));
+                    }
                     let mut buf = Vec::new();
                     let _ = codec.try_encode_udaf(aggr_udf, &mut buf);
                     (
@@ -348,9 +366,15 @@ pub fn serialize_expr(
                 order_by,
                 window_frame,
                 fun_definition,
+                null_treatment: null_treatment
+                    .map(|n| protobuf::NullTreatment::from(n).into()),
+                filter: match filter {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
             };
             protobuf::LogicalExprNode {
-                expr_type: Some(ExprType::WindowExpr(window_expr)),
+                expr_type: Some(ExprType::WindowExpr(Box::new(window_expr))),
             }
         }
         Expr::AggregateFunction(expr::AggregateFunction {
@@ -361,7 +385,7 @@ pub fn serialize_expr(
                     ref distinct,
                     ref filter,
                     ref order_by,
-                    null_treatment: _,
+                    ref null_treatment,
                 },
         }) => {
             let mut buf = Vec::new();
@@ -381,6 +405,9 @@ pub fn serialize_expr(
                             None => vec![],
                         },
                         fun_definition: (!buf.is_empty()).then_some(buf),
+                        null_treatment: null_treatment
+                            .map(|n| protobuf::NullTreatment::from(n).into()),
+                        sum_overflow: sum_overflow(func).into(),
                     },
                 ))),
             }
@@ -399,6 +426,7 @@ pub fn serialize_expr(
                     fun_name: func.name().to_string(),
                     fun_definition: (!buf.is_empty()).then_some(buf),
                     args: serialize_exprs(args, codec)?,
+                    propagate_nulls: propagates_nulls(func),
                 })),
             }
         }
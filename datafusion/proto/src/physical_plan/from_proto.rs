@@ -48,6 +48,7 @@ use datafusion::physical_plan::{Partitioning, PhysicalExpr, WindowExpr};
 use datafusion_common::{not_impl_err, DataFusionError, Result};
 use datafusion_proto_common::common::proto_error;
 
+use crate::common::with_propagate_nulls;
 use crate::convert_required;
 use crate::logical_plan::{self};
 use crate::protobuf;
@@ -134,6 +135,11 @@ pub fn parse_physical_window_expr(
 
     let order_by =
         parse_physical_sort_exprs(&proto.order_by, registry, input_schema, codec)?;
+    let filter = proto
+        .filter
+        .as_deref()
+        .map(|filter| parse_physical_expr(filter, registry, input_schema, codec))
+        .transpose()?;
 
     let window_frame = proto
         .window_frame
@@ -179,6 +185,7 @@ pub fn parse_physical_window_expr(
         Arc::new(window_frame),
         &extended_schema,
         false,
+        filter,
     )
 }
 
@@ -353,12 +360,15 @@ pub fn parse_physical_expr(
             convert_required!(e.arrow_type)?,
         )),
         ExprType::ScalarUdf(e) => {
-            let udf = match &e.fun_definition {
+            let mut udf = match &e.fun_definition {
                 Some(buf) => codec.try_decode_udf(&e.name, buf)?,
                 None => registry
                     .udf(e.name.as_str())
                     .or_else(|_| codec.try_decode_udf(&e.name, &[]))?,
             };
+            if e.propagate_nulls {
+                udf = with_propagate_nulls(udf)?;
+            }
             let scalar_fun_def = Arc::clone(&udf);
 
             let args = parse_physical_exprs(&e.args, registry, input_schema, codec)?;
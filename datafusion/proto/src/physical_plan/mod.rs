@@ -20,7 +20,7 @@ use std::sync::Arc;
 
 use self::from_proto::parse_protobuf_partitioning;
 use self::to_proto::{serialize_partitioning, serialize_physical_expr};
-use crate::common::{byte_to_string, str_to_byte};
+use crate::common::{byte_to_string, str_to_byte, with_sum_overflow};
 use crate::physical_plan::from_proto::{
     parse_physical_expr, parse_physical_sort_expr, parse_physical_sort_exprs,
     parse_physical_window_expr, parse_protobuf_file_scan_config,
@@ -1057,6 +1057,10 @@ impl protobuf::PhysicalPlanNode {
                                             })?
                                         }
                                     };
+                                    let agg_udf = with_sum_overflow(
+                                        agg_udf,
+                                        agg_node.sum_overflow,
+                                    )?;
 
                                     AggregateExprBuilder::new(agg_udf, input_phy_expr)
                                         .schema(Arc::clone(&physical_schema))
@@ -41,6 +41,7 @@ use datafusion::{
 use datafusion_common::{internal_err, not_impl_err, DataFusionError, Result};
 use datafusion_expr::WindowFrame;
 
+use crate::common::{propagates_nulls, sum_overflow};
 use crate::protobuf::{
     self, physical_aggregate_expr_node, physical_window_expr_node, PhysicalSortExprNode,
     PhysicalSortExprNodeCollection,
@@ -71,6 +72,7 @@ pub fn serialize_physical_aggr_expr(
                 distinct: aggr_expr.is_distinct(),
                 ignore_nulls: aggr_expr.ignore_nulls(),
                 fun_definition: (!buf.is_empty()).then_some(buf),
+                sum_overflow: sum_overflow(aggr_expr.fun()).into(),
             },
         )),
     })
@@ -87,6 +89,11 @@ fn serialize_physical_window_aggr_expr(
             "Distinct aggregate functions not supported in window expressions"
         );
     }
+    if sum_overflow(aggr_expr.fun()) != protobuf::SumOverflowMode::Wrapping {
+        return not_impl_err!(
+            "Sum with an overflow mode not supported in window expressions"
+        );
+    }
 
     let mut buf = Vec::new();
     codec.try_encode_udaf(aggr_expr.fun(), &mut buf)?;
@@ -103,12 +110,16 @@ pub fn serialize_physical_window_expr(
     codec: &dyn PhysicalExtensionCodec,
 ) -> Result<protobuf::PhysicalWindowExprNode> {
     let expr = window_expr.as_any();
-    let args = window_expr.expressions().to_vec();
+    let mut args = window_expr.expressions().to_vec();
     let window_frame = window_expr.get_window_frame();
 
+    let mut filter = None;
     let (window_function, fun_definition) = if let Some(plain_aggr_window_expr) =
         expr.downcast_ref::<PlainAggregateWindowExpr>()
     {
+        // the filter is serialized separately from the arguments
+        args = plain_aggr_window_expr.get_aggregate_expr().expressions();
+        filter = plain_aggr_window_expr.get_filter();
         serialize_physical_window_aggr_expr(
             plain_aggr_window_expr.get_aggregate_expr(),
             window_frame,
@@ -117,6 +128,8 @@ pub fn serialize_physical_window_expr(
     } else if let Some(sliding_aggr_window_expr) =
         expr.downcast_ref::<SlidingAggregateWindowExpr>()
     {
+        args = sliding_aggr_window_expr.get_aggregate_expr().expressions();
+        filter = sliding_aggr_window_expr.get_filter();
         serialize_physical_window_aggr_expr(
             sliding_aggr_window_expr.get_aggregate_expr(),
             window_frame,
@@ -161,6 +174,9 @@ pub fn serialize_physical_window_expr(
         window_function: Some(window_function),
         name: window_expr.name().to_string(),
         fun_definition,
+        filter: filter
+            .map(|filter| serialize_physical_expr(filter, codec).map(Box::new))
+            .transpose()?,
     })
 }
 
@@ -353,6 +369,7 @@ pub fn serialize_physical_expr(
                     fun_definition: (!buf.is_empty()).then_some(buf),
                     return_type: Some(expr.return_type().try_into()?),
                     nullable: expr.nullable(),
+                    propagate_nulls: propagates_nulls(expr.fun()),
                 },
             )),
         })
@@ -80,11 +80,13 @@ use datafusion_expr::{
     WindowUDFImpl,
 };
 use datafusion_functions_aggregate::average::avg_udaf;
+use datafusion_functions_aggregate::expr_ext::SumOverflow;
 use datafusion_functions_aggregate::expr_fn::{
     approx_distinct, array_agg, avg, bit_and, bit_or, bit_xor, bool_and, bool_or, corr,
     nth_value,
 };
 use datafusion_functions_aggregate::string_agg::string_agg;
+use datafusion_functions_aggregate::sum::{sum_udaf, OverflowMode, Sum};
 use datafusion_functions_window_common::field::WindowUDFFieldArgs;
 use datafusion_functions_window_common::partition::PartitionEvaluatorArgs;
 use datafusion_proto::bytes::{
@@ -2100,21 +2102,22 @@ fn roundtrip_ilike() {
 
 #[test]
 fn roundtrip_similar_to() {
-    fn similar_to(negated: bool, escape_char: Option<char>) {
+    fn similar_to(negated: bool, escape_char: Option<char>, case_insensitive: bool) {
         let test_expr = Expr::SimilarTo(Like::new(
             negated,
             Box::new(col("col")),
             Box::new(lit("[0-9]+")),
             escape_char,
-            false,
+            case_insensitive,
         ));
         let ctx = SessionContext::new();
         roundtrip_expr_test(test_expr, ctx);
     }
-    similar_to(true, Some('X'));
-    similar_to(false, Some('\\'));
-    similar_to(true, None);
-    similar_to(false, None);
+    similar_to(true, Some('X'), false);
+    similar_to(false, Some('\\'), false);
+    similar_to(true, None, false);
+    similar_to(false, None, false);
+    similar_to(false, None, true);
 }
 
 #[test]
@@ -2286,6 +2289,45 @@ fn roundtrip_scalar_udf_extension_codec() {
     roundtrip_json_test(&proto);
 }
 
+#[test]
+fn roundtrip_greatest_least_propagating_nulls() {
+    let ctx = SessionContext::new();
+    roundtrip_expr_test(greatest(vec![col("a"), col("b")]), ctx.clone());
+    roundtrip_expr_test(
+        greatest_propagating_nulls(vec![col("a"), col("b")]),
+        ctx.clone(),
+    );
+    roundtrip_expr_test(least_propagating_nulls(vec![col("a"), col("b")]), ctx);
+}
+
+#[test]
+fn roundtrip_sum_overflow() {
+    let ctx = SessionContext::new();
+    for overflow in [
+        OverflowMode::Wrapping,
+        OverflowMode::Checked,
+        OverflowMode::Saturating,
+    ] {
+        roundtrip_expr_test(
+            sum_udaf().call(vec![col("a")]).with_overflow(overflow),
+            ctx.clone(),
+        );
+    }
+
+    // window expressions can not serialize the overflow mode
+    let sum =
+        AggregateUDF::new_from_impl(Sum::new().with_overflow(OverflowMode::Checked));
+    let test_expr = Expr::WindowFunction(expr::WindowFunction::new(
+        WindowFunctionDefinition::AggregateUDF(Arc::new(sum)),
+        vec![col("a")],
+    ));
+    let err = serialize_expr(&test_expr, &DefaultLogicalExtensionCodec {}).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "General error: Proto serialization error: sum with an overflow mode is not supported in window expressions"
+    );
+}
+
 #[test]
 fn roundtrip_aggregate_udf_extension_codec() {
     let udf = AggregateUDF::from(MyAggregateUDF::new("DataFusion".to_owned()));
@@ -2549,6 +2591,17 @@ fn roundtrip_window() {
         WindowFunctionDefinition::AggregateUDF(avg_udaf()),
         vec![col("col1")],
     ))
+    .window_frame(row_number_frame.clone())
+    .build()
+    .unwrap();
+
+    // 8. test with FILTER
+    let test_expr8 = Expr::WindowFunction(expr::WindowFunction::new(
+        WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+        vec![col("col1")],
+    ))
+    .filter(col("col2").gt(lit(1)))
+    .order_by(vec![col("col2").sort(true, true)])
     .window_frame(row_number_frame)
     .build()
     .unwrap();
@@ -2561,7 +2614,8 @@ fn roundtrip_window() {
     roundtrip_expr_test(test_expr4, ctx.clone());
     roundtrip_expr_test(test_expr5, ctx.clone());
     roundtrip_expr_test(test_expr6, ctx.clone());
-    roundtrip_expr_test(text_expr7, ctx);
+    roundtrip_expr_test(text_expr7, ctx.clone());
+    roundtrip_expr_test(test_expr8, ctx);
 }
 
 #[tokio::test]
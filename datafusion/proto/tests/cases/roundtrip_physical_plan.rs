@@ -53,7 +53,9 @@ use datafusion::datasource::physical_plan::{
 use datafusion::datasource::sink::DataSinkExec;
 use datafusion::datasource::source::DataSourceExec;
 use datafusion::execution::FunctionRegistry;
-use datafusion::functions_aggregate::sum::sum_udaf;
+use datafusion::functions::core::greatest::GreatestFunc;
+use datafusion::functions::core::least::LeastFunc;
+use datafusion::functions_aggregate::sum::{sum_udaf, OverflowMode, Sum};
 use datafusion::functions_window::nth_value::nth_value_udwf;
 use datafusion::functions_window::row_number::row_number_udwf;
 use datafusion::logical_expr::{create_udf, JoinType, Operator, Volatility};
@@ -397,12 +399,20 @@ fn roundtrip_window() -> Result<()> {
         .build()
         .map(Arc::new)?;
 
-    let sliding_aggr_window_expr = Arc::new(SlidingAggregateWindowExpr::new(
-        sum_expr,
-        &[],
-        &LexOrdering::default(),
-        Arc::new(window_frame),
-    ));
+    let sliding_aggr_window_expr = Arc::new(
+        SlidingAggregateWindowExpr::new(
+            sum_expr,
+            &[],
+            &LexOrdering::default(),
+            Arc::new(window_frame),
+        )
+        .with_filter(Some(binary(
+            col("a", &schema)?,
+            Operator::Gt,
+            lit(1i64),
+            &schema,
+        )?)),
+    );
 
     let input = Arc::new(EmptyExec::new(schema.clone()));
 
@@ -461,6 +471,42 @@ fn rountrip_aggregate() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn roundtrip_sum_overflow() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, false);
+    let field_b = Field::new("b", DataType::Int64, false);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+
+    let groups: Vec<(Arc<dyn PhysicalExpr>, String)> =
+        vec![(col("a", &schema)?, "unused".to_string())];
+
+    let aggregates = [
+        OverflowMode::Wrapping,
+        OverflowMode::Checked,
+        OverflowMode::Saturating,
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, overflow)| {
+        let sum = AggregateUDF::new_from_impl(Sum::new().with_overflow(overflow));
+        AggregateExprBuilder::new(Arc::new(sum), vec![col("b", &schema)?])
+            .schema(Arc::clone(&schema))
+            .alias(format!("sum{i}"))
+            .build()
+            .map(Arc::new)
+    })
+    .collect::<Result<Vec<_>>>()?;
+
+    roundtrip_test(Arc::new(AggregateExec::try_new(
+        AggregateMode::Final,
+        PhysicalGroupBy::new_single(groups),
+        aggregates,
+        vec![None; 3],
+        Arc::new(EmptyExec::new(schema.clone())),
+        schema,
+    )?))
+}
+
 #[test]
 fn rountrip_aggregate_with_limit() -> Result<()> {
     let field_a = Field::new("a", DataType::Int64, false);
@@ -994,6 +1040,37 @@ fn roundtrip_scalar_udf() -> Result<()> {
     roundtrip_test_with_context(Arc::new(project), &ctx)
 }
 
+#[test]
+fn roundtrip_greatest_least_propagating_nulls() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, true);
+    let field_b = Field::new("b", DataType::Int64, true);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+
+    let input = Arc::new(EmptyExec::new(schema.clone()));
+
+    let udfs = [
+        ScalarUDF::new_from_impl(GreatestFunc::new()),
+        ScalarUDF::new_from_impl(GreatestFunc::new().with_propagate_nulls(true)),
+        ScalarUDF::new_from_impl(LeastFunc::new().with_propagate_nulls(true)),
+    ];
+    let exprs = udfs
+        .into_iter()
+        .enumerate()
+        .map(|(i, udf)| {
+            let expr = ScalarFunctionExpr::new(
+                udf.name(),
+                Arc::new(udf.clone()),
+                vec![col("a", &schema)?, col("b", &schema)?],
+                Field::new("f", DataType::Int64, true),
+            );
+            Ok((Arc::new(expr) as _, format!("expr{i}")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let project = ProjectionExec::try_new(exprs, input)?;
+    roundtrip_test(Arc::new(project))
+}
+
 #[derive(Debug)]
 struct UDFExtensionCodec;
 
@@ -1556,7 +1633,7 @@ fn roundtrip_unnest() -> Result<()> {
     let output_schema =
         Arc::new(Schema::new(vec![fa, fb0, fc1, fc2, fd0, fe1, fe2, fe3]));
     let input = Arc::new(EmptyExec::new(input_schema));
-    let options = UnnestOptions::default();
+    let options = UnnestOptions::default().with_preserve_empty_lists(true);
     let unnest = UnnestExec::new(
         input,
         vec![
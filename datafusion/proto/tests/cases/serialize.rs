@@ -21,10 +21,16 @@ use arrow::array::ArrayRef;
 use arrow::datatypes::DataType;
 
 use datafusion::execution::FunctionRegistry;
+use datafusion::functions_nested::expr_ext::IndexAccessor;
 use datafusion::prelude::SessionContext;
-use datafusion_expr::{col, create_udf, lit, ColumnarValue};
-use datafusion_expr::{Expr, Volatility};
+use datafusion_expr::expr::WindowFunction;
+use datafusion_expr::sqlparser::ast::NullTreatment;
+use datafusion_expr::{col, create_udf, lit, ColumnarValue, ExprFunctionExt, Like};
+use datafusion_expr::{Expr, Volatility, WindowFunctionDefinition};
+use datafusion_functions::core::expr_ext::FieldAccessor;
 use datafusion_functions::string;
+use datafusion_functions_aggregate::count::count_udaf;
+use datafusion_functions_aggregate::first_last::first_value_udaf;
 use datafusion_proto::bytes::Serializeable;
 use datafusion_proto::logical_plan::to_proto::serialize_expr;
 use datafusion_proto::logical_plan::DefaultLogicalExtensionCodec;
@@ -136,6 +142,54 @@ fn roundtrip_qualified_alias() {
     assert_eq!(qual_alias, roundtrip_expr(&qual_alias));
 }
 
+#[test]
+fn roundtrip_expr_bytes() {
+    let ctx = SessionContext::new();
+    let first_value = first_value_udaf()
+        .call(vec![col("a")])
+        .order_by(vec![col("b").sort(true, false)])
+        .filter(col("b").gt(lit(1)))
+        .null_treatment(NullTreatment::IgnoreNulls)
+        .build()
+        .unwrap();
+    let window_first_value = Expr::WindowFunction(WindowFunction::new(
+        WindowFunctionDefinition::AggregateUDF(first_value_udaf()),
+        vec![col("a")],
+    ))
+    .partition_by(vec![col("b")])
+    .null_treatment(NullTreatment::RespectNulls)
+    .build()
+    .unwrap();
+    let exprs = vec![
+        col("props").field("a").field("b"),
+        col("list").index(lit(1)).alias("first"),
+        first_value,
+        count_udaf()
+            .call(vec![col("a")])
+            .distinct()
+            .build()
+            .unwrap(),
+        window_first_value,
+        Expr::SimilarTo(Like::new(
+            false,
+            Box::new(col("a")),
+            Box::new(lit("x%")),
+            None,
+            true,
+        )),
+    ];
+    for expr in exprs {
+        let bytes = expr.to_bytes().unwrap();
+        assert_eq!(expr, Expr::from_bytes_with_registry(&bytes, &ctx).unwrap());
+    }
+
+    // expressions that can not be serialized are an error
+    let err = Expr::ScalarVariable(DataType::Int64, vec!["@a".to_string()])
+        .to_bytes()
+        .unwrap_err();
+    assert!(err.to_string().contains("Scalar Variable not supported"));
+}
+
 #[test]
 fn roundtrip_deeply_nested_binary_expr() {
     // We need more stack space so this doesn't overflow in dev builds
@@ -336,12 +336,17 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
 
             if let Ok(fun) = self.find_window_func(&name) {
                 let args = self.function_args_to_expr(args, schema, planner_context)?;
+                let filter: Option<Box<Expr>> = filter
+                    .map(|e| self.sql_expr_to_logical_expr(*e, schema, planner_context))
+                    .transpose()?
+                    .map(Box::new);
                 let mut window_expr = RawWindowExpr {
                     func_def: fun,
                     args,
                     partition_by,
                     order_by,
                     window_frame,
+                    filter,
                     null_treatment,
                 };
 
@@ -358,15 +363,20 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     partition_by,
                     order_by,
                     window_frame,
+                    filter,
                     null_treatment,
                 } = window_expr;
 
-                return Expr::WindowFunction(expr::WindowFunction::new(func_def, args))
-                    .partition_by(partition_by)
-                    .order_by(order_by)
-                    .window_frame(window_frame)
-                    .null_treatment(null_treatment)
-                    .build();
+                let mut builder =
+                    Expr::WindowFunction(expr::WindowFunction::new(func_def, args))
+                        .partition_by(partition_by)
+                        .order_by(order_by)
+                        .window_frame(window_frame)
+                        .null_treatment(null_treatment);
+                if let Some(filter) = filter {
+                    builder = builder.filter(*filter);
+                }
+                return builder.build();
             }
         } else {
             // User defined aggregate functions (UDAF) have precedence in case it has the same name as a scalar built-in function
@@ -381,6 +391,14 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                         fm.name()
                     );
                 }
+                if let Some(null_treatment) = null_treatment {
+                    if !fm.supports_null_treatment(null_treatment) {
+                        return plan_err!(
+                            "{null_treatment} is not permitted for {}",
+                            fm.name()
+                        );
+                    }
+                }
 
                 let mut args =
                     self.function_args_to_expr(args, schema, planner_context)?;
@@ -173,6 +173,21 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         Ok(expr)
     }
 
+    /// Generate a relational expression from a SQL expression without
+    /// resolving it against a schema.
+    ///
+    /// Every identifier is planned as an [`Expr::Column`] without checking
+    /// that such a column exists, and no types are inferred, so the result
+    /// can be resolved later once a schema is available (for example to
+    /// collect the columns a SQL snippet references).
+    pub fn sql_to_unresolved_expr_with_alias(
+        &self,
+        sql: SQLExprWithAlias,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Expr> {
+        self.sql_expr_to_logical_expr_with_alias(sql, &DFSchema::empty(), planner_context)
+    }
+
     /// Rewrite aliases which are not-complete (e.g. ones that only include only table qualifier in a schema.table qualified relation)
     fn rewrite_partial_qualifier(&self, expr: Expr, schema: &DFSchema) -> Expr {
         match expr {
@@ -197,12 +197,17 @@ impl Unparser<'_> {
                         partition_by,
                         order_by,
                         window_frame,
+                        filter,
                         ..
                     },
             }) => {
                 let func_name = fun.name();
 
                 let args = self.function_args_to_sql(args)?;
+                let filter = match filter {
+                    Some(filter) => Some(Box::new(self.expr_to_sql_inner(filter)?)),
+                    None => None,
+                };
 
                 let units = match window_frame.units {
                     datafusion_expr::window_frame::WindowFrameUnits::Rows => {
@@ -259,7 +264,7 @@ impl Unparser<'_> {
                         args,
                         clauses: vec![],
                     }),
-                    filter: None,
+                    filter,
                     null_treatment: None,
                     over,
                     within_group: vec![],
@@ -1736,6 +1741,7 @@ mod tests {
     use datafusion_functions::expr_fn::{get_field, named_struct};
     use datafusion_functions_aggregate::count::count_udaf;
     use datafusion_functions_aggregate::expr_fn::sum;
+    use datafusion_functions_aggregate::sum::sum_udaf;
     use datafusion_functions_nested::expr_fn::{array_element, make_array};
     use datafusion_functions_nested::map::map;
     use datafusion_functions_window::rank::rank_udwf;
@@ -2026,6 +2032,7 @@ mod tests {
                         partition_by: vec![],
                         order_by: vec![],
                         window_frame: WindowFrame::new(None),
+                        filter: None,
                         null_treatment: None,
                     },
                 }),
@@ -2051,11 +2058,23 @@ mod tests {
                                 ScalarValue::UInt32(Some(2)),
                             ),
                         ),
+                        filter: None,
                         null_treatment: None,
                     },
                 }),
                 r#"count(*) OVER (ORDER BY a DESC NULLS FIRST RANGE BETWEEN 6 PRECEDING AND 2 FOLLOWING)"#,
             ),
+            (
+                Expr::WindowFunction(WindowFunction::new(
+                    WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+                    vec![col("a")],
+                ))
+                .filter(col("b").gt(lit(1)))
+                    .order_by(vec![col("c").sort(true, true)])
+                    .build()
+                    .unwrap(),
+                "sum(a) FILTER (WHERE (b > 1)) OVER (ORDER BY c ASC NULLS FIRST ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+            ),
             (col("a").is_not_null(), r#"a IS NOT NULL"#),
             (col("a").is_null(), r#"a IS NULL"#),
             (
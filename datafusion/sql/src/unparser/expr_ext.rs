@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extension methods for rendering [`Expr`]s as SQL text.
+
+use datafusion_common::Result;
+use datafusion_expr::Expr;
+use sqlparser::dialect::Dialect as ParserDialect;
+use sqlparser::keywords::ALL_KEYWORDS;
+
+use super::dialect::Dialect;
+use super::Unparser;
+
+/// Adds methods to [`Expr`] that convert it back to SQL text with the
+/// [`Unparser`].
+///
+/// # Example
+/// ```
+/// # use datafusion_expr::{col, ident, lit};
+/// # use datafusion_sql::sqlparser::dialect::{GenericDialect, MySqlDialect};
+/// # use datafusion_sql::unparser::ExprToSqlExt;
+/// let expr = ((col("a") + col("b")) * ident("Total Price")).gt(lit(4));
+/// assert_eq!(
+///     expr.to_sql_string(&GenericDialect {}).unwrap(),
+///     r#"(a + b) * "Total Price" > 4"#
+/// );
+/// assert_eq!(
+///     expr.to_sql_string(&MySqlDialect {}).unwrap(),
+///     "(a + b) * `Total Price` > 4"
+/// );
+/// ```
+pub trait ExprToSqlExt {
+    /// Renders the expression as SQL text for `dialect`.
+    ///
+    /// Parentheses are only added where operator precedence requires them,
+    /// and identifiers are quoted with the quote character of `dialect` when
+    /// they would otherwise not parse back to the same name: when they are
+    /// keywords, contain characters other than letters, digits and
+    /// underscores, or contain upper case letters, which are normalized to
+    /// lower case when parsed without quotes.
+    /// Parsing the result, for example with [`SessionContext::parse_sql_expr`],
+    /// gives back an equivalent expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression can not be represented as SQL, for
+    /// example a wildcard or a scalar value of an unsupported type.
+    ///
+    /// [`SessionContext::parse_sql_expr`]: https://docs.rs/datafusion/latest/datafusion/execution/context/struct.SessionContext.html#method.parse_sql_expr
+    fn to_sql_string(&self, dialect: &dyn ParserDialect) -> Result<String>;
+}
+
+impl ExprToSqlExt for Expr {
+    fn to_sql_string(&self, dialect: &dyn ParserDialect) -> Result<String> {
+        let dialect = QuotingDialect {
+            quote_style: dialect.identifier_quote_style("").unwrap_or('"'),
+        };
+        let sql = Unparser::new(&dialect)
+            .with_pretty(true)
+            .expr_to_sql(self)?;
+        Ok(sql.to_string())
+    }
+}
+
+/// Unparser [`Dialect`] that quotes identifiers with the quote character of
+/// a sqlparser dialect, and otherwise uses the defaults, which DataFusion
+/// parses back to the same types and functions
+struct QuotingDialect {
+    quote_style: char,
+}
+
+impl Dialect for QuotingDialect {
+    fn identifier_quote_style(&self, identifier: &str) -> Option<char> {
+        // Lower case identifiers that only contain letters, digits and
+        // underscores are valid without quotes in all dialects
+        let mut chars = identifier.chars();
+        let is_plain = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        // Like the `DefaultDialect`, don't quote `id` even though it is a
+        // keyword in some dialects
+        let id_upper = identifier.to_uppercase();
+        let is_keyword = id_upper != "ID" && ALL_KEYWORDS.contains(&id_upper.as_str());
+        (!is_plain || is_keyword).then_some(self.quote_style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use datafusion_expr::{cast, col, ident, lit, not};
+    use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect};
+
+    #[test]
+    fn test_to_sql_string_precedence() -> Result<()> {
+        let tests = [
+            (col("a") + col("b") * col("c"), "a + b * c"),
+            ((col("a") + col("b")) * col("c"), "(a + b) * c"),
+            (col("a") - (col("b") - col("c")), "a - (b - c)"),
+            ((col("a") - col("b")) - col("c"), "a - b - c"),
+            (col("a") / (col("b") * col("c")), "a / (b * c)"),
+            (
+                col("a").eq(lit(1)).or(col("b").eq(lit(2))).and(col("c")),
+                "(a = 1 OR b = 2) AND c",
+            ),
+            (not(col("a").and(col("b"))), "NOT (a AND b)"),
+            ((col("a") + lit(1)).is_null(), "a + 1 IS NULL"),
+            (
+                cast(col("a") + lit(1), arrow::datatypes::DataType::Int64),
+                "CAST((a + 1) AS BIGINT)",
+            ),
+        ];
+        for (expr, expected) in tests {
+            assert_eq!(expr.to_sql_string(&GenericDialect {})?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_sql_string_quoting() -> Result<()> {
+        let expr = ident("select")
+            .eq(ident("Name"))
+            .and(ident("my col").eq(ident("a_1")))
+            .and(ident("id").eq(lit("it's")));
+
+        assert_eq!(
+            expr.to_sql_string(&GenericDialect {})?,
+            r#""select" = "Name" AND "my col" = a_1 AND id = 'it''s'"#
+        );
+        assert_eq!(
+            expr.to_sql_string(&MySqlDialect {})?,
+            "`select` = `Name` AND `my col` = a_1 AND id = 'it''s'"
+        );
+        assert_eq!(
+            expr.to_sql_string(&PostgreSqlDialect {})?,
+            r#""select" = "Name" AND "my col" = a_1 AND id = 'it''s'"#
+        );
+        Ok(())
+    }
+}
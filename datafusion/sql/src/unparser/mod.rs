@@ -19,6 +19,7 @@
 
 pub mod ast;
 mod expr;
+mod expr_ext;
 mod plan;
 mod rewrite;
 mod utils;
@@ -26,6 +27,7 @@ mod utils;
 use self::dialect::{DefaultDialect, Dialect};
 use crate::unparser::extension_unparser::UserDefinedLogicalNodeUnparser;
 pub use expr::expr_to_sql;
+pub use expr_ext::ExprToSqlExt;
 pub use plan::plan_to_sql;
 use std::sync::Arc;
 pub mod dialect;
@@ -111,6 +111,7 @@ pub async fn from_window_function(
             .await?,
             order_by,
             window_frame,
+            filter: None,
             null_treatment: None,
         },
     }))
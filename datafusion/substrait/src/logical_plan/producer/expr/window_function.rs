@@ -41,9 +41,13 @@ pub fn from_window_function(
                 partition_by,
                 order_by,
                 window_frame,
+                filter,
                 null_treatment: _,
             },
     } = window_fn;
+    if filter.is_some() {
+        return not_impl_err!("Window function with FILTER is not supported");
+    }
     // function reference
     let function_anchor = producer.register_function(fun.to_string());
     // arguments
@@ -1225,6 +1225,31 @@ async fn new_test_grammar() -> Result<()> {
     .await
 }
 
+/// Like [`new_test_grammar`], but casts each column to a different type, so
+/// the casts are not removed by the optimizer.
+#[tokio::test]
+async fn new_test_grammar_changing_types() -> Result<()> {
+    roundtrip_all_types(
+        "select
+            int8_col::boolean,
+            int16_col::tinyint,
+            uint16_col::tinyint unsigned,
+            int32_col::smallint,
+            uint32_col::smallint unsigned,
+            int64_col::integer,
+            uint64_col::integer unsigned,
+            uint8_col::bigint,
+            bool_col::bigint unsigned,
+            float64_col::float,
+            float32_col::double,
+            decimal_256_col::decimal(10, 2),
+            date64_col::date,
+            large_binary_col::bytea
+            from data",
+    )
+    .await
+}
+
 #[tokio::test]
 async fn extension_logical_plan() -> Result<()> {
     let ctx = create_context().await?;